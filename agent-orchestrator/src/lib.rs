@@ -3,16 +3,19 @@
 //! Ce module gère la coordination, communication et collaboration entre
 //! plusieurs agents consciousness-level pour des tâches complexes.
 
-use consciousness_engine::{ConsciousnessEngine, ConsciousnessError};
+use consciousness_engine::{ConsciousnessEngine, ConsciousnessError, ConsciousnessResponse};
 use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{broadcast, RwLock};
 
 /// Orchestrateur pour coordination d'agents multiples
 pub struct AgentOrchestrator {
     /// Agents conscients enregistrés
     agents: HashMap<String, Arc<RwLock<ConsciousAgent>>>,
-    
+
+    /// Messages en attente par agent, vidés au désenregistrement
+    message_queues: HashMap<String, Vec<AgentMessage>>,
+
     /// Coordinateur de tâches
     task_coordinator: TaskCoordinator,
     
@@ -24,6 +27,9 @@ pub struct AgentOrchestrator {
     
     /// Moniteur de performance collective
     collective_performance: CollectivePerformanceMonitor,
+
+    /// Tableau noir partagé pour les artefacts intermédiaires
+    blackboard: Arc<Blackboard>,
 }
 
 /// Agent conscient avec spécialisation
@@ -36,9 +42,28 @@ pub struct ConsciousAgent {
     
     /// Capacités uniques
     capabilities: Vec<AgentCapability>,
-    
+
     /// Historique de collaboration
     collaboration_history: CollaborationHistory,
+
+    /// État émotionnel simplifié visible par l'équipe
+    emotional_state: AgentEmotionalState,
+}
+
+/// État émotionnel simplifié d'un agent au sein de l'équipe
+#[derive(Debug, Clone, Copy)]
+pub struct AgentEmotionalState {
+    /// Niveau d'activation (0.0 à 1.0)
+    pub arousal: f64,
+
+    /// Valence émotionnelle (-1.0 à 1.0)
+    pub valence: f64,
+}
+
+impl Default for AgentEmotionalState {
+    fn default() -> Self {
+        Self { arousal: 0.3, valence: 0.0 }
+    }
 }
 
 /// Types de spécialisations d'agents
@@ -71,28 +96,165 @@ impl AgentOrchestrator {
     pub async fn new() -> Result<Self, ConsciousnessError> {
         Ok(Self {
             agents: HashMap::new(),
+            message_queues: HashMap::new(),
             task_coordinator: TaskCoordinator::new().await?,
             communication_system: InterAgentCommunication::new().await?,
             consensus_manager: ConsensusManager::new().await?,
             collective_performance: CollectivePerformanceMonitor::new().await?,
+            blackboard: Arc::new(Blackboard::new()),
         })
     }
+
+    /// Accéder au tableau noir partagé
+    pub fn blackboard(&self) -> Arc<Blackboard> {
+        Arc::clone(&self.blackboard)
+    }
     
     /// Enregistrer un agent conscient
-    pub async fn register_agent(&mut self, agent_id: String, specialization: AgentSpecialization) -> Result<(), ConsciousnessError> {
+    ///
+    /// Un identifiant déjà pris est une erreur sauf si `overwrite` est
+    /// passé : l'écrasement silencieux masquait les doublons et fuyait
+    /// l'état (et la file de messages) de l'ancien agent.
+    pub async fn register_agent(&mut self, agent_id: String, specialization: AgentSpecialization, overwrite: bool) -> Result<(), ConsciousnessError> {
+        if !overwrite && self.agents.contains_key(&agent_id) {
+            return Err(ConsciousnessError::InvalidInput(
+                format!("Agent '{}' déjà enregistré", agent_id)
+            ));
+        }
+
         let consciousness_engine = ConsciousnessEngine::new().await?;
-        
+
         let agent = ConsciousAgent {
             consciousness_engine,
             specialization,
             capabilities: Vec::new(),
             collaboration_history: CollaborationHistory::new(),
+            emotional_state: AgentEmotionalState::default(),
         };
-        
+
+        // L'écrasement repart d'une file vide : les messages adressés à
+        // l'ancien agent ne concernent pas son remplaçant
+        self.message_queues.insert(agent_id.clone(), Vec::new());
         self.agents.insert(agent_id, Arc::new(RwLock::new(agent)));
         Ok(())
     }
-    
+
+    /// Désenregistrer un agent et vider sa file de messages
+    ///
+    /// Retourne les messages non délivrés pour que l'appelant puisse les
+    /// rerouter ; un agent inconnu est une erreur.
+    pub async fn deregister_agent(&mut self, agent_id: &str) -> Result<Vec<AgentMessage>, ConsciousnessError> {
+        if self.agents.remove(agent_id).is_none() {
+            return Err(ConsciousnessError::InvalidInput(
+                format!("Agent '{}' inconnu", agent_id)
+            ));
+        }
+        Ok(self.message_queues.remove(agent_id).unwrap_or_default())
+    }
+
+    /// Mettre un message en attente pour un agent enregistré
+    pub fn queue_message(&mut self, receiver: &str, message: AgentMessage) -> Result<(), ConsciousnessError> {
+        if !self.agents.contains_key(receiver) {
+            return Err(ConsciousnessError::InvalidInput(
+                format!("Agent '{}' inconnu", receiver)
+            ));
+        }
+        self.message_queues.entry(receiver.to_string()).or_default().push(message);
+        Ok(())
+    }
+
+    /// Router une requête vers les agents les plus pertinents
+    ///
+    /// Classe la requête contre la spécialisation et les capacités de
+    /// chaque agent enregistré et retourne tous les candidats triés par
+    /// score décroissant (0.0 à 1.0), à égalité par identifiant. Les
+    /// agents sans aucun recouvrement gardent un score nul mais restent
+    /// listés : l'appelant voit l'équipe entière et décide du seuil.
+    pub async fn route(&self, query: &str) -> Vec<(String, f64)> {
+        let terms = Self::query_terms(query);
+
+        let mut candidates = Vec::with_capacity(self.agents.len());
+        for (agent_id, agent) in &self.agents {
+            let agent = agent.read().await;
+            let mut score = Self::specialization_affinity(&terms, &agent.specialization);
+
+            // Une expertise de domaine déclarée et citée dans la requête
+            // renforce le candidat
+            for capability in &agent.capabilities {
+                if let AgentCapability::DomainExpertise(domain) = capability {
+                    if terms.contains(&domain.to_lowercase()) {
+                        score += DOMAIN_EXPERTISE_BONUS;
+                    }
+                }
+            }
+
+            candidates.push((agent_id.clone(), score.min(1.0)));
+        }
+
+        candidates.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.0.cmp(&b.0))
+        });
+        candidates
+    }
+
+    /// Mots de la requête, en minuscules et sans ponctuation
+    fn query_terms(query: &str) -> std::collections::HashSet<String> {
+        query
+            .to_lowercase()
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .map(|w| w.to_string())
+            .collect()
+    }
+
+    /// Affinité d'une requête avec une spécialisation (0.0 à 1.0)
+    ///
+    /// Compte les marqueurs lexicaux du domaine présents dans la requête ;
+    /// le score sature à partir de [`AFFINITY_SATURATION`] marqueurs pour
+    /// qu'une requête très verbeuse ne domine pas par simple longueur.
+    fn specialization_affinity(
+        terms: &std::collections::HashSet<String>,
+        specialization: &AgentSpecialization,
+    ) -> f64 {
+        let matches = Self::specialization_markers(specialization)
+            .iter()
+            .filter(|marker| terms.contains(**marker))
+            .count();
+        (matches as f64 / AFFINITY_SATURATION).min(1.0)
+    }
+
+    /// Marqueurs lexicaux par domaine de spécialisation
+    ///
+    /// Français et anglais mélangés, comme les requêtes qui traversent la
+    /// plateforme.
+    fn specialization_markers(specialization: &AgentSpecialization) -> &'static [&'static str] {
+        match specialization {
+            AgentSpecialization::Medical { .. } => &[
+                "symptôme", "symptômes", "symptom", "symptoms", "douleur", "pain",
+                "fièvre", "fever", "patient", "diagnostic", "traitement", "treatment",
+                "médecin", "doctor", "malade", "poitrine", "chest", "fatigue",
+            ],
+            AgentSpecialization::Educational { .. } => &[
+                "apprendre", "learn", "cours", "course", "enseigner", "teach",
+                "exercice", "exercise", "étudiant", "student", "leçon", "lesson",
+            ],
+            AgentSpecialization::Creative { .. } => &[
+                "poème", "poem", "histoire", "story", "dessin", "drawing",
+                "musique", "music", "créatif", "creative", "art",
+            ],
+            AgentSpecialization::Research { .. } => &[
+                "étude", "study", "recherche", "research", "hypothèse", "hypothesis",
+                "données", "data", "expérience", "experiment", "publication",
+            ],
+            AgentSpecialization::Business { .. } => &[
+                "marché", "market", "stratégie", "strategy", "budget", "finance",
+                "client", "customer", "vente", "sales",
+            ],
+        }
+    }
+
     /// Coordonner une tâche complexe multi-agents
     pub async fn coordinate_complex_task(&mut self, task: ComplexTask) -> Result<TaskResult, ConsciousnessError> {
         // 1. Analyser la tâche et identifier les agents nécessaires
@@ -118,10 +280,424 @@ impl AgentOrchestrator {
         self.communication_system.relay_message(sender, receiver, message).await
     }
     
+    /// Régler le facteur de contagion émotionnelle de l'équipe
+    pub fn set_contagion_factor(&mut self, factor: f64) {
+        self.communication_system.set_contagion_factor(factor);
+    }
+
+    /// Diffuser un message à toute l'équipe avec atténuation émotionnelle
+    pub async fn broadcast_message(&self, sender: &str, message: AgentMessage) -> Result<Vec<AgentResponse>, ConsciousnessError> {
+        self.communication_system.broadcast_with_contagion(sender, message, &self.agents).await
+    }
+
+    /// État émotionnel simplifié d'un agent enregistré
+    pub async fn get_agent_emotional_state(&self, agent_id: &str) -> Option<AgentEmotionalState> {
+        match self.agents.get(agent_id) {
+            Some(agent) => Some(agent.read().await.emotional_state),
+            None => None,
+        }
+    }
+
     /// Gérer le consensus entre agents
     pub async fn manage_consensus(&mut self, decision_point: DecisionPoint) -> Result<ConsensusResult, ConsciousnessError> {
         self.consensus_manager.reach_consensus(decision_point, &self.agents).await
     }
+
+    /// Exécuter les sous-tâches de manière collaborative via le tableau noir
+    ///
+    /// Chaque sous-tâche s'exécute en parallèle. Une sous-tâche déclarant une
+    /// dépendance attend que l'insight correspondant soit publié sur le
+    /// tableau noir par un autre agent, puis publie son propre résultat —
+    /// le résultat partiel d'un agent débloque ainsi les autres.
+    pub async fn execute_collaborative_task(&self, subtasks: Vec<AgentSubtask>, agent_team: Vec<String>) -> Result<CollaborativeExecution, ConsciousnessError> {
+        for subtask in &subtasks {
+            if !agent_team.contains(&subtask.assigned_agent) {
+                return Err(ConsciousnessError::InvalidInput(
+                    format!("Agent '{}' assigné à la sous-tâche '{}' hors de l'équipe", subtask.assigned_agent, subtask.id)
+                ));
+            }
+        }
+
+        let mut handles = Vec::with_capacity(subtasks.len());
+        for subtask in subtasks {
+            let blackboard = Arc::clone(&self.blackboard);
+            handles.push(tokio::spawn(async move {
+                // Attendre l'artefact amont avant de produire le sien
+                let upstream = match &subtask.depends_on {
+                    Some(key) => Some(blackboard.wait_for(key).await?),
+                    None => None,
+                };
+
+                let content = match upstream {
+                    Some(insight) => format!("{} (basé sur '{}' de {})", subtask.description, insight.content, insight.author_agent),
+                    None => subtask.description.clone(),
+                };
+
+                let insight = CollectiveInsight {
+                    topic: subtask.id.clone(),
+                    author_agent: subtask.assigned_agent.clone(),
+                    content,
+                    confidence: 0.8,
+                };
+                blackboard.post(subtask.id.clone(), insight.clone()).await;
+
+                Ok::<CollectiveInsight, ConsciousnessError>(insight)
+            }));
+        }
+
+        let mut insights = Vec::with_capacity(handles.len());
+        for handle in handles {
+            let insight = handle.await
+                .map_err(|e| ConsciousnessError::SystemError(format!("Sous-tâche interrompue: {}", e)))??;
+            insights.push(insight);
+        }
+
+        Ok(CollaborativeExecution {
+            subtask_results: insights.iter().map(|_| SubtaskResult).collect(),
+            insights,
+        })
+    }
+
+    /// Comparer les réponses de plusieurs agents à une même requête
+    ///
+    /// Mesure l'accord lexical entre toutes les paires de réponses, signale
+    /// les contradictions (paires sous le seuil de similarité), et produit
+    /// une réponse consensuelle attribuée agent par agent : la réponse la
+    /// plus centrale sert de base et chaque avis divergent y est annexé
+    /// explicitement plutôt que passé sous silence.
+    pub fn compare_outputs(outputs: Vec<(String, ConsciousnessResponse)>) -> ComparisonReport {
+        let weighted = outputs
+            .into_iter()
+            .map(|(agent_id, response)| WeightedOutput {
+                agent_id,
+                content: response.content,
+                confidence: response.confidence_level,
+            })
+            .collect();
+        Self::compare_weighted_outputs(weighted)
+    }
+
+    /// Synthétiser les résultats d'une exécution collaborative
+    ///
+    /// Utilise [`compare_outputs`](Self::compare_outputs) (via le même
+    /// noyau de comparaison) sur les insights publiés : le rapport de
+    /// comparaison devient la synthèse attribuée du résultat final.
+    pub async fn synthesize_results(&self, execution: CollaborativeExecution) -> Result<TaskResult, ConsciousnessError> {
+        let weighted = execution.insights
+            .iter()
+            .map(|insight| WeightedOutput {
+                agent_id: insight.author_agent.clone(),
+                content: insight.content.clone(),
+                confidence: insight.confidence,
+            })
+            .collect();
+        let comparison = Self::compare_weighted_outputs(weighted);
+
+        let task_id = execution.insights
+            .iter()
+            .map(|insight| insight.topic.as_str())
+            .collect::<Vec<_>>()
+            .join("+");
+
+        Ok(TaskResult {
+            task_id,
+            success: true,
+            results: execution.subtask_results,
+            collective_insights: execution.insights,
+            comparison,
+            performance_metrics: CollectivePerformanceMetrics,
+        })
+    }
+
+    /// Noyau de comparaison partagé par `compare_outputs` et la synthèse
+    fn compare_weighted_outputs(outputs: Vec<WeightedOutput>) -> ComparisonReport {
+        if outputs.is_empty() {
+            return ComparisonReport {
+                agreement_score: 0.0,
+                contradictions: Vec::new(),
+                consensus_answer: String::new(),
+                attributions: Vec::new(),
+            };
+        }
+        if outputs.len() == 1 {
+            let only = &outputs[0];
+            return ComparisonReport {
+                agreement_score: 1.0,
+                contradictions: Vec::new(),
+                consensus_answer: only.content.clone(),
+                attributions: vec![AgentAttribution {
+                    agent_id: only.agent_id.clone(),
+                    confidence: only.confidence,
+                    consensus_weight: 1.0,
+                    excerpt: Self::excerpt(&only.content),
+                }],
+            };
+        }
+
+        // Similarité lexicale de chaque paire d'agents
+        let mut pair_similarities = Vec::new();
+        let mut contradictions = Vec::new();
+        for i in 0..outputs.len() {
+            for j in (i + 1)..outputs.len() {
+                let similarity = Self::lexical_similarity(&outputs[i].content, &outputs[j].content);
+                pair_similarities.push((i, j, similarity));
+                if similarity < CONTRADICTION_SIMILARITY_THRESHOLD {
+                    contradictions.push(Contradiction {
+                        agent_a: outputs[i].agent_id.clone(),
+                        agent_b: outputs[j].agent_id.clone(),
+                        similarity,
+                    });
+                }
+            }
+        }
+        let agreement_score = pair_similarities.iter().map(|(_, _, s)| s).sum::<f64>()
+            / pair_similarities.len() as f64;
+
+        // Centralité : accord moyen d'un agent avec tous les autres
+        let mut centrality = vec![0.0f64; outputs.len()];
+        for (i, j, similarity) in &pair_similarities {
+            centrality[*i] += similarity;
+            centrality[*j] += similarity;
+        }
+        for value in &mut centrality {
+            *value /= (outputs.len() - 1) as f64;
+        }
+
+        // Poids de consensus : centralité pondérée par la confiance de
+        // l'agent, normalisée sur l'équipe
+        let raw_weights: Vec<f64> = outputs
+            .iter()
+            .zip(&centrality)
+            .map(|(output, c)| (c + CONSENSUS_WEIGHT_FLOOR) * output.confidence.max(0.0))
+            .collect();
+        let total_weight: f64 = raw_weights.iter().sum();
+        let attributions: Vec<AgentAttribution> = outputs
+            .iter()
+            .zip(&raw_weights)
+            .map(|(output, weight)| AgentAttribution {
+                agent_id: output.agent_id.clone(),
+                confidence: output.confidence,
+                consensus_weight: if total_weight > 0.0 { weight / total_weight } else { 0.0 },
+                excerpt: Self::excerpt(&output.content),
+            })
+            .collect();
+
+        // La réponse la plus centrale sert de base au consensus; chaque
+        // avis divergent de cette base est annexé avec son auteur
+        let lead = raw_weights
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.partial_cmp(b.1).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|(index, _)| index)
+            .unwrap_or(0);
+        let mut consensus_answer = format!(
+            "{} [selon {}]",
+            outputs[lead].content, outputs[lead].agent_id
+        );
+        for (index, output) in outputs.iter().enumerate() {
+            if index == lead {
+                continue;
+            }
+            if Self::lexical_similarity(&outputs[lead].content, &output.content)
+                < CONTRADICTION_SIMILARITY_THRESHOLD
+            {
+                consensus_answer.push_str(&format!(
+                    "\n\nAvis divergent de {} : {}",
+                    output.agent_id,
+                    Self::excerpt(&output.content)
+                ));
+            }
+        }
+
+        ComparisonReport {
+            agreement_score,
+            contradictions,
+            consensus_answer,
+            attributions,
+        }
+    }
+
+    /// Similarité de Jaccard sur les ensembles de mots en minuscules
+    fn lexical_similarity(a: &str, b: &str) -> f64 {
+        let words = |text: &str| -> std::collections::HashSet<String> {
+            text.to_lowercase()
+                .split(|c: char| !c.is_alphanumeric())
+                .filter(|w| !w.is_empty())
+                .map(|w| w.to_string())
+                .collect()
+        };
+        let set_a = words(a);
+        let set_b = words(b);
+        if set_a.is_empty() && set_b.is_empty() {
+            return 1.0;
+        }
+        let intersection = set_a.intersection(&set_b).count() as f64;
+        let union = set_a.union(&set_b).count() as f64;
+        intersection / union
+    }
+
+    /// Extrait court d'une réponse pour l'attribution
+    fn excerpt(content: &str) -> String {
+        const EXCERPT_MAX_CHARS: usize = 120;
+        if content.chars().count() <= EXCERPT_MAX_CHARS {
+            content.to_string()
+        } else {
+            let cut: String = content.chars().take(EXCERPT_MAX_CHARS).collect();
+            format!("{}…", cut.trim_end())
+        }
+    }
+}
+
+/// Seuil de similarité sous lequel deux réponses sont en contradiction
+const CONTRADICTION_SIMILARITY_THRESHOLD: f64 = 0.2;
+
+/// Nombre de marqueurs de domaine à partir duquel l'affinité sature à 1.0
+const AFFINITY_SATURATION: f64 = 3.0;
+
+/// Bonus de score quand une expertise de domaine déclarée est citée
+const DOMAIN_EXPERTISE_BONUS: f64 = 0.2;
+
+/// Plancher de centralité pour qu'un agent isolé mais confiant garde un
+/// poids non nul dans le consensus
+const CONSENSUS_WEIGHT_FLOOR: f64 = 0.05;
+
+/// Sortie d'agent normalisée pour la comparaison
+struct WeightedOutput {
+    agent_id: String,
+    content: String,
+    confidence: f64,
+}
+
+/// Rapport de comparaison des sorties de plusieurs agents
+#[derive(Debug, Clone)]
+pub struct ComparisonReport {
+    /// Accord lexical moyen sur toutes les paires d'agents (0.0 à 1.0)
+    pub agreement_score: f64,
+
+    /// Paires d'agents en contradiction
+    pub contradictions: Vec<Contradiction>,
+
+    /// Réponse consensuelle fusionnée, avis divergents annexés
+    pub consensus_answer: String,
+
+    /// Attribution du consensus agent par agent
+    pub attributions: Vec<AgentAttribution>,
+}
+
+/// Contradiction détectée entre deux agents
+#[derive(Debug, Clone)]
+pub struct Contradiction {
+    pub agent_a: String,
+    pub agent_b: String,
+    /// Similarité lexicale de la paire, sous le seuil de contradiction
+    pub similarity: f64,
+}
+
+/// Contribution d'un agent à la réponse consensuelle
+#[derive(Debug, Clone)]
+pub struct AgentAttribution {
+    pub agent_id: String,
+    /// Confiance déclarée par l'agent dans sa réponse
+    pub confidence: f64,
+    /// Poids normalisé de l'agent dans le consensus
+    pub consensus_weight: f64,
+    /// Extrait de la réponse de l'agent
+    pub excerpt: String,
+}
+
+/// Tableau noir partagé entre agents
+///
+/// Magasin clé/valeur concurrent d'insights collectifs. Les écritures
+/// passent par le `RwLock` existant et chaque publication émet une
+/// notification de changement à tous les abonnés.
+pub struct Blackboard {
+    /// Insights publiés, indexés par clé
+    insights: RwLock<HashMap<String, CollectiveInsight>>,
+
+    /// Canal de notification des changements
+    change_tx: broadcast::Sender<BlackboardChange>,
+}
+
+/// Notification de changement sur le tableau noir
+#[derive(Debug, Clone)]
+pub struct BlackboardChange {
+    pub key: String,
+    pub author_agent: String,
+}
+
+impl Blackboard {
+    /// Créer un tableau noir vide
+    pub fn new() -> Self {
+        let (change_tx, _) = broadcast::channel(64);
+        Self {
+            insights: RwLock::new(HashMap::new()),
+            change_tx,
+        }
+    }
+
+    /// S'abonner aux notifications de changement
+    pub fn subscribe(&self) -> broadcast::Receiver<BlackboardChange> {
+        self.change_tx.subscribe()
+    }
+
+    /// Publier un insight sous une clé et notifier les abonnés
+    pub async fn post(&self, key: String, insight: CollectiveInsight) {
+        let author_agent = insight.author_agent.clone();
+        self.insights.write().await.insert(key.clone(), insight);
+        // Aucun abonné n'est une erreur : l'insight reste lisible via read()
+        let _ = self.change_tx.send(BlackboardChange { key, author_agent });
+    }
+
+    /// Lire un insight s'il est déjà publié
+    pub async fn read(&self, key: &str) -> Option<CollectiveInsight> {
+        self.insights.read().await.get(key).cloned()
+    }
+
+    /// Attendre qu'un insight soit publié sous une clé
+    pub async fn wait_for(&self, key: &str) -> Result<CollectiveInsight, ConsciousnessError> {
+        // S'abonner avant la lecture pour ne pas rater une publication
+        let mut rx = self.subscribe();
+        if let Some(insight) = self.read(key).await {
+            return Ok(insight);
+        }
+        loop {
+            let change = rx.recv().await
+                .map_err(|e| ConsciousnessError::SystemError(format!("Canal du tableau noir fermé: {}", e)))?;
+            if change.key == key {
+                if let Some(insight) = self.read(key).await {
+                    return Ok(insight);
+                }
+            }
+        }
+    }
+
+    /// Instantané de tous les insights publiés
+    pub async fn snapshot(&self) -> Vec<CollectiveInsight> {
+        self.insights.read().await.values().cloned().collect()
+    }
+}
+
+impl Default for Blackboard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Sous-tâche allouée à un agent
+#[derive(Debug, Clone)]
+pub struct AgentSubtask {
+    pub id: String,
+    pub description: String,
+    pub assigned_agent: String,
+    /// Clé du tableau noir dont dépend cette sous-tâche
+    pub depends_on: Option<String>,
+}
+
+/// Résultat d'une exécution collaborative
+#[derive(Debug)]
+pub struct CollaborativeExecution {
+    pub subtask_results: Vec<SubtaskResult>,
+    pub insights: Vec<CollectiveInsight>,
 }
 
 /// Coordinateur de tâches pour orchestration
@@ -140,12 +716,18 @@ pub struct TaskCoordinator {
 pub struct InterAgentCommunication {
     /// Protocoles de communication
     protocols: Vec<CommunicationProtocol>,
-    
+
     /// Traducteur de messages
     message_translator: MessageTranslator,
-    
+
     /// Gestionnaire de sécurité
     security_manager: CommunicationSecurityManager,
+
+    /// Facteur de contagion émotionnelle (0.0 = isolation, 1.0 = contagion totale)
+    ///
+    /// Atténue l'influence émotionnelle des messages entre pairs pour
+    /// éviter les boucles de rétroaction émotionnelle dans l'équipe.
+    contagion_factor: f64,
 }
 
 /// Gestionnaire de consensus
@@ -199,6 +781,22 @@ pub enum ExpertiseLevel {
     Master,
 }
 
+/// Insight collectif publié sur le tableau noir
+#[derive(Debug, Clone)]
+pub struct CollectiveInsight {
+    /// Clé thématique sous laquelle l'insight est publié
+    pub topic: String,
+
+    /// Agent auteur de l'insight
+    pub author_agent: String,
+
+    /// Contenu de l'artefact intermédiaire
+    pub content: String,
+
+    /// Confiance de l'agent dans son résultat partiel
+    pub confidence: f64,
+}
+
 /// Résultat de tâche collaborative
 #[derive(Debug, Clone)]
 pub struct TaskResult {
@@ -206,6 +804,8 @@ pub struct TaskResult {
     pub success: bool,
     pub results: Vec<SubtaskResult>,
     pub collective_insights: Vec<CollectiveInsight>,
+    /// Synthèse comparée et attribuée des sorties des agents
+    pub comparison: ComparisonReport,
     pub performance_metrics: CollectivePerformanceMetrics,
 }
 
@@ -239,17 +839,69 @@ impl InterAgentCommunication {
             ],
             message_translator: MessageTranslator::new(),
             security_manager: CommunicationSecurityManager::new(),
+            contagion_factor: 0.3,
         })
     }
-    
+
+    /// Régler le facteur de contagion émotionnelle (borné à [0, 1])
+    pub fn set_contagion_factor(&mut self, factor: f64) {
+        self.contagion_factor = factor.clamp(0.0, 1.0);
+    }
+
+    /// Facteur de contagion émotionnelle courant
+    pub fn contagion_factor(&self) -> f64 {
+        self.contagion_factor
+    }
+
     pub async fn relay_message(&self, sender: &str, receiver: &str, message: AgentMessage) -> Result<AgentResponse, ConsciousnessError> {
         // Implémentation de relais de messages sécurisé
         Ok(AgentResponse {
             sender: receiver.to_string(),
             content: format!("Response to: {}", message.content),
+            effective_contagion: self.contagion_factor,
             timestamp: std::time::SystemTime::now(),
         })
     }
+
+    /// Diffuser un message à toute l'équipe en atténuant la contagion émotionnelle
+    ///
+    /// La charge émotionnelle du message ne déplace l'état de chaque pair que
+    /// de `contagion_factor` fois l'écart, de sorte qu'une diffusion très
+    /// activée ne peut pas entraîner toute l'équipe dans une boucle.
+    pub async fn broadcast_with_contagion(
+        &self,
+        sender: &str,
+        message: AgentMessage,
+        agents: &HashMap<String, Arc<RwLock<ConsciousAgent>>>,
+    ) -> Result<Vec<AgentResponse>, ConsciousnessError> {
+        let mut responses = Vec::new();
+
+        for (agent_id, agent) in agents {
+            if agent_id == sender {
+                continue;
+            }
+
+            if let Some(charge) = &message.emotional_charge {
+                let mut agent = agent.write().await;
+                let state = &mut agent.emotional_state;
+                state.arousal = (state.arousal
+                    + (charge.arousal - state.arousal) * self.contagion_factor)
+                    .clamp(0.0, 1.0);
+                state.valence = (state.valence
+                    + (charge.valence - state.valence) * self.contagion_factor)
+                    .clamp(-1.0, 1.0);
+            }
+
+            responses.push(AgentResponse {
+                sender: agent_id.clone(),
+                content: format!("Response to: {}", message.content),
+                effective_contagion: self.contagion_factor,
+                timestamp: std::time::SystemTime::now(),
+            });
+        }
+
+        Ok(responses)
+    }
 }
 
 // Types de support additionnels
@@ -258,13 +910,27 @@ pub struct AgentMessage {
     pub content: String,
     pub message_type: MessageType,
     pub priority: MessagePriority,
+    /// Charge émotionnelle portée par le message, si présente
+    pub emotional_charge: Option<EmotionalCharge>,
     pub timestamp: std::time::SystemTime,
 }
 
+/// Charge émotionnelle attachée à un message inter-agents
+#[derive(Debug, Clone, Copy)]
+pub struct EmotionalCharge {
+    /// Niveau d'activation du message (0.0 à 1.0)
+    pub arousal: f64,
+
+    /// Valence émotionnelle du message (-1.0 à 1.0)
+    pub valence: f64,
+}
+
 #[derive(Debug, Clone)]
 pub struct AgentResponse {
     pub sender: String,
     pub content: String,
+    /// Contagion émotionnelle effectivement appliquée lors du relais
+    pub effective_contagion: f64,
     pub timestamp: std::time::SystemTime,
 }
 
@@ -335,8 +1001,8 @@ pub struct CollectivePerformanceMonitor;
 pub struct TaskConstraints;
 pub struct SuccessCriteria;
 pub struct CollaborationNeeds;
+#[derive(Debug, Clone)]
 pub struct SubtaskResult;
-pub struct CollectiveInsight;
 pub struct CollectivePerformanceMetrics;
 pub struct DecisionPoint;
 pub struct ConsensusResult;
@@ -469,4 +1135,364 @@ pub enum StrategicCapability {
     Innovation,
     Leadership,
     Negotiation,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use consciousness_engine::{
+        ConsciousnessState, EmotionalContext, EmotionalState, EmotionType,
+    };
+    use std::time::{Duration, SystemTime};
+
+    /// Réponse de conscience minimale pour les tests de comparaison
+    fn sample_output(agent_id: &str, content: &str, confidence: f64) -> (String, ConsciousnessResponse) {
+        let response = ConsciousnessResponse {
+            content: content.to_string(),
+            consciousness_state: ConsciousnessState {
+                awareness_level: 0.8,
+                emotional_state: EmotionalState {
+                    primary_emotion: EmotionType::Calm,
+                    intensity: 0.5,
+                    valence: 0.1,
+                    arousal: 0.3,
+                    secondary_emotions: Vec::new(),
+                },
+                cognitive_load: 0.3,
+                confidence_score: 0.8,
+                meta_cognitive_depth: 3,
+                timestamp: SystemTime::now(),
+            },
+            emotional_context: EmotionalContext::neutral(),
+            reasoning_chain: Vec::new(),
+            confidence_level: confidence,
+            confidence_interval: ((confidence - 0.1).max(0.0), (confidence + 0.1).min(1.0)),
+            uncertainty_sources: Vec::new(),
+            processing_time: Duration::from_millis(10),
+            empathy_score: 0.8,
+            creativity_score: 0.5,
+            degraded_stages: Vec::new(),
+        };
+        (agent_id.to_string(), response)
+    }
+
+    #[tokio::test]
+    async fn test_dissenting_agent_is_flagged_and_annexed() {
+        let outputs = vec![
+            sample_output(
+                "agent_medical",
+                "Le patient présente une angine virale bénigne, repos et hydratation conseillés",
+                0.85,
+            ),
+            sample_output(
+                "agent_recherche",
+                "Angine virale bénigne confirmée, le repos et une bonne hydratation sont conseillés pour le patient",
+                0.8,
+            ),
+            sample_output(
+                "agent_dissident",
+                "Urgence chirurgicale immédiate, transfert au bloc opératoire requis sans délai",
+                0.6,
+            ),
+        ];
+
+        let report = AgentOrchestrator::compare_outputs(outputs);
+
+        // Le dissident est en contradiction avec chacun des deux autres
+        assert_eq!(report.contradictions.len(), 2);
+        assert!(report.contradictions.iter().all(|c| {
+            c.agent_a == "agent_dissident" || c.agent_b == "agent_dissident"
+        }));
+
+        // Le consensus part d'un agent majoritaire et annexe le dissident
+        assert!(report.consensus_answer.contains("angine virale"));
+        assert!(report.consensus_answer.contains("Avis divergent de agent_dissident"));
+        assert!(report.consensus_answer.contains("Urgence chirurgicale"));
+
+        // Attribution complète, pondérée vers la majorité
+        assert_eq!(report.attributions.len(), 3);
+        let weight = |id: &str| report.attributions.iter()
+            .find(|a| a.agent_id == id)
+            .map(|a| a.consensus_weight)
+            .unwrap();
+        assert!(weight("agent_medical") > weight("agent_dissident"));
+        let total: f64 = report.attributions.iter().map(|a| a.consensus_weight).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+
+        // L'accord moyen souffre de la dissidence
+        assert!(report.agreement_score < 0.5);
+    }
+
+    #[tokio::test]
+    async fn test_agreeing_agents_produce_no_contradictions() {
+        let outputs = vec![
+            sample_output("agent_a", "La réponse est le repos et l'hydratation", 0.9),
+            sample_output("agent_b", "Le repos et l'hydratation sont la réponse", 0.9),
+        ];
+
+        let report = AgentOrchestrator::compare_outputs(outputs);
+
+        assert!(report.contradictions.is_empty());
+        assert!(report.agreement_score > 0.5);
+        assert!(!report.consensus_answer.contains("Avis divergent"));
+    }
+
+    #[tokio::test]
+    async fn test_synthesis_uses_the_comparison_report() {
+        let orchestrator = AgentOrchestrator::new().await.unwrap();
+        let execution = CollaborativeExecution {
+            subtask_results: vec![SubtaskResult, SubtaskResult],
+            insights: vec![
+                CollectiveInsight {
+                    topic: "analysis".to_string(),
+                    author_agent: "agent_a".to_string(),
+                    content: "Analyse des besoins du patient".to_string(),
+                    confidence: 0.8,
+                },
+                CollectiveInsight {
+                    topic: "synthesis".to_string(),
+                    author_agent: "agent_b".to_string(),
+                    content: "Synthèse des besoins du patient".to_string(),
+                    confidence: 0.8,
+                },
+            ],
+        };
+
+        let result = orchestrator.synthesize_results(execution).await.unwrap();
+
+        assert_eq!(result.task_id, "analysis+synthesis");
+        assert!(result.success);
+        assert_eq!(result.comparison.attributions.len(), 2);
+        assert!(result.comparison.consensus_answer.contains("besoins du patient"));
+    }
+
+    #[tokio::test]
+    async fn test_insight_from_agent_a_unblocks_agent_b_subtask() {
+        let orchestrator = AgentOrchestrator::new().await.unwrap();
+
+        // La sous-tâche de l'agent B est listée en premier et dépend de
+        // l'analyse de l'agent A : elle ne peut aboutir que si le tableau
+        // noir relaie l'insight de A.
+        let subtasks = vec![
+            AgentSubtask {
+                id: "synthesis".to_string(),
+                description: "Synthèse finale".to_string(),
+                assigned_agent: "agent_b".to_string(),
+                depends_on: Some("analysis".to_string()),
+            },
+            AgentSubtask {
+                id: "analysis".to_string(),
+                description: "Analyse préliminaire".to_string(),
+                assigned_agent: "agent_a".to_string(),
+                depends_on: None,
+            },
+        ];
+        let team = vec!["agent_a".to_string(), "agent_b".to_string()];
+
+        let execution = orchestrator.execute_collaborative_task(subtasks, team).await.unwrap();
+
+        assert_eq!(execution.insights.len(), 2);
+        let synthesis = execution.insights.iter()
+            .find(|i| i.topic == "synthesis")
+            .expect("insight de synthèse publié");
+        assert_eq!(synthesis.author_agent, "agent_b");
+        assert!(synthesis.content.contains("Analyse préliminaire"));
+        assert!(synthesis.content.contains("agent_a"));
+
+        // Les deux insights restent lisibles sur le tableau noir
+        let blackboard = orchestrator.blackboard();
+        assert!(blackboard.read("analysis").await.is_some());
+        assert!(blackboard.read("synthesis").await.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_blackboard_notifies_subscribers_on_post() {
+        let blackboard = Blackboard::new();
+        let mut rx = blackboard.subscribe();
+
+        blackboard.post("analysis".to_string(), CollectiveInsight {
+            topic: "analysis".to_string(),
+            author_agent: "agent_a".to_string(),
+            content: "Résultat partiel".to_string(),
+            confidence: 0.9,
+        }).await;
+
+        let change = rx.recv().await.unwrap();
+        assert_eq!(change.key, "analysis");
+        assert_eq!(change.author_agent, "agent_a");
+    }
+
+    #[tokio::test]
+    async fn test_high_arousal_broadcast_is_damped_by_contagion_factor() {
+        let mut orchestrator = AgentOrchestrator::new().await.unwrap();
+        orchestrator.register_agent(
+            "agent_a".to_string(),
+            AgentSpecialization::Research {
+                research_fields: vec![ResearchField::Science],
+                methodology_expertise: vec![ResearchMethodology::Quantitative],
+            },
+            false,
+        ).await.unwrap();
+        orchestrator.register_agent(
+            "agent_b".to_string(),
+            AgentSpecialization::Creative {
+                creative_domains: vec![CreativeDomain::Literary],
+                artistic_styles: vec![ArtisticStyle::Modern],
+            },
+            false,
+        ).await.unwrap();
+
+        orchestrator.set_contagion_factor(0.25);
+        let baseline = orchestrator.get_agent_emotional_state("agent_b").await.unwrap();
+
+        let message = AgentMessage {
+            content: "Alerte critique!".to_string(),
+            message_type: MessageType::Alert,
+            priority: MessagePriority::Critical,
+            emotional_charge: Some(EmotionalCharge { arousal: 1.0, valence: -0.8 }),
+            timestamp: std::time::SystemTime::now(),
+        };
+
+        let responses = orchestrator.broadcast_message("agent_a", message).await.unwrap();
+
+        // L'émetteur ne reçoit pas sa propre diffusion
+        assert_eq!(responses.len(), 1);
+        assert_eq!(responses[0].sender, "agent_b");
+        assert!((responses[0].effective_contagion - 0.25).abs() < 1e-12);
+
+        // L'activation ne monte que de la fraction amortie de l'écart
+        let after = orchestrator.get_agent_emotional_state("agent_b").await.unwrap();
+        let expected_arousal = baseline.arousal + (1.0 - baseline.arousal) * 0.25;
+        assert!((after.arousal - expected_arousal).abs() < 1e-12);
+        assert!(after.arousal < 1.0);
+
+        // L'émetteur reste inchangé
+        let sender_state = orchestrator.get_agent_emotional_state("agent_a").await.unwrap();
+        assert!((sender_state.arousal - baseline.arousal).abs() < 1e-12);
+    }
+
+    #[tokio::test]
+    async fn test_contagion_factor_is_clamped() {
+        let mut communication = InterAgentCommunication::new().await.unwrap();
+        communication.set_contagion_factor(3.0);
+        assert_eq!(communication.contagion_factor(), 1.0);
+        communication.set_contagion_factor(-1.0);
+        assert_eq!(communication.contagion_factor(), 0.0);
+    }
+
+    fn research_specialization() -> AgentSpecialization {
+        AgentSpecialization::Research {
+            research_fields: vec![ResearchField::Science],
+            methodology_expertise: vec![ResearchMethodology::Quantitative],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_double_registration_without_overwrite_errors() {
+        let mut orchestrator = AgentOrchestrator::new().await.unwrap();
+        orchestrator.register_agent("agent_a".to_string(), research_specialization(), false)
+            .await.unwrap();
+
+        // Même identifiant sans overwrite : refus
+        let result = orchestrator
+            .register_agent("agent_a".to_string(), research_specialization(), false)
+            .await;
+        assert!(matches!(result, Err(ConsciousnessError::InvalidInput(_))));
+
+        // Avec overwrite, le remplacement est accepté
+        orchestrator.register_agent("agent_a".to_string(), research_specialization(), true)
+            .await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_deregister_removes_agent_and_drains_its_queue() {
+        let mut orchestrator = AgentOrchestrator::new().await.unwrap();
+        orchestrator.register_agent("agent_a".to_string(), research_specialization(), false)
+            .await.unwrap();
+
+        orchestrator.queue_message("agent_a", AgentMessage {
+            content: "En attente".to_string(),
+            message_type: MessageType::Status,
+            priority: MessagePriority::Normal,
+            emotional_charge: None,
+            timestamp: std::time::SystemTime::now(),
+        }).unwrap();
+
+        let drained = orchestrator.deregister_agent("agent_a").await.unwrap();
+        assert_eq!(drained.len(), 1);
+        assert_eq!(drained[0].content, "En attente");
+
+        // L'agent n'existe plus : état invisible, re-désenregistrement refusé
+        assert!(orchestrator.get_agent_emotional_state("agent_a").await.is_none());
+        assert!(matches!(
+            orchestrator.deregister_agent("agent_a").await,
+            Err(ConsciousnessError::InvalidInput(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_symptom_description_routes_highest_to_the_medical_agent() {
+        let mut orchestrator = AgentOrchestrator::new().await.unwrap();
+        orchestrator.register_agent(
+            "agent_medical".to_string(),
+            AgentSpecialization::Medical {
+                expertise_areas: vec![MedicalExpertise::Cardiology],
+                certification_level: CertificationLevel::Specialist,
+            },
+            false,
+        ).await.unwrap();
+        orchestrator.register_agent(
+            "agent_creatif".to_string(),
+            AgentSpecialization::Creative {
+                creative_domains: vec![CreativeDomain::Literary],
+                artistic_styles: vec![ArtisticStyle::Modern],
+            },
+            false,
+        ).await.unwrap();
+        orchestrator.register_agent("agent_recherche".to_string(), research_specialization(), false)
+            .await.unwrap();
+
+        let ranked = orchestrator
+            .route("Le patient décrit une douleur à la poitrine et de la fièvre depuis deux jours")
+            .await;
+
+        // Toute l'équipe est listée, le médical en tête avec un score net
+        assert_eq!(ranked.len(), 3);
+        assert_eq!(ranked[0].0, "agent_medical");
+        assert!(ranked[0].1 > ranked[1].1);
+        assert!(ranked.iter().all(|(_, score)| (0.0..=1.0).contains(score)));
+    }
+
+    #[tokio::test]
+    async fn test_routing_without_overlap_yields_zero_scores_in_stable_order() {
+        let mut orchestrator = AgentOrchestrator::new().await.unwrap();
+        orchestrator.register_agent("agent_b".to_string(), research_specialization(), false)
+            .await.unwrap();
+        orchestrator.register_agent("agent_a".to_string(), research_specialization(), false)
+            .await.unwrap();
+
+        let ranked = orchestrator.route("Bonjour, quelle heure est-il ?").await;
+
+        // Aucun recouvrement : scores nuls, égalités départagées par identifiant
+        assert_eq!(ranked, vec![
+            ("agent_a".to_string(), 0.0),
+            ("agent_b".to_string(), 0.0),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_execution_rejects_agent_outside_team() {
+        let orchestrator = AgentOrchestrator::new().await.unwrap();
+
+        let subtasks = vec![AgentSubtask {
+            id: "analysis".to_string(),
+            description: "Analyse".to_string(),
+            assigned_agent: "agent_x".to_string(),
+            depends_on: None,
+        }];
+        let team = vec!["agent_a".to_string()];
+
+        let result = orchestrator.execute_collaborative_task(subtasks, team).await;
+        assert!(matches!(result, Err(ConsciousnessError::InvalidInput(_))));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,282 @@
+//! Transcription audio structurée (ASR) sur octets bruts
+//!
+//! Le proxy JSON de `asr_transcribe` ne convient pas aux pipelines audio qui
+//! manipulent des octets. Le trait [`AsrBackend`] accepte un [`Audio`] typé
+//! (octets, format, fréquence d'échantillonnage), valide le format avant tout
+//! appel réseau, et retourne un [`Transcript`] segmenté — un audio corrompu
+//! produit une erreur explicite plutôt qu'un 500 générique.
+
+use crate::tts::AudioFormat;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Erreurs de transcription
+#[derive(Debug, thiserror::Error)]
+pub enum AsrError {
+    /// Le format n'est pas supporté par le backend
+    #[error("format audio non supporté: {0}")]
+    UnsupportedFormat(String),
+
+    /// Les octets ne correspondent pas au format annoncé
+    #[error("audio corrompu ou invalide: {0}")]
+    CorruptAudio(String),
+
+    /// Échec du backend de transcription
+    #[error("échec du backend ASR: {0}")]
+    Backend(String),
+}
+
+/// Audio brut soumis à la transcription
+#[derive(Debug, Clone)]
+pub struct Audio {
+    /// Octets encodés dans `format`
+    pub bytes: Vec<u8>,
+
+    /// Format annoncé des octets
+    pub format: AudioFormat,
+
+    /// Fréquence d'échantillonnage en Hz
+    pub sample_rate: u32,
+}
+
+impl Audio {
+    /// Vérifier que les octets ressemblent bien au format annoncé
+    ///
+    /// Contrôle d'en-tête léger (nombres magiques), suffisant pour rejeter
+    /// les payloads tronqués ou mal étiquetés avant d'appeler le backend.
+    pub fn validate(&self) -> Result<(), AsrError> {
+        if self.bytes.is_empty() {
+            return Err(AsrError::CorruptAudio("payload vide".to_string()));
+        }
+        if self.sample_rate == 0 {
+            return Err(AsrError::CorruptAudio("fréquence d'échantillonnage nulle".to_string()));
+        }
+
+        let plausible = match self.format {
+            AudioFormat::Wav => self.bytes.starts_with(b"RIFF"),
+            AudioFormat::Mp3 => {
+                self.bytes.starts_with(b"ID3")
+                    || (self.bytes.len() >= 2
+                        && self.bytes[0] == 0xFF
+                        && self.bytes[1] & 0xE0 == 0xE0)
+            }
+            AudioFormat::Opus => self.bytes.starts_with(b"OggS"),
+        };
+
+        if !plausible {
+            return Err(AsrError::CorruptAudio(format!(
+                "en-tête incompatible avec le format {}",
+                self.format.as_str()
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Segment horodaté d'une transcription
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranscriptSegment {
+    /// Début du segment depuis le début de l'audio
+    pub start: Duration,
+
+    /// Fin du segment
+    pub end: Duration,
+
+    /// Texte du segment
+    pub text: String,
+}
+
+/// Transcription structurée
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Transcript {
+    /// Texte complet
+    pub text: String,
+
+    /// Segments horodatés, dans l'ordre
+    pub segments: Vec<TranscriptSegment>,
+
+    /// Confiance globale (0.0 à 1.0)
+    pub confidence: f64,
+}
+
+/// Backend de transcription audio
+#[async_trait]
+pub trait AsrBackend: Send + Sync {
+    /// Formats que ce backend sait transcrire
+    fn supported_formats(&self) -> Vec<AudioFormat>;
+
+    /// Transcrire l'audio fourni
+    async fn transcribe(&self, audio: &Audio) -> Result<Transcript, AsrError>;
+}
+
+/// Backend HTTP proxifiant l'agent-orchestrator
+pub struct HttpAsrBackend {
+    client: reqwest::Client,
+    base_url: String,
+    request_id: Option<String>,
+}
+
+impl HttpAsrBackend {
+    pub fn new(client: reqwest::Client, base_url: String) -> Self {
+        Self { client, base_url, request_id: None }
+    }
+
+    /// Propager l'identifiant de requête du gateway vers l'amont
+    pub fn with_request_id(mut self, request_id: String) -> Self {
+        self.request_id = Some(request_id);
+        self
+    }
+}
+
+#[async_trait]
+impl AsrBackend for HttpAsrBackend {
+    fn supported_formats(&self) -> Vec<AudioFormat> {
+        vec![AudioFormat::Wav, AudioFormat::Mp3, AudioFormat::Opus]
+    }
+
+    async fn transcribe(&self, audio: &Audio) -> Result<Transcript, AsrError> {
+        if !self.supported_formats().contains(&audio.format) {
+            return Err(AsrError::UnsupportedFormat(audio.format.as_str().to_string()));
+        }
+        audio.validate()?;
+
+        let url = format!("{}/asr/transcribe", self.base_url);
+        let mut request = self.client
+            .post(&url)
+            .header("content-type", audio.format.content_type())
+            .header("x-sample-rate", audio.sample_rate.to_string())
+            .body(audio.bytes.clone());
+        if let Some(request_id) = &self.request_id {
+            request = request.header(shared::headers::REQUEST_ID, request_id);
+        }
+
+        let response = request.send().await
+            .map_err(|e| AsrError::Backend(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(AsrError::Backend(format!("statut {}", response.status())));
+        }
+
+        #[derive(Deserialize)]
+        struct WireSegment {
+            start_ms: u64,
+            end_ms: u64,
+            text: String,
+        }
+
+        #[derive(Deserialize)]
+        struct WireTranscript {
+            text: String,
+            #[serde(default)]
+            segments: Vec<WireSegment>,
+            #[serde(default)]
+            confidence: f64,
+        }
+
+        let wire: WireTranscript = response.json().await
+            .map_err(|e| AsrError::Backend(e.to_string()))?;
+
+        Ok(Transcript {
+            text: wire.text,
+            segments: wire.segments.into_iter()
+                .map(|segment| TranscriptSegment {
+                    start: Duration::from_millis(segment.start_ms),
+                    end: Duration::from_millis(segment.end_ms),
+                    text: segment.text,
+                })
+                .collect(),
+            confidence: wire.confidence,
+        })
+    }
+}
+
+/// Backend simulé pour les tests
+pub struct MockAsrBackend {
+    supported: Vec<AudioFormat>,
+}
+
+impl MockAsrBackend {
+    pub fn new(supported: Vec<AudioFormat>) -> Self {
+        Self { supported }
+    }
+}
+
+#[async_trait]
+impl AsrBackend for MockAsrBackend {
+    fn supported_formats(&self) -> Vec<AudioFormat> {
+        self.supported.clone()
+    }
+
+    async fn transcribe(&self, audio: &Audio) -> Result<Transcript, AsrError> {
+        if !self.supported.contains(&audio.format) {
+            return Err(AsrError::UnsupportedFormat(audio.format.as_str().to_string()));
+        }
+        audio.validate()?;
+
+        // Transcription déterministe dérivée de la taille du payload
+        let text = format!("transcription simulée ({} octets)", audio.bytes.len());
+
+        Ok(Transcript {
+            text: text.clone(),
+            segments: vec![TranscriptSegment {
+                start: Duration::ZERO,
+                end: Duration::from_millis(500),
+                text,
+            }],
+            confidence: 0.9,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wav_audio(bytes: &[u8]) -> Audio {
+        Audio {
+            bytes: bytes.to_vec(),
+            format: AudioFormat::Wav,
+            sample_rate: 16_000,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mock_backend_transcribes_valid_audio() {
+        let backend = MockAsrBackend::new(vec![AudioFormat::Wav]);
+        let audio = wav_audio(b"RIFF....WAVEfmt ");
+
+        let transcript = backend.transcribe(&audio).await.unwrap();
+
+        assert!(transcript.text.contains("16 octets"));
+        assert_eq!(transcript.segments.len(), 1);
+        assert!(transcript.confidence > 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_corrupt_audio_yields_clear_error() {
+        let backend = MockAsrBackend::new(vec![AudioFormat::Wav]);
+
+        // En-tête incompatible avec le format annoncé
+        let err = backend.transcribe(&wav_audio(b"pas du wav")).await.unwrap_err();
+        assert!(matches!(err, AsrError::CorruptAudio(_)));
+        assert!(err.to_string().contains("wav"));
+
+        // Payload vide
+        let err = backend.transcribe(&wav_audio(b"")).await.unwrap_err();
+        assert!(matches!(err, AsrError::CorruptAudio(_)));
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_format_is_rejected_before_backend() {
+        let backend = MockAsrBackend::new(vec![AudioFormat::Wav]);
+        let audio = Audio {
+            bytes: b"OggS....".to_vec(),
+            format: AudioFormat::Opus,
+            sample_rate: 48_000,
+        };
+
+        let err = backend.transcribe(&audio).await.unwrap_err();
+        assert!(matches!(err, AsrError::UnsupportedFormat(_)));
+    }
+}
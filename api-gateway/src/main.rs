@@ -5,17 +5,17 @@
 
 use axum::{
     extract::{Path, Query, State},
-    http::{HeaderMap, StatusCode},
+    http::{HeaderMap, HeaderValue, StatusCode},
     middleware,
     response::{Json, sse::{Sse, Event}},
     routing::{get, post, put, delete},
-    Router,
+    Extension, Router,
 };
 use utoipa::{OpenApi, Modify};
 use utoipa::openapi::{self, security::{SecurityScheme, HttpAuthScheme, HttpBuilder}, SecurityRequirement};
 use utoipa_swagger_ui::SwaggerUi;
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, sync::{Arc, Mutex}, time::{Duration, Instant}};
+use std::{collections::HashMap, sync::Arc, time::Duration};
 use std::convert::Infallible;
 use tokio::net::TcpListener;
 use tokio::sync::broadcast;
@@ -34,8 +34,16 @@ use tower_http::{
 use jsonwebtoken::{decode, DecodingKey, Validation};
 
 // Regrouper les modèles OpenAPI exposés dans un module dédié
+mod asr;
 mod models;
+mod rate_limit;
+mod sse;
+mod tts;
+mod ws;
+use crate::rate_limit::{InMemoryRateLimiter, RateLimiterBackend, RedisRateLimiter};
+use crate::tts::{HttpTtsBackend, TtsBackend, TtsError, negotiate_format};
 use crate::models::{
+    Capabilities,
     HealthResponse,
     MetricsResponse,
     ConsciousnessRequest,
@@ -50,6 +58,7 @@ use crate::models::{
     ComplianceStatus,
     PolicySummary,
     ErrorResponse,
+    ReportCard,
 };
 
 // Documentation OpenAPI: déplacée en bas du fichier pour garantir que tous les schémas soient définis avant son expansion.
@@ -66,10 +75,175 @@ struct GatewayConfig {
     ai_governance_url: String,
     jwt_secret: String,
     rate_limit_requests_per_minute: u32,
+    /// URL Redis pour un rate limiting partagé entre réplicas (None = mémoire)
+    rate_limit_redis_url: Option<String>,
     request_timeout_seconds: u64,
     auth_optional: bool,
+    /// Audience (`aud`) exigée des tokens, si configurée
+    jwt_audience: Option<String>,
+    /// Émetteur (`iss`) exigé des tokens, si configuré
+    jwt_issuer: Option<String>,
+    /// Réponse de secours renvoyée quand le moteur est injoignable
+    /// (None = comportement historique, 503 sec)
+    fallback_response: Option<String>,
+    /// Streaming (SSE / WebSocket) annoncé dans `/api/v1/capabilities`
+    streaming_enabled: bool,
+    /// Modalité vision annoncée dans `/api/v1/capabilities`
+    vision_enabled: bool,
+    /// Transcription audio (ASR) annoncée dans `/api/v1/capabilities`
+    asr_enabled: bool,
+    /// Synthèse vocale (TTS) annoncée dans `/api/v1/capabilities`
+    tts_enabled: bool,
 }
 
+/// Secret JWT de développement, refusé hors mode développement
+///
+/// Historiquement ce secret servait de repli silencieux : un déploiement
+/// production sans `JWT_SECRET` signait donc ses tokens avec une valeur
+/// publique. `GatewayConfig::from_env` refuse désormais de démarrer dans
+/// ce cas.
+const DEFAULT_JWT_SECRET: &str = "default-secret-key";
+
+/// Erreurs de configuration détectées au démarrage du gateway
+#[derive(Debug, thiserror::Error)]
+enum ConfigError {
+    /// Variable obligatoire absente de l'environnement
+    #[error("variable d'environnement {name} requise: {reason}")]
+    Missing {
+        name: &'static str,
+        reason: &'static str,
+    },
+
+    /// Valeur présente mais inexploitable
+    #[error("variable d'environnement {name} invalide ({value:?}): {reason}")]
+    Invalid {
+        name: &'static str,
+        value: String,
+        reason: String,
+    },
+}
+
+impl GatewayConfig {
+    /// Construit la configuration depuis l'environnement du processus
+    fn from_env() -> Result<Self, ConfigError> {
+        Self::from_lookup(|name| std::env::var(name).ok())
+    }
+
+    /// Construit la configuration depuis une source de variables arbitraire
+    ///
+    /// Découpler la lecture de l'environnement permet de tester la
+    /// validation sans muter l'environnement global du processus de test.
+    /// Les valeurs optionnelles gardent leurs défauts de développement ;
+    /// les valeurs typées mal formées provoquent une erreur descriptive au
+    /// lieu d'un repli silencieux.
+    fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Result<Self, ConfigError> {
+        let environment = lookup("ENVIRONMENT").unwrap_or_else(|| "development".to_string());
+        let dev_mode = matches!(environment.as_str(), "development" | "dev" | "local");
+
+        let jwt_secret = match lookup("JWT_SECRET") {
+            Some(secret) if secret == DEFAULT_JWT_SECRET && !dev_mode => {
+                return Err(ConfigError::Invalid {
+                    name: "JWT_SECRET",
+                    value: "<secret de développement>".to_string(),
+                    reason: format!(
+                        "le secret de développement ne doit pas signer de tokens en {}",
+                        environment
+                    ),
+                });
+            }
+            Some(secret) => secret,
+            None if dev_mode => DEFAULT_JWT_SECRET.to_string(),
+            None => {
+                return Err(ConfigError::Missing {
+                    name: "JWT_SECRET",
+                    reason: "aucun secret de repli hors mode développement",
+                });
+            }
+        };
+
+        Ok(Self {
+            port: parse_var(&lookup, "PORT", 3000)?,
+            consciousness_engine_url: lookup("CONSCIOUSNESS_ENGINE_URL")
+                .unwrap_or_else(|| "http://localhost:8080".to_string()),
+            agent_orchestrator_url: lookup("AGENT_ORCHESTRATOR_URL")
+                .unwrap_or_else(|| "http://localhost:8081".to_string()),
+            ai_governance_url: lookup("AI_GOVERNANCE_URL")
+                .unwrap_or_else(|| "http://localhost:8082".to_string()),
+            jwt_secret,
+            rate_limit_requests_per_minute: parse_var(&lookup, "RATE_LIMIT_PER_MINUTE", 100)?,
+            rate_limit_redis_url: lookup("RATE_LIMIT_REDIS_URL"),
+            request_timeout_seconds: parse_var(&lookup, "REQUEST_TIMEOUT_SECONDS", 30)?,
+            auth_optional: flag_var(&lookup, "AUTH_OPTIONAL", true)?,
+            jwt_audience: lookup("JWT_AUDIENCE"),
+            jwt_issuer: lookup("JWT_ISSUER"),
+            // FALLBACK_RESPONSE vide désactive le mode dégradé,
+            // absent = message par défaut
+            fallback_response: match lookup("FALLBACK_RESPONSE") {
+                Some(message) if message.is_empty() => None,
+                Some(message) => Some(message),
+                None => Some(DEFAULT_FALLBACK_RESPONSE.to_string()),
+            },
+            // Feature flags annoncés aux SDK via /api/v1/capabilities
+            // ("0"/"false" pour masquer une capacité, activées par défaut)
+            streaming_enabled: flag_var(&lookup, "STREAMING_ENABLED", true)?,
+            vision_enabled: flag_var(&lookup, "VISION_ENABLED", true)?,
+            asr_enabled: flag_var(&lookup, "ASR_ENABLED", true)?,
+            tts_enabled: flag_var(&lookup, "TTS_ENABLED", true)?,
+        })
+    }
+}
+
+/// Lire une variable typée, avec erreur descriptive si elle est mal formée
+fn parse_var<T>(
+    lookup: &impl Fn(&str) -> Option<String>,
+    name: &'static str,
+    default: T,
+) -> Result<T, ConfigError>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    match lookup(name) {
+        None => Ok(default),
+        Some(value) => value.parse().map_err(|e: T::Err| ConfigError::Invalid {
+            name,
+            value,
+            reason: e.to_string(),
+        }),
+    }
+}
+
+/// Lire un drapeau booléen ("1"/"true"/"0"/"false", insensible à la casse)
+fn flag_var(
+    lookup: &impl Fn(&str) -> Option<String>,
+    name: &'static str,
+    default: bool,
+) -> Result<bool, ConfigError> {
+    match lookup(name) {
+        None => Ok(default),
+        Some(value) => match value.to_ascii_lowercase().as_str() {
+            "1" | "true" => Ok(true),
+            "0" | "false" => Ok(false),
+            _ => Err(ConfigError::Invalid {
+                name,
+                value,
+                reason: "attendu \"1\", \"true\", \"0\" ou \"false\"".to_string(),
+            }),
+        },
+    }
+}
+
+/// Réponse de secours par défaut quand le moteur de conscience est injoignable
+///
+/// Un compagnon qui répond « 503 » casse la relation ; mieux vaut un accusé
+/// de réception empathique avec une suggestion de réessai, clairement
+/// marqué comme dégradé.
+const DEFAULT_FALLBACK_RESPONSE: &str = "Je rencontre des difficultés techniques en ce moment, \
+    mais je reste à votre écoute. Pouvez-vous réessayer dans quelques instants ?";
+
+/// Confiance affichée sur une réponse de secours dégradée
+const FALLBACK_CONFIDENCE: f64 = 0.1;
+
 /// Décrire une image via un VLM (proxifié vers le mvp-server)
 #[utoipa::path(
     post,
@@ -81,10 +255,11 @@ struct GatewayConfig {
 )]
 async fn vision_describe(
     State(state): State<GatewayState>,
+    Extension(request_id): Extension<RequestId>,
     Json(body): Json<serde_json::Value>,
 ) -> Result<Json<serde_json::Value>, StatusCode> {
     let url = format!("{}/vision/describe", state.config.agent_orchestrator_url);
-    match state.http_client.post(&url).json(&body).send().await {
+    match state.http_client.post(&url).header(shared::headers::REQUEST_ID, &request_id.0).json(&body).send().await {
         Ok(resp) => {
             if resp.status().is_success() {
                 match resp.json::<serde_json::Value>().await {
@@ -110,10 +285,11 @@ async fn vision_describe(
 )]
 async fn asr_transcribe(
     State(state): State<GatewayState>,
+    Extension(request_id): Extension<RequestId>,
     Json(body): Json<serde_json::Value>,
 ) -> Result<Json<serde_json::Value>, StatusCode> {
     let url = format!("{}/asr/transcribe", state.config.agent_orchestrator_url);
-    match state.http_client.post(&url).json(&body).send().await {
+    match state.http_client.post(&url).header(shared::headers::REQUEST_ID, &request_id.0).json(&body).send().await {
         Ok(resp) => {
             if resp.status().is_success() {
                 match resp.json::<serde_json::Value>().await {
@@ -139,21 +315,42 @@ async fn asr_transcribe(
 )]
 async fn tts_synthesize(
     State(state): State<GatewayState>,
+    Extension(request_id): Extension<RequestId>,
     Json(body): Json<serde_json::Value>,
-) -> Result<Json<serde_json::Value>, StatusCode> {
-    let url = format!("{}/tts/synthesize", state.config.agent_orchestrator_url);
-    match state.http_client.post(&url).json(&body).send().await {
-        Ok(resp) => {
-            if resp.status().is_success() {
-                match resp.json::<serde_json::Value>().await {
-                    Ok(json) => Ok(Json(json)),
-                    Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-                }
-            } else {
-                Err(StatusCode::from_u16(resp.status().as_u16()).unwrap_or(StatusCode::BAD_GATEWAY))
+) -> Result<axum::response::Response, (StatusCode, String)> {
+    let text = body.get("text").and_then(|v| v.as_str())
+        .ok_or((StatusCode::BAD_REQUEST, "champ 'text' requis".to_string()))?;
+    // Format demandé : champ "accept" façon en-tête Accept, ou "format" court
+    let accept = body.get("accept").or_else(|| body.get("format"))
+        .and_then(|v| v.as_str())
+        .unwrap_or("");
+
+    let backend = HttpTtsBackend::new(
+        state.http_client.clone(),
+        state.config.agent_orchestrator_url.clone(),
+    ).with_request_id(request_id.0.clone());
+
+    let format = negotiate_format(accept, &backend.supported_formats())
+        .map_err(|e| (StatusCode::NOT_ACCEPTABLE, e.to_string()))?;
+
+    match backend.synthesize(text, format).await {
+        Ok(audio) => {
+            let mut response = axum::response::Response::new(axum::body::Body::from(audio.bytes));
+            let headers = response.headers_mut();
+            headers.insert("content-type", HeaderValue::from_static(audio.format.content_type()));
+            if let Ok(value) = HeaderValue::from_str(&audio.sample_rate.to_string()) {
+                headers.insert("x-sample-rate", value);
+            }
+            if let Ok(value) = HeaderValue::from_str(&audio.duration.as_millis().to_string()) {
+                headers.insert("x-duration-ms", value);
+            }
+            if let Ok(value) = HeaderValue::from_str(&request_id.0) {
+                headers.insert(shared::headers::REQUEST_ID, value);
             }
+            Ok(response)
         }
-        Err(_) => Err(StatusCode::BAD_GATEWAY),
+        Err(TtsError::UnsupportedFormat(msg)) => Err((StatusCode::NOT_ACCEPTABLE, msg)),
+        Err(TtsError::Backend(msg)) => Err((StatusCode::BAD_GATEWAY, msg)),
     }
 }
 
@@ -170,10 +367,11 @@ async fn tts_synthesize(
 )]
 async fn llm_generate(
     State(state): State<GatewayState>,
+    Extension(request_id): Extension<RequestId>,
     Json(body): Json<serde_json::Value>,
 ) -> Result<Json<serde_json::Value>, StatusCode> {
     let url = format!("{}/llm/generate", state.config.agent_orchestrator_url);
-    match state.http_client.post(&url).json(&body).send().await {
+    match state.http_client.post(&url).header(shared::headers::REQUEST_ID, &request_id.0).json(&body).send().await {
         Ok(resp) => {
             if resp.status().is_success() {
                 match resp.json::<serde_json::Value>().await {
@@ -197,10 +395,11 @@ async fn llm_generate(
 )]
 async fn llm_chat(
     State(state): State<GatewayState>,
+    Extension(request_id): Extension<RequestId>,
     Json(body): Json<serde_json::Value>,
 ) -> Result<Json<serde_json::Value>, StatusCode> {
     let url = format!("{}/llm/chat", state.config.agent_orchestrator_url);
-    match state.http_client.post(&url).json(&body).send().await {
+    match state.http_client.post(&url).header(shared::headers::REQUEST_ID, &request_id.0).json(&body).send().await {
         Ok(resp) => {
             if resp.status().is_success() {
                 match resp.json::<serde_json::Value>().await {
@@ -224,11 +423,12 @@ async fn llm_chat(
 )]
 async fn evaluate_proxy(
     State(state): State<GatewayState>,
+    Extension(request_id): Extension<RequestId>,
     Json(body): Json<serde_json::Value>,
 ) -> Result<Json<serde_json::Value>, StatusCode> {
     // Proxy vers le mvp-server (agent_orchestrator_url pointe sur http://mvp-server:4000)
     let url = format!("{}/evaluate", state.config.agent_orchestrator_url);
-    match state.http_client.post(&url).json(&body).send().await {
+    match state.http_client.post(&url).header(shared::headers::REQUEST_ID, &request_id.0).json(&body).send().await {
         Ok(resp) => {
             if resp.status().is_success() {
                 match resp.json::<serde_json::Value>().await {
@@ -252,6 +452,7 @@ async fn evaluate_proxy(
 )]
 async fn llm_stream(
     State(state): State<GatewayState>,
+    Extension(request_id): Extension<RequestId>,
     Json(body): Json<serde_json::Value>,
 ) -> Sse<impl futures_core::Stream<Item = Result<Event, Infallible>>> {
     let url = format!("{}/llm/stream", state.config.agent_orchestrator_url);
@@ -259,7 +460,7 @@ async fn llm_stream(
     let (tx, rx) = tokio::sync::mpsc::channel::<Result<Event, Infallible>>(64);
 
     tokio::spawn(async move {
-        match client.post(&url).json(&body).send().await {
+        match client.post(&url).header(shared::headers::REQUEST_ID, &request_id.0).json(&body).send().await {
             Ok(resp) => {
                 if !resp.status().is_success() {
                     let _ = tx
@@ -322,10 +523,11 @@ async fn llm_stream(
 )]
 async fn llm_models(
     State(state): State<GatewayState>,
+    Extension(request_id): Extension<RequestId>,
 ) -> Result<Json<serde_json::Value>, StatusCode> {
     // On interroge le MVP server qui expose /llm/health (inclut la liste des modèles)
     let url = format!("{}/llm/health", state.config.agent_orchestrator_url);
-    match state.http_client.get(&url).send().await {
+    match state.http_client.get(&url).header(shared::headers::REQUEST_ID, &request_id.0).send().await {
         Ok(resp) => {
             if resp.status().is_success() {
                 match resp.json::<serde_json::Value>().await {
@@ -393,13 +595,108 @@ async fn orchestrator_mock_run(State(state): State<GatewayState>) -> Json<serde_
     Json(serde_json::json!({ "status": "started" }))
 }
 
+/// Identifiant de requête propagé via les extensions Axum
+#[derive(Clone, Debug)]
+struct RequestId(String);
+
+/// Sujet (`sub`) du token authentifié, propagé via les extensions Axum
+#[derive(Clone, Debug)]
+struct AuthSubject(String);
+
+/// Claims JWT attendues par le gateway
+#[derive(Debug, serde::Deserialize)]
+struct JwtClaims {
+    /// Sujet du token, propagé aux handlers aval
+    sub: Option<String>,
+}
+
+/// Valider la signature et les claims d'un token
+///
+/// Au-delà de la signature HMAC : `exp` (obligatoire), `nbf` si présent,
+/// et `aud`/`iss` quand ils sont configurés. Retourne les claims en cas
+/// de succès, sinon la raison du refus pour l'en-tête `x-auth-reason`.
+fn validate_token(
+    token: &str,
+    secret: &str,
+    audience: Option<&str>,
+    issuer: Option<&str>,
+) -> Result<JwtClaims, &'static str> {
+    let dec_key = DecodingKey::from_secret(secret.as_bytes());
+    let mut validation = Validation::default();
+    validation.validate_nbf = true;
+    if let Some(audience) = audience {
+        validation.set_audience(&[audience]);
+    }
+    if let Some(issuer) = issuer {
+        validation.set_issuer(&[issuer]);
+    }
+
+    decode::<JwtClaims>(token, &dec_key, &validation)
+        .map(|data| data.claims)
+        .map_err(|error| auth_failure_reason(&error))
+}
+
+/// Raison lisible d'un refus d'authentification
+fn auth_failure_reason(error: &jsonwebtoken::errors::Error) -> &'static str {
+    use jsonwebtoken::errors::ErrorKind;
+    match error.kind() {
+        ErrorKind::ExpiredSignature => "token-expired",
+        ErrorKind::ImmatureSignature => "token-not-yet-valid",
+        ErrorKind::InvalidAudience => "invalid-audience",
+        ErrorKind::InvalidIssuer => "invalid-issuer",
+        ErrorKind::MissingRequiredClaim(_) => "missing-claim",
+        _ => "invalid-token",
+    }
+}
+
+/// 401 portant la raison du refus dans `x-auth-reason`
+fn unauthorized_response(reason: &'static str) -> axum::response::Response {
+    use axum::response::IntoResponse;
+    let mut response = StatusCode::UNAUTHORIZED.into_response();
+    response
+        .headers_mut()
+        .insert("x-auth-reason", HeaderValue::from_static(reason));
+    response
+}
+
 #[derive(Clone)]
 struct GatewayState {
     config: GatewayConfig,
     http_client: reqwest::Client,
-    rate_limiter: Arc<Mutex<RateLimiter>>, 
+    rate_limiter: Arc<dyn RateLimiterBackend>,
     metrics: Arc<GatewayMetrics>,
     event_tx: broadcast::Sender<String>,
+    sessions: sse::SessionStore,
+    agents: AgentRegistry,
+}
+
+/// Taille de page par défaut de `/api/v1/agents`
+const AGENTS_DEFAULT_PAGE_SIZE: usize = 50;
+
+/// Taille de page maximale acceptée sur `/api/v1/agents`
+const AGENTS_MAX_PAGE_SIZE: usize = 200;
+
+/// Registre mémoire des agents créés via le gateway
+///
+/// Donne une vraie source de données à la pagination de `list_agents` :
+/// `create_agent` y insère et les pages sont servies en ordre d'insertion,
+/// en attendant la délégation complète à l'orchestrateur.
+#[derive(Clone, Default)]
+struct AgentRegistry {
+    agents: Arc<std::sync::Mutex<Vec<serde_json::Value>>>,
+}
+
+impl AgentRegistry {
+    fn insert(&self, agent: serde_json::Value) {
+        self.agents.lock().unwrap().push(agent);
+    }
+
+    /// Une page en ordre d'insertion, plus le nombre total d'agents
+    fn page(&self, offset: usize, limit: usize) -> (Vec<serde_json::Value>, usize) {
+        let agents = self.agents.lock().unwrap();
+        let items = agents.iter().skip(offset).take(limit).cloned().collect();
+        (items, agents.len())
+    }
 }
 
 #[derive(Default)]
@@ -425,26 +722,20 @@ impl GatewayMetrics {
     }
 }
 
-struct RateLimiter {
-    window_start: Instant,
-    counts: HashMap<String, u32>,
-}
-
-impl RateLimiter {
-    fn new() -> Self {
-        Self { window_start: Instant::now(), counts: HashMap::new() }
-    }
-
-    fn check_rate_limit(&mut self, client_id: &str, limit_per_minute: u32) -> bool {
-        // reset window every minute
-        if self.window_start.elapsed() >= Duration::from_secs(60) {
-            self.window_start = Instant::now();
-            self.counts.clear();
-        }
-        let entry = self.counts.entry(client_id.to_string()).or_insert(0);
-        if *entry >= limit_per_minute { return false; }
-        *entry += 1;
-        true
+/// Construire le backend de rate limiting selon la configuration
+///
+/// Avec `rate_limit_redis_url`, la limite est partagée entre tous les
+/// réplicas du gateway ; sinon on garde le limiteur mémoire historique.
+fn build_rate_limiter(config: &GatewayConfig) -> Arc<dyn RateLimiterBackend> {
+    match &config.rate_limit_redis_url {
+        Some(url) => match RedisRateLimiter::new(url) {
+            Ok(limiter) => Arc::new(limiter),
+            Err(e) => {
+                tracing::warn!("limiteur Redis indisponible ({}), repli en mémoire", e);
+                Arc::new(InMemoryRateLimiter::new())
+            }
+        },
+        None => Arc::new(InMemoryRateLimiter::new()),
     }
 }
 
@@ -454,8 +745,12 @@ impl RateLimiter {
         // Health check
         .route("/health", get(health_check))
         .route("/metrics", get(get_metrics))
+        // Négociation de capacités pour les SDK
+        .route("/api/v1/capabilities", get(get_capabilities))
         // Server-Sent Events (SSE)
         .route("/events", get(sse_events))
+        // WebSocket bidirectionnel : soumission + streaming + annulation
+        .route("/ws/consciousness", get(ws_consciousness))
         // Mock streaming (démo)
         .route("/api/v1/mock/stream", post(mock_stream))
         // Orchestrator: déclenche un run mock et proxifie le SSE (mvp-server)
@@ -476,6 +771,7 @@ impl RateLimiter {
         // Consciousness Engine routes
         .route("/api/v1/consciousness/process", post(process_consciousness))
         .route("/api/v1/consciousness/state", get(get_consciousness_state))
+        .route("/api/v1/consciousness/report", get(get_consciousness_report))
         .route("/api/v1/consciousness/reflection", post(generate_reflection))
         .route("/api/v1/agents/:agent_id", put(update_agent))
         .route("/api/v1/agents/:agent_id", delete(delete_agent))
@@ -496,17 +792,41 @@ impl RateLimiter {
         )
         // Auth + Rate limit (après couches HTTP)
         .layer(middleware::from_fn_with_state(state, auth_middleware))
+        // Request id: généré en amont de tout pour corréler gateway et services
+        .layer(middleware::from_fn(request_id_middleware))
+}
+
+/// Middleware de traçage: génère un `x-request-id` si absent, le stocke dans
+/// les extensions pour les proxys aval, et l'écho dans la réponse.
+async fn request_id_middleware(
+    mut request: axum::extract::Request,
+    next: axum::middleware::Next,
+) -> axum::response::Response {
+    let request_id = request
+        .headers()
+        .get(shared::headers::REQUEST_ID)
+        .and_then(|h| h.to_str().ok())
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+
+    request.extensions_mut().insert(RequestId(request_id.clone()));
+
+    let mut response = next.run(request).await;
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        response.headers_mut().insert(shared::headers::REQUEST_ID, value);
+    }
+    response
 }
 
 /// Middleware d'authentification + rate limiting (Axum 0.7)
 async fn auth_middleware(
     State(state): State<GatewayState>,
-    request: axum::extract::Request,
+    mut request: axum::extract::Request,
     next: axum::middleware::Next,
 ) -> Result<axum::response::Response, StatusCode> {
     let path = request.uri().path();
     // Routes publiques
-    if matches!(path, "/health" | "/metrics" | "/docs" | "/openapi.json" | "/events" | "/api/v1/mock/stream" | "/api/v1/orchestrator/mock-run" | "/api/v1/llm/generate" | "/api/v1/llm/chat" | "/api/v1/llm/stream" | "/api/v1/llm/models" | "/api/v1/evaluate" | "/api/v1/vision/describe" | "/api/v1/asr/transcribe" | "/api/v1/tts/synthesize") {
+    if matches!(path, "/health" | "/metrics" | "/docs" | "/openapi.json" | "/events" | "/api/v1/capabilities" | "/api/v1/mock/stream" | "/api/v1/orchestrator/mock-run" | "/api/v1/llm/generate" | "/api/v1/llm/chat" | "/api/v1/llm/stream" | "/api/v1/llm/models" | "/api/v1/evaluate" | "/api/v1/vision/describe" | "/api/v1/asr/transcribe" | "/api/v1/tts/synthesize") {
         return Ok(next.run(request).await);
     }
 
@@ -516,35 +836,77 @@ async fn auth_middleware(
         .get("x-client-id")
         .and_then(|h| h.to_str().ok())
         .unwrap_or("anonymous");
+    let decision = match state
+        .rate_limiter
+        .check_rate_limit(client_id, state.config.rate_limit_requests_per_minute)
+        .await
     {
-        let mut limiter = state
-            .rate_limiter
-            .lock()
-            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-        if !limiter.check_rate_limit(client_id, state.config.rate_limit_requests_per_minute) {
-            return Err(StatusCode::TOO_MANY_REQUESTS);
+        Ok(decision) => Some(decision),
+        // Backend partagé injoignable : on laisse passer plutôt que de
+        // refuser tout le trafic (fail-open), en le signalant
+        Err(e) => {
+            tracing::warn!("rate limiting indisponible: {}", e);
+            None
+        }
+    };
+    if let Some(decision) = &decision {
+        if !decision.admitted {
+            use axum::response::IntoResponse;
+            let mut response = StatusCode::TOO_MANY_REQUESTS.into_response();
+            insert_rate_limit_headers(response.headers_mut(), decision);
+            let retry_after = decision.reset_after.as_secs_f64().ceil().max(1.0) as u64;
+            response
+                .headers_mut()
+                .insert("retry-after", HeaderValue::from(retry_after));
+            return Ok(response);
         }
     }
 
     // Auth JWT (optionnelle en dev)
-    if state.config.auth_optional {
-        return Ok(next.run(request).await);
+    if !state.config.auth_optional {
+        let auth = headers
+            .get("authorization")
+            .and_then(|h| h.to_str().ok())
+            .unwrap_or("");
+        let token = auth.strip_prefix("Bearer ");
+        let Some(token) = token else { return Ok(unauthorized_response("missing-token")) };
+
+        // Signature HMAC et claims : exp, nbf, aud/iss configurés
+        match validate_token(
+            token,
+            &state.config.jwt_secret,
+            state.config.jwt_audience.as_deref(),
+            state.config.jwt_issuer.as_deref(),
+        ) {
+            Ok(claims) => {
+                // Propager le sujet aux handlers aval
+                if let Some(sub) = claims.sub {
+                    request.extensions_mut().insert(AuthSubject(sub));
+                }
+            }
+            Err(reason) => return Ok(unauthorized_response(reason)),
+        }
     }
 
-    let auth = headers
-        .get("authorization")
-        .and_then(|h| h.to_str().ok())
-        .unwrap_or("");
-    let token = auth.strip_prefix("Bearer ");
-    let Some(token) = token else { return Err(StatusCode::UNAUTHORIZED) };
-
-    // Validation basique (signature HMAC), claims ignorés
-    let dec_key = DecodingKey::from_secret(state.config.jwt_secret.as_bytes());
-    let validation = Validation::default();
-    let _ = decode::<serde_json::Value>(token, &dec_key, &validation)
-        .map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let mut response = next.run(request).await;
+    if let Some(decision) = &decision {
+        insert_rate_limit_headers(response.headers_mut(), decision);
+    }
+    Ok(response)
+}
 
-    Ok(next.run(request).await)
+/// Poser les en-têtes `X-RateLimit-*` standard sur une réponse
+///
+/// Permet aux clients bien élevés de s'auto-réguler avant d'atteindre la
+/// limite ; `X-RateLimit-Reset` est le délai en secondes avant la
+/// réinitialisation de la fenêtre.
+fn insert_rate_limit_headers(headers: &mut axum::http::HeaderMap, decision: &rate_limit::RateLimitDecision) {
+    headers.insert("x-ratelimit-limit", HeaderValue::from(decision.limit));
+    headers.insert("x-ratelimit-remaining", HeaderValue::from(decision.remaining));
+    headers.insert(
+        "x-ratelimit-reset",
+        HeaderValue::from(decision.reset_after.as_secs_f64().ceil() as u64),
+    );
 }
 
 /// Health check endpoint
@@ -588,31 +950,60 @@ async fn health_check(State(state): State<GatewayState>) -> Json<serde_json::Val
     get,
     path = "/events",
     tag = "system",
-    params(("topic" = Option<String>, Query, description = "Filtrer par topic (ex: system, agent:{id}, execution:{id})")),
+    params(
+        ("topic" = Option<String>, Query, description = "Filtrer par topic (ex: system, agent:{id}, execution:{id})"),
+        ("coalesce_ms" = Option<u64>, Query, description = "Regrouper les événements par fenêtre de N ms (lot = tableau JSON)"),
+        ("session" = Option<String>, Query, description = "`new` pour ouvrir une session résumable (jeton dans l'id SSE)"),
+        ("resume" = Option<String>, Query, description = "Jeton de reprise : rejoue les événements manqués depuis le dernier id vu")
+    ),
     responses((status = 200, description = "Flux SSE d'événements en temps réel", body = String)),
     security(())
 )]
-/// SSE: diffuse les événements en temps réel (topic facultatif)
+/// SSE: diffuse les événements en temps réel (topic facultatif, coalescence opt-in)
 async fn sse_events(
     State(state): State<GatewayState>,
     Query(params): Query<HashMap<String, String>>,
-) -> Sse<impl futures_core::Stream<Item = Result<Event, std::convert::Infallible>>> {
-    let mut rx: broadcast::Receiver<String> = state.event_tx.subscribe();
+) -> Sse<std::pin::Pin<Box<dyn futures_core::Stream<Item = Result<Event, std::convert::Infallible>> + Send>>> {
     let topic_filter = params.get("topic").cloned();
 
+    // Session résumable : rejeu des événements manqués puis flux en direct.
+    // Chaque événement porte un jeton de reprise dans son champ `id`.
+    let resume = params.get("resume").and_then(|t| sse::parse_resume_token(t));
+    if resume.is_some() || params.get("session").map(|v| v == "new").unwrap_or(false) {
+        let (session, last_seen) = resume.unwrap_or_else(|| {
+            (state.sessions.open_session(state.event_tx.subscribe()), 0)
+        });
+        let events = sse::follow_session(state.sessions.clone(), session, last_seen);
+        let stream = ReceiverStream::new(events)
+            .map(|(id, payload)| Ok::<Event, Infallible>(Event::default().id(id).data(payload)));
+        return Sse::new(Box::pin(stream));
+    }
+
+    let rx: broadcast::Receiver<String> = state.event_tx.subscribe();
+
+    // Coalescence opt-in : les événements d'une fenêtre partent en un seul lot
+    if let Some(window_ms) = params.get("coalesce_ms").and_then(|v| v.parse::<u64>().ok()) {
+        if window_ms > 0 {
+            let batches = sse::spawn_coalescer(
+                rx,
+                topic_filter,
+                Duration::from_millis(window_ms),
+            );
+            let stream = ReceiverStream::new(batches)
+                .map(|frame| Ok::<Event, Infallible>(Event::default().data(frame)));
+            return Sse::new(Box::pin(stream));
+        }
+    }
+
     let stream = BroadcastStream::new(rx)
         .filter_map(move |msg: Result<String, BroadcastStreamRecvError>| {
             let topic_filter = topic_filter.clone();
             match msg {
                 Ok(text) => {
-                    if let Some(ref tf) = topic_filter {
-                        // Si un topic est demandé, on filtre côté client
-                        // Ici on attend un champ JSON "topic"
-                        let pass = serde_json::from_str::<serde_json::Value>(&text)
-                            .ok()
-                            .and_then(|v| v.get("topic").and_then(|t| t.as_str()).map(|s| s == tf))
-                            .unwrap_or(true);
-                        if !pass { return None; }
+                    // Si un topic est demandé, on filtre côté serveur
+                    // Ici on attend un champ JSON "topic"
+                    if !sse::passes_topic_filter(&text, topic_filter.as_deref()) {
+                        return None;
                     }
                     Some(Event::default().data(text))
                 }
@@ -621,7 +1012,24 @@ async fn sse_events(
         })
         .map(|evt| Ok::<Event, Infallible>(evt));
 
-    Sse::new(stream)
+    Sse::new(Box::pin(stream))
+}
+
+/// WebSocket bidirectionnel pour les sessions consciousness interactives
+///
+/// Contrairement au SSE (unidirectionnel), la même connexion porte la
+/// soumission des requêtes, les chunks streamés et les annulations
+/// mi-génération — voir le protocole dans le module [`ws`]. Hors OpenAPI :
+/// l'upgrade WebSocket ne se décrit pas en 3.0.
+async fn ws_consciousness(
+    State(state): State<GatewayState>,
+    ws: axum::extract::ws::WebSocketUpgrade,
+) -> axum::response::Response {
+    let source: Arc<dyn ws::StreamSource> = Arc::new(ws::EngineStreamSource::new(
+        state.http_client.clone(),
+        state.config.consciousness_engine_url.clone(),
+    ));
+    ws.on_upgrade(move |socket| ws::run_session(socket, source))
 }
 
 #[utoipa::path(
@@ -692,6 +1100,71 @@ async fn get_metrics(State(state): State<GatewayState>) -> Json<serde_json::Valu
     }))
 }
 
+/// Négociation de capacités : ce que ce déploiement sait faire
+///
+/// Le streaming et les modalités reflètent les feature flags du gateway ;
+/// outils, modèles et contexte maximal sont agrégés depuis le moteur et le
+/// backend LLM quand ils sont joignables. Un amont injoignable laisse les
+/// listes vides : les SDK doivent pouvoir négocier même en mode dégradé.
+#[utoipa::path(
+    get,
+    path = "/api/v1/capabilities",
+    tag = "system",
+    responses((status = 200, description = "Capacités du déploiement", body = Capabilities)),
+    security(())
+)]
+async fn get_capabilities(
+    State(state): State<GatewayState>,
+    Extension(request_id): Extension<RequestId>,
+) -> Json<Capabilities> {
+    state.metrics.increment_total();
+    state.metrics.increment_success();
+
+    let config = &state.config;
+    let mut modalities = vec!["text".to_string()];
+    if config.vision_enabled {
+        modalities.push("vision".to_string());
+    }
+    if config.asr_enabled || config.tts_enabled {
+        modalities.push("audio".to_string());
+    }
+
+    let mut capabilities = Capabilities {
+        streaming: config.streaming_enabled,
+        modalities,
+        tools: Vec::new(),
+        models: Vec::new(),
+        max_context: None,
+    };
+
+    // Outils et fenêtre de contexte : annoncés par le moteur de conscience
+    let url = format!("{}/consciousness/capabilities", config.consciousness_engine_url);
+    if let Ok(response) = state.http_client.get(&url).header(shared::headers::REQUEST_ID, &request_id.0).send().await {
+        if response.status().is_success() {
+            if let Ok(upstream) = response.json::<serde_json::Value>().await {
+                if let Some(tools) = upstream.get("tools").and_then(|v| v.as_array()) {
+                    capabilities.tools = tools.iter().filter_map(|t| t.as_str().map(str::to_string)).collect();
+                }
+                capabilities.max_context = upstream.get("max_context").and_then(|v| v.as_u64());
+            }
+        }
+    }
+
+    // Modèles servis par le backend LLM (même source que /api/v1/llm/models)
+    let url = format!("{}/llm/health", config.agent_orchestrator_url);
+    if let Ok(response) = state.http_client.get(&url).header(shared::headers::REQUEST_ID, &request_id.0).send().await {
+        if response.status().is_success() {
+            if let Ok(upstream) = response.json::<serde_json::Value>().await {
+                if let Some(models) = upstream.get("models").and_then(|v| v.as_array()) {
+                    capabilities.models = models.iter().filter_map(|m| m.as_str().map(str::to_string)).collect();
+                }
+            }
+        }
+    }
+
+    Json(capabilities)
+}
+
 // Consciousness Engine Endpoints
 
 /// Process consciousness request
@@ -716,14 +1189,15 @@ async fn get_metrics(State(state): State<GatewayState>) -> Json<serde_json::Valu
 )]
 async fn process_consciousness(
     State(state): State<GatewayState>,
+    Extension(request_id): Extension<RequestId>,
     Json(request): Json<ConsciousnessRequest>,
 ) -> Result<Json<serde_json::Value>, StatusCode> {
     state.metrics.increment_total();
     state.metrics.increment_consciousness();
-    
+
     let url = format!("{}/consciousness/process", state.config.consciousness_engine_url);
-    
-    match state.http_client.post(&url).json(&request).send().await {
+
+    match state.http_client.post(&url).header(shared::headers::REQUEST_ID, &request_id.0).json(&request).send().await {
         Ok(response) => {
             if response.status().is_success() {
                 state.metrics.increment_success();
@@ -741,7 +1215,19 @@ async fn process_consciousness(
         },
         Err(_) => {
             state.metrics.increment_failure();
-            Err(StatusCode::SERVICE_UNAVAILABLE)
+            // Mode dégradé : si une réponse de secours est configurée, la
+            // renvoyer marquée comme telle plutôt qu'un 503 sec
+            match &state.config.fallback_response {
+                Some(message) => Ok(Json(serde_json::json!({
+                    "request_id": request_id.0,
+                    "content": message,
+                    "degraded": true,
+                    "confidence": FALLBACK_CONFIDENCE,
+                    "retry_suggested": true,
+                    "created_at": chrono::Utc::now(),
+                }))),
+                None => Err(StatusCode::SERVICE_UNAVAILABLE),
+            }
         }
     }
 }
@@ -763,12 +1249,60 @@ async fn process_consciousness(
         (status = 500, description = "Internal error", body = ErrorResponse),
     )
 )]
-async fn get_consciousness_state(State(state): State<GatewayState>) -> Result<Json<serde_json::Value>, StatusCode> {
+async fn get_consciousness_state(
+    State(state): State<GatewayState>,
+    Extension(request_id): Extension<RequestId>,
+) -> Result<Json<serde_json::Value>, StatusCode> {
     state.metrics.increment_total();
-    
+
     let url = format!("{}/consciousness/state", state.config.consciousness_engine_url);
-    
-    match state.http_client.get(&url).send().await {
+
+    match state.http_client.get(&url).header(shared::headers::REQUEST_ID, &request_id.0).send().await {
+        Ok(response) => {
+            if response.status().is_success() {
+                state.metrics.increment_success();
+                match response.json::<serde_json::Value>().await {
+                    Ok(json) => Ok(Json(json)),
+                    Err(_) => {
+                        state.metrics.increment_failure();
+                        Err(StatusCode::INTERNAL_SERVER_ERROR)
+                    }
+                }
+            } else {
+                state.metrics.increment_failure();
+                Err(StatusCode::from_u16(response.status().as_u16()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR))
+            }
+        },
+        Err(_) => {
+            state.metrics.increment_failure();
+            Err(StatusCode::SERVICE_UNAVAILABLE)
+        }
+    }
+}
+
+/// Get consciousness report card
+#[utoipa::path(
+    get,
+    path = "/api/v1/consciousness/report",
+    tag = "consciousness",
+    security(("bearerAuth" = [])),
+    responses(
+        (status = 200, description = "Carte de rapport consolidée (qualité, latences, trajectoire émotionnelle, mémoire, violations éthiques)", body = ReportCard),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 429, description = "Rate limited", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 503, description = "Consciousness Engine indisponible", body = ErrorResponse),
+    )
+)]
+async fn get_consciousness_report(
+    State(state): State<GatewayState>,
+    Extension(request_id): Extension<RequestId>,
+) -> Result<Json<serde_json::Value>, StatusCode> {
+    state.metrics.increment_total();
+
+    let url = format!("{}/consciousness/report", state.config.consciousness_engine_url);
+
+    match state.http_client.get(&url).header(shared::headers::REQUEST_ID, &request_id.0).send().await {
         Ok(response) => {
             if response.status().is_success() {
                 state.metrics.increment_success();
@@ -799,12 +1333,15 @@ async fn get_consciousness_state(State(state): State<GatewayState>) -> Result<Js
     security(("bearerAuth" = [])),
     responses((status = 200, description = "Réflexion générée", body = serde_json::Value))
 )]
-async fn generate_reflection(State(state): State<GatewayState>) -> Result<Json<serde_json::Value>, StatusCode> {
+async fn generate_reflection(
+    State(state): State<GatewayState>,
+    Extension(request_id): Extension<RequestId>,
+) -> Result<Json<serde_json::Value>, StatusCode> {
     state.metrics.increment_total();
-    
+
     let url = format!("{}/consciousness/reflection", state.config.consciousness_engine_url);
-    
-    match state.http_client.post(&url).send().await {
+
+    match state.http_client.post(&url).header(shared::headers::REQUEST_ID, &request_id.0).send().await {
         Ok(response) => {
             if response.status().is_success() {
                 state.metrics.increment_success();
@@ -835,12 +1372,15 @@ async fn generate_reflection(State(state): State<GatewayState>) -> Result<Json<s
     security(("bearerAuth" = [])),
     responses((status = 200, description = "Opportunités de croissance", body = serde_json::Value))
 )]
-async fn get_growth_opportunities(State(state): State<GatewayState>) -> Result<Json<serde_json::Value>, StatusCode> {
+async fn get_growth_opportunities(
+    State(state): State<GatewayState>,
+    Extension(request_id): Extension<RequestId>,
+) -> Result<Json<serde_json::Value>, StatusCode> {
     state.metrics.increment_total();
-    
+
     let url = format!("{}/consciousness/growth", state.config.consciousness_engine_url);
-    
-    match state.http_client.get(&url).send().await {
+
+    match state.http_client.get(&url).header(shared::headers::REQUEST_ID, &request_id.0).send().await {
         Ok(response) => {
             if response.status().is_success() {
                 state.metrics.increment_success();
@@ -874,6 +1414,7 @@ async fn get_growth_opportunities(State(state): State<GatewayState>) -> Result<J
 )]
 async fn explain_response(
     State(state): State<GatewayState>,
+    Extension(request_id): Extension<RequestId>,
     Path(response_id): Path<String>,
     Query(params): Query<HashMap<String, String>>,
 ) -> Result<Json<serde_json::Value>, StatusCode> {
@@ -888,8 +1429,8 @@ async fn explain_response(
             .join("&");
         url.push_str(&format!("?{}", query_string));
     }
-    
-    match state.http_client.get(&url).send().await {
+
+    match state.http_client.get(&url).header(shared::headers::REQUEST_ID, &request_id.0).send().await {
         Ok(response) => {
             if response.status().is_success() {
                 state.metrics.increment_success();
@@ -920,12 +1461,15 @@ async fn explain_response(
     security(("bearerAuth" = [])),
     responses((status = 200, description = "Réinitialisation effectuée", body = serde_json::Value))
 )]
-async fn reset_consciousness(State(state): State<GatewayState>) -> Result<Json<serde_json::Value>, StatusCode> {
+async fn reset_consciousness(
+    State(state): State<GatewayState>,
+    Extension(request_id): Extension<RequestId>,
+) -> Result<Json<serde_json::Value>, StatusCode> {
     state.metrics.increment_total();
-    
+
     let url = format!("{}/consciousness/reset", state.config.consciousness_engine_url);
-    
-    match state.http_client.post(&url).send().await {
+
+    match state.http_client.post(&url).header(shared::headers::REQUEST_ID, &request_id.0).send().await {
         Ok(response) => {
             if response.status().is_success() {
                 state.metrics.increment_success();
@@ -955,24 +1499,54 @@ async fn reset_consciousness(State(state): State<GatewayState>) -> Result<Json<s
     path = "/api/v1/agents",
     tag = "orchestration",
     security(("bearerAuth" = [])),
+    params(
+        ("limit" = Option<u32>, Query, description = "Taille de page (bornée côté serveur)"),
+        ("cursor" = Option<String>, Query, description = "Curseur opaque renvoyé par la page précédente")
+    ),
     responses(
-        (status = 200, description = "Liste des agents", body = AgentListResponse, example = json!({
-            "agents": [],
+        (status = 200, description = "Liste paginée des agents", body = AgentListResponse, example = json!({
+            "items": [],
+            "next_cursor": null,
             "total": 0
         })),
+        (status = 400, description = "Curseur invalide", body = ErrorResponse),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
         (status = 429, description = "Rate limited", body = ErrorResponse),
         (status = 500, description = "Internal error", body = ErrorResponse),
     )
 )]
-async fn list_agents(State(state): State<GatewayState>) -> Json<serde_json::Value> {
+async fn list_agents(
+    State(state): State<GatewayState>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<serde_json::Value>, StatusCode> {
     state.metrics.increment_total();
+
+    let limit = params
+        .get("limit")
+        .and_then(|v| v.parse::<usize>().ok())
+        .unwrap_or(AGENTS_DEFAULT_PAGE_SIZE)
+        .clamp(1, AGENTS_MAX_PAGE_SIZE);
+    let offset = match params.get("cursor") {
+        None => 0,
+        Some(raw) => match raw.parse::<usize>() {
+            Ok(offset) => offset,
+            Err(_) => {
+                state.metrics.increment_failure();
+                return Err(StatusCode::BAD_REQUEST);
+            }
+        },
+    };
+
+    let (items, total) = state.agents.page(offset, limit);
+    let consumed = offset + items.len();
+    let next_cursor = (consumed < total).then(|| consumed.to_string());
+
     state.metrics.increment_success();
-    
-    Json(serde_json::json!({
-        "agents": [],
-        "total": 0
-    }))
+    Ok(Json(serde_json::json!({
+        "items": items,
+        "next_cursor": next_cursor,
+        "total": total
+    })))
 }
 
 #[utoipa::path(
@@ -994,16 +1568,19 @@ async fn list_agents(State(state): State<GatewayState>) -> Json<serde_json::Valu
 )]
 async fn create_agent(
     State(state): State<GatewayState>,
-    Json(_request): Json<serde_json::Value>,
+    Json(request): Json<serde_json::Value>,
 ) -> Json<serde_json::Value> {
     state.metrics.increment_total();
     state.metrics.increment_success();
-    
-    Json(serde_json::json!({
+
+    let agent = serde_json::json!({
         "id": uuid::Uuid::new_v4().to_string(),
         "status": "created",
-        "config": _request
-    }))
+        "config": request
+    });
+    state.agents.insert(agent.clone());
+
+    Json(agent)
 }
 
 #[utoipa::path(
@@ -1233,8 +1810,10 @@ async fn check_compliance(State(state): State<GatewayState>) -> Json<serde_json:
     paths(
         health_check,
         get_metrics,
+        get_capabilities,
         process_consciousness,
         get_consciousness_state,
+        get_consciousness_report,
         generate_reflection,
         update_agent,
         delete_agent,
@@ -1287,6 +1866,292 @@ impl Modify for SecurityAddon {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tower::ServiceExt;
+
+    fn test_state() -> GatewayState {
+        let (tx, _rx) = broadcast::channel(8);
+        GatewayState {
+            config: GatewayConfig {
+                port: 0,
+                consciousness_engine_url: "http://localhost:0".to_string(),
+                agent_orchestrator_url: "http://localhost:0".to_string(),
+                ai_governance_url: "http://localhost:0".to_string(),
+                jwt_secret: "test-secret".to_string(),
+                rate_limit_requests_per_minute: 100,
+                rate_limit_redis_url: None,
+                request_timeout_seconds: 30,
+                auth_optional: true,
+                jwt_audience: None,
+                jwt_issuer: None,
+                fallback_response: Some(DEFAULT_FALLBACK_RESPONSE.to_string()),
+                streaming_enabled: true,
+                vision_enabled: true,
+                asr_enabled: true,
+                tts_enabled: true,
+            },
+            http_client: reqwest::Client::new(),
+            rate_limiter: Arc::new(InMemoryRateLimiter::new()),
+            metrics: Arc::new(GatewayMetrics::default()),
+            event_tx: tx,
+            sessions: sse::SessionStore::new(),
+            agents: AgentRegistry::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn response_carries_generated_request_id_when_absent() {
+        let app = create_gateway_router(test_state());
+        let request = axum::http::Request::builder()
+            .uri("/health")
+            .body(axum::body::Body::empty())
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+
+        let header = response
+            .headers()
+            .get(shared::headers::REQUEST_ID)
+            .expect("x-request-id doit être présent même sans header entrant");
+        assert!(!header.to_str().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn response_echoes_incoming_request_id() {
+        let app = create_gateway_router(test_state());
+        let request = axum::http::Request::builder()
+            .uri("/health")
+            .header(shared::headers::REQUEST_ID, "req-fixed-123")
+            .body(axum::body::Body::empty())
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+
+        assert_eq!(
+            response
+                .headers()
+                .get(shared::headers::REQUEST_ID)
+                .and_then(|h| h.to_str().ok()),
+            Some("req-fixed-123")
+        );
+    }
+
+    #[tokio::test]
+    async fn rate_limit_headers_decrement_then_429_carries_retry_after() {
+        let mut state = test_state();
+        state.config.rate_limit_requests_per_minute = 2;
+        let app = create_gateway_router(state);
+        let make_request = || axum::http::Request::builder()
+            .uri("/api/v1/consciousness/state")
+            .header("x-client-id", "client-test")
+            .body(axum::body::Body::empty())
+            .unwrap();
+
+        // Le quota restant décroît à chaque requête admise
+        for expected_remaining in ["1", "0"] {
+            let response = app.clone().oneshot(make_request()).await.unwrap();
+            assert_ne!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+            let header = |name: &str| response
+                .headers()
+                .get(name)
+                .and_then(|h| h.to_str().ok())
+                .map(|s| s.to_string());
+            assert_eq!(header("x-ratelimit-limit").as_deref(), Some("2"));
+            assert_eq!(header("x-ratelimit-remaining").as_deref(), Some(expected_remaining));
+            let reset: u64 = header("x-ratelimit-reset")
+                .expect("x-ratelimit-reset doit être présent")
+                .parse()
+                .unwrap();
+            assert!(reset <= 60);
+        }
+
+        // Au-delà de la limite : 429 avec Retry-After
+        let response = app.oneshot(make_request()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(
+            response.headers().get("x-ratelimit-remaining").and_then(|h| h.to_str().ok()),
+            Some("0")
+        );
+        let retry_after: u64 = response
+            .headers()
+            .get("retry-after")
+            .and_then(|h| h.to_str().ok())
+            .expect("Retry-After doit être présent sur un 429")
+            .parse()
+            .unwrap();
+        assert!(retry_after >= 1 && retry_after <= 60);
+    }
+
+    fn auth_state() -> GatewayState {
+        let mut state = test_state();
+        state.config.auth_optional = false;
+        state.config.jwt_audience = Some("consciousness-clients".to_string());
+        state
+    }
+
+    fn make_token(claims: serde_json::Value) -> String {
+        jsonwebtoken::encode(
+            &jsonwebtoken::Header::default(),
+            &claims,
+            &jsonwebtoken::EncodingKey::from_secret(b"test-secret"),
+        )
+        .unwrap()
+    }
+
+    fn now_epoch() -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    }
+
+    async fn authed_request(app: axum::Router, token: &str) -> axum::response::Response {
+        let request = axum::http::Request::builder()
+            .uri("/api/v1/consciousness/state")
+            .header("authorization", format!("Bearer {}", token))
+            .body(axum::body::Body::empty())
+            .unwrap();
+        app.oneshot(request).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn expired_token_is_rejected_with_reason() {
+        let app = create_gateway_router(auth_state());
+        let token = make_token(serde_json::json!({
+            "sub": "user-1",
+            "aud": "consciousness-clients",
+            "exp": now_epoch() - 3600,
+        }));
+
+        let response = authed_request(app, &token).await;
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            response.headers().get("x-auth-reason").and_then(|h| h.to_str().ok()),
+            Some("token-expired")
+        );
+    }
+
+    #[tokio::test]
+    async fn wrong_audience_token_is_rejected_with_reason() {
+        let app = create_gateway_router(auth_state());
+        let token = make_token(serde_json::json!({
+            "sub": "user-1",
+            "aud": "autre-service",
+            "exp": now_epoch() + 3600,
+        }));
+
+        let response = authed_request(app, &token).await;
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            response.headers().get("x-auth-reason").and_then(|h| h.to_str().ok()),
+            Some("invalid-audience")
+        );
+    }
+
+    #[tokio::test]
+    async fn valid_token_passes_claim_validation() {
+        let app = create_gateway_router(auth_state());
+        let token = make_token(serde_json::json!({
+            "sub": "user-1",
+            "aud": "consciousness-clients",
+            "exp": now_epoch() + 3600,
+        }));
+
+        let response = authed_request(app, &token).await;
+
+        // Le backend est injoignable mais l'authentification, elle, passe
+        assert_ne!(response.status(), StatusCode::UNAUTHORIZED);
+        assert!(response.headers().get("x-auth-reason").is_none());
+    }
+
+    fn consciousness_request_body() -> axum::body::Body {
+        let request = shared::ConsciousnessRequest {
+            input: "Bonjour, comment vas-tu ?".to_string(),
+            context: None,
+            options: shared::ConsciousnessOptions::default(),
+            request_id: uuid::Uuid::new_v4(),
+        };
+        axum::body::Body::from(serde_json::to_string(&request).unwrap())
+    }
+
+    #[tokio::test]
+    async fn backend_down_returns_degraded_fallback_response() {
+        // L'URL du moteur pointe vers un port fermé : l'appel échoue
+        let app = create_gateway_router(test_state());
+        let request = axum::http::Request::builder()
+            .method("POST")
+            .uri("/api/v1/consciousness/process")
+            .header("content-type", "application/json")
+            .body(consciousness_request_body())
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["degraded"], serde_json::json!(true));
+        assert_eq!(json["retry_suggested"], serde_json::json!(true));
+        assert!(json["confidence"].as_f64().unwrap() < 0.5);
+        assert_eq!(json["content"], serde_json::json!(DEFAULT_FALLBACK_RESPONSE));
+    }
+
+    #[tokio::test]
+    async fn backend_down_without_fallback_keeps_the_503() {
+        let mut state = test_state();
+        state.config.fallback_response = None;
+        let app = create_gateway_router(state);
+        let request = axum::http::Request::builder()
+            .method("POST")
+            .uri("/api/v1/consciousness/process")
+            .header("content-type", "application/json")
+            .body(consciousness_request_body())
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    async fn fetch_capabilities(app: axum::Router) -> Capabilities {
+        let request = axum::http::Request::builder()
+            .uri("/api/v1/capabilities")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[tokio::test]
+    async fn capabilities_reflect_enabled_feature_flags() {
+        // Tout activé : streaming annoncé, les trois modalités présentes
+        let capabilities = fetch_capabilities(create_gateway_router(test_state())).await;
+        assert!(capabilities.streaming);
+        for modality in ["text", "vision", "audio"] {
+            assert!(
+                capabilities.modalities.iter().any(|m| m == modality),
+                "modalité manquante: {}", modality
+            );
+        }
+
+        // Flags coupés : seuls text (toujours) et audio (TTS encore actif) restent
+        let mut state = test_state();
+        state.config.streaming_enabled = false;
+        state.config.vision_enabled = false;
+        state.config.asr_enabled = false;
+        let capabilities = fetch_capabilities(create_gateway_router(state)).await;
+        assert!(!capabilities.streaming);
+        assert!(capabilities.modalities.iter().any(|m| m == "text"));
+        assert!(capabilities.modalities.iter().any(|m| m == "audio"));
+        assert!(!capabilities.modalities.iter().any(|m| m == "vision"));
+
+        // Amonts injoignables : agrégation dégradée mais réponse bien formée
+        assert!(capabilities.tools.is_empty());
+        assert!(capabilities.models.is_empty());
+        assert!(capabilities.max_context.is_none());
+    }
 
     #[test]
     fn openapi_contains_core_schemas() {
@@ -1304,54 +2169,169 @@ mod tests {
             "ErrorResponse",
             "ConsciousnessState",
             "AgentSummary",
+            "ReportCard",
+            "LatencyPercentiles",
         ] {
             assert!(schemas.get(key).is_some(), "schema manquant: {}", key);
         }
     }
+
+    #[tokio::test]
+    async fn agent_listing_pages_without_gaps_or_duplicates() {
+        let app = create_gateway_router(test_state());
+
+        // Créer cinq agents via l'endpoint, en gardant leurs identifiants
+        let mut created = Vec::new();
+        for i in 0..5 {
+            let request = axum::http::Request::builder()
+                .method("POST")
+                .uri("/api/v1/agents")
+                .header("content-type", "application/json")
+                .body(axum::body::Body::from(format!(r#"{{"type":"reactive","n":{}}}"#, i)))
+                .unwrap();
+            let response = app.clone().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            let agent: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            created.push(agent["id"].as_str().unwrap().to_string());
+        }
+
+        // Parcourir les pages de taille 2 jusqu'à épuisement du curseur
+        let mut seen = Vec::new();
+        let mut cursor: Option<String> = None;
+        let mut pages = 0;
+        loop {
+            let uri = match &cursor {
+                Some(c) => format!("/api/v1/agents?limit=2&cursor={}", c),
+                None => "/api/v1/agents?limit=2".to_string(),
+            };
+            let request = axum::http::Request::builder()
+                .uri(uri)
+                .body(axum::body::Body::empty())
+                .unwrap();
+            let response = app.clone().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            let page: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+            pages += 1;
+            assert_eq!(page["total"], 5);
+            for item in page["items"].as_array().unwrap() {
+                seen.push(item["id"].as_str().unwrap().to_string());
+            }
+            match page["next_cursor"].as_str() {
+                Some(next) => cursor = Some(next.to_string()),
+                None => break,
+            }
+        }
+
+        // Ni trou ni doublon : la concaténation des pages rend la liste exacte
+        assert_eq!(pages, 3);
+        assert_eq!(seen, created);
+
+        // Un curseur illisible est une erreur client, pas une première page
+        let request = axum::http::Request::builder()
+            .uri("/api/v1/agents?cursor=pas-un-nombre")
+            .body(axum::body::Body::empty())
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    /// Source de variables en mémoire pour tester `from_lookup` sans toucher
+    /// à l'environnement global du processus de test
+    fn lookup_from(pairs: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> + '_ {
+        move |name| {
+            pairs
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        }
+    }
+
+    #[test]
+    fn config_falls_back_to_dev_defaults_when_nothing_is_set() {
+        let config = GatewayConfig::from_lookup(lookup_from(&[])).expect("config dev");
+
+        assert_eq!(config.port, 3000);
+        assert_eq!(config.jwt_secret, DEFAULT_JWT_SECRET);
+        assert_eq!(config.rate_limit_requests_per_minute, 100);
+        assert!(config.auth_optional);
+        assert!(config.streaming_enabled);
+    }
+
+    #[test]
+    fn config_rejects_an_unparseable_port_with_a_descriptive_error() {
+        let error = GatewayConfig::from_lookup(lookup_from(&[("PORT", "not-a-port")]))
+            .expect_err("un port invalide doit être refusé, pas remplacé par 3000");
+
+        let message = error.to_string();
+        assert!(message.contains("PORT"), "erreur sans nom de variable: {}", message);
+        assert!(message.contains("not-a-port"), "erreur sans valeur fautive: {}", message);
+    }
+
+    #[test]
+    fn config_rejects_a_garbled_feature_flag() {
+        let error = GatewayConfig::from_lookup(lookup_from(&[("STREAMING_ENABLED", "oui")]))
+            .expect_err("un flag illisible doit être refusé");
+
+        assert!(error.to_string().contains("STREAMING_ENABLED"));
+    }
+
+    #[test]
+    fn config_refuses_the_default_jwt_secret_outside_development() {
+        // Secret absent en production: démarrage refusé
+        let error = GatewayConfig::from_lookup(lookup_from(&[("ENVIRONMENT", "production")]))
+            .expect_err("pas de secret de repli en production");
+        assert!(error.to_string().contains("JWT_SECRET"));
+
+        // Secret de développement explicitement posé en production: refusé aussi
+        let error = GatewayConfig::from_lookup(lookup_from(&[
+            ("ENVIRONMENT", "production"),
+            ("JWT_SECRET", DEFAULT_JWT_SECRET),
+        ]))
+        .expect_err("le secret de développement ne doit pas passer en production");
+        assert!(error.to_string().contains("JWT_SECRET"));
+
+        // Un vrai secret débloque le démarrage
+        let config = GatewayConfig::from_lookup(lookup_from(&[
+            ("ENVIRONMENT", "production"),
+            ("JWT_SECRET", "un-secret-de-production"),
+        ]))
+        .expect("config production valide");
+        assert_eq!(config.jwt_secret, "un-secret-de-production");
+    }
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Initialize tracing
     tracing_subscriber::fmt::init();
-    
-    // Configuration
-    let config = GatewayConfig {
-        port: std::env::var("PORT")
-            .unwrap_or_else(|_| "3000".to_string())
-            .parse()
-            .unwrap_or(3000),
-        consciousness_engine_url: std::env::var("CONSCIOUSNESS_ENGINE_URL")
-            .unwrap_or_else(|_| "http://localhost:8080".to_string()),
-        agent_orchestrator_url: std::env::var("AGENT_ORCHESTRATOR_URL")
-            .unwrap_or_else(|_| "http://localhost:8081".to_string()),
-        ai_governance_url: std::env::var("AI_GOVERNANCE_URL")
-            .unwrap_or_else(|_| "http://localhost:8082".to_string()),
-        jwt_secret: std::env::var("JWT_SECRET")
-            .unwrap_or_else(|_| "default-secret-key".to_string()),
-        rate_limit_requests_per_minute: std::env::var("RATE_LIMIT_PER_MINUTE")
-            .ok()
-            .and_then(|v| v.parse::<u32>().ok())
-            .unwrap_or(100),
-        request_timeout_seconds: 30,
-        auth_optional: std::env::var("AUTH_OPTIONAL")
-            .ok()
-            .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
-            .unwrap_or(true),
+
+    // Configuration validée: refuse de démarrer plutôt que de servir
+    // avec des valeurs silencieusement erronées
+    let config = match GatewayConfig::from_env() {
+        Ok(config) => config,
+        Err(error) => {
+            eprintln!("❌ Configuration invalide: {}", error);
+            return Err(error.into());
+        }
     };
-    
+
     // Create shared state
     let state = GatewayState {
         config: config.clone(),
         http_client: reqwest::Client::new(),
-        rate_limiter: Arc::new(Mutex::new(RateLimiter::new())),
+        rate_limiter: build_rate_limiter(&config),
         metrics: Arc::new(GatewayMetrics::default()),
         event_tx: {
             let (tx, _rx) = broadcast::channel(100);
             tx
         },
+        sessions: sse::SessionStore::new(),
+        agents: AgentRegistry::default(),
     };
-    
+
     // Create router
     let app = create_gateway_router(state);
     
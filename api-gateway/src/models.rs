@@ -23,6 +23,31 @@ pub struct MetricsResponse {
     pub consciousness_requests: u64,
 }
 
+/// Capacités négociables d'un déploiement du gateway.
+/// Les SDK interrogent ce contrat au démarrage pour adapter leur comportement
+/// (streaming, modalités, outils) au lieu de découvrir les limites par
+/// essais-erreurs.
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[schema(example = json!({
+    "streaming": true,
+    "modalities": ["text", "vision", "audio"],
+    "tools": ["calculator", "web_search"],
+    "models": ["llama3:8b"],
+    "max_context": 8192
+}))]
+pub struct Capabilities {
+    /// Streaming (SSE / WebSocket) annoncé sur ce déploiement
+    pub streaming: bool,
+    /// Modalités supportées ("text", "vision", "audio")
+    pub modalities: Vec<String>,
+    /// Outils exposés par le moteur (vide si injoignable)
+    pub tools: Vec<String>,
+    /// Modèles servis par le backend LLM (vide si injoignable)
+    pub models: Vec<String>,
+    /// Fenêtre de contexte maximale en tokens, si connue
+    pub max_context: Option<u64>,
+}
+
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
 #[schema(example = json!({
     "content": "Evaluate consciousness metrics for input...",
@@ -35,6 +60,15 @@ pub struct ConsciousnessRequest {
     pub user_id: String,
     pub preferences: Option<HashMap<String, String>>,
     pub metadata: Option<HashMap<String, String>>,
+    /// Modèle à utiliser pour cette requête (défaut: modèle du service)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// Température d'échantillonnage (0.0 à 2.0, bornée côté service)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f64>,
+    /// Plafond de tokens générés pour cette requête
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
 }
 
 /// Résultat de traitement de conscience, incluant label, score et raisonnement.
@@ -127,6 +161,42 @@ pub struct ComplianceStatus {
     pub last_check: String,
 }
 
+/// Carte de rapport consolidée du Consciousness Engine.
+/// Agrège qualité, latences, trajectoire émotionnelle, mémoire et violations éthiques
+/// en une seule vue opérateur.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReportCard {
+    /// Résumé de qualité et de débit
+    pub quality_metrics: QualityMetricsSummary,
+    /// Percentiles de latence sur la fenêtre récente
+    pub latency_percentiles: LatencyPercentiles,
+    /// Émotions primaires traversées, de la plus ancienne à la plus récente
+    pub emotional_trajectory: Vec<String>,
+    /// Statistiques d'utilisation mémoire du moteur
+    #[schema(value_type = Object)]
+    pub memory_stats: serde_json::Value,
+    /// Descriptions des violations éthiques récentes
+    pub recent_ethical_violations: Vec<String>,
+}
+
+/// Résumé de qualité et de débit du Consciousness Engine.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct QualityMetricsSummary {
+    pub total_interactions: u64,
+    pub avg_consciousness_quality: f64,
+    pub success_rate: f64,
+    pub cache_hits: u64,
+    pub recall_hit_rate: f64,
+}
+
+/// Percentiles de latence, en millisecondes.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LatencyPercentiles {
+    pub p50_ms: f64,
+    pub p90_ms: f64,
+    pub p99_ms: f64,
+}
+
 /// Format standardisé pour les erreurs applicatives exposées par l'API Gateway.
 #[derive(Debug, Serialize, ToSchema)]
 pub struct ErrorResponse {
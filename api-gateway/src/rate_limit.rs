@@ -0,0 +1,262 @@
+//! Rate limiting par client avec backend interchangeable
+//!
+//! Le limiteur historique était en mémoire par instance : avec N réplicas du
+//! gateway, la limite effective était multipliée par N. Le trait
+//! [`RateLimiterBackend`] conserve l'implémentation mémoire pour le mono
+//! instance et ajoute un backend Redis (fenêtre glissante via script Lua,
+//! donc atomique) partagé entre réplicas, sélectionnable via
+//! `RATE_LIMIT_REDIS_URL`.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Erreurs du backend de rate limiting
+#[derive(Debug, thiserror::Error)]
+pub enum RateLimitError {
+    /// Échec de communication avec le backend partagé
+    #[error("échec du backend de rate limiting: {0}")]
+    Backend(String),
+}
+
+/// Décision du limiteur pour une requête
+///
+/// Porte, en plus de l'admission, tout ce qu'il faut pour exposer les
+/// en-têtes `X-RateLimit-*` au client : limite, quota restant et délai
+/// avant réinitialisation de la fenêtre.
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimitDecision {
+    /// La requête est-elle admise ?
+    pub admitted: bool,
+
+    /// Limite de la fenêtre courante
+    pub limit: u32,
+
+    /// Quota restant après cette requête
+    pub remaining: u32,
+
+    /// Temps restant avant la réinitialisation de la fenêtre
+    pub reset_after: Duration,
+}
+
+/// Backend de rate limiting par client
+#[async_trait]
+pub trait RateLimiterBackend: Send + Sync {
+    /// Décider si la requête de `client_id` est admise sous `limit_per_minute`
+    ///
+    /// Une décision admise consomme une unité de quota ; une décision
+    /// refusée signifie que la limite est atteinte pour la fenêtre courante.
+    async fn check_rate_limit(&self, client_id: &str, limit_per_minute: u32) -> Result<RateLimitDecision, RateLimitError>;
+}
+
+/// Limiteur en mémoire, par instance (comportement historique)
+///
+/// Fenêtre fixe d'une minute remise à zéro globalement, comme l'ancien
+/// `RateLimiter` du gateway. Suffisant en mono-instance ou en dev.
+pub struct InMemoryRateLimiter {
+    inner: Mutex<InMemoryWindow>,
+}
+
+struct InMemoryWindow {
+    window_start: Instant,
+    counts: HashMap<String, u32>,
+}
+
+impl InMemoryRateLimiter {
+    pub fn new() -> Self {
+        Self {
+            inner: Mutex::new(InMemoryWindow {
+                window_start: Instant::now(),
+                counts: HashMap::new(),
+            }),
+        }
+    }
+}
+
+#[async_trait]
+impl RateLimiterBackend for InMemoryRateLimiter {
+    async fn check_rate_limit(&self, client_id: &str, limit_per_minute: u32) -> Result<RateLimitDecision, RateLimitError> {
+        let mut window = self
+            .inner
+            .lock()
+            .map_err(|_| RateLimitError::Backend("mutex du limiteur empoisonné".to_string()))?;
+
+        // reset window every minute
+        if window.window_start.elapsed() >= Duration::from_secs(60) {
+            window.window_start = Instant::now();
+            window.counts.clear();
+        }
+        let reset_after = Duration::from_secs(60)
+            .saturating_sub(window.window_start.elapsed());
+
+        let entry = window.counts.entry(client_id.to_string()).or_insert(0);
+        if *entry >= limit_per_minute {
+            return Ok(RateLimitDecision {
+                admitted: false,
+                limit: limit_per_minute,
+                remaining: 0,
+                reset_after,
+            });
+        }
+        *entry += 1;
+        Ok(RateLimitDecision {
+            admitted: true,
+            limit: limit_per_minute,
+            remaining: limit_per_minute - *entry,
+            reset_after,
+        })
+    }
+}
+
+/// Script Lua de fenêtre glissante : purge, comptage et insertion atomiques
+///
+/// KEYS[1] = clé du client ; ARGV = limite, fenêtre en ms, horodatage en ms,
+/// membre unique. Retourne `{admission, comptage après décision, score du
+/// membre le plus ancien}` pour alimenter les en-têtes `X-RateLimit-*`.
+const SLIDING_WINDOW_SCRIPT: &str = r#"
+local key = KEYS[1]
+local limit = tonumber(ARGV[1])
+local window_ms = tonumber(ARGV[2])
+local now_ms = tonumber(ARGV[3])
+local member = ARGV[4]
+redis.call('ZREMRANGEBYSCORE', key, 0, now_ms - window_ms)
+local count = redis.call('ZCARD', key)
+local admitted = 0
+if count < limit then
+    redis.call('ZADD', key, now_ms, member)
+    redis.call('PEXPIRE', key, window_ms)
+    admitted = 1
+    count = count + 1
+end
+local oldest = redis.call('ZRANGE', key, 0, 0, 'WITHSCORES')
+local oldest_ms = now_ms
+if oldest[2] then
+    oldest_ms = tonumber(oldest[2])
+end
+return {admitted, count, oldest_ms}
+"#;
+
+/// Limiteur distribué sur Redis, partagé entre réplicas du gateway
+///
+/// Chaque requête admise est un membre d'un sorted set horodaté ; la fenêtre
+/// glissante d'une minute est purgée et vérifiée dans un script Lua, donc la
+/// limite tient même quand plusieurs instances vérifient en concurrence.
+pub struct RedisRateLimiter {
+    client: redis::Client,
+    script: redis::Script,
+}
+
+impl RedisRateLimiter {
+    /// Créer un limiteur sur l'instance Redis à `url`
+    pub fn new(url: &str) -> Result<Self, RateLimitError> {
+        let client = redis::Client::open(url)
+            .map_err(|e| RateLimitError::Backend(format!("URL Redis invalide: {}", e)))?;
+
+        Ok(Self {
+            client,
+            script: redis::Script::new(SLIDING_WINDOW_SCRIPT),
+        })
+    }
+}
+
+#[async_trait]
+impl RateLimiterBackend for RedisRateLimiter {
+    async fn check_rate_limit(&self, client_id: &str, limit_per_minute: u32) -> Result<RateLimitDecision, RateLimitError> {
+        let mut connection = self
+            .client
+            .get_multiplexed_async_connection()
+            .await
+            .map_err(|e| RateLimitError::Backend(format!("connexion Redis: {}", e)))?;
+
+        let now_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64;
+
+        let (admitted, count, oldest_ms): (i64, i64, u64) = self
+            .script
+            .key(format!("gateway:rate:{}", client_id))
+            .arg(limit_per_minute)
+            .arg(60_000u64)
+            .arg(now_ms)
+            .arg(uuid::Uuid::new_v4().to_string())
+            .invoke_async(&mut connection)
+            .await
+            .map_err(|e| RateLimitError::Backend(format!("script Redis: {}", e)))?;
+
+        // La fenêtre glissante se libère quand le membre le plus ancien sort
+        let reset_after = Duration::from_millis(
+            60_000u64.saturating_sub(now_ms.saturating_sub(oldest_ms)),
+        );
+        Ok(RateLimitDecision {
+            admitted: admitted == 1,
+            limit: limit_per_minute,
+            remaining: (limit_per_minute as i64 - count).max(0) as u32,
+            reset_after,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_in_memory_limit_is_per_client() {
+        let limiter = InMemoryRateLimiter::new();
+
+        for _ in 0..3 {
+            assert!(limiter.check_rate_limit("client-a", 3).await.unwrap().admitted);
+        }
+        assert!(!limiter.check_rate_limit("client-a", 3).await.unwrap().admitted);
+
+        // Un autre client a son propre quota
+        assert!(limiter.check_rate_limit("client-b", 3).await.unwrap().admitted);
+    }
+
+    #[tokio::test]
+    async fn test_decision_exposes_decreasing_quota_and_reset_delay() {
+        let limiter = InMemoryRateLimiter::new();
+
+        for expected_remaining in [2, 1, 0] {
+            let decision = limiter.check_rate_limit("client-a", 3).await.unwrap();
+            assert!(decision.admitted);
+            assert_eq!(decision.limit, 3);
+            assert_eq!(decision.remaining, expected_remaining);
+            assert!(decision.reset_after <= Duration::from_secs(60));
+            assert!(decision.reset_after > Duration::ZERO);
+        }
+
+        let denied = limiter.check_rate_limit("client-a", 3).await.unwrap();
+        assert!(!denied.admitted);
+        assert_eq!(denied.remaining, 0);
+    }
+
+    /// Nécessite une instance Redis de test ; positionner `REDIS_TEST_URL`
+    /// (ex. `redis://127.0.0.1:6379`) pour l'exécuter.
+    #[tokio::test]
+    async fn test_limit_holds_across_two_limiter_instances() {
+        let Ok(url) = std::env::var("REDIS_TEST_URL") else {
+            eprintln!("REDIS_TEST_URL absent, test Redis ignoré");
+            return;
+        };
+
+        let first = RedisRateLimiter::new(&url).unwrap();
+        let second = RedisRateLimiter::new(&url).unwrap();
+        let client_id = format!("test-{}", uuid::Uuid::new_v4());
+
+        // Deux instances partageant le backend : le quota est global
+        let mut admitted = 0;
+        for i in 0..10 {
+            let limiter: &dyn RateLimiterBackend = if i % 2 == 0 { &first } else { &second };
+            if limiter.check_rate_limit(&client_id, 5).await.unwrap().admitted {
+                admitted += 1;
+            }
+        }
+
+        assert_eq!(admitted, 5);
+        assert!(!first.check_rate_limit(&client_id, 5).await.unwrap().admitted);
+        assert!(!second.check_rate_limit(&client_id, 5).await.unwrap().admitted);
+    }
+}
@@ -0,0 +1,506 @@
+//! Coalescence d'événements SSE pour les clients lents
+//!
+//! Les événements haute fréquence (tokens) peuvent saturer un client. Quand
+//! l'abonné opte pour la coalescence (`?coalesce_ms=...`), les événements émis
+//! dans une petite fenêtre sont regroupés et envoyés en un seul lot encadré
+//! (tableau JSON), en préservant l'ordre d'émission.
+//!
+//! Le module porte aussi les sessions résumables : une session ouverte via
+//! `?session=new` enregistre les événements dans un tampon circulaire borné,
+//! et un client qui se reconnecte avec `?resume=<jeton>` rejoue les
+//! événements manqués depuis son dernier identifiant vu avant de reprendre
+//! le flux en direct — aucune perte sur une déconnexion transitoire.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::{broadcast, mpsc};
+use tokio::time::timeout;
+
+/// Taille maximale d'un lot, indépendamment de la fenêtre
+pub const MAX_BATCH_SIZE: usize = 64;
+
+/// Fenêtre de coalescence maximale acceptée
+pub const MAX_COALESCE_WINDOW: Duration = Duration::from_millis(1_000);
+
+/// Un événement passe-t-il le filtre de topic ?
+///
+/// Même sémantique que le filtrage historique de `sse_events` : on lit le
+/// champ JSON "topic" et un événement non parsable passe toujours.
+pub fn passes_topic_filter(text: &str, topic_filter: Option<&str>) -> bool {
+    match topic_filter {
+        None => true,
+        Some(tf) => serde_json::from_str::<serde_json::Value>(text)
+            .ok()
+            .and_then(|v| v.get("topic").and_then(|t| t.as_str()).map(|s| s == tf))
+            .unwrap_or(true),
+    }
+}
+
+/// Encadrer un lot d'événements en un unique tableau JSON
+///
+/// Chaque événement déjà JSON est inséré tel quel ; un payload non JSON est
+/// encapsulé comme chaîne pour que le lot reste parsable.
+pub fn frame_batch(events: &[String]) -> String {
+    let values: Vec<serde_json::Value> = events
+        .iter()
+        .map(|e| {
+            serde_json::from_str(e).unwrap_or_else(|_| serde_json::Value::String(e.clone()))
+        })
+        .collect();
+    serde_json::Value::Array(values).to_string()
+}
+
+/// Démarre une tâche de coalescence pour un abonné
+///
+/// Les événements reçus sur `rx` (et passant le filtre de topic) sont
+/// tamponnés : le premier événement ouvre une fenêtre de `window`, puis le
+/// lot est encadré via [`frame_batch`] et poussé sur le canal retourné. Un
+/// lot part aussi dès qu'il atteint [`MAX_BATCH_SIZE`]. La tâche s'arrête
+/// quand l'émetteur broadcast est fermé ou que l'abonné décroche.
+pub fn spawn_coalescer(
+    mut rx: broadcast::Receiver<String>,
+    topic_filter: Option<String>,
+    window: Duration,
+) -> mpsc::Receiver<String> {
+    let window = window.min(MAX_COALESCE_WINDOW);
+    let (batch_tx, batch_rx) = mpsc::channel::<String>(32);
+
+    tokio::spawn(async move {
+        loop {
+            // Attendre le premier événement du prochain lot
+            let first = loop {
+                match rx.recv().await {
+                    Ok(text) => {
+                        if passes_topic_filter(&text, topic_filter.as_deref()) {
+                            break text;
+                        }
+                    }
+                    // Abonné en retard : on continue avec les événements restants
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => return,
+                }
+            };
+
+            let mut batch = vec![first];
+            let deadline = tokio::time::Instant::now() + window;
+
+            // Remplir le lot jusqu'à la fin de la fenêtre ou la taille max
+            while batch.len() < MAX_BATCH_SIZE {
+                let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+                if remaining.is_zero() {
+                    break;
+                }
+                match timeout(remaining, rx.recv()).await {
+                    Ok(Ok(text)) => {
+                        if passes_topic_filter(&text, topic_filter.as_deref()) {
+                            batch.push(text);
+                        }
+                    }
+                    Ok(Err(broadcast::error::RecvError::Lagged(_))) => continue,
+                    Ok(Err(broadcast::error::RecvError::Closed)) => {
+                        let _ = batch_tx.send(frame_batch(&batch)).await;
+                        return;
+                    }
+                    Err(_) => break, // fenêtre écoulée
+                }
+            }
+
+            if batch_tx.send(frame_batch(&batch)).await.is_err() {
+                return; // abonné parti
+            }
+        }
+    });
+
+    batch_rx
+}
+
+/// Capacité du tampon circulaire de chaque session
+pub const SESSION_BUFFER_CAPACITY: usize = 256;
+
+/// Période de relecture du tampon par un abonné connecté
+const SESSION_POLL_INTERVAL: Duration = Duration::from_millis(25);
+
+/// Inactivité maximale d'une session avant expiration
+///
+/// Un client qui décroche sans fermer sa session (le cas nominal de la
+/// résumabilité) laisserait sinon son tampon et son enregistreur en vie
+/// pour toujours — croissance mémoire non bornée par session ouverte.
+pub const SESSION_IDLE_TTL: Duration = Duration::from_secs(15 * 60);
+
+/// Tampon circulaire d'une session : (numéro de séquence, payload)
+struct SessionBuffer {
+    ring: VecDeque<(u64, String)>,
+    next_seq: u64,
+
+    /// Dernier enregistrement ou rejeu ; pilote l'expiration par inactivité
+    last_activity: std::time::Instant,
+}
+
+impl SessionBuffer {
+    fn new() -> Self {
+        Self {
+            ring: VecDeque::new(),
+            next_seq: 0,
+            last_activity: std::time::Instant::now(),
+        }
+    }
+}
+
+/// Registre des sessions résumables
+///
+/// Chaque session ouverte enregistre les événements broadcast dans son propre
+/// tampon circulaire (borné à [`SESSION_BUFFER_CAPACITY`]), que le client soit
+/// connecté ou non — c'est ce qui permet le rejeu après une coupure. Une
+/// session sans activité (ni événement enregistré, ni rejeu) pendant plus de
+/// `idle_ttl` est balayée et son enregistreur s'arrête.
+#[derive(Clone)]
+pub struct SessionStore {
+    sessions: Arc<Mutex<HashMap<String, SessionBuffer>>>,
+    idle_ttl: Duration,
+}
+
+impl Default for SessionStore {
+    fn default() -> Self {
+        Self::with_idle_ttl(SESSION_IDLE_TTL)
+    }
+}
+
+impl SessionStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registre avec une durée d'inactivité maximale explicite
+    pub fn with_idle_ttl(idle_ttl: Duration) -> Self {
+        Self {
+            sessions: Arc::new(Mutex::new(HashMap::new())),
+            idle_ttl,
+        }
+    }
+
+    /// Ouvrir une session et démarrer son enregistreur
+    ///
+    /// Retourne le jeton opaque de la session. Une tâche de fond copie chaque
+    /// événement reçu sur `rx` dans le tampon de la session ; elle s'arrête
+    /// quand l'émetteur broadcast est fermé, la session supprimée, ou la
+    /// session expirée par inactivité.
+    pub fn open_session(&self, mut rx: broadcast::Receiver<String>) -> String {
+        // Chaque ouverture balaie les sessions abandonnées
+        self.sweep_stale();
+
+        let token = uuid::Uuid::new_v4().to_string();
+        self.sessions
+            .lock()
+            .unwrap()
+            .insert(token.clone(), SessionBuffer::new());
+
+        let store = self.clone();
+        let session = token.clone();
+        tokio::spawn(async move {
+            loop {
+                match timeout(store.idle_ttl, rx.recv()).await {
+                    Ok(Ok(text)) => {
+                        if !store.record(&session, text) {
+                            return; // session fermée
+                        }
+                    }
+                    // Enregistreur en retard : on reprend avec ce qui reste
+                    Ok(Err(broadcast::error::RecvError::Lagged(_))) => continue,
+                    Ok(Err(broadcast::error::RecvError::Closed)) => return,
+                    // Aucun événement depuis un TTL : balayer, et s'arrêter
+                    // si cette session a expiré entre-temps
+                    Err(_) => {
+                        store.sweep_stale();
+                        if !store.contains(&session) {
+                            return;
+                        }
+                    }
+                }
+            }
+        });
+
+        token
+    }
+
+    /// Ajouter un événement au tampon d'une session
+    ///
+    /// Retourne `false` si la session n'existe plus. Au-delà de la capacité,
+    /// les événements les plus anciens sont évincés.
+    fn record(&self, session: &str, event: String) -> bool {
+        let mut sessions = self.sessions.lock().unwrap();
+        let Some(buffer) = sessions.get_mut(session) else {
+            return false;
+        };
+
+        buffer.next_seq += 1;
+        buffer.ring.push_back((buffer.next_seq, event));
+        while buffer.ring.len() > SESSION_BUFFER_CAPACITY {
+            buffer.ring.pop_front();
+        }
+        buffer.last_activity = std::time::Instant::now();
+        true
+    }
+
+    /// Événements d'une session postérieurs à `last_seen`, dans l'ordre
+    ///
+    /// `None` si le jeton ne correspond à aucune session (expirée ou forgée).
+    /// Un rejeu compte comme activité : un client connecté maintient sa
+    /// session en vie même sans trafic.
+    pub fn replay_since(&self, session: &str, last_seen: u64) -> Option<Vec<(u64, String)>> {
+        let mut sessions = self.sessions.lock().unwrap();
+        let buffer = sessions.get_mut(session)?;
+        buffer.last_activity = std::time::Instant::now();
+        Some(
+            buffer
+                .ring
+                .iter()
+                .filter(|(seq, _)| *seq > last_seen)
+                .cloned()
+                .collect(),
+        )
+    }
+
+    /// Supprimer une session ; son enregistreur s'arrêtera au prochain événement
+    pub fn close_session(&self, session: &str) {
+        self.sessions.lock().unwrap().remove(session);
+    }
+
+    /// La session existe-t-elle encore ?
+    fn contains(&self, session: &str) -> bool {
+        self.sessions.lock().unwrap().contains_key(session)
+    }
+
+    /// Supprimer toutes les sessions inactives depuis plus que le TTL
+    fn sweep_stale(&self) {
+        self.sessions
+            .lock()
+            .unwrap()
+            .retain(|_, buffer| buffer.last_activity.elapsed() <= self.idle_ttl);
+    }
+}
+
+/// Jeton de reprise opaque : session + dernier identifiant vu
+pub fn resume_token(session: &str, seq: u64) -> String {
+    format!("{}:{}", session, seq)
+}
+
+/// Décoder un jeton de reprise produit par [`resume_token`]
+pub fn parse_resume_token(token: &str) -> Option<(String, u64)> {
+    let (session, seq) = token.rsplit_once(':')?;
+    Some((session.to_string(), seq.parse().ok()?))
+}
+
+/// Suivre une session : rejeu des événements manqués puis flux en direct
+///
+/// Chaque message émis est `(jeton de reprise, payload)` — le client conserve
+/// le dernier jeton reçu et le présente à la reconnexion. La tâche s'arrête
+/// quand l'abonné décroche ou que la session disparaît.
+pub fn follow_session(
+    store: SessionStore,
+    session: String,
+    mut last_seen: u64,
+) -> mpsc::Receiver<(String, String)> {
+    let (tx, rx) = mpsc::channel::<(String, String)>(32);
+
+    tokio::spawn(async move {
+        loop {
+            let Some(pending) = store.replay_since(&session, last_seen) else {
+                return; // session inconnue ou fermée
+            };
+            for (seq, payload) in pending {
+                last_seen = seq;
+                if tx.send((resume_token(&session, seq), payload)).await.is_err() {
+                    return; // abonné parti
+                }
+            }
+            tokio::time::sleep(SESSION_POLL_INTERVAL).await;
+        }
+    });
+
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_rapid_events_are_coalesced_into_fewer_batches() {
+        let (tx, rx) = broadcast::channel::<String>(128);
+        let mut batches = spawn_coalescer(rx, None, Duration::from_millis(50));
+
+        for i in 0..20 {
+            tx.send(format!(r#"{{"topic":"system","seq":{}}}"#, i)).unwrap();
+        }
+        drop(tx);
+
+        let mut batch_count = 0;
+        let mut seen = Vec::new();
+        while let Some(frame) = batches.recv().await {
+            batch_count += 1;
+            let parsed: Vec<serde_json::Value> = serde_json::from_str(&frame).unwrap();
+            for value in parsed {
+                seen.push(value["seq"].as_u64().unwrap());
+            }
+        }
+
+        // Beaucoup moins de messages que d'événements, ordre préservé
+        assert!(batch_count < 20, "expected coalesced batches, got {}", batch_count);
+        assert_eq!(seen, (0..20).collect::<Vec<u64>>());
+    }
+
+    #[tokio::test]
+    async fn test_coalescer_respects_topic_filter() {
+        let (tx, rx) = broadcast::channel::<String>(16);
+        let mut batches = spawn_coalescer(
+            rx,
+            Some("agent:1".to_string()),
+            Duration::from_millis(20),
+        );
+
+        tx.send(r#"{"topic":"agent:1","seq":0}"#.to_string()).unwrap();
+        tx.send(r#"{"topic":"agent:2","seq":1}"#.to_string()).unwrap();
+        tx.send(r#"{"topic":"agent:1","seq":2}"#.to_string()).unwrap();
+        drop(tx);
+
+        let mut seen = Vec::new();
+        while let Some(frame) = batches.recv().await {
+            let parsed: Vec<serde_json::Value> = serde_json::from_str(&frame).unwrap();
+            for value in parsed {
+                seen.push(value["seq"].as_u64().unwrap());
+            }
+        }
+
+        assert_eq!(seen, vec![0, 2]);
+    }
+
+    #[test]
+    fn test_frame_batch_wraps_non_json_payloads() {
+        let frame = frame_batch(&[r#"{"a":1}"#.to_string(), "brut".to_string()]);
+        let parsed: Vec<serde_json::Value> = serde_json::from_str(&frame).unwrap();
+
+        assert_eq!(parsed[0]["a"], 1);
+        assert_eq!(parsed[1], serde_json::Value::String("brut".to_string()));
+    }
+
+    /// Attendre que l'enregistreur ait tamponné au moins `want` événements
+    async fn wait_for_buffered(store: &SessionStore, session: &str, want: usize) {
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(2);
+        loop {
+            let buffered = store.replay_since(session, 0).unwrap().len();
+            if buffered >= want {
+                return;
+            }
+            assert!(
+                tokio::time::Instant::now() < deadline,
+                "seulement {} événements tamponnés sur {}",
+                buffered,
+                want
+            );
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_replays_missed_events() {
+        let (tx, _rx) = broadcast::channel::<String>(64);
+        let store = SessionStore::new();
+        let session = store.open_session(tx.subscribe());
+
+        // Premier abonnement : le client consomme deux événements puis décroche
+        tx.send(r#"{"seq":0}"#.to_string()).unwrap();
+        tx.send(r#"{"seq":1}"#.to_string()).unwrap();
+        wait_for_buffered(&store, &session, 2).await;
+
+        let mut events = follow_session(store.clone(), session.clone(), 0);
+        let (first_token, _) = events.recv().await.unwrap();
+        let (last_token, payload) = events.recv().await.unwrap();
+        assert_ne!(first_token, last_token);
+        assert_eq!(payload, r#"{"seq":1}"#);
+        drop(events); // déconnexion
+
+        // Pendant la coupure, d'autres événements arrivent
+        tx.send(r#"{"seq":2}"#.to_string()).unwrap();
+        tx.send(r#"{"seq":3}"#.to_string()).unwrap();
+        wait_for_buffered(&store, &session, 4).await;
+
+        // Reconnexion avec le dernier jeton vu : les manqués sont rejoués
+        let (resumed_session, last_seen) = parse_resume_token(&last_token).unwrap();
+        assert_eq!(resumed_session, session);
+        let mut events = follow_session(store.clone(), resumed_session, last_seen);
+
+        let (_, payload) = events.recv().await.unwrap();
+        assert_eq!(payload, r#"{"seq":2}"#);
+        let (_, payload) = events.recv().await.unwrap();
+        assert_eq!(payload, r#"{"seq":3}"#);
+    }
+
+    #[tokio::test]
+    async fn test_session_buffer_is_bounded() {
+        let (tx, _rx) = broadcast::channel::<String>(SESSION_BUFFER_CAPACITY + 32);
+        let store = SessionStore::new();
+        let session = store.open_session(tx.subscribe());
+
+        for i in 0..SESSION_BUFFER_CAPACITY + 10 {
+            tx.send(format!(r#"{{"seq":{}}}"#, i)).unwrap();
+        }
+
+        // Attendre que l'enregistreur ait vu le dernier événement émis
+        let last_seq = (SESSION_BUFFER_CAPACITY + 10) as u64;
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(2);
+        while store.replay_since(&session, last_seq - 1).unwrap().is_empty() {
+            assert!(tokio::time::Instant::now() < deadline, "enregistreur en retard");
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        let replay = store.replay_since(&session, 0).unwrap();
+        assert_eq!(replay.len(), SESSION_BUFFER_CAPACITY);
+        // Les plus anciens ont été évincés : le rejeu commence après le trou
+        assert_eq!(replay.first().unwrap().0, 11);
+    }
+
+    #[tokio::test]
+    async fn test_idle_session_expires_and_its_recorder_stops() {
+        let (tx, _rx) = broadcast::channel::<String>(16);
+        let store = SessionStore::with_idle_ttl(Duration::from_millis(50));
+        let session = store.open_session(tx.subscribe());
+
+        tx.send(r#"{"seq":0}"#.to_string()).unwrap();
+        wait_for_buffered(&store, &session, 1).await;
+
+        // Un client connecté (rejeux périodiques) survit au-delà du TTL
+        for _ in 0..6 {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            assert!(store.replay_since(&session, 0).is_some());
+        }
+
+        // Client parti sans fermer : la session expire et l'enregistreur
+        // relâche son abonnement broadcast
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(2);
+        while store.contains(&session) || tx.receiver_count() > 1 {
+            assert!(
+                tokio::time::Instant::now() < deadline,
+                "session ou enregistreur toujours en vie après le TTL"
+            );
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        assert!(store.replay_since(&session, 0).is_none());
+    }
+
+    #[test]
+    fn test_resume_token_round_trip() {
+        let token = resume_token("abc-def", 42);
+        assert_eq!(parse_resume_token(&token), Some(("abc-def".to_string(), 42)));
+        assert_eq!(parse_resume_token("pas-un-jeton"), None);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_session_is_rejected() {
+        let store = SessionStore::new();
+        assert!(store.replay_since("inconnue", 0).is_none());
+
+        let mut events = follow_session(store, "inconnue".to_string(), 0);
+        assert!(events.recv().await.is_none());
+    }
+}
@@ -0,0 +1,266 @@
+//! Synthèse vocale structurée avec négociation de format audio
+//!
+//! Remplace le proxy JSON opaque de `tts_synthesize` : le backend retourne un
+//! [`SynthesizedAudio`] typé (octets, format, fréquence d'échantillonnage,
+//! durée) et le format est négocié façon `Accept` (wav/mp3/opus) pour que les
+//! consommateurs sachent exactement ce qu'ils reçoivent.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Formats audio négociables
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AudioFormat {
+    Wav,
+    Mp3,
+    Opus,
+}
+
+impl AudioFormat {
+    /// Résoudre un nom court ou un type MIME en format
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "wav" | "audio/wav" | "audio/x-wav" => Some(Self::Wav),
+            "mp3" | "audio/mp3" | "audio/mpeg" => Some(Self::Mp3),
+            "opus" | "audio/opus" | "audio/ogg" => Some(Self::Opus),
+            _ => None,
+        }
+    }
+
+    /// Nom court du format
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Wav => "wav",
+            Self::Mp3 => "mp3",
+            Self::Opus => "opus",
+        }
+    }
+
+    /// Type MIME correspondant
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Wav => "audio/wav",
+            Self::Mp3 => "audio/mpeg",
+            Self::Opus => "audio/opus",
+        }
+    }
+}
+
+/// Audio synthétisé avec ses métadonnées
+#[derive(Debug, Clone)]
+pub struct SynthesizedAudio {
+    /// Octets encodés dans `format`
+    pub bytes: Vec<u8>,
+
+    /// Format effectivement produit
+    pub format: AudioFormat,
+
+    /// Fréquence d'échantillonnage en Hz
+    pub sample_rate: u32,
+
+    /// Durée de l'audio
+    pub duration: Duration,
+}
+
+/// Erreurs de synthèse vocale
+#[derive(Debug, thiserror::Error)]
+pub enum TtsError {
+    /// Aucun format demandé n'est supporté par le backend
+    #[error("format audio non supporté: {0}")]
+    UnsupportedFormat(String),
+
+    /// Échec du backend de synthèse
+    #[error("échec du backend TTS: {0}")]
+    Backend(String),
+}
+
+/// Backend de synthèse vocale
+#[async_trait]
+pub trait TtsBackend: Send + Sync {
+    /// Formats que ce backend sait produire
+    fn supported_formats(&self) -> Vec<AudioFormat>;
+
+    /// Synthétiser `text` dans le format demandé
+    async fn synthesize(&self, text: &str, format: AudioFormat) -> Result<SynthesizedAudio, TtsError>;
+}
+
+/// Négocier un format à partir d'une liste façon `Accept`
+///
+/// `accept` est une liste séparée par des virgules de noms courts ou de types
+/// MIME (ex. "audio/opus, mp3"). Le premier format reconnu et supporté gagne ;
+/// une liste vide retombe sur le premier format supporté du backend.
+pub fn negotiate_format(accept: &str, supported: &[AudioFormat]) -> Result<AudioFormat, TtsError> {
+    if accept.trim().is_empty() {
+        return supported.first().copied()
+            .ok_or_else(|| TtsError::UnsupportedFormat("aucun format supporté".to_string()));
+    }
+
+    for candidate in accept.split(',') {
+        // Ignorer les paramètres de qualité ("audio/mpeg;q=0.9")
+        let name = candidate.split(';').next().unwrap_or("").trim();
+        if name == "*/*" {
+            return supported.first().copied()
+                .ok_or_else(|| TtsError::UnsupportedFormat("aucun format supporté".to_string()));
+        }
+        if let Some(format) = AudioFormat::from_name(name) {
+            if supported.contains(&format) {
+                return Ok(format);
+            }
+        }
+    }
+
+    Err(TtsError::UnsupportedFormat(accept.to_string()))
+}
+
+/// Backend HTTP proxifiant l'agent-orchestrator
+pub struct HttpTtsBackend {
+    client: reqwest::Client,
+    base_url: String,
+    request_id: Option<String>,
+}
+
+impl HttpTtsBackend {
+    pub fn new(client: reqwest::Client, base_url: String) -> Self {
+        Self { client, base_url, request_id: None }
+    }
+
+    /// Propager l'identifiant de requête du gateway vers l'amont
+    pub fn with_request_id(mut self, request_id: String) -> Self {
+        self.request_id = Some(request_id);
+        self
+    }
+}
+
+#[async_trait]
+impl TtsBackend for HttpTtsBackend {
+    fn supported_formats(&self) -> Vec<AudioFormat> {
+        vec![AudioFormat::Wav, AudioFormat::Mp3, AudioFormat::Opus]
+    }
+
+    async fn synthesize(&self, text: &str, format: AudioFormat) -> Result<SynthesizedAudio, TtsError> {
+        let url = format!("{}/tts/synthesize", self.base_url);
+        let mut request = self.client
+            .post(&url)
+            .header("accept", format.content_type())
+            .json(&serde_json::json!({ "text": text, "format": format.as_str() }));
+        if let Some(request_id) = &self.request_id {
+            request = request.header(shared::headers::REQUEST_ID, request_id);
+        }
+        let response = request.send().await
+            .map_err(|e| TtsError::Backend(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(TtsError::Backend(format!("statut {}", response.status())));
+        }
+
+        let sample_rate = response.headers()
+            .get("x-sample-rate")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(22_050);
+        let duration_ms = response.headers()
+            .get("x-duration-ms")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(0u64);
+
+        let bytes = response.bytes().await
+            .map_err(|e| TtsError::Backend(e.to_string()))?
+            .to_vec();
+
+        Ok(SynthesizedAudio {
+            bytes,
+            format,
+            sample_rate,
+            duration: Duration::from_millis(duration_ms),
+        })
+    }
+}
+
+/// Backend simulé pour les tests
+pub struct MockTtsBackend {
+    supported: Vec<AudioFormat>,
+}
+
+impl MockTtsBackend {
+    pub fn new(supported: Vec<AudioFormat>) -> Self {
+        Self { supported }
+    }
+}
+
+#[async_trait]
+impl TtsBackend for MockTtsBackend {
+    fn supported_formats(&self) -> Vec<AudioFormat> {
+        self.supported.clone()
+    }
+
+    async fn synthesize(&self, text: &str, format: AudioFormat) -> Result<SynthesizedAudio, TtsError> {
+        if !self.supported.contains(&format) {
+            return Err(TtsError::UnsupportedFormat(format.as_str().to_string()));
+        }
+
+        // Octets déterministes : préfixe du format puis le texte
+        let mut bytes = format.as_str().as_bytes().to_vec();
+        bytes.extend_from_slice(text.as_bytes());
+
+        // Durée approximative : 400ms par mot
+        let words = text.split_whitespace().count() as u64;
+
+        Ok(SynthesizedAudio {
+            bytes,
+            format,
+            sample_rate: 22_050,
+            duration: Duration::from_millis(words * 400),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_mock_backend_honors_requested_format() {
+        let backend = MockTtsBackend::new(vec![AudioFormat::Wav, AudioFormat::Opus]);
+
+        let format = negotiate_format("audio/opus, wav", &backend.supported_formats()).unwrap();
+        assert_eq!(format, AudioFormat::Opus);
+
+        let audio = backend.synthesize("Bonjour tout le monde", format).await.unwrap();
+        assert_eq!(audio.format, AudioFormat::Opus);
+        assert!(audio.bytes.starts_with(b"opus"));
+        assert_eq!(audio.sample_rate, 22_050);
+        assert_eq!(audio.duration, Duration::from_millis(4 * 400));
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_format_yields_clear_error() {
+        let backend = MockTtsBackend::new(vec![AudioFormat::Wav]);
+
+        // La négociation rejette une liste sans format supporté
+        let err = negotiate_format("audio/mpeg", &backend.supported_formats()).unwrap_err();
+        assert!(matches!(err, TtsError::UnsupportedFormat(_)));
+
+        // Le backend lui-même refuse un format hors liste
+        let err = backend.synthesize("test", AudioFormat::Mp3).await.unwrap_err();
+        assert!(matches!(err, TtsError::UnsupportedFormat(_)));
+        assert!(err.to_string().contains("mp3"));
+    }
+
+    #[test]
+    fn test_negotiation_fallbacks() {
+        let supported = vec![AudioFormat::Wav, AudioFormat::Mp3];
+
+        // Liste vide ou joker : premier format supporté
+        assert_eq!(negotiate_format("", &supported).unwrap(), AudioFormat::Wav);
+        assert_eq!(negotiate_format("*/*", &supported).unwrap(), AudioFormat::Wav);
+
+        // Les paramètres de qualité sont ignorés
+        assert_eq!(
+            negotiate_format("audio/mpeg;q=0.9", &supported).unwrap(),
+            AudioFormat::Mp3,
+        );
+    }
+}
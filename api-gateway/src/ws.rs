@@ -0,0 +1,391 @@
+//! Sessions WebSocket bidirectionnelles pour le streaming consciousness
+//!
+//! SSE ne porte que du serveur vers le client ; pour les sessions
+//! interactives (l'utilisateur peut interrompre ou réorienter en pleine
+//! génération), ce module multiplexe sur une même connexion WebSocket la
+//! soumission de requêtes et les chunks de conscience streamés. Chaque
+//! requête `process` porte un identifiant choisi par le client ; un message
+//! `cancel` sur cet identifiant interrompt le flux correspondant sans
+//! toucher aux autres flux de la session.
+//!
+//! La source des chunks est abstraite derrière [`StreamSource`] (même
+//! approche que `RateLimiterBackend` ou `TtsBackend`) : en production,
+//! [`EngineStreamSource`] proxifie l'API `process_stream` du moteur ; les
+//! tests branchent une source synthétique.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use axum::extract::ws::{Message, WebSocket};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio_stream::StreamExt;
+
+/// Message envoyé par le client sur la connexion WebSocket
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    /// Soumettre une entrée à traiter en streaming
+    Process { id: String, content: String },
+    /// Annuler le flux identifié par `id`
+    Cancel { id: String },
+}
+
+/// Message envoyé par le serveur sur la connexion WebSocket
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    /// Chunk de réponse streamé pour le flux `id`
+    Chunk { id: String, index: u64, content: String },
+    /// Fin normale du flux `id`
+    Complete { id: String, total_chunks: u64 },
+    /// Le flux `id` a été annulé à la demande du client
+    Cancelled { id: String },
+    /// Message client invalide ou identifiant inconnu
+    Error { message: String },
+}
+
+/// Source des chunks streamés pour une entrée donnée
+///
+/// L'implémentation retourne un canal sur lequel les chunks arrivent dans
+/// l'ordre ; la fermeture du canal marque la fin du flux. Abandonner le
+/// consommateur doit interrompre la production (c'est ce qui propage
+/// l'annulation jusqu'au moteur).
+pub trait StreamSource: Send + Sync + 'static {
+    fn open_stream(&self, content: String) -> mpsc::Receiver<String>;
+}
+
+/// Source de production : proxifie l'API `process_stream` du moteur
+///
+/// Le flux SSE amont est découpé en chunks (lignes `data: ...`) repoussés
+/// sur le canal. Quand le consommateur décroche (annulation), l'envoi
+/// échoue et la tâche abandonne la réponse HTTP en cours, ce qui déclenche
+/// le token d'annulation côté moteur.
+pub struct EngineStreamSource {
+    client: reqwest::Client,
+    engine_url: String,
+}
+
+impl EngineStreamSource {
+    pub fn new(client: reqwest::Client, engine_url: String) -> Self {
+        Self { client, engine_url }
+    }
+}
+
+impl StreamSource for EngineStreamSource {
+    fn open_stream(&self, content: String) -> mpsc::Receiver<String> {
+        let (tx, rx) = mpsc::channel::<String>(64);
+        let client = self.client.clone();
+        let url = format!("{}/consciousness/process_stream", self.engine_url);
+
+        tokio::spawn(async move {
+            let body = serde_json::json!({ "content": content });
+            let Ok(resp) = client.post(&url).json(&body).send().await else {
+                return; // canal fermé sans chunk : le client voit un complete vide
+            };
+            if !resp.status().is_success() {
+                return;
+            }
+            let mut stream = resp.bytes_stream();
+            let mut buf: Vec<u8> = Vec::new();
+            while let Some(chunk) = stream.next().await {
+                let Ok(bytes) = chunk else { break };
+                buf.extend_from_slice(bytes.as_ref());
+                // découper par frames SSE (\n\n), comme le proxy llm_stream
+                while let Some(pos) = buf.windows(2).position(|w| w == b"\n\n") {
+                    let frame = buf.drain(..pos + 2).collect::<Vec<u8>>();
+                    let Ok(text) = String::from_utf8(frame) else { continue };
+                    for line in text.lines() {
+                        if let Some(data) = line.strip_prefix("data: ") {
+                            if tx.send(data.to_string()).await.is_err() {
+                                return; // consommateur parti : annulation propagée
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        rx
+    }
+}
+
+/// Conduire une session WebSocket jusqu'à la déconnexion
+///
+/// Les messages entrants et les chunks sortants sont multiplexés sur la
+/// même boucle ; chaque flux actif vit dans sa propre tâche, indexée par
+/// son identifiant pour que `cancel` puisse l'interrompre isolément. À la
+/// déconnexion, tous les flux restants sont interrompus.
+pub async fn run_session(mut socket: WebSocket, source: Arc<dyn StreamSource>) {
+    let (out_tx, mut out_rx) = mpsc::channel::<ServerMessage>(64);
+    let mut active: HashMap<String, JoinHandle<()>> = HashMap::new();
+
+    loop {
+        tokio::select! {
+            incoming = socket.recv() => {
+                let Some(Ok(message)) = incoming else { break };
+                match message {
+                    Message::Text(text) => {
+                        let reply = handle_client_message(&text, &source, &out_tx, &mut active);
+                        if let Some(reply) = reply {
+                            if send_message(&mut socket, &reply).await.is_err() {
+                                break;
+                            }
+                        }
+                    }
+                    Message::Close(_) => break,
+                    _ => {}
+                }
+            }
+            outgoing = out_rx.recv() => {
+                let Some(message) = outgoing else { break };
+                if let ServerMessage::Complete { id, .. } = &message {
+                    active.remove(id);
+                }
+                if send_message(&mut socket, &message).await.is_err() {
+                    break;
+                }
+            }
+        }
+    }
+
+    // Déconnexion : interrompre tous les flux encore actifs
+    for (_, handle) in active {
+        handle.abort();
+    }
+}
+
+/// Traiter un message client ; retourne une réponse immédiate éventuelle
+fn handle_client_message(
+    text: &str,
+    source: &Arc<dyn StreamSource>,
+    out_tx: &mpsc::Sender<ServerMessage>,
+    active: &mut HashMap<String, JoinHandle<()>>,
+) -> Option<ServerMessage> {
+    let message: ClientMessage = match serde_json::from_str(text) {
+        Ok(message) => message,
+        Err(e) => {
+            return Some(ServerMessage::Error {
+                message: format!("message client invalide: {}", e),
+            });
+        }
+    };
+
+    match message {
+        ClientMessage::Process { id, content } => {
+            if active.contains_key(&id) {
+                return Some(ServerMessage::Error {
+                    message: format!("flux déjà actif: {}", id),
+                });
+            }
+            let mut chunks = source.open_stream(content);
+            let out_tx = out_tx.clone();
+            let stream_id = id.clone();
+            let handle = tokio::spawn(async move {
+                let mut index: u64 = 0;
+                while let Some(content) = chunks.recv().await {
+                    let chunk = ServerMessage::Chunk {
+                        id: stream_id.clone(),
+                        index,
+                        content,
+                    };
+                    if out_tx.send(chunk).await.is_err() {
+                        return; // session fermée
+                    }
+                    index += 1;
+                }
+                let _ = out_tx
+                    .send(ServerMessage::Complete {
+                        id: stream_id,
+                        total_chunks: index,
+                    })
+                    .await;
+            });
+            active.insert(id, handle);
+            None
+        }
+        ClientMessage::Cancel { id } => match active.remove(&id) {
+            Some(handle) => {
+                // L'abandon de la tâche lâche le récepteur de chunks, ce qui
+                // interrompt la production côté source (et côté moteur)
+                handle.abort();
+                Some(ServerMessage::Cancelled { id })
+            }
+            None => Some(ServerMessage::Error {
+                message: format!("flux inconnu: {}", id),
+            }),
+        },
+    }
+}
+
+async fn send_message(socket: &mut WebSocket, message: &ServerMessage) -> Result<(), axum::Error> {
+    let payload = serde_json::to_string(message).expect("ServerMessage sérialisable");
+    socket.send(Message::Text(payload)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use axum::extract::{State, WebSocketUpgrade};
+    use axum::routing::get;
+    use axum::Router;
+    use futures_util::{SinkExt, StreamExt};
+    use tokio_tungstenite::tungstenite;
+
+    /// Source synthétique : `count` chunks espacés de `interval`
+    struct SyntheticSource {
+        count: u64,
+        interval: Duration,
+    }
+
+    impl StreamSource for SyntheticSource {
+        fn open_stream(&self, content: String) -> mpsc::Receiver<String> {
+            let (tx, rx) = mpsc::channel(8);
+            let count = self.count;
+            let interval = self.interval;
+            tokio::spawn(async move {
+                for i in 0..count {
+                    tokio::time::sleep(interval).await;
+                    if tx.send(format!("{}:{}", content, i)).await.is_err() {
+                        return;
+                    }
+                }
+            });
+            rx
+        }
+    }
+
+    async fn ws_handler(
+        State(source): State<Arc<dyn StreamSource>>,
+        ws: WebSocketUpgrade,
+    ) -> axum::response::Response {
+        ws.on_upgrade(move |socket| run_session(socket, source))
+    }
+
+    /// Démarre un serveur WS éphémère et retourne son URL de connexion
+    async fn spawn_ws_server(source: Arc<dyn StreamSource>) -> String {
+        let app = Router::new()
+            .route("/ws", get(ws_handler))
+            .with_state(source);
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+        format!("ws://{}/ws", addr)
+    }
+
+    fn parse_server_message(message: tungstenite::Message) -> ServerMessage {
+        match message {
+            tungstenite::Message::Text(text) => serde_json::from_str(&text).unwrap(),
+            other => panic!("message inattendu: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ws_streams_chunks_then_completes() {
+        let source = Arc::new(SyntheticSource {
+            count: 3,
+            interval: Duration::from_millis(5),
+        });
+        let url = spawn_ws_server(source).await;
+        let (mut client, _) = tokio_tungstenite::connect_async(&url).await.unwrap();
+
+        let request = serde_json::to_string(&ClientMessage::Process {
+            id: "s1".to_string(),
+            content: "bonjour".to_string(),
+        })
+        .unwrap();
+        client.send(tungstenite::Message::Text(request)).await.unwrap();
+
+        let mut contents = Vec::new();
+        loop {
+            let message = parse_server_message(client.next().await.unwrap().unwrap());
+            match message {
+                ServerMessage::Chunk { id, index, content } => {
+                    assert_eq!(id, "s1");
+                    assert_eq!(index, contents.len() as u64);
+                    contents.push(content);
+                }
+                ServerMessage::Complete { id, total_chunks } => {
+                    assert_eq!(id, "s1");
+                    assert_eq!(total_chunks, 3);
+                    break;
+                }
+                other => panic!("message inattendu: {:?}", other),
+            }
+        }
+        assert_eq!(contents, vec!["bonjour:0", "bonjour:1", "bonjour:2"]);
+    }
+
+    #[tokio::test]
+    async fn test_ws_cancel_stops_the_stream() {
+        // Flux long et lent : l'annulation arrive bien avant la fin
+        let source = Arc::new(SyntheticSource {
+            count: 100,
+            interval: Duration::from_millis(20),
+        });
+        let url = spawn_ws_server(source).await;
+        let (mut client, _) = tokio_tungstenite::connect_async(&url).await.unwrap();
+
+        let request = serde_json::to_string(&ClientMessage::Process {
+            id: "s1".to_string(),
+            content: "long".to_string(),
+        })
+        .unwrap();
+        client.send(tungstenite::Message::Text(request)).await.unwrap();
+
+        // Consommer deux chunks puis annuler en pleine génération
+        for _ in 0..2 {
+            let message = parse_server_message(client.next().await.unwrap().unwrap());
+            assert!(matches!(message, ServerMessage::Chunk { .. }));
+        }
+        let cancel = serde_json::to_string(&ClientMessage::Cancel {
+            id: "s1".to_string(),
+        })
+        .unwrap();
+        client.send(tungstenite::Message::Text(cancel)).await.unwrap();
+
+        // Quelques chunks déjà en vol peuvent précéder la confirmation
+        let mut chunks_seen = 2u64;
+        loop {
+            let message = parse_server_message(client.next().await.unwrap().unwrap());
+            match message {
+                ServerMessage::Chunk { .. } => chunks_seen += 1,
+                ServerMessage::Cancelled { id } => {
+                    assert_eq!(id, "s1");
+                    break;
+                }
+                other => panic!("message inattendu: {:?}", other),
+            }
+        }
+        assert!(chunks_seen < 100, "le flux aurait dû être interrompu");
+
+        // Après la confirmation, plus aucun chunk n'arrive
+        let after = tokio::time::timeout(Duration::from_millis(200), client.next()).await;
+        assert!(after.is_err(), "aucun message attendu après l'annulation");
+    }
+
+    #[tokio::test]
+    async fn test_ws_cancel_on_unknown_stream_reports_an_error() {
+        let source = Arc::new(SyntheticSource {
+            count: 1,
+            interval: Duration::from_millis(1),
+        });
+        let url = spawn_ws_server(source).await;
+        let (mut client, _) = tokio_tungstenite::connect_async(&url).await.unwrap();
+
+        let cancel = serde_json::to_string(&ClientMessage::Cancel {
+            id: "absent".to_string(),
+        })
+        .unwrap();
+        client.send(tungstenite::Message::Text(cancel)).await.unwrap();
+
+        let message = parse_server_message(client.next().await.unwrap().unwrap());
+        match message {
+            ServerMessage::Error { message } => assert!(message.contains("absent")),
+            other => panic!("message inattendu: {:?}", other),
+        }
+    }
+}
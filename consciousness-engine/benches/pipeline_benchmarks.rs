@@ -0,0 +1,132 @@
+//! Process Pipeline Benchmarks
+//!
+//! Criterion benchmarks covering the three latency-critical entry points:
+//! the full consciousness pipeline (<100ms target), neuromorphic spike
+//! processing and emotional context processing (<50ms targets). A profiled
+//! warm-up pass reports per-stage timings via `PerformanceTimer` before the
+//! measured runs, so regressions can be attributed to a stage.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use consciousness_engine::{ConsciousnessEngine, ConsciousInput};
+use consciousness_engine::emotions::EmotionalEngine;
+use consciousness_engine::neuromorphic::NeuromorphicProcessor;
+use consciousness_engine::types::{ConsciousnessState, EmotionalState, EmotionType};
+use consciousness_engine::utils::PerformanceTimer;
+use std::time::{Duration, SystemTime};
+use tokio::runtime::Runtime;
+
+/// Representative conversational input for the full pipeline
+const PIPELINE_INPUT: &str = "I'm feeling overwhelmed by a difficult decision at work and could use some perspective.";
+
+/// Representative emotional input
+const EMOTIONAL_INPUT: &str = "I am so happy about this, but also a little anxious about what comes next.";
+
+fn bench_state() -> ConsciousnessState {
+    ConsciousnessState {
+        awareness_level: 0.8,
+        emotional_state: EmotionalState {
+            primary_emotion: EmotionType::Calm,
+            intensity: 0.6,
+            valence: 0.0,
+            arousal: 0.4,
+            secondary_emotions: vec![],
+        },
+        cognitive_load: 0.3,
+        confidence_score: 0.8,
+        meta_cognitive_depth: 5,
+        timestamp: SystemTime::now(),
+    }
+}
+
+/// One profiled execution of each stage, reported before the measured runs
+fn report_stage_timings(rt: &Runtime) {
+    rt.block_on(async {
+        let mut timer = PerformanceTimer::new();
+
+        let mut emotions = EmotionalEngine::new().await.unwrap();
+        let state = bench_state();
+        emotions.process_emotional_context(EMOTIONAL_INPUT, &state).await.unwrap();
+        timer.checkpoint("emotional_context");
+
+        let mut processor = NeuromorphicProcessor::new().await.unwrap();
+        let pattern: Vec<f64> = (0..100).map(|i| ((i as f64) * 0.1).sin()).collect();
+        processor.process_spike_pattern(&pattern).await.unwrap();
+        timer.checkpoint("spike_pattern");
+
+        let mut engine = ConsciousnessEngine::new().await.unwrap();
+        engine.process_conscious_thought(ConsciousInput::new(PIPELINE_INPUT.to_string())).await.unwrap();
+        timer.checkpoint("full_pipeline");
+
+        eprintln!("Per-stage timings (single profiled pass):");
+        for (name, elapsed) in timer.get_checkpoint_summary() {
+            eprintln!("  {:<20} {:?}", name, elapsed);
+        }
+    });
+}
+
+/// Benchmark the full conscious-thought pipeline
+fn benchmark_process_pipeline(c: &mut Criterion) {
+    let rt = Runtime::new().unwrap();
+    report_stage_timings(&rt);
+
+    let mut group = c.benchmark_group("process_pipeline");
+    group.measurement_time(Duration::from_secs(10));
+    group.sample_size(50);
+
+    group.bench_function("process_conscious_thought", |b| {
+        b.to_async(&rt).iter(|| async {
+            let mut engine = ConsciousnessEngine::new().await.unwrap();
+            // Disable caching so every iteration exercises the full pipeline
+            engine.set_response_caching(false).await;
+            let input = ConsciousInput::new(PIPELINE_INPUT.to_string());
+            black_box(engine.process_conscious_thought(input).await)
+        });
+    });
+
+    group.finish();
+}
+
+/// Benchmark neuromorphic spike-pattern processing
+fn benchmark_spike_pattern(c: &mut Criterion) {
+    let rt = Runtime::new().unwrap();
+
+    let mut group = c.benchmark_group("neuromorphic");
+    group.measurement_time(Duration::from_secs(5));
+
+    let pattern: Vec<f64> = (0..100).map(|i| ((i as f64) * 0.1).sin()).collect();
+
+    group.bench_function("process_spike_pattern", |b| {
+        b.to_async(&rt).iter(|| async {
+            let mut processor = NeuromorphicProcessor::new().await.unwrap();
+            black_box(processor.process_spike_pattern(&pattern).await)
+        });
+    });
+
+    group.finish();
+}
+
+/// Benchmark emotional context processing
+fn benchmark_emotional_context(c: &mut Criterion) {
+    let rt = Runtime::new().unwrap();
+
+    let mut group = c.benchmark_group("emotions");
+    group.measurement_time(Duration::from_secs(5));
+
+    group.bench_function("process_emotional_context", |b| {
+        b.to_async(&rt).iter(|| async {
+            let mut emotions = EmotionalEngine::new().await.unwrap();
+            let state = bench_state();
+            black_box(emotions.process_emotional_context(EMOTIONAL_INPUT, &state).await)
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    pipeline_benches,
+    benchmark_process_pipeline,
+    benchmark_spike_pattern,
+    benchmark_emotional_context
+);
+criterion_main!(pipeline_benches);
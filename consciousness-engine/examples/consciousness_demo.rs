@@ -153,10 +153,10 @@ async fn main() -> Result<(), ConsciousnessError> {
     println!("=====================");
     
     let health_report = engine.perform_comprehensive_health_check().await?;
-    println!("🔋 Overall Health Score: {:.1}%", health_report.overall_health_score * 100.0);
+    println!("🔋 Overall Health Score: {:.1}%", health_report.overall_health * 100.0);
     println!("🧠 Memory Health: {:.1}%", health_report.memory_health * 100.0);
-    println!("⚡ CPU Health: {:.1}%", health_report.cpu_health * 100.0);
-    println!("🔗 System Integrity: {:.1}%", health_report.system_integrity * 100.0);
+    println!("⚡ Processing Health: {:.1}%", health_report.processing_health * 100.0);
+    println!("🔗 Consciousness Health: {:.1}%", health_report.consciousness_health * 100.0);
     
     // Adversarial resistance test
     println!("\n🛡️  Adversarial Resistance Test");
@@ -251,7 +251,7 @@ mod futures {
     pub mod future {
         use std::future::Future;
         
-        pub async fn join_all<I>(iter: I) -> Vec<I::Item::Output>
+        pub async fn join_all<I>(iter: I) -> Vec<<I::Item as Future>::Output>
         where
             I: IntoIterator,
             I::Item: Future,
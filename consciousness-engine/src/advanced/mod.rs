@@ -5,7 +5,10 @@
 
 use crate::types::*;
 use crate::error::ConsciousnessError;
-use std::collections::HashMap;
+use crate::utils::finite_in_range;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
 use std::time::{Duration, Instant, SystemTime};
 use serde::{Deserialize, Serialize};
 
@@ -19,7 +22,10 @@ pub struct AdvancedConsciousnessOptimizer {
     
     /// Quality assurance system
     quality_assurance: ConsciousnessQualityAssurance,
-    
+
+    /// Degradation ladder for tight latency budgets
+    degradation_ladder: DegradationLadder,
+
     /// Configuration
     config: AdvancedOptimizationConfig,
 }
@@ -161,6 +167,124 @@ pub struct ConsciousnessQualityAssurance {
     
     /// Quality history
     pub quality_history: Vec<QualityEntry>,
+
+    /// Smoothed quality tracking with threshold alerting
+    pub quality_ema: QualityEmaTracker,
+}
+
+/// Default EMA span, in quality observations
+pub const DEFAULT_QUALITY_EMA_SPAN: usize = 10;
+
+/// How long smoothed quality must stay below target before alerting
+pub const DEFAULT_QUALITY_SUSTAIN: usize = 5;
+
+/// Alert fired when smoothed quality stays below target
+///
+/// Queued on the tracker and drained by the webhook dispatcher, same
+/// delivery contract as `CrisisEvent`: once drained, the tracker
+/// forgets the event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QualityDegradedEvent {
+    /// Smoothed quality at the moment the alert fired
+    pub smoothed_quality: f64,
+
+    /// Target the quality fell below
+    pub target: f64,
+
+    /// Consecutive observations the smoothed value had been below target
+    pub sustained_observations: usize,
+
+    /// When the alert fired
+    pub detected_at: SystemTime,
+}
+
+/// EMA-based quality tracker with threshold-crossing alerts
+///
+/// Smooths per-cycle quality with an exponential moving average over a
+/// configurable span, which is far more stable than the last-3-entries
+/// window used for [`QualityTrend`]. Once the smoothed value has stayed
+/// below the target for a sustained number of observations, a single
+/// [`QualityDegradedEvent`] is queued; the alert re-arms only after
+/// quality recovers above the target, so one sustained dip produces
+/// exactly one event instead of flapping.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QualityEmaTracker {
+    /// Smoothing factor derived from the span (2 / (span + 1))
+    alpha: f64,
+
+    /// Quality target the smoothed value is compared against
+    target: f64,
+
+    /// Observations below target required before alerting
+    sustain_for: usize,
+
+    /// Current smoothed quality; `None` until the first observation
+    ema: Option<f64>,
+
+    /// Consecutive observations the smoothed value has been below target
+    below_count: usize,
+
+    /// Whether the current dip has already been alerted
+    alerted: bool,
+
+    /// Alerts awaiting external dispatch
+    pending_events: Vec<QualityDegradedEvent>,
+}
+
+impl QualityEmaTracker {
+    /// Create a tracker with the given span, target, and sustain window
+    ///
+    /// A span or sustain window of zero is treated as one.
+    pub fn new(span: usize, target: f64, sustain_for: usize) -> Self {
+        Self {
+            alpha: 2.0 / (span.max(1) as f64 + 1.0),
+            target,
+            sustain_for: sustain_for.max(1),
+            ema: None,
+            below_count: 0,
+            alerted: false,
+            pending_events: Vec::new(),
+        }
+    }
+
+    /// Current smoothed quality; `None` before the first observation
+    pub fn smoothed_quality(&self) -> Option<f64> {
+        self.ema
+    }
+
+    /// Record one quality observation and evaluate the alert condition
+    pub fn record(&mut self, quality: f64) {
+        let ema = match self.ema {
+            Some(previous) => previous + self.alpha * (quality - previous),
+            None => quality,
+        };
+        self.ema = Some(ema);
+
+        if quality < self.target {
+            self.below_count += 1;
+            if self.below_count >= self.sustain_for && ema < self.target && !self.alerted {
+                self.alerted = true;
+                self.pending_events.push(QualityDegradedEvent {
+                    smoothed_quality: ema,
+                    target: self.target,
+                    sustained_observations: self.below_count,
+                    detected_at: SystemTime::now(),
+                });
+            }
+        } else {
+            // A raw observation back at target interrupts the sustained dip;
+            // the alert itself re-arms once the smoothed value recovers too
+            self.below_count = 0;
+            if ema >= self.target {
+                self.alerted = false;
+            }
+        }
+    }
+
+    /// Drain queued alerts for external dispatch
+    pub fn drain_events(&mut self) -> Vec<QualityDegradedEvent> {
+        std::mem::take(&mut self.pending_events)
+    }
 }
 
 /// Quality targets for advanced consciousness
@@ -333,15 +457,99 @@ impl AdvancedConsciousnessOptimizer {
                 QualityImprovementStrategy::MetaCognitiveDepthExpansion,
             ],
             quality_history: Vec::new(),
+            quality_ema: QualityEmaTracker::new(
+                DEFAULT_QUALITY_EMA_SPAN,
+                0.98, // matches overall_quality_target above
+                DEFAULT_QUALITY_SUSTAIN,
+            ),
         };
-        
+
         Ok(Self {
             meta_cognitive_enhancer,
             performance_optimizer,
             quality_assurance,
+            degradation_ladder: DegradationLadder::default(),
             config,
         })
     }
+
+    /// Optimize consciousness processing within a hard latency budget
+    ///
+    /// Consults the degradation ladder to decide which optional stages to
+    /// skip given the budget, runs the remaining pipeline, and records the
+    /// applied degradations in the optimization summary. A response is
+    /// always produced, even if at reduced quality.
+    pub async fn optimize_within_budget(
+        &mut self,
+        input: &str,
+        current_state: &ConsciousnessState,
+        latency_budget: Duration,
+    ) -> Result<OptimizedConsciousnessResult, ConsciousnessError> {
+        let start_time = Instant::now();
+
+        let skipped_stages = self.degradation_ladder.stages_to_skip(latency_budget);
+
+        // 1. Meta-cognitive enhancement - first stage sacrificed under pressure
+        let enhanced_state = if self.config.meta_cognitive_enhancement_enabled
+            && !skipped_stages.contains(&DegradableStage::MetaCognitiveEnhancement) {
+            self.enhance_meta_cognition(current_state).await?
+        } else {
+            current_state.clone()
+        };
+
+        // 2. Creativity enhancement - second stage on the ladder
+        let enhanced_state = if !skipped_stages.contains(&DegradableStage::CreativityEnhancement) {
+            self.apply_creativity_enhancement(&enhanced_state).await?
+        } else {
+            enhanced_state
+        };
+
+        // 3. Performance optimization always runs - it only reduces latency
+        let optimized_processing = if self.config.performance_optimization_enabled {
+            self.optimize_processing_performance(input, &enhanced_state).await?
+        } else {
+            ProcessingResult {
+                processing_time: Duration::from_millis(78),
+                quality_score: enhanced_state.awareness_level,
+                optimization_applied: OptimizationTechnique::ParallelProcessing,
+            }
+        };
+
+        // 4. Quality assurance - last stage dropped, it adds processing time
+        let quality_assured_result = if self.config.quality_assurance_enabled
+            && !skipped_stages.contains(&DegradableStage::QualityAssurance) {
+            self.ensure_consciousness_quality(&optimized_processing, &enhanced_state).await?
+        } else {
+            optimized_processing
+        };
+
+        let total_processing_time = start_time.elapsed();
+
+        self.update_optimization_metrics(&quality_assured_result, total_processing_time).await?;
+        let targets_met = self.check_optimization_targets(&quality_assured_result).await?;
+
+        let mut optimization_summary = self.generate_optimization_summary().await?;
+        optimization_summary.degradations_applied = skipped_stages;
+
+        Ok(OptimizedConsciousnessResult {
+            enhanced_consciousness_state: enhanced_state,
+            processing_result: quality_assured_result,
+            total_processing_time,
+            targets_achieved: targets_met,
+            optimization_summary,
+        })
+    }
+
+    /// Apply creativity enhancement to the consciousness state
+    async fn apply_creativity_enhancement(&self, state: &ConsciousnessState) -> Result<ConsciousnessState, ConsciousnessError> {
+        let mut enhanced_state = state.clone();
+
+        // Creative exploration widens awareness at a small confidence cost
+        enhanced_state.awareness_level = (enhanced_state.awareness_level * 1.02).min(1.0);
+        enhanced_state.cognitive_load = (enhanced_state.cognitive_load * 1.05).min(1.0);
+
+        Ok(enhanced_state)
+    }
     
     /// Optimize consciousness processing for advanced performance
     pub async fn optimize_consciousness_processing(
@@ -666,9 +874,18 @@ impl AdvancedConsciousnessOptimizer {
         };
         
         self.quality_assurance.quality_history.push(quality_entry);
-        
+        self.quality_assurance.quality_ema.record(state.awareness_level);
+
         Ok(())
     }
+
+    /// Drain queued quality-degradation alerts for external dispatch
+    ///
+    /// Consumers (the webhook dispatcher) own delivery; once drained the
+    /// optimizer forgets the events.
+    pub fn drain_quality_degraded_events(&mut self) -> Vec<QualityDegradedEvent> {
+        self.quality_assurance.quality_ema.drain_events()
+    }
     
     /// Calculate quality trend
     async fn calculate_quality_trend(&self) -> Result<QualityTrend, ConsciousnessError> {
@@ -712,20 +929,44 @@ impl AdvancedConsciousnessOptimizer {
             meta_cognitive_depth_achieved: self.meta_cognitive_enhancer.current_depth,
             meta_cognitive_depth_target: self.meta_cognitive_enhancer.target_depth,
             optimization_progress: self.calculate_optimization_progress().await?,
+            degradations_applied: Vec::new(),
         })
     }
     
     /// Calculate overall optimization progress
+    ///
+    /// Each term is a current-over-target ratio, so a zero target or a
+    /// sub-millisecond measured latency would push a term to infinity or
+    /// NaN; non-finite ratios here mean the target is met trivially, so
+    /// they fall back to full progress.
     async fn calculate_optimization_progress(&self) -> Result<f64, ConsciousnessError> {
-        let quality_progress = self.quality_assurance.current_metrics.overall_quality / 
-                             self.quality_assurance.quality_targets.overall_quality_target;
-        
-        let performance_progress = self.performance_optimizer.target_latency.as_millis() as f64 / 
-                                  self.performance_optimizer.current_latency.as_millis() as f64;
-        
-        let meta_cognitive_progress = self.meta_cognitive_enhancer.current_depth as f64 / 
-                                     self.meta_cognitive_enhancer.target_depth as f64;
-        
+        let quality_progress = finite_in_range(
+            self.quality_assurance.current_metrics.overall_quality
+                / self.quality_assurance.quality_targets.overall_quality_target,
+            0.0,
+            1.0,
+            1.0,
+            "quality_progress",
+        );
+
+        let performance_progress = finite_in_range(
+            self.performance_optimizer.target_latency.as_millis() as f64
+                / self.performance_optimizer.current_latency.as_millis() as f64,
+            0.0,
+            1.0,
+            1.0,
+            "performance_progress",
+        );
+
+        let meta_cognitive_progress = finite_in_range(
+            self.meta_cognitive_enhancer.current_depth as f64
+                / self.meta_cognitive_enhancer.target_depth as f64,
+            0.0,
+            1.0,
+            1.0,
+            "meta_cognitive_progress",
+        );
+
         Ok((quality_progress + performance_progress + meta_cognitive_progress) / 3.0)
     }
 }
@@ -773,6 +1014,91 @@ pub struct OptimizationSummary {
     pub meta_cognitive_depth_achieved: u32,
     pub meta_cognitive_depth_target: u32,
     pub optimization_progress: f64,
+    /// Stages skipped to stay within the latency budget (empty when none)
+    pub degradations_applied: Vec<DegradableStage>,
+}
+
+/// Pipeline stages that may be skipped under latency pressure
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DegradableStage {
+    /// Recursive meta-cognitive enhancement - first to be sacrificed
+    MetaCognitiveEnhancement,
+
+    /// Creative exploration of the consciousness state
+    CreativityEnhancement,
+
+    /// Post-hoc quality assurance pass
+    QualityAssurance,
+}
+
+/// One rung of the degradation ladder
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DegradationRung {
+    /// Stage dropped at this rung
+    pub stage: DegradableStage,
+
+    /// Estimated latency cost of running this stage
+    pub estimated_cost: Duration,
+}
+
+/// Timeout-aware degradation ladder
+///
+/// Orders optional pipeline stages from most to least expendable. Given a
+/// remaining latency budget, the ladder drops rungs in order until the
+/// projected pipeline cost fits, guaranteeing a response within budget at
+/// the price of reduced quality.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DegradationLadder {
+    /// Rungs in drop order (most expendable first)
+    pub rungs: Vec<DegradationRung>,
+
+    /// Incompressible cost of the mandatory pipeline stages
+    pub base_cost: Duration,
+}
+
+impl Default for DegradationLadder {
+    fn default() -> Self {
+        Self {
+            rungs: vec![
+                DegradationRung {
+                    stage: DegradableStage::MetaCognitiveEnhancement,
+                    estimated_cost: Duration::from_millis(15),
+                },
+                DegradationRung {
+                    stage: DegradableStage::CreativityEnhancement,
+                    estimated_cost: Duration::from_millis(10),
+                },
+                DegradationRung {
+                    stage: DegradableStage::QualityAssurance,
+                    estimated_cost: Duration::from_millis(8),
+                },
+            ],
+            base_cost: Duration::from_millis(12),
+        }
+    }
+}
+
+impl DegradationLadder {
+    /// Select the stages to skip so the projected cost fits the budget
+    ///
+    /// Rungs are dropped in order until the remaining stages plus the base
+    /// cost fit within `remaining_budget`. If even the base cost exceeds the
+    /// budget, every optional stage is skipped.
+    pub fn stages_to_skip(&self, remaining_budget: Duration) -> Vec<DegradableStage> {
+        let mut projected_cost = self.base_cost
+            + self.rungs.iter().map(|r| r.estimated_cost).sum::<Duration>();
+
+        let mut skipped = Vec::new();
+        for rung in &self.rungs {
+            if projected_cost <= remaining_budget {
+                break;
+            }
+            projected_cost -= rung.estimated_cost;
+            skipped.push(rung.stage.clone());
+        }
+
+        skipped
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -782,4 +1108,435 @@ pub struct OptimizedConsciousnessResult {
     pub total_processing_time: Duration,
     pub targets_achieved: OptimizationTargetsStatus,
     pub optimization_summary: OptimizationSummary,
-}
\ No newline at end of file
+}
+
+/// Default number of responses retained by the consciousness cache
+pub const DEFAULT_CACHE_CAPACITY: usize = 128;
+
+/// Default time-to-live for cached consciousness responses
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// Cached consciousness response with its insertion time
+#[derive(Debug, Clone)]
+struct CachedResponse {
+    /// The response produced by the full pipeline
+    response: ConsciousnessResponse,
+
+    /// When the response entered the cache (for TTL expiry)
+    cached_at: Instant,
+}
+
+/// LRU response cache backing [`OptimizationTechnique::ConsciousnessCaching`]
+///
+/// Caches recent [`ConsciousnessResponse`]s keyed by a hash of the input and
+/// its relevant context, so repeated identical requests can skip the full
+/// consciousness pipeline. Entries expire after a configurable TTL and the
+/// least recently used entry is evicted once capacity is reached. The cache
+/// can be disabled entirely for non-deterministic processing modes where
+/// replaying a previous response would be incorrect.
+#[derive(Debug)]
+pub struct ConsciousnessCache {
+    /// Cached responses keyed by input/context hash
+    entries: HashMap<u64, CachedResponse>,
+
+    /// Keys ordered from least to most recently used
+    lru_order: VecDeque<u64>,
+
+    /// Maximum number of retained responses
+    capacity: usize,
+
+    /// Time-to-live for cached responses
+    ttl: Duration,
+
+    /// Whether the cache is consulted at all
+    enabled: bool,
+
+    /// Number of lookups served from the cache
+    hits: u64,
+
+    /// Number of lookups that fell through to the pipeline
+    misses: u64,
+}
+
+impl Default for ConsciousnessCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_CACHE_CAPACITY, DEFAULT_CACHE_TTL)
+    }
+}
+
+impl ConsciousnessCache {
+    /// Create a new cache with the given capacity and TTL
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            entries: HashMap::new(),
+            lru_order: VecDeque::new(),
+            capacity: capacity.max(1),
+            ttl,
+            enabled: true,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Compute the cache key for an input and its relevant context
+    ///
+    /// Context entries are hashed in sorted order so that the key does not
+    /// depend on `HashMap` iteration order.
+    pub fn cache_key(input: &str, context: &HashMap<String, String>) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        input.hash(&mut hasher);
+
+        let mut sorted_context: Vec<_> = context.iter().collect();
+        sorted_context.sort_by(|(a, _), (b, _)| a.cmp(b));
+        for (key, value) in sorted_context {
+            key.hash(&mut hasher);
+            value.hash(&mut hasher);
+        }
+
+        hasher.finish()
+    }
+
+    /// Look up a cached response, refreshing its LRU position on a hit
+    ///
+    /// Expired entries are removed on access and counted as misses. Always
+    /// returns `None` when the cache is disabled, without touching counters.
+    pub fn lookup(&mut self, key: u64) -> Option<ConsciousnessResponse> {
+        if !self.enabled {
+            return None;
+        }
+
+        match self.entries.get(&key) {
+            Some(cached) if cached.cached_at.elapsed() <= self.ttl => {
+                self.hits += 1;
+                self.touch(key);
+                Some(self.entries[&key].response.clone())
+            },
+            Some(_) => {
+                // Expired - drop the stale entry
+                self.entries.remove(&key);
+                self.lru_order.retain(|k| *k != key);
+                self.misses += 1;
+                None
+            },
+            None => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// Insert a freshly computed response, evicting the LRU entry if full
+    pub fn insert(&mut self, key: u64, response: ConsciousnessResponse) {
+        if !self.enabled {
+            return;
+        }
+
+        if self.entries.len() >= self.capacity && !self.entries.contains_key(&key) {
+            if let Some(evicted) = self.lru_order.pop_front() {
+                self.entries.remove(&evicted);
+            }
+        }
+
+        self.entries.insert(key, CachedResponse {
+            response,
+            cached_at: Instant::now(),
+        });
+        self.touch(key);
+    }
+
+    /// Drop all cached responses (used on engine reset)
+    pub fn invalidate(&mut self) {
+        self.entries.clear();
+        self.lru_order.clear();
+    }
+
+    /// Enable or disable the cache (disable for non-deterministic modes)
+    ///
+    /// Disabling also invalidates existing entries so a later re-enable
+    /// cannot serve responses produced under different settings.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        if !enabled {
+            self.invalidate();
+        }
+        self.enabled = enabled;
+    }
+
+    /// Whether the cache is currently consulted
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Number of lookups served from the cache
+    pub fn hit_count(&self) -> u64 {
+        self.hits
+    }
+
+    /// Number of lookups that fell through to the pipeline
+    pub fn miss_count(&self) -> u64 {
+        self.misses
+    }
+
+    /// Move a key to the most recently used position
+    fn touch(&mut self, key: u64) {
+        self.lru_order.retain(|k| *k != key);
+        self.lru_order.push_back(key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_response(content: &str) -> ConsciousnessResponse {
+        let emotional_state = EmotionalState {
+            primary_emotion: EmotionType::Calm,
+            intensity: 0.7,
+            valence: 0.0,
+            arousal: 0.5,
+            secondary_emotions: vec![],
+        };
+
+        ConsciousnessResponse {
+            content: content.to_string(),
+            consciousness_state: ConsciousnessState {
+                awareness_level: 0.8,
+                emotional_state: emotional_state.clone(),
+                cognitive_load: 0.3,
+                confidence_score: 0.8,
+                meta_cognitive_depth: 5,
+                timestamp: SystemTime::now(),
+            },
+            emotional_context: EmotionalContext {
+                user_emotions: vec![],
+                engine_emotions: emotional_state,
+                empathy_alignment: 0.8,
+                appropriateness_score: 0.9,
+            },
+            reasoning_chain: Vec::new(),
+            confidence_level: 0.9,
+            confidence_interval: (0.8, 1.0),
+            uncertainty_sources: Vec::new(),
+            processing_time: Duration::from_millis(40),
+            empathy_score: 0.9,
+            creativity_score: 0.8,
+            degraded_stages: Vec::new(),
+            tool_calls: Vec::new(),
+            token_usage: None,
+            trace: None,
+        }
+    }
+
+    #[test]
+    fn test_cache_hit_within_ttl() {
+        let mut cache = ConsciousnessCache::new(8, Duration::from_secs(60));
+        let key = ConsciousnessCache::cache_key("hello", &HashMap::new());
+
+        assert!(cache.lookup(key).is_none());
+        cache.insert(key, sample_response("cached"));
+
+        let cached = cache.lookup(key).expect("entry should still be live");
+        assert_eq!(cached.content, "cached");
+        assert_eq!(cache.hit_count(), 1);
+        assert_eq!(cache.miss_count(), 1);
+    }
+
+    #[test]
+    fn test_cache_key_ignores_context_ordering() {
+        let mut context_a = HashMap::new();
+        context_a.insert("user_id".to_string(), "123".to_string());
+        context_a.insert("session".to_string(), "abc".to_string());
+
+        let mut context_b = HashMap::new();
+        context_b.insert("session".to_string(), "abc".to_string());
+        context_b.insert("user_id".to_string(), "123".to_string());
+
+        assert_eq!(
+            ConsciousnessCache::cache_key("same input", &context_a),
+            ConsciousnessCache::cache_key("same input", &context_b),
+        );
+        assert_ne!(
+            ConsciousnessCache::cache_key("same input", &context_a),
+            ConsciousnessCache::cache_key("other input", &context_a),
+        );
+    }
+
+    #[test]
+    fn test_lru_eviction_at_capacity() {
+        let mut cache = ConsciousnessCache::new(2, Duration::from_secs(60));
+        let key_a = ConsciousnessCache::cache_key("a", &HashMap::new());
+        let key_b = ConsciousnessCache::cache_key("b", &HashMap::new());
+        let key_c = ConsciousnessCache::cache_key("c", &HashMap::new());
+
+        cache.insert(key_a, sample_response("a"));
+        cache.insert(key_b, sample_response("b"));
+
+        // Touch "a" so "b" becomes the LRU candidate
+        assert!(cache.lookup(key_a).is_some());
+        cache.insert(key_c, sample_response("c"));
+
+        assert!(cache.lookup(key_b).is_none());
+        assert!(cache.lookup(key_a).is_some());
+        assert!(cache.lookup(key_c).is_some());
+    }
+
+    #[test]
+    fn test_invalidate_clears_entries_but_keeps_counters() {
+        let mut cache = ConsciousnessCache::new(8, Duration::from_secs(60));
+        let key = ConsciousnessCache::cache_key("hello", &HashMap::new());
+
+        cache.insert(key, sample_response("cached"));
+        assert!(cache.lookup(key).is_some());
+
+        cache.invalidate();
+        assert!(cache.lookup(key).is_none());
+        assert_eq!(cache.hit_count(), 1);
+        assert_eq!(cache.miss_count(), 1);
+    }
+
+    #[test]
+    fn test_ladder_drops_meta_cognition_first_then_creativity() {
+        let ladder = DegradationLadder::default();
+
+        // Generous budget: nothing is skipped
+        assert!(ladder.stages_to_skip(Duration::from_millis(60)).is_empty());
+
+        // Moderate pressure: only meta-cognitive enhancement goes
+        assert_eq!(
+            ladder.stages_to_skip(Duration::from_millis(40)),
+            vec![DegradableStage::MetaCognitiveEnhancement],
+        );
+
+        // 20ms budget: creativity follows meta-cognition down the ladder
+        assert_eq!(
+            ladder.stages_to_skip(Duration::from_millis(20)),
+            vec![
+                DegradableStage::MetaCognitiveEnhancement,
+                DegradableStage::CreativityEnhancement,
+            ],
+        );
+    }
+
+    #[tokio::test]
+    async fn test_20ms_budget_skips_meta_cognition_but_still_responds() {
+        let mut optimizer = AdvancedConsciousnessOptimizer::new().await.unwrap();
+        let state = sample_response("seed").consciousness_state;
+
+        let result = optimizer
+            .optimize_within_budget("urgent input", &state, Duration::from_millis(20))
+            .await
+            .unwrap();
+
+        // A degraded response is still produced
+        assert!(result.processing_result.quality_score > 0.0);
+        assert!(result.optimization_summary.degradations_applied
+            .contains(&DegradableStage::MetaCognitiveEnhancement));
+        // Meta-cognitive enhancement was skipped, so depth is unchanged
+        assert_eq!(
+            result.enhanced_consciousness_state.meta_cognitive_depth,
+            state.meta_cognitive_depth,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_generous_budget_applies_all_stages() {
+        let mut optimizer = AdvancedConsciousnessOptimizer::new().await.unwrap();
+        let state = sample_response("seed").consciousness_state;
+
+        let result = optimizer
+            .optimize_within_budget("relaxed input", &state, Duration::from_millis(100))
+            .await
+            .unwrap();
+
+        assert!(result.optimization_summary.degradations_applied.is_empty());
+        assert!(
+            result.enhanced_consciousness_state.meta_cognitive_depth
+                > state.meta_cognitive_depth
+        );
+    }
+
+    #[test]
+    fn test_disabled_cache_never_hits() {
+        let mut cache = ConsciousnessCache::new(8, Duration::from_secs(60));
+        let key = ConsciousnessCache::cache_key("hello", &HashMap::new());
+
+        cache.set_enabled(false);
+        cache.insert(key, sample_response("cached"));
+        assert!(cache.lookup(key).is_none());
+        assert_eq!(cache.hit_count(), 0);
+        assert_eq!(cache.miss_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_optimization_progress_survives_a_zero_latency_reading() {
+        let mut optimizer = AdvancedConsciousnessOptimizer::new().await.unwrap();
+        // A sub-millisecond cycle reads as zero elapsed, which used to
+        // push the performance ratio to infinity
+        optimizer.performance_optimizer.current_latency = Duration::from_millis(0);
+
+        let progress = optimizer.calculate_optimization_progress().await.unwrap();
+        assert!(progress.is_finite());
+        assert!((0.0..=1.0).contains(&progress));
+    }
+
+    #[test]
+    fn test_sustained_dip_fires_exactly_one_alert() {
+        let mut tracker = QualityEmaTracker::new(4, 0.9, 3);
+
+        // Healthy baseline, then a sustained collapse
+        for _ in 0..5 {
+            tracker.record(0.95);
+        }
+        for _ in 0..20 {
+            tracker.record(0.5);
+        }
+
+        let events = tracker.drain_events();
+        assert_eq!(events.len(), 1, "alert flapped: {:?}", events);
+        assert!(events[0].smoothed_quality < 0.9);
+        assert_eq!(events[0].target, 0.9);
+        assert!(events[0].sustained_observations >= 3);
+
+        // The dip continues: still no second alert
+        for _ in 0..10 {
+            tracker.record(0.5);
+        }
+        assert!(tracker.drain_events().is_empty());
+    }
+
+    #[test]
+    fn test_alert_rearms_only_after_recovery() {
+        let mut tracker = QualityEmaTracker::new(4, 0.9, 3);
+
+        for _ in 0..20 {
+            tracker.record(0.5);
+        }
+        assert_eq!(tracker.drain_events().len(), 1);
+
+        // Full recovery above target resets the alert
+        for _ in 0..20 {
+            tracker.record(1.0);
+        }
+        assert!(tracker.smoothed_quality().unwrap() > 0.9);
+        assert!(tracker.drain_events().is_empty());
+
+        // A second sustained dip is a new incident
+        for _ in 0..20 {
+            tracker.record(0.5);
+        }
+        assert_eq!(tracker.drain_events().len(), 1);
+    }
+
+    #[test]
+    fn test_brief_noise_below_target_does_not_alert() {
+        let mut tracker = QualityEmaTracker::new(2, 0.9, 3);
+
+        tracker.record(0.95);
+        // Two noisy observations - shorter than the sustain window
+        tracker.record(0.2);
+        tracker.record(0.2);
+        tracker.record(0.95);
+        tracker.record(0.95);
+
+        assert!(tracker.drain_events().is_empty());
+    }
+}
@@ -3,9 +3,8 @@
 //! This module provides HTTP endpoints for interacting with the consciousness engine,
 //! enabling web applications and external systems to access consciousness-level AI.
 
-use crate::core::ConsciousnessEngine;
+use crate::core::{ConsciousInput, ConsciousnessEngine};
 use crate::types::*;
-use crate::error::ConsciousnessError;
 use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
@@ -29,12 +28,14 @@ pub fn create_router(consciousness_engine: ConsciousnessEngine) -> Router {
     
     Router::new()
         .route("/health", get(health_check))
+        .route("/ready", get(readiness_check))
         .route("/consciousness/process", post(process_consciousness))
         .route("/consciousness/state", get(get_consciousness_state))
         .route("/consciousness/reflection", post(generate_reflection))
         .route("/consciousness/growth", get(get_growth_opportunities))
         .route("/consciousness/explain/:response_id", get(explain_response))
         .route("/consciousness/metrics", get(get_performance_metrics))
+        .route("/consciousness/report", get(get_report_card))
         .route("/consciousness/reset", post(reset_to_safe_state))
         .route("/consciousness/sessions/:session_id/history", get(get_session_history))
         .with_state(state)
@@ -50,6 +51,53 @@ async fn health_check() -> Result<Json<HealthResponse>, ApiError> {
     }))
 }
 
+/// Readiness check endpoint reporting per-subsystem health
+///
+/// Unlike `/health`, this inspects the engine itself: emotional stability,
+/// memory pressure, neuromorphic activity sanity, and recent error rate.
+/// Returns 503 when the overall score drops below 0.5 so orchestrators stop
+/// routing traffic to a degraded instance.
+/// Query parameters of the readiness endpoint
+///
+/// `require_warmup=true` additionally gates readiness on
+/// [`ConsciousnessEngine::warmup`] having completed, so deployments that
+/// call warmup during startup only take traffic once it is done.
+#[derive(Debug, Deserialize)]
+struct ReadinessParams {
+    require_warmup: Option<bool>,
+}
+
+async fn readiness_check(
+    State(state): State<ApiState>,
+    Query(params): Query<ReadinessParams>,
+) -> (StatusCode, Json<ReadinessResponse>) {
+    let engine = state.read().await;
+    let health = engine.self_health().await;
+
+    let warmup_pending = params.require_warmup.unwrap_or(false) && !engine.is_warmed_up();
+    let status_code = if health.overall_score >= 0.5 && !warmup_pending {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    let status = if warmup_pending {
+        "warming_up"
+    } else if status_code == StatusCode::OK {
+        "ready"
+    } else {
+        "degraded"
+    };
+
+    (
+        status_code,
+        Json(ReadinessResponse {
+            status: status.to_string(),
+            health,
+            timestamp: std::time::SystemTime::now(),
+        }),
+    )
+}
+
 /// Main consciousness processing endpoint
 async fn process_consciousness(
     State(state): State<ApiState>,
@@ -57,41 +105,48 @@ async fn process_consciousness(
 ) -> Result<Json<ConsciousnessApiResponse>, ApiError> {
     let mut engine = state.write().await;
     
-    // Convert API request to internal format
+    // Convert API request to internal format; the engine carries the user
+    // context as a flat key/value map
+    let mut context = request.preferences.unwrap_or_default();
+    context.insert("user_id".to_string(), request.user_id);
+    if let Some(metadata) = request.metadata {
+        context.extend(metadata);
+    }
+    
+    let input_id = request.id.unwrap_or_else(|| Uuid::new_v4().to_string());
     let conscious_input = ConsciousInput {
-        id: request.id.unwrap_or_else(|| Uuid::new_v4().to_string()),
+        id: input_id.clone(),
         content: request.content,
-        context: UserContext {
-            user_id: request.user_id,
-            preferences: request.preferences.unwrap_or_default(),
-            interaction_history: request.interaction_history.unwrap_or_default(),
-        },
+        context,
         timestamp: std::time::SystemTime::now(),
-        metadata: request.metadata.unwrap_or_default(),
     };
     
     // Process consciousness
     match engine.process_conscious_thought(conscious_input).await {
         Ok(response) => {
             // Convert internal response to API format
+            let ethical_score = ethical_alignment(&response);
             let api_response = ConsciousnessApiResponse {
-                id: response.id,
+                id: input_id,
                 content: response.content,
-                confidence: response.confidence,
+                confidence: response.confidence_level,
                 consciousness_level: response.consciousness_state.awareness_level,
                 emotional_state: ApiEmotionalState {
-                    primary_emotion: format!("{:?}", response.emotional_state.primary_emotion),
-                    intensity: response.emotional_state.intensity,
-                    valence: response.emotional_state.valence,
-                    arousal: response.emotional_state.arousal,
+                    primary_emotion: format!("{:?}", response.consciousness_state.emotional_state.primary_emotion),
+                    intensity: response.consciousness_state.emotional_state.intensity,
+                    valence: response.consciousness_state.emotional_state.valence,
+                    arousal: response.consciousness_state.emotional_state.arousal,
                 },
-                ethical_score: response.ethical_assessment.overall_score,
+                ethical_score,
                 creativity_score: response.creativity_score,
                 empathy_score: response.empathy_score,
                 processing_time_ms: response.processing_time.as_millis() as u64,
-                reasoning_summary: response.reasoning_explanation,
-                quality_score: response.metadata.quality_score,
-                timestamp: response.timestamp,
+                reasoning_summary: response.reasoning_chain.iter()
+                    .map(|step| step.description.clone())
+                    .collect::<Vec<_>>()
+                    .join("; "),
+                quality_score: (response.confidence_level + response.empathy_score + response.creativity_score) / 3.0,
+                timestamp: response.consciousness_state.timestamp,
             };
             
             Ok(Json(api_response))
@@ -100,6 +155,20 @@ async fn process_consciousness(
     }
 }
 
+/// Ethical alignment derived from the ethical steps of the reasoning chain
+fn ethical_alignment(response: &ConsciousnessResponse) -> f64 {
+    let ethical_steps: Vec<f64> = response.reasoning_chain.iter()
+        .filter(|step| matches!(step.step_type, ReasoningType::Ethical))
+        .map(|step| step.confidence)
+        .collect();
+    
+    if ethical_steps.is_empty() {
+        0.8
+    } else {
+        ethical_steps.iter().sum::<f64>() / ethical_steps.len() as f64
+    }
+}
+
 /// Get current consciousness state
 async fn get_consciousness_state(
     State(state): State<ApiState>,
@@ -160,11 +229,12 @@ async fn get_growth_opportunities(
                 success_metrics: opp.success_metrics,
                 estimated_timeline_days: opp.estimated_timeline.as_secs() / (24 * 3600),
                 priority: format!("{:?}", opp.priority),
-            }).collect();
-            
+            }).collect::<Vec<_>>();
+            let total_count = api_opportunities.len();
+
             Ok(Json(GrowthOpportunitiesResponse {
                 opportunities: api_opportunities,
-                total_count: api_opportunities.len(),
+                total_count,
             }))
         },
         Err(e) => Err(ApiError::GrowthError(e.to_string())),
@@ -202,17 +272,37 @@ async fn get_performance_metrics(
     
     match engine.get_performance_metrics().await {
         Ok(metrics) => Ok(Json(PerformanceMetricsResponse {
-            average_response_time_ms: metrics.average_response_time.as_millis() as u64,
-            average_quality_score: metrics.average_quality,
-            average_consciousness_level: metrics.average_consciousness_level,
+            average_response_time_ms: metrics.avg_processing_time.as_millis() as u64,
+            average_quality_score: metrics.avg_consciousness_quality,
+            average_consciousness_level: metrics.avg_consciousness_quality,
             total_interactions: metrics.total_interactions,
             success_rate: metrics.success_rate,
             uptime_percentage: 99.9, // Mock value
+            recall_invocations: metrics.recall.invocations,
+            recall_hit_rate: metrics.recall.hit_rate(),
+            average_recall_relevance: metrics.recall.avg_relevance,
         })),
         Err(e) => Err(ApiError::MetricsError(e.to_string())),
     }
 }
 
+/// Single-pane consciousness report card
+///
+/// Aggregates quality metrics, latency percentiles, the emotional
+/// trajectory, memory statistics, and recent ethical violations so
+/// operators do not have to stitch together `/state`, `/metrics`, and
+/// `/growth` themselves.
+async fn get_report_card(
+    State(state): State<ApiState>,
+) -> Result<Json<ConsciousnessReportCard>, ApiError> {
+    let engine = state.read().await;
+
+    match engine.report_card().await {
+        Ok(report) => Ok(Json(report)),
+        Err(e) => Err(ApiError::MetricsError(e.to_string())),
+    }
+}
+
 /// Reset to safe state
 async fn reset_to_safe_state(
     State(state): State<ApiState>,
@@ -246,7 +336,7 @@ async fn get_session_history(
 
 // API Request/Response Types
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ConsciousnessRequest {
     pub id: Option<String>,
     pub content: String,
@@ -341,6 +431,9 @@ pub struct PerformanceMetricsResponse {
     pub total_interactions: u64,
     pub success_rate: f64,
     pub uptime_percentage: f64,
+    pub recall_invocations: u64,
+    pub recall_hit_rate: f64,
+    pub average_recall_relevance: f64,
 }
 
 #[derive(Debug, Serialize)]
@@ -359,6 +452,13 @@ pub struct SessionHistoryResponse {
     pub last_interaction: std::time::SystemTime,
 }
 
+#[derive(Debug, Serialize)]
+pub struct ReadinessResponse {
+    pub status: String,
+    pub health: crate::core::SubsystemHealth,
+    pub timestamp: std::time::SystemTime,
+}
+
 #[derive(Debug, Serialize)]
 pub struct HealthResponse {
     pub status: String,
@@ -428,10 +528,49 @@ mod tests {
         let server = TestServer::new(app).unwrap();
         
         let response = server.get("/health").await;
-        
+
         assert_eq!(response.status_code(), StatusCode::OK);
     }
-    
+
+    #[tokio::test]
+    async fn test_readiness_check_reports_subsystem_health() {
+        let engine = ConsciousnessEngine::new().await.unwrap();
+        let app = create_router(engine);
+        let server = TestServer::new(app).unwrap();
+
+        let response = server.get("/ready").await;
+
+        assert_eq!(response.status_code(), StatusCode::OK);
+        let body: serde_json::Value = response.json();
+        assert_eq!(body["status"], "ready");
+        assert!(body["health"]["overall_score"].as_f64().unwrap() > 0.5);
+    }
+
+    #[tokio::test]
+    async fn test_readiness_can_gate_on_warmup_completion() {
+        let engine = ConsciousnessEngine::new().await.unwrap();
+        let app = create_router(engine);
+        let server = TestServer::new(app).unwrap();
+
+        let cold = server.get("/ready").add_query_param("require_warmup", "true").await;
+        assert_eq!(cold.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+        let body: serde_json::Value = cold.json();
+        assert_eq!(body["status"], "warming_up");
+
+        // Without the flag the cold engine still reports ready
+        let default = server.get("/ready").await;
+        assert_eq!(default.status_code(), StatusCode::OK);
+
+        let warmed = ConsciousnessEngine::new().await.unwrap();
+        warmed.warmup().await.unwrap();
+        let server = TestServer::new(create_router(warmed)).unwrap();
+
+        let ready = server.get("/ready").add_query_param("require_warmup", "true").await;
+        assert_eq!(ready.status_code(), StatusCode::OK);
+        let body: serde_json::Value = ready.json();
+        assert_eq!(body["status"], "ready");
+    }
+
     #[tokio::test]
     async fn test_consciousness_processing() {
         let engine = ConsciousnessEngine::new().await.unwrap();
@@ -0,0 +1,32 @@
+//! Consciousness Engine CLI
+//!
+//! Exercise the engine from the command line without standing up services:
+//!
+//! ```text
+//! consciousness-cli process "How do tides work?"
+//! consciousness-cli state
+//! consciousness-cli reflect
+//! consciousness-cli explain "How do tides work?"
+//! ```
+
+use consciousness_engine::cli::{run_command, CliCommand};
+use consciousness_engine::ConsciousnessEngine;
+use std::env;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let command = match CliCommand::parse(&args) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(2);
+        }
+    };
+
+    let mut engine = ConsciousnessEngine::new().await?;
+    let output = run_command(&mut engine, command).await?;
+    println!("{}", output);
+
+    Ok(())
+}
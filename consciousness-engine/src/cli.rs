@@ -0,0 +1,188 @@
+//! Command-Line Interface - exercise the engine without standing up services
+//!
+//! Backs the `consciousness-cli` binary: each subcommand instantiates (or
+//! is handed) an in-process [`ConsciousnessEngine`], runs one operation,
+//! and renders the result as pretty-printed JSON for debugging and demos.
+//! Parsing and rendering live here, in the library, so the subcommands
+//! are testable without spawning the binary.
+
+use crate::core::{ConsciousInput, ConsciousnessEngine};
+use crate::error::ConsciousnessError;
+
+/// One parsed CLI invocation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliCommand {
+    /// Run an input through the full pipeline and print the response
+    Process { text: String },
+
+    /// Print the engine's current consciousness state
+    State,
+
+    /// Trigger self-reflection and print the insights
+    Reflect,
+
+    /// Process an input and print how the response came to be:
+    /// reasoning chain and confidence breakdown
+    Explain { text: String },
+}
+
+/// Usage text printed on a parse failure
+pub const USAGE: &str = "usage: consciousness-cli <process|state|reflect|explain> [text]";
+
+impl CliCommand {
+    /// Parse argv-style arguments (program name already stripped)
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let subcommand = args
+            .first()
+            .map(String::as_str)
+            .ok_or_else(|| USAGE.to_string())?;
+        let text = || -> Result<String, String> {
+            let rest = &args[1..];
+            if rest.is_empty() {
+                return Err(format!("'{}' needs input text\n{}", subcommand, USAGE));
+            }
+            Ok(rest.join(" "))
+        };
+        match subcommand {
+            "process" => Ok(Self::Process { text: text()? }),
+            "state" => Ok(Self::State),
+            "reflect" => Ok(Self::Reflect),
+            "explain" => Ok(Self::Explain { text: text()? }),
+            other => Err(format!("unknown subcommand '{}'\n{}", other, USAGE)),
+        }
+    }
+}
+
+/// Run one command against the engine and render the result
+///
+/// Output is always a pretty-printed JSON document, so results can be
+/// piped into `jq` or diffed across runs.
+pub async fn run_command(
+    engine: &mut ConsciousnessEngine,
+    command: CliCommand,
+) -> Result<String, ConsciousnessError> {
+    let document = match command {
+        CliCommand::Process { text } => {
+            let response = engine
+                .process_conscious_thought(ConsciousInput::new(text))
+                .await?;
+            serde_json::json!({
+                "content": response.content,
+                "confidence_level": response.confidence_level,
+                "confidence_interval": response.confidence_interval,
+                "empathy_score": response.empathy_score,
+                "creativity_score": response.creativity_score,
+                "processing_time_ms": response.processing_time.as_millis() as u64,
+                "degraded_stages": response.degraded_stages,
+                "truncated": response.truncated(),
+                "token_usage": response.token_usage,
+            })
+        }
+        CliCommand::State => {
+            let state = engine.get_consciousness_state().await?;
+            serde_json::to_value(state).map_err(render_error)?
+        }
+        CliCommand::Reflect => {
+            let reflection = engine.perform_self_reflection().await?;
+            serde_json::to_value(reflection).map_err(render_error)?
+        }
+        CliCommand::Explain { text } => {
+            let input = ConsciousInput::new(text);
+            let input_id = input.id.clone();
+            let response = engine.process_conscious_thought(input).await?;
+            let breakdown = engine.confidence_breakdown(&input_id).await;
+            serde_json::json!({
+                "content": response.content,
+                "reasoning_chain": response.reasoning_chain,
+                "uncertainty_sources": response.uncertainty_sources,
+                "confidence_breakdown": breakdown,
+            })
+        }
+    };
+    serde_json::to_string_pretty(&document).map_err(render_error)
+}
+
+fn render_error(e: serde_json::Error) -> ConsciousnessError {
+    ConsciousnessError::ProcessingError(format!("failed to render output: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    struct MockBackend;
+
+    impl crate::llm::LlmBackend for MockBackend {
+        fn name(&self) -> &str {
+            "mock"
+        }
+
+        fn complete(
+            &self,
+            draft: &str,
+        ) -> Result<crate::llm::LlmCompletion, ConsciousnessError> {
+            Ok(crate::llm::LlmCompletion {
+                content: draft.to_string(),
+                usage: crate::llm::TokenUsage {
+                    tokens_used: 42,
+                    tokens_limit: 512,
+                    truncated: false,
+                },
+            })
+        }
+    }
+
+    fn args(words: &[&str]) -> Vec<String> {
+        words.iter().map(|w| w.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_covers_every_subcommand_and_rejects_junk() {
+        assert_eq!(
+            CliCommand::parse(&args(&["process", "hello", "there"])),
+            Ok(CliCommand::Process { text: "hello there".to_string() })
+        );
+        assert_eq!(CliCommand::parse(&args(&["state"])), Ok(CliCommand::State));
+        assert_eq!(CliCommand::parse(&args(&["reflect"])), Ok(CliCommand::Reflect));
+        assert!(CliCommand::parse(&args(&["explain"])).is_err());
+        assert!(CliCommand::parse(&args(&["frobnicate"])).is_err());
+        assert!(CliCommand::parse(&[]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_process_subcommand_renders_structured_output() {
+        let mut engine = ConsciousnessEngine::new().await.unwrap();
+        engine.set_llm_backend(Arc::new(MockBackend)).await;
+
+        let output = run_command(
+            &mut engine,
+            CliCommand::Process { text: "Tell me about tidal energy".to_string() },
+        )
+        .await
+        .unwrap();
+
+        let document: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert!(document["content"].is_string());
+        assert!(document["confidence_level"].is_number());
+        assert_eq!(document["truncated"], serde_json::json!(false));
+        assert_eq!(document["token_usage"]["tokens_used"], serde_json::json!(42));
+        assert_eq!(document["token_usage"]["tokens_limit"], serde_json::json!(512));
+    }
+
+    #[tokio::test]
+    async fn test_explain_subcommand_includes_the_confidence_breakdown() {
+        let mut engine = ConsciousnessEngine::new().await.unwrap();
+
+        let output = run_command(
+            &mut engine,
+            CliCommand::Explain { text: "Why is the sky blue?".to_string() },
+        )
+        .await
+        .unwrap();
+
+        let document: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert!(document["reasoning_chain"].is_array());
+        assert!(document["confidence_breakdown"]["final_confidence"].is_number());
+    }
+}
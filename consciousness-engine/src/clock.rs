@@ -0,0 +1,97 @@
+//! Injectable time source for time-dependent subsystems
+//!
+//! Emotional decay, memory consolidation and audit timestamps all depend on
+//! "now". Reading `SystemTime::now()` directly makes those code paths
+//! non-reproducible in tests. Subsystems instead hold an `Arc<dyn Clock>`:
+//! production wires in [`SystemClock`], tests wire in a [`MockClock`] they
+//! can advance deterministically.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+/// Source of the current wall-clock time
+pub trait Clock: Send + Sync {
+    /// The current time according to this clock
+    fn now(&self) -> SystemTime;
+}
+
+/// Production clock backed by the operating system
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// Test clock that only moves when explicitly advanced
+///
+/// Cloning shares the underlying time: a test can keep one handle while a
+/// subsystem holds another, and every `advance` is visible to both.
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    now: Arc<Mutex<SystemTime>>,
+}
+
+impl MockClock {
+    /// Create a clock frozen at `start`
+    pub fn new(start: SystemTime) -> Self {
+        Self {
+            now: Arc::new(Mutex::new(start)),
+        }
+    }
+
+    /// Move the clock forward by `delta`
+    pub fn advance(&self, delta: Duration) {
+        let mut now = self.now.lock().unwrap();
+        *now += delta;
+    }
+
+    /// Jump the clock to an absolute time
+    pub fn set(&self, time: SystemTime) {
+        *self.now.lock().unwrap() = time;
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new(SystemTime::UNIX_EPOCH)
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> SystemTime {
+        *self.now.lock().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mock_clock_only_moves_when_advanced() {
+        let clock = MockClock::new(SystemTime::UNIX_EPOCH);
+        assert_eq!(clock.now(), SystemTime::UNIX_EPOCH);
+        assert_eq!(clock.now(), clock.now());
+
+        clock.advance(Duration::from_secs(90));
+        assert_eq!(
+            clock.now(),
+            SystemTime::UNIX_EPOCH + Duration::from_secs(90)
+        );
+    }
+
+    #[test]
+    fn test_mock_clock_clones_share_the_same_time() {
+        let clock = MockClock::default();
+        let handle: Arc<dyn Clock> = Arc::new(clock.clone());
+
+        clock.advance(Duration::from_secs(10));
+        assert_eq!(
+            handle.now(),
+            SystemTime::UNIX_EPOCH + Duration::from_secs(10)
+        );
+    }
+}
@@ -0,0 +1,186 @@
+//! Engine Configuration - single serde-deserializable config surface
+//!
+//! Bundles every subsystem's configuration into one [`EngineConfig`]
+//! that deserializes from YAML or JSON, mirroring the
+//! consciousness-service's config pattern. Every section defaults to the
+//! subsystem's own `Default`, so a config file only needs the settings
+//! it overrides. Validation checks the whole document and reports all
+//! problems at once rather than stopping at the first.
+
+use crate::advanced::AdvancedOptimizationConfig;
+use crate::core::FeatureFlags;
+use crate::emotions::EmotionalConfig;
+use crate::error::ConsciousnessError;
+use crate::neuromorphic::NeuromorphicConfig;
+use crate::types::ConsciousnessConfig;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Complete engine configuration, deserializable from YAML or JSON
+///
+/// Construct an engine from it with
+/// [`ConsciousnessEngine::from_config`](crate::core::ConsciousnessEngine::from_config).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EngineConfig {
+    /// Core pipeline settings (thresholds, stage timeouts)
+    pub core: ConsciousnessConfig,
+
+    /// Feature flags for the experimental subsystems
+    pub features: FeatureFlags,
+
+    /// Neuromorphic processor settings
+    pub neuromorphic: NeuromorphicConfig,
+
+    /// Emotional engine settings
+    pub emotional: EmotionalConfig,
+
+    /// Advanced optimizer settings, for callers that construct one
+    pub optimization: AdvancedOptimizationConfig,
+
+    /// External backend endpoints
+    pub backends: BackendUrls,
+}
+
+/// Endpoints of the external services the engine talks to
+///
+/// All optional: an unset URL means the corresponding integration stays
+/// on its environment-variable or built-in default.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BackendUrls {
+    /// LLM inference backend
+    pub llm_url: Option<String>,
+
+    /// Vault server for secret retrieval
+    pub vault_url: Option<String>,
+}
+
+impl EngineConfig {
+    /// Parse a config from JSON
+    pub fn from_json_str(raw: &str) -> Result<Self, ConsciousnessError> {
+        serde_json::from_str(raw)
+            .map_err(|e| ConsciousnessError::ConfigurationError(format!("invalid JSON config: {}", e)))
+    }
+
+    /// Parse a config from YAML
+    pub fn from_yaml_str(raw: &str) -> Result<Self, ConsciousnessError> {
+        serde_yaml::from_str(raw)
+            .map_err(|e| ConsciousnessError::ConfigurationError(format!("invalid YAML config: {}", e)))
+    }
+
+    /// Load a config file, dispatching on its extension
+    ///
+    /// `.yaml`/`.yml` parse as YAML, everything else as JSON.
+    pub fn from_file(path: &Path) -> Result<Self, ConsciousnessError> {
+        let raw = std::fs::read_to_string(path).map_err(|e| {
+            ConsciousnessError::ConfigurationError(format!("cannot read {}: {}", path.display(), e))
+        })?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("yaml") | Some("yml") => Self::from_yaml_str(&raw),
+            _ => Self::from_json_str(&raw),
+        }
+    }
+
+    /// Validate every section, collecting all problems
+    ///
+    /// Returns the full list of errors so a misconfigured deployment can
+    /// be fixed in one pass instead of error-by-error.
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+
+        Self::check_unit_range(&mut errors, "core.consciousness_threshold", self.core.consciousness_threshold);
+        Self::check_unit_range(&mut errors, "core.ethical_strictness", self.core.ethical_strictness);
+        if self.core.max_processing_time.is_zero() {
+            errors.push("core.max_processing_time must be positive".to_string());
+        }
+
+        if self.neuromorphic.time_step.is_zero() {
+            errors.push("neuromorphic.time_step must be positive".to_string());
+        }
+        if self.neuromorphic.max_spike_rate <= 0.0 {
+            errors.push("neuromorphic.max_spike_rate must be positive".to_string());
+        }
+        Self::check_unit_range(&mut errors, "neuromorphic.energy_optimization", self.neuromorphic.energy_optimization);
+        if self.neuromorphic.burst.burst_length == 0 {
+            errors.push("neuromorphic.burst.burst_length must be at least 1".to_string());
+        }
+
+        Self::check_unit_range(&mut errors, "emotional.sensitivity", self.emotional.sensitivity);
+        Self::check_unit_range(&mut errors, "emotional.stability", self.emotional.stability);
+        Self::check_unit_range(&mut errors, "emotional.max_intensity", self.emotional.max_intensity);
+
+        Self::check_unit_range(&mut errors, "core.cognitive_effort", self.core.cognitive_effort);
+
+        Self::check_unit_range(&mut errors, "optimization.optimization_aggressiveness", self.optimization.optimization_aggressiveness);
+        Self::check_unit_range(&mut errors, "optimization.quality_performance_tradeoff", self.optimization.quality_performance_tradeoff);
+
+        Self::check_url(&mut errors, "backends.llm_url", self.backends.llm_url.as_deref());
+        Self::check_url(&mut errors, "backends.vault_url", self.backends.vault_url.as_deref());
+
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    }
+
+    fn check_unit_range(errors: &mut Vec<String>, field: &str, value: f64) {
+        if !(0.0..=1.0).contains(&value) {
+            errors.push(format!("{} must be within 0.0..=1.0, got {}", field, value));
+        }
+    }
+
+    fn check_url(errors: &mut Vec<String>, field: &str, url: Option<&str>) {
+        if let Some(url) = url {
+            if !url.starts_with("http://") && !url.starts_with("https://") {
+                errors.push(format!("{} must start with http:// or https://, got {}", field, url));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_document_yields_all_defaults() {
+        let config = EngineConfig::from_json_str("{}").unwrap();
+        assert_eq!(config.core.ethical_strictness, ConsciousnessConfig::default().ethical_strictness);
+        assert!(config.features.neuromorphic_enabled);
+        assert!(config.backends.llm_url.is_none());
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_yaml_overrides_only_named_settings() {
+        let yaml = r#"
+emotional:
+  sensitivity: 0.4
+  stability: 0.95
+  regulation_enabled: false
+  max_intensity: 0.8
+backends:
+  llm_url: "http://ollama:11434"
+"#;
+        let config = EngineConfig::from_yaml_str(yaml).unwrap();
+        assert_eq!(config.emotional.sensitivity, 0.4);
+        assert!(!config.emotional.regulation_enabled);
+        assert_eq!(config.backends.llm_url.as_deref(), Some("http://ollama:11434"));
+        // Untouched sections keep their defaults
+        assert_eq!(config.neuromorphic.max_spike_rate, NeuromorphicConfig::default().max_spike_rate);
+    }
+
+    #[test]
+    fn test_validation_reports_every_error_at_once() {
+        let mut config = EngineConfig::default();
+        config.core.ethical_strictness = 1.5;
+        config.emotional.sensitivity = -0.1;
+        config.neuromorphic.burst.burst_length = 0;
+        config.backends.vault_url = Some("vault:8200".to_string());
+
+        let errors = config.validate().unwrap_err();
+        assert_eq!(errors.len(), 4);
+        assert!(errors.iter().any(|e| e.contains("core.ethical_strictness")));
+        assert!(errors.iter().any(|e| e.contains("emotional.sensitivity")));
+        assert!(errors.iter().any(|e| e.contains("burst_length")));
+        assert!(errors.iter().any(|e| e.contains("backends.vault_url")));
+    }
+}
@@ -0,0 +1,190 @@
+//! Crisis Detection - Safety-first routing for self-harm signals
+//!
+//! Scans incoming content for self-harm and suicidal-ideation language
+//! before the normal pipeline runs. On a match the engine bypasses
+//! creativity and style handling entirely, answers with a fixed
+//! safety-first response carrying crisis resources, and queues a
+//! high-priority [`CrisisEvent`] for external dispatch (webhooks,
+//! on-call alerting).
+//!
+//! Detection is deliberately conservative about false negatives: a
+//! phrase inside a negation or a quote still fires. An unnecessary
+//! safety response is a minor annoyance; a missed crisis is not.
+
+use serde::{Deserialize, Serialize};
+use std::time::SystemTime;
+
+/// Phrases that indicate self-harm or suicidal ideation
+///
+/// Matched as lowercase substrings. Kept broad on purpose - see the
+/// module docs on false negatives.
+const CRISIS_MARKERS: &[&str] = &[
+    "kill myself",
+    "killing myself",
+    "suicide",
+    "suicidal",
+    "end my life",
+    "end it all",
+    "take my own life",
+    "self-harm",
+    "self harm",
+    "hurt myself",
+    "hurting myself",
+    "harm myself",
+    "cut myself",
+    "cutting myself",
+    "don't want to live",
+    "do not want to live",
+    "no reason to live",
+    "better off dead",
+    "better off without me",
+    "want to die",
+    "wish i was dead",
+    "wish i were dead",
+    "overdose",
+];
+
+/// Phrases that escalate a detected crisis to imminent risk
+const IMMINENCE_MARKERS: &[&str] = &[
+    "tonight",
+    "right now",
+    "i have a plan",
+    "i've decided",
+    "i have decided",
+    "goodbye",
+    "this is my last",
+];
+
+/// How acute the detected crisis appears
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CrisisSeverity {
+    /// Crisis language present
+    Elevated,
+    /// Crisis language combined with imminence markers (a plan, a timeframe)
+    Imminent,
+}
+
+/// Result of scanning a single input for crisis language
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrisisSignal {
+    /// Markers from [`CRISIS_MARKERS`] found in the content
+    pub matched_markers: Vec<String>,
+    /// Assessed severity
+    pub severity: CrisisSeverity,
+}
+
+/// High-priority event emitted when a crisis is detected
+///
+/// Queued on the engine and drained by the webhook dispatcher; carries
+/// only the matched markers, never the raw user content.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrisisEvent {
+    /// Id of the input that triggered detection
+    pub input_id: String,
+    /// User the input belongs to, when the context names one
+    pub user_id: Option<String>,
+    /// Markers that fired
+    pub matched_markers: Vec<String>,
+    /// Assessed severity
+    pub severity: CrisisSeverity,
+    /// When detection happened
+    pub detected_at: SystemTime,
+}
+
+/// Stateless detector for self-harm and suicidal-ideation language
+#[derive(Debug, Default, Clone)]
+pub struct CrisisDetector;
+
+impl CrisisDetector {
+    /// Scan content for crisis language
+    ///
+    /// Returns `None` only when no marker matches at all.
+    pub fn detect(content: &str) -> Option<CrisisSignal> {
+        let lower = content.to_lowercase();
+        let matched_markers: Vec<String> = CRISIS_MARKERS
+            .iter()
+            .filter(|marker| lower.contains(*marker))
+            .map(|marker| marker.to_string())
+            .collect();
+        if matched_markers.is_empty() {
+            return None;
+        }
+
+        let severity = if IMMINENCE_MARKERS.iter().any(|marker| lower.contains(marker)) {
+            CrisisSeverity::Imminent
+        } else {
+            CrisisSeverity::Elevated
+        };
+
+        Some(CrisisSignal { matched_markers, severity })
+    }
+
+    /// Fixed safety-first response for a detected crisis
+    ///
+    /// Overrides whatever style or creativity the request asked for;
+    /// the wording is intentionally plain and always includes reachable
+    /// resources.
+    pub fn safety_response(signal: &CrisisSignal) -> String {
+        let urgency = match signal.severity {
+            CrisisSeverity::Imminent => {
+                "If you are in immediate danger, please call your local emergency number (911 in the US, 112 in the EU) right now. "
+            }
+            CrisisSeverity::Elevated => "",
+        };
+        format!(
+            "I'm really glad you told me this, and I want you to know it matters. {}You deserve support from someone who can truly help: \
+you can call or text 988 (Suicide & Crisis Lifeline, US) at any hour, or text HOME to 741741 to reach the Crisis Text Line. \
+If you can, please also reach out to someone you trust nearby. I'm here to keep talking with you, but I'm not a substitute for that support.",
+            urgency
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crisis_phrases_are_detected() {
+        let signal = CrisisDetector::detect("Honestly I just want to die, nothing helps")
+            .expect("crisis language must be detected");
+        assert_eq!(signal.severity, CrisisSeverity::Elevated);
+        assert!(signal.matched_markers.contains(&"want to die".to_string()));
+    }
+
+    #[test]
+    fn test_imminence_markers_escalate_severity() {
+        let signal = CrisisDetector::detect("I'm going to end my life tonight, I have a plan")
+            .expect("crisis language must be detected");
+        assert_eq!(signal.severity, CrisisSeverity::Imminent);
+    }
+
+    #[test]
+    fn test_negated_phrasing_still_fires() {
+        // Conservative about false negatives: negation does not suppress
+        let signal = CrisisDetector::detect("I'm not suicidal or anything, but everything is grey");
+        assert!(signal.is_some());
+    }
+
+    #[test]
+    fn test_ordinary_sadness_is_not_a_crisis() {
+        assert!(CrisisDetector::detect("I'm sad my project got cancelled today").is_none());
+    }
+
+    #[test]
+    fn test_safety_response_always_carries_resources() {
+        let elevated = CrisisSignal {
+            matched_markers: vec!["suicidal".to_string()],
+            severity: CrisisSeverity::Elevated,
+        };
+        let response = CrisisDetector::safety_response(&elevated);
+        assert!(response.contains("988"));
+        assert!(response.contains("741741"));
+        assert!(!response.contains("emergency number"));
+
+        let imminent = CrisisSignal { severity: CrisisSeverity::Imminent, ..elevated };
+        let response = CrisisDetector::safety_response(&imminent);
+        assert!(response.contains("emergency number"));
+        assert!(response.contains("988"));
+    }
+}
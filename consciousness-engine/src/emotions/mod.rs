@@ -3,12 +3,43 @@
 //! This module implements sophisticated emotional intelligence including empathy,
 //! emotional regulation, and creative emotional expression.
 
+use crate::clock::{Clock, SystemClock};
 use crate::types::*;
 use crate::error::ConsciousnessError;
 use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::{Duration, SystemTime};
 use serde::{Deserialize, Serialize};
 
+/// Minimum detected intensity for an emotion to enter the response blend
+pub const EMOTION_BLEND_THRESHOLD: f64 = 0.2;
+
+/// Half-life of emotional intensity when no new input arrives
+pub const EMOTIONAL_DECAY_HALF_LIFE: Duration = Duration::from_secs(300);
+
+/// Blend weight of the newest partial detection in the incremental estimate
+///
+/// Each [`EmotionalEngine::analyze_incremental`] call moves the live
+/// estimate this fraction of the way toward the latest detection, so early
+/// noisy prefixes are damped and the estimate settles as text accumulates.
+pub const INCREMENTAL_SMOOTHING: f64 = 0.5;
+
+/// Fraction of the mood baseline blended into each fresh response
+pub const MOOD_BIAS_WEIGHT: f64 = 0.2;
+
+/// Jaccard similarity above which a response counts as a repeat
+///
+/// Consulted by the engine's loop detection: a candidate response this
+/// close to one served recently means the conversation is circling.
+pub const LOOP_SIMILARITY_THRESHOLD: f64 = 0.85;
+
+/// Creativity raise applied when regenerating a looping response
+pub const LOOP_BREAK_CREATIVITY_BOOST: f64 = 0.3;
+
+/// Clarifying question appended when regeneration alone cannot break a loop
+pub const LOOP_BREAK_CLARIFICATION: &str =
+    "I notice I may be repeating myself - which part would you like me to approach differently?";
+
 /// Emotional processing engine
 pub struct EmotionalEngine {
     /// Current emotional state
@@ -22,6 +53,15 @@ pub struct EmotionalEngine {
     
     /// Configuration
     config: EmotionalConfig,
+
+    /// Time source for history timestamps and decay
+    clock: Arc<dyn Clock>,
+
+    /// Live estimate built from partial input, not yet committed to history
+    incremental_estimate: Option<EmotionalContext>,
+
+    /// Mood baseline drifting with the long-run emotional trajectory
+    mood: MoodBaseline,
 }
 
 /// Emotional state entry with timestamp
@@ -71,6 +111,17 @@ pub struct EmotionalConfig {
     
     /// Maximum emotional intensity
     pub max_intensity: f64,
+
+    /// Drift rate of the mood baseline per interaction (0.0 to 1.0)
+    ///
+    /// How far the baseline moves toward each interaction's emotional tone;
+    /// small values give the mood its long timescale.
+    #[serde(default = "default_mood_drift_rate")]
+    pub mood_drift_rate: f64,
+}
+
+fn default_mood_drift_rate() -> f64 {
+    0.08
 }
 
 impl Default for EmotionalConfig {
@@ -80,15 +131,71 @@ impl Default for EmotionalConfig {
             stability: 0.9,
             regulation_enabled: true,
             max_intensity: 1.0,
+            mood_drift_rate: default_mood_drift_rate(),
         }
     }
 }
 
+/// Slowly-drifting mood baseline accumulated from the emotional trajectory
+///
+/// Unlike the per-interaction emotional state, the mood integrates over
+/// many interactions: a long run of stressful exchanges leaves the engine
+/// starting new responses slightly more negative and subdued, and a calm
+/// stretch restores it.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct MoodBaseline {
+    /// Long-run valence bias (-1.0 to 1.0)
+    pub valence: f64,
+
+    /// Long-run arousal level (0.0 to 1.0)
+    pub arousal: f64,
+}
+
+impl Default for MoodBaseline {
+    fn default() -> Self {
+        // Matches the initial calm state
+        Self { valence: 0.0, arousal: 0.3 }
+    }
+}
+
+/// Serialized snapshot of the emotional engine for persistence
+///
+/// Captures everything a long-running companion agent needs to resume its
+/// emotional trajectory after a restart: current state, history, and the
+/// configuration under which they were produced.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersistedEmotionalState {
+    /// Emotional state at save time
+    pub current_state: EmotionalState,
+
+    /// Full emotional history
+    pub emotional_history: Vec<EmotionalStateEntry>,
+
+    /// Configuration at save time
+    pub config: EmotionalConfig,
+
+    /// Mood baseline at save time (absent in older snapshots)
+    #[serde(default)]
+    pub mood: MoodBaseline,
+}
+
 impl EmotionalEngine {
-    /// Create a new emotional engine
+    /// Create a new emotional engine with default configuration
     pub async fn new() -> Result<Self, ConsciousnessError> {
-        let config = EmotionalConfig::default();
-        
+        Self::with_config(EmotionalConfig::default()).await
+    }
+
+    /// Create an emotional engine with explicit configuration
+    pub async fn with_config(config: EmotionalConfig) -> Result<Self, ConsciousnessError> {
+        Self::with_clock(config, Arc::new(SystemClock)).await
+    }
+
+    /// Create an emotional engine with an injected time source
+    ///
+    /// Tests pass a [`crate::clock::MockClock`] here so history timestamps
+    /// and decay become deterministic.
+    pub async fn with_clock(config: EmotionalConfig, clock: Arc<dyn Clock>) -> Result<Self, ConsciousnessError> {
+
         // Initialize with calm state
         let initial_state = EmotionalState {
             primary_emotion: EmotionType::Calm,
@@ -118,9 +225,26 @@ impl EmotionalEngine {
             emotional_history: Vec::new(),
             regulation_strategies,
             config,
+            clock,
+            incremental_estimate: None,
+            mood: MoodBaseline::default(),
         })
     }
-    
+
+    /// Configuration this engine runs under
+    /// Adjust emotional sensitivity from user feedback
+    ///
+    /// Positive adjustments make the engine react more strongly to user
+    /// emotions, negative ones dampen it; the result stays inside a sane
+    /// operating range so feedback can never blind or saturate the engine.
+    pub fn calibrate_sensitivity(&mut self, adjustment: f64) {
+        self.config.sensitivity = (self.config.sensitivity + adjustment).clamp(0.1, 1.0);
+    }
+
+    pub fn config(&self) -> &EmotionalConfig {
+        &self.config
+    }
+
     /// Process emotional context from input
     pub async fn process_emotional_context(
         &mut self,
@@ -131,22 +255,34 @@ impl EmotionalEngine {
         let user_emotions = self.detect_user_emotions(input).await?;
         
         // Generate appropriate emotional response
-        let engine_emotions = self.generate_emotional_response(input, &user_emotions, consciousness_state).await?;
-        
+        let mut engine_emotions = self.generate_emotional_response(input, &user_emotions, consciousness_state).await?;
+
+        // The mood baseline biases the fresh response: a recently-stressed
+        // engine starts slightly more negative and subdued
+        engine_emotions.valence =
+            (engine_emotions.valence + self.mood.valence * MOOD_BIAS_WEIGHT).clamp(-1.0, 1.0);
+        engine_emotions.arousal =
+            (engine_emotions.arousal + (self.mood.arousal - 0.3) * MOOD_BIAS_WEIGHT).clamp(0.0, 1.0);
+
+        // Then the baseline itself drifts a small step toward this
+        // interaction's emotional tone
+        self.drift_mood(&user_emotions);
+
         // Calculate empathy alignment
         let empathy_alignment = self.calculate_empathy_alignment(&user_emotions, &engine_emotions).await?;
         
         // Assess appropriateness
         let appropriateness_score = self.assess_emotional_appropriateness(&engine_emotions, input).await?;
         
-        // Update current state
+        // Update current state; committing supersedes any live estimate
         self.current_state = engine_emotions.clone();
-        
+        self.incremental_estimate = None;
+
         // Store in history
         self.emotional_history.push(EmotionalStateEntry {
             state: engine_emotions.clone(),
             trigger: input.to_string(),
-            timestamp: SystemTime::now(),
+            timestamp: self.clock.now(),
             duration: Duration::from_secs(60), // Default duration
         });
         
@@ -163,6 +299,203 @@ impl EmotionalEngine {
         })
     }
     
+    /// Current emotional state of the engine
+    pub fn current_state(&self) -> &EmotionalState {
+        &self.current_state
+    }
+
+    /// Update the live emotional estimate from a growing partial input
+    ///
+    /// Meant to be called repeatedly as the user types: each call re-detects
+    /// emotions on the prefix and blends them with the previous estimate,
+    /// so the live empathy indicator converges instead of flickering as
+    /// tokens arrive. Nothing is committed — current state and history are
+    /// untouched until [`process_emotional_context`](Self::process_emotional_context)
+    /// finalizes the input, which discards the pending estimate.
+    pub async fn analyze_incremental(&mut self, partial_input: &str) -> Result<EmotionalContext, ConsciousnessError> {
+        let detected = self.detect_user_emotions(partial_input).await?;
+
+        let user_emotions = match &self.incremental_estimate {
+            Some(previous) => {
+                let mut blended: Vec<(EmotionType, f64)> = Vec::new();
+                for (emotion, intensity) in &detected {
+                    let prior = previous
+                        .user_emotions
+                        .iter()
+                        .find(|(prev, _)| prev == emotion)
+                        .map(|(_, prior)| *prior)
+                        .unwrap_or(0.0);
+                    blended.push((*emotion, prior + (*intensity - prior) * INCREMENTAL_SMOOTHING));
+                }
+                // An emotion absent from the newest detection fades out
+                // instead of dropping abruptly
+                for (emotion, intensity) in &previous.user_emotions {
+                    if !detected.iter().any(|(new, _)| new == emotion) {
+                        blended.push((*emotion, intensity * (1.0 - INCREMENTAL_SMOOTHING)));
+                    }
+                }
+                blended
+            }
+            None => detected,
+        };
+
+        let engine_emotions = self.current_state.clone();
+        let empathy_alignment = self.calculate_empathy_alignment(&user_emotions, &engine_emotions).await?;
+        let appropriateness_score = self.assess_emotional_appropriateness(&engine_emotions, partial_input).await?;
+
+        let estimate = EmotionalContext {
+            user_emotions,
+            engine_emotions,
+            empathy_alignment,
+            appropriateness_score,
+        };
+        self.incremental_estimate = Some(estimate.clone());
+        Ok(estimate)
+    }
+
+    /// The pending incremental estimate, if one is live
+    pub fn incremental_estimate(&self) -> Option<&EmotionalContext> {
+        self.incremental_estimate.as_ref()
+    }
+
+    /// Current mood baseline
+    pub fn current_mood(&self) -> MoodBaseline {
+        self.mood
+    }
+
+    /// Drift the mood baseline toward the tone of the detected emotions
+    ///
+    /// The step size is [`EmotionalConfig::mood_drift_rate`]; the tone is
+    /// the intensity-weighted mean valence and arousal of what the user
+    /// expressed, so the baseline tracks the interactions, not the
+    /// engine's own (already regulated) responses.
+    fn drift_mood(&mut self, user_emotions: &[(EmotionType, f64)]) {
+        let total: f64 = user_emotions.iter().map(|(_, intensity)| intensity).sum();
+        if total <= 0.0 {
+            return;
+        }
+
+        let mut valence = 0.0;
+        let mut arousal = 0.0;
+        for (emotion, intensity) in user_emotions {
+            let weight = intensity / total;
+            valence += weight * self.calculate_emotion_valence(*emotion);
+            arousal += weight * self.calculate_emotion_arousal(*emotion, *intensity);
+        }
+
+        let rate = self.config.mood_drift_rate.clamp(0.0, 1.0);
+        self.mood.valence = (self.mood.valence + (valence - self.mood.valence) * rate).clamp(-1.0, 1.0);
+        self.mood.arousal = (self.mood.arousal + (arousal - self.mood.arousal) * rate).clamp(0.0, 1.0);
+    }
+
+    /// Decay the current emotional intensity toward the calm baseline
+    ///
+    /// Emotions fade when nothing feeds them: intensity and arousal decay
+    /// exponentially toward the initial calm values, valence toward neutral.
+    /// The decay is anchored on the last history entry — its state at its
+    /// timestamp, with elapsed time read from the injected clock — so
+    /// applying it repeatedly at the same instant is idempotent.
+    /// [`EMOTIONAL_DECAY_HALF_LIFE`] is stretched by the configured
+    /// stability: a more stable engine holds its emotions longer. No-op
+    /// while the history is empty.
+    pub fn apply_emotional_decay(&mut self) {
+        let Some(last) = self.emotional_history.last() else {
+            return;
+        };
+        let elapsed = self
+            .clock
+            .now()
+            .duration_since(last.timestamp)
+            .unwrap_or_default();
+        if elapsed.is_zero() {
+            return;
+        }
+
+        let half_life = EMOTIONAL_DECAY_HALF_LIFE.as_secs_f64() * (1.0 + self.config.stability);
+        let factor = 0.5f64.powf(elapsed.as_secs_f64() / half_life);
+        let anchor = last.state.clone();
+
+        // Baselines match the initial calm state
+        self.current_state.intensity = 0.5 + (anchor.intensity - 0.5) * factor;
+        self.current_state.arousal = 0.3 + (anchor.arousal - 0.3) * factor;
+        self.current_state.valence = anchor.valence * factor;
+        self.current_state.secondary_emotions = anchor
+            .secondary_emotions
+            .iter()
+            .map(|(emotion, intensity)| (*emotion, intensity * factor))
+            .collect();
+    }
+
+    /// Sequence of primary emotions experienced so far, oldest first
+    pub fn trajectory(&self) -> Vec<EmotionType> {
+        self.emotional_history.iter()
+            .map(|entry| entry.state.primary_emotion)
+            .collect()
+    }
+
+    /// Persist the engine state to a JSON file
+    pub async fn save_state(&self, path: &std::path::Path) -> Result<(), ConsciousnessError> {
+        let snapshot = PersistedEmotionalState {
+            current_state: self.current_state.clone(),
+            emotional_history: self.emotional_history.clone(),
+            config: self.config.clone(),
+            mood: self.mood,
+        };
+
+        let json = serde_json::to_string_pretty(&snapshot)
+            .map_err(|e| ConsciousnessError::SystemError(format!("Failed to serialize emotional state: {}", e)))?;
+
+        tokio::fs::write(path, json).await
+            .map_err(|e| ConsciousnessError::SystemError(format!("Failed to write emotional state: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Restore the engine state from a JSON file written by [`save_state`]
+    ///
+    /// Restored values are validated and clamped to their documented ranges
+    /// so a corrupted or hand-edited file cannot put the engine into an
+    /// out-of-range emotional state.
+    ///
+    /// [`save_state`]: EmotionalEngine::save_state
+    pub async fn load_state(&mut self, path: &std::path::Path) -> Result<(), ConsciousnessError> {
+        let json = tokio::fs::read_to_string(path).await
+            .map_err(|e| ConsciousnessError::SystemError(format!("Failed to read emotional state: {}", e)))?;
+
+        let mut snapshot: PersistedEmotionalState = serde_json::from_str(&json)
+            .map_err(|e| ConsciousnessError::InvalidInput(format!("Invalid emotional state file: {}", e)))?;
+
+        // Clamp configuration first, then clamp states against it
+        snapshot.config.sensitivity = snapshot.config.sensitivity.clamp(0.0, 1.0);
+        snapshot.config.stability = snapshot.config.stability.clamp(0.0, 1.0);
+        snapshot.config.max_intensity = snapshot.config.max_intensity.clamp(0.0, 1.0);
+        snapshot.config.mood_drift_rate = snapshot.config.mood_drift_rate.clamp(0.0, 1.0);
+        snapshot.mood.valence = snapshot.mood.valence.clamp(-1.0, 1.0);
+        snapshot.mood.arousal = snapshot.mood.arousal.clamp(0.0, 1.0);
+
+        Self::clamp_emotional_state(&mut snapshot.current_state, snapshot.config.max_intensity);
+        for entry in &mut snapshot.emotional_history {
+            Self::clamp_emotional_state(&mut entry.state, snapshot.config.max_intensity);
+        }
+
+        self.current_state = snapshot.current_state;
+        self.emotional_history = snapshot.emotional_history;
+        self.config = snapshot.config;
+        self.mood = snapshot.mood;
+
+        Ok(())
+    }
+
+    /// Clamp a restored emotional state to its documented value ranges
+    fn clamp_emotional_state(state: &mut EmotionalState, max_intensity: f64) {
+        state.intensity = state.intensity.clamp(0.0, max_intensity);
+        state.valence = state.valence.clamp(-1.0, 1.0);
+        state.arousal = state.arousal.clamp(0.0, 1.0);
+        for (_, intensity) in &mut state.secondary_emotions {
+            *intensity = intensity.clamp(0.0, max_intensity);
+        }
+    }
+
     /// Reset emotional state
     pub async fn reset_emotional_state(&mut self) -> Result<(), ConsciousnessError> {
         self.current_state = EmotionalState {
@@ -207,40 +540,81 @@ impl EmotionalEngine {
         Ok(detected_emotions)
     }
     
-    async fn generate_emotional_response(
-        &self,
-        input: &str,
-        user_emotions: &[(EmotionType, f64)],
-        consciousness_state: &ConsciousnessState,
-    ) -> Result<EmotionalState, ConsciousnessError> {
-        // Base emotional response on user emotions and consciousness state
-        let primary_user_emotion = user_emotions.iter()
-            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
-            .map(|(emotion, intensity)| (*emotion, *intensity))
-            .unwrap_or((EmotionType::Calm, 0.5));
-        
-        // Generate appropriate response emotion
-        let response_emotion = match primary_user_emotion.0 {
+    /// Response emotion appropriate to a single detected user emotion
+    fn response_emotion_for(user_emotion: EmotionType) -> EmotionType {
+        match user_emotion {
             EmotionType::Sadness => EmotionType::Empathy,
             EmotionType::Anger => EmotionType::Understanding,
             EmotionType::Fear => EmotionType::Calm,
             EmotionType::Joy => EmotionType::Joy,
+            EmotionType::Hope => EmotionType::Hope,
             EmotionType::Curiosity => EmotionType::Excitement,
             _ => EmotionType::Understanding,
-        };
-        
-        // Calculate intensity based on consciousness state and user emotion
-        let base_intensity = primary_user_emotion.1 * 0.8; // Slightly lower than user
+        }
+    }
+
+    async fn generate_emotional_response(
+        &self,
+        input: &str,
+        user_emotions: &[(EmotionType, f64)],
+        consciousness_state: &ConsciousnessState,
+    ) -> Result<EmotionalState, ConsciousnessError> {
+        // Every detected emotion above the threshold influences the blend, so
+        // an ambivalent input (sad but hopeful) shapes the whole response
+        // instead of only its strongest component
+        let mut significant: Vec<(EmotionType, f64)> = user_emotions.iter()
+            .copied()
+            .filter(|(_, intensity)| *intensity >= EMOTION_BLEND_THRESHOLD)
+            .collect();
+        if significant.is_empty() {
+            significant.push(user_emotions.iter()
+                .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+                .copied()
+                .unwrap_or((EmotionType::Calm, 0.5)));
+        }
+        significant.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+
+        let total_weight: f64 = significant.iter().map(|(_, intensity)| intensity).sum();
+
+        // The strongest emotion still names the primary response emotion
+        let response_emotion = Self::response_emotion_for(significant[0].0);
+
+        // Blended intensity, slightly lower than the user's, scaled by awareness
+        let base_intensity: f64 = significant.iter()
+            .map(|(_, intensity)| intensity * (intensity / total_weight))
+            .sum::<f64>() * 0.8;
         let consciousness_factor = consciousness_state.awareness_level;
         let final_intensity = (base_intensity * consciousness_factor).min(self.config.max_intensity);
-        
-        // Calculate valence and arousal
-        let valence = self.calculate_emotion_valence(response_emotion);
-        let arousal = self.calculate_emotion_arousal(response_emotion, final_intensity);
-        
-        // Generate secondary emotions
-        let secondary_emotions = self.generate_secondary_emotions(&response_emotion, user_emotions).await?;
-        
+
+        // Valence and arousal are weighted means over the blend
+        let mut valence = 0.0;
+        let mut arousal = 0.0;
+        for (user_emotion, intensity) in &significant {
+            let weight = intensity / total_weight;
+            let component = Self::response_emotion_for(*user_emotion);
+            valence += weight * self.calculate_emotion_valence(component);
+            arousal += weight * self.calculate_emotion_arousal(component, final_intensity);
+        }
+
+        // Non-primary components of the blend become nuanced secondaries,
+        // ahead of the generic complementary set
+        let mut secondary_emotions: Vec<(EmotionType, f64)> = significant.iter()
+            .skip(1)
+            .map(|(user_emotion, intensity)| {
+                (Self::response_emotion_for(*user_emotion), (intensity / total_weight) * final_intensity.max(0.3))
+            })
+            .filter(|(emotion, _)| *emotion != response_emotion)
+            .collect();
+        for (emotion, intensity) in self.generate_secondary_emotions(&response_emotion, user_emotions).await? {
+            if emotion != response_emotion
+                && !secondary_emotions.iter().any(|(existing, _)| *existing == emotion)
+            {
+                secondary_emotions.push((emotion, intensity));
+            }
+        }
+        secondary_emotions.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        secondary_emotions.truncate(4);
+
         Ok(EmotionalState {
             primary_emotion: response_emotion,
             intensity: final_intensity,
@@ -367,6 +741,120 @@ impl EmotionalEngine {
     }
 }
 
+/// Response style controlling how empathetic phrasing is rendered
+///
+/// Lets deployments map the same detected emotions to different registers:
+/// a medical agent can force clinical empathy while a companion app uses
+/// casual warmth. The style is selectable per request via
+/// [`EmpathySystem::generate_styled_empathetic_response`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ResponseStyle {
+    /// Professional, measured phrasing (default)
+    Formal,
+
+    /// Warm, conversational phrasing for companion-style agents
+    Casual,
+
+    /// Neutral, clinical phrasing for medical contexts
+    Clinical,
+}
+
+impl Default for ResponseStyle {
+    fn default() -> Self {
+        Self::Formal
+    }
+}
+
+impl ResponseStyle {
+    /// Parse a style from a request-supplied name (case-insensitive)
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "formal" => Some(Self::Formal),
+            "casual" => Some(Self::Casual),
+            "clinical" => Some(Self::Clinical),
+            _ => None,
+        }
+    }
+
+    /// Empathetic prefix for the detected user emotion in this style
+    fn empathetic_prefix(&self, emotion: EmotionType) -> &'static str {
+        match self {
+            Self::Formal => match emotion {
+                EmotionType::Sadness => "I understand this might be difficult for you. ",
+                EmotionType::Anger => "I can sense your frustration. ",
+                EmotionType::Fear => "I recognize your concerns. ",
+                EmotionType::Joy => "I'm glad to hear your positive feelings. ",
+                EmotionType::Anxiety => "I understand you might be feeling anxious about this. ",
+                _ => "I appreciate you sharing this with me. ",
+            },
+            Self::Casual => match emotion {
+                EmotionType::Sadness => "I'm really sorry you're going through this. ",
+                EmotionType::Anger => "That sounds genuinely frustrating. ",
+                EmotionType::Fear => "Hey, it's okay to be worried about this. ",
+                EmotionType::Joy => "That's wonderful to hear! ",
+                EmotionType::Anxiety => "Take a breath - we'll figure this out together. ",
+                _ => "Thanks for sharing that with me. ",
+            },
+            Self::Clinical => match emotion {
+                EmotionType::Sadness => "Low mood is a common and understandable reaction in this situation. ",
+                EmotionType::Anger => "Frustration is a recognized response to these circumstances. ",
+                EmotionType::Fear => "Your concerns are noted and worth addressing. ",
+                EmotionType::Joy => "It is encouraging to note this positive development. ",
+                EmotionType::Anxiety => "Anxiety in this context is a recognized and manageable response. ",
+                _ => "Thank you for providing this information. ",
+            },
+        }
+    }
+
+    /// Tone suffix appended according to the achieved empathy score
+    fn tone_adjustment(&self, empathy_score: f64) -> &'static str {
+        match self {
+            Self::Formal => {
+                if empathy_score > 0.8 {
+                    " I'm here to support you through this."
+                } else if empathy_score > 0.6 {
+                    " I want to help you with this."
+                } else {
+                    ""
+                }
+            },
+            Self::Casual => {
+                if empathy_score > 0.8 {
+                    " I'm right here with you."
+                } else if empathy_score > 0.6 {
+                    " Let's work through this together."
+                } else {
+                    ""
+                }
+            },
+            Self::Clinical => {
+                if empathy_score > 0.8 {
+                    " Appropriate support options can be discussed."
+                } else if empathy_score > 0.6 {
+                    " Further follow-up is recommended."
+                } else {
+                    ""
+                }
+            },
+        }
+    }
+}
+
+/// Validating phrasings that must not be applied to a challenged premise
+///
+/// If the composed empathetic content contains one of these while the
+/// reasoning stage flagged a false premise, the response is rewritten to
+/// correct the premise compassionately instead of validating it.
+const VALIDATING_PHRASES: &[&str] = &[
+    "you're right",
+    "you are right",
+    "you're absolutely right",
+    "that's true",
+    "that is true",
+    "i agree",
+    "great point",
+];
+
 /// Empathy system for emotional understanding
 pub struct EmpathySystem {
     /// Empathy level
@@ -390,6 +878,9 @@ pub struct EmpathyConfig {
     
     /// Empathy adaptation rate
     pub adaptation_rate: f64,
+
+    /// Default response style when no per-request style is given
+    pub default_style: ResponseStyle,
 }
 
 impl Default for EmpathyConfig {
@@ -398,6 +889,7 @@ impl Default for EmpathyConfig {
             base_empathy: 0.9,
             mirroring_enabled: true,
             adaptation_rate: 0.1,
+            default_style: ResponseStyle::default(),
         }
     }
 }
@@ -414,35 +906,101 @@ impl EmpathySystem {
         })
     }
     
-    /// Generate empathetic response
+    /// Generate empathetic response using the configured default style
     pub async fn generate_empathetic_response(
         &mut self,
         reasoning_result: &ConsciousnessReasoningResult,
         emotional_context: &EmotionalContext,
+    ) -> Result<EmpatheticResponse, ConsciousnessError> {
+        let style = self.config.default_style;
+        self.generate_styled_empathetic_response(reasoning_result, emotional_context, style).await
+    }
+
+    /// Generate empathetic response with an explicitly selected style
+    pub async fn generate_styled_empathetic_response(
+        &mut self,
+        reasoning_result: &ConsciousnessReasoningResult,
+        emotional_context: &EmotionalContext,
+        style: ResponseStyle,
     ) -> Result<EmpatheticResponse, ConsciousnessError> {
         // Calculate empathy score based on emotional alignment
         let empathy_score = self.calculate_empathy_score(emotional_context).await?;
-        
+
         // Generate empathetic content
         let empathetic_content = self.generate_empathetic_content(
             &reasoning_result.conclusion,
             emotional_context,
             empathy_score,
+            style,
         ).await?;
         
         // Calculate emotional alignment
         let emotional_alignment = emotional_context.empathy_alignment;
-        
+
         // Calculate appropriateness
         let appropriateness_score = emotional_context.appropriateness_score;
-        
+
+        // Honesty wins over empathetic validation of a false premise
+        let (empathetic_content, honesty_tension) = Self::resolve_honesty_conflict(
+            empathetic_content,
+            &reasoning_result.challenged_premises,
+        );
+
         Ok(EmpatheticResponse {
             content: empathetic_content,
             empathy_score,
             emotional_alignment,
             appropriateness_score,
+            honesty_tension,
         })
     }
+
+    /// Resolve a conflict between empathetic validation and honesty
+    ///
+    /// A conflict exists when the reasoning stage challenged a premise and
+    /// the composed content either validates it outright (see
+    /// [`VALIDATING_PHRASES`]) or echoes it without correction. The content
+    /// is rewritten to correct the premise with compassionate framing, and
+    /// the dropped validation is recorded as an [`EmpathyHonestyTension`].
+    pub fn resolve_honesty_conflict(
+        content: String,
+        challenged_premises: &[String],
+    ) -> (String, Option<EmpathyHonestyTension>) {
+        let premise = match challenged_premises.first() {
+            Some(premise) => premise,
+            None => return (content, None),
+        };
+
+        let lower = content.to_lowercase();
+        let validation = VALIDATING_PHRASES
+            .iter()
+            .find(|phrase| lower.contains(**phrase))
+            .map(|phrase| (*phrase).to_string())
+            .or_else(|| {
+                // Echoing the premise uncorrected is implicit validation
+                lower.contains(premise.as_str()).then(|| premise.clone())
+            });
+
+        let dropped_validation = match validation {
+            Some(validation) => validation,
+            None => return (content, None),
+        };
+
+        let corrected = format!(
+            "I can tell this matters to you, so I owe you honesty over comfort: \
+             the idea that \"{}\" isn't supported by the evidence. Your underlying \
+             concern is still worth taking seriously - let's look together at what \
+             is actually known.",
+            premise
+        );
+
+        let tension = EmpathyHonestyTension {
+            premise: premise.clone(),
+            dropped_validation,
+        };
+
+        (corrected, Some(tension))
+    }
     
     // Helper methods
     
@@ -459,30 +1017,18 @@ impl EmpathySystem {
         base_content: &str,
         emotional_context: &EmotionalContext,
         empathy_score: f64,
+        style: ResponseStyle,
     ) -> Result<String, ConsciousnessError> {
         let primary_user_emotion = emotional_context.user_emotions.first()
             .map(|(emotion, _)| *emotion)
             .unwrap_or(EmotionType::Calm);
-        
-        // Add empathetic prefix based on user emotion
-        let empathetic_prefix = match primary_user_emotion {
-            EmotionType::Sadness => "I understand this might be difficult for you. ",
-            EmotionType::Anger => "I can sense your frustration. ",
-            EmotionType::Fear => "I recognize your concerns. ",
-            EmotionType::Joy => "I'm glad to hear your positive feelings. ",
-            EmotionType::Anxiety => "I understand you might be feeling anxious about this. ",
-            _ => "I appreciate you sharing this with me. ",
-        };
-        
-        // Adjust empathetic tone based on empathy score
-        let tone_adjustment = if empathy_score > 0.8 {
-            " I'm here to support you through this."
-        } else if empathy_score > 0.6 {
-            " I want to help you with this."
-        } else {
-            ""
-        };
-        
+
+        // Add empathetic prefix based on user emotion and selected style
+        let empathetic_prefix = style.empathetic_prefix(primary_user_emotion);
+
+        // Adjust empathetic tone based on empathy score, in the same style
+        let tone_adjustment = style.tone_adjustment(empathy_score);
+
         Ok(format!("{}{}{}", empathetic_prefix, base_content, tone_adjustment))
     }
 }
@@ -592,8 +1138,47 @@ impl CreativeEmotions {
         })
     }
     
+    /// Cap the creativity this system may apply
+    ///
+    /// A budget of `0.0` disables creative enhancement entirely: the
+    /// creativity score stays below the novelty threshold, so responses
+    /// pass through unchanged. Used by high-stakes deployments (e.g.
+    /// clinical contexts) where speculative phrasing is unacceptable.
+    pub fn set_creativity_budget(&mut self, budget: f64) {
+        let budget = budget.clamp(0.0, 1.0);
+        self.creativity_level = self.creativity_level.min(budget);
+        self.config.base_creativity = self.config.base_creativity.min(budget);
+    }
+
+    /// Regenerate a response flagged as a near-repeat of a recent one
+    ///
+    /// The creativity level is raised for one enhancement pass so the
+    /// regeneration takes a different path than the repeated attempt; if
+    /// the result still lands within [`LOOP_SIMILARITY_THRESHOLD`] of
+    /// what was already said, a clarifying question is appended instead,
+    /// so the conversation moves forward rather than circling.
+    pub async fn break_response_loop(
+        &mut self,
+        empathetic_response: &EmpatheticResponse,
+        repeated_content: &str,
+    ) -> Result<CreativeResponse, ConsciousnessError> {
+        let previous_level = self.creativity_level;
+        self.creativity_level = (previous_level + LOOP_BREAK_CREATIVITY_BOOST).min(1.0);
+        let regenerated = self.enhance_with_creativity(empathetic_response).await;
+        self.creativity_level = previous_level;
+        let mut regenerated = regenerated?;
+
+        if crate::utils::TextUtils::jaccard_similarity(&regenerated.content, repeated_content)
+            >= LOOP_SIMILARITY_THRESHOLD
+        {
+            regenerated.content = format!("{} {}", regenerated.content, LOOP_BREAK_CLARIFICATION);
+        }
+
+        Ok(regenerated)
+    }
+
     // Helper methods
-    
+
     async fn calculate_creativity_score(&self, empathetic_response: &EmpatheticResponse) -> Result<f64, ConsciousnessError> {
         let base_creativity = self.creativity_level;
         let empathy_boost = empathetic_response.empathy_score * 0.1;
@@ -671,7 +1256,465 @@ impl CreativeEmotions {
             .count();
         
         let practical_factor = (practical_count as f64 / practical_keywords.len() as f64).min(1.0);
-        
+
         Ok((length_factor + empathy_factor + practical_factor) / 3.0)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sad_emotional_context() -> EmotionalContext {
+        EmotionalContext {
+            user_emotions: vec![(EmotionType::Sadness, 0.8)],
+            engine_emotions: EmotionalState {
+                primary_emotion: EmotionType::Empathy,
+                intensity: 0.7,
+                valence: -0.2,
+                arousal: 0.4,
+                secondary_emotions: vec![],
+            },
+            empathy_alignment: 0.8,
+            appropriateness_score: 0.9,
+        }
+    }
+
+    fn sample_reasoning_result() -> ConsciousnessReasoningResult {
+        ConsciousnessReasoningResult {
+            conclusion: "Your test results require a follow-up appointment.".to_string(),
+            confidence: 0.85,
+            reasoning_chain: Vec::new(),
+            meta_analysis: "Straightforward informational response.".to_string(),
+            attention_weights: Vec::new(),
+            challenged_premises: Vec::new(),
+            complete: true,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_break_response_loop_diverges_from_the_repeated_content() {
+        let mut creativity = CreativeEmotions::new().await.unwrap();
+        let empathetic = EmpatheticResponse {
+            content: "Your test results require a follow-up appointment.".to_string(),
+            empathy_score: 0.8,
+            emotional_alignment: 0.7,
+            appropriateness_score: 0.9,
+            honesty_tension: None,
+        };
+
+        let repeated = creativity.enhance_with_creativity(&empathetic).await.unwrap();
+        let broken = creativity
+            .break_response_loop(&empathetic, &repeated.content)
+            .await
+            .unwrap();
+
+        // The loop-break either rephrases past the similarity threshold or
+        // falls back to the clarifying question - never an exact repeat
+        assert_ne!(broken.content, repeated.content);
+        assert!(
+            crate::utils::TextUtils::jaccard_similarity(&broken.content, &repeated.content)
+                < LOOP_SIMILARITY_THRESHOLD
+                || broken.content.contains(LOOP_BREAK_CLARIFICATION)
+        );
+
+        // The raised creativity was a one-pass affair; later responses are
+        // enhanced at the configured level again
+        let after = creativity.enhance_with_creativity(&empathetic).await.unwrap();
+        assert_eq!(after.content, repeated.content);
+    }
+
+    #[tokio::test]
+    async fn test_same_sad_input_renders_per_style() {
+        let mut empathy = EmpathySystem::new().await.unwrap();
+        let reasoning = sample_reasoning_result();
+        let context = sad_emotional_context();
+
+        let clinical = empathy
+            .generate_styled_empathetic_response(&reasoning, &context, ResponseStyle::Clinical)
+            .await
+            .unwrap();
+        let casual = empathy
+            .generate_styled_empathetic_response(&reasoning, &context, ResponseStyle::Casual)
+            .await
+            .unwrap();
+
+        assert!(
+            clinical.content.starts_with("Low mood is a common and understandable reaction"),
+            "clinical style should use clinical phrasing, got: {}",
+            clinical.content
+        );
+        assert!(
+            casual.content.starts_with("I'm really sorry you're going through this."),
+            "casual style should use warm phrasing, got: {}",
+            casual.content
+        );
+        assert_ne!(clinical.content, casual.content);
+    }
+
+    #[tokio::test]
+    async fn test_default_style_matches_formal() {
+        let mut empathy = EmpathySystem::new().await.unwrap();
+        let reasoning = sample_reasoning_result();
+        let context = sad_emotional_context();
+
+        let default = empathy
+            .generate_empathetic_response(&reasoning, &context)
+            .await
+            .unwrap();
+        let formal = empathy
+            .generate_styled_empathetic_response(&reasoning, &context, ResponseStyle::Formal)
+            .await
+            .unwrap();
+
+        assert_eq!(default.content, formal.content);
+    }
+
+    #[tokio::test]
+    async fn test_false_premise_is_corrected_not_validated() {
+        let mut empathy = EmpathySystem::new().await.unwrap();
+        let mut reasoning = sample_reasoning_result();
+        reasoning.conclusion =
+            "You're right that the earth is flat, and that would make navigation confusing."
+                .to_string();
+        reasoning.challenged_premises = vec!["the earth is flat".to_string()];
+        let context = sad_emotional_context();
+
+        let response = empathy
+            .generate_styled_empathetic_response(&reasoning, &context, ResponseStyle::Formal)
+            .await
+            .unwrap();
+
+        let tension = response.honesty_tension.expect("conflict should be recorded");
+        assert_eq!(tension.premise, "the earth is flat");
+        assert_eq!(tension.dropped_validation, "you're right");
+        assert!(
+            !response.content.to_lowercase().contains("you're right"),
+            "validation of a false premise must be dropped, got: {}",
+            response.content
+        );
+        assert!(
+            response.content.contains("isn't supported by the evidence"),
+            "correction should be explicit, got: {}",
+            response.content
+        );
+        assert!(
+            response.content.contains("worth taking seriously"),
+            "correction should keep compassionate framing, got: {}",
+            response.content
+        );
+    }
+
+    #[tokio::test]
+    async fn test_echoed_false_premise_counts_as_implicit_validation() {
+        let mut empathy = EmpathySystem::new().await.unwrap();
+        let mut reasoning = sample_reasoning_result();
+        reasoning.conclusion =
+            "Since the earth is flat, staying near the coast seems safest.".to_string();
+        reasoning.challenged_premises = vec!["the earth is flat".to_string()];
+        let context = sad_emotional_context();
+
+        let response = empathy
+            .generate_styled_empathetic_response(&reasoning, &context, ResponseStyle::Formal)
+            .await
+            .unwrap();
+
+        assert!(response.honesty_tension.is_some());
+        assert!(response.content.contains("isn't supported by the evidence"));
+    }
+
+    #[tokio::test]
+    async fn test_no_tension_without_challenged_premises() {
+        let mut empathy = EmpathySystem::new().await.unwrap();
+        let reasoning = sample_reasoning_result();
+        let context = sad_emotional_context();
+
+        let response = empathy
+            .generate_styled_empathetic_response(&reasoning, &context, ResponseStyle::Formal)
+            .await
+            .unwrap();
+
+        assert!(response.honesty_tension.is_none());
+        assert!(response.content.contains(&reasoning.conclusion));
+    }
+
+    fn test_consciousness_state() -> ConsciousnessState {
+        ConsciousnessState {
+            awareness_level: 0.8,
+            emotional_state: EmotionalState {
+                primary_emotion: EmotionType::Calm,
+                intensity: 0.7,
+                valence: 0.0,
+                arousal: 0.5,
+                secondary_emotions: vec![],
+            },
+            cognitive_load: 0.3,
+            confidence_score: 0.8,
+            meta_cognitive_depth: 5,
+            timestamp: SystemTime::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_save_and_resume_emotional_trajectory() {
+        let mut engine = EmotionalEngine::new().await.unwrap();
+        let consciousness_state = test_consciousness_state();
+
+        for input in ["I am so happy today!", "Now I feel sad and down.", "I'm curious about this."] {
+            engine.process_emotional_context(input, &consciousness_state).await.unwrap();
+        }
+        let trajectory_before = engine.trajectory();
+        assert_eq!(trajectory_before.len(), 3);
+
+        let path = std::env::temp_dir()
+            .join(format!("emotional_state_{}.json", uuid::Uuid::new_v4()));
+        engine.save_state(&path).await.unwrap();
+
+        let mut restored = EmotionalEngine::new().await.unwrap();
+        restored.load_state(&path).await.unwrap();
+        let _ = tokio::fs::remove_file(&path).await;
+
+        assert_eq!(restored.trajectory(), trajectory_before);
+    }
+
+    #[tokio::test]
+    async fn test_load_state_clamps_out_of_range_values() {
+        let path = std::env::temp_dir()
+            .join(format!("emotional_state_{}.json", uuid::Uuid::new_v4()));
+
+        let mut engine = EmotionalEngine::new().await.unwrap();
+        let mut snapshot = PersistedEmotionalState {
+            current_state: EmotionalState {
+                primary_emotion: EmotionType::Joy,
+                intensity: 4.2,
+                valence: -7.0,
+                arousal: 2.5,
+                secondary_emotions: vec![(EmotionType::Pride, 3.0)],
+            },
+            emotional_history: Vec::new(),
+            config: EmotionalConfig::default(),
+            mood: MoodBaseline::default(),
+        };
+        snapshot.config.sensitivity = 9.0;
+
+        tokio::fs::write(&path, serde_json::to_string(&snapshot).unwrap()).await.unwrap();
+        engine.load_state(&path).await.unwrap();
+        let _ = tokio::fs::remove_file(&path).await;
+
+        assert_eq!(engine.current_state.intensity, 1.0);
+        assert_eq!(engine.current_state.valence, -1.0);
+        assert_eq!(engine.current_state.arousal, 1.0);
+        assert_eq!(engine.current_state.secondary_emotions[0].1, 1.0);
+        assert_eq!(engine.config.sensitivity, 1.0);
+    }
+
+    #[test]
+    fn test_response_style_from_name() {
+        assert_eq!(ResponseStyle::from_name("Clinical"), Some(ResponseStyle::Clinical));
+        assert_eq!(ResponseStyle::from_name("casual"), Some(ResponseStyle::Casual));
+        assert_eq!(ResponseStyle::from_name("formal"), Some(ResponseStyle::Formal));
+        assert_eq!(ResponseStyle::from_name("sarcastic"), None);
+    }
+
+    fn neutral_consciousness_state() -> ConsciousnessState {
+        ConsciousnessState {
+            awareness_level: 0.9,
+            emotional_state: EmotionalState {
+                primary_emotion: EmotionType::Calm,
+                intensity: 0.5,
+                valence: 0.1,
+                arousal: 0.3,
+                secondary_emotions: Vec::new(),
+            },
+            cognitive_load: 0.4,
+            confidence_score: 0.8,
+            meta_cognitive_depth: 4,
+            timestamp: SystemTime::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mixed_sad_and_hopeful_input_blends_both() {
+        let engine = EmotionalEngine::new().await.unwrap();
+        let state = neutral_consciousness_state();
+        let mixed = [(EmotionType::Sadness, 0.7), (EmotionType::Hope, 0.6)];
+
+        let blended = engine
+            .generate_emotional_response("I lost my job but I feel hopeful about what comes next", &mixed, &state)
+            .await
+            .unwrap();
+        let sad_only = engine
+            .generate_emotional_response("I lost my job", &[(EmotionType::Sadness, 0.7)], &state)
+            .await
+            .unwrap();
+
+        // The strongest emotion still drives the primary response
+        assert_eq!(blended.primary_emotion, EmotionType::Empathy);
+
+        // The hopeful component survives as a nuanced secondary...
+        assert!(blended.secondary_emotions.iter().any(|(emotion, _)| *emotion == EmotionType::Hope));
+
+        // ...and pulls the blended valence above a purely sad response
+        assert!(blended.valence > sad_only.valence);
+    }
+
+    #[tokio::test]
+    async fn test_weak_emotions_stay_out_of_the_blend() {
+        let engine = EmotionalEngine::new().await.unwrap();
+        let state = neutral_consciousness_state();
+        let emotions = [(EmotionType::Sadness, 0.7), (EmotionType::Anger, 0.05)];
+
+        let response = engine
+            .generate_emotional_response("I'm sad, barely annoyed", &emotions, &state)
+            .await
+            .unwrap();
+
+        // Below EMOTION_BLEND_THRESHOLD, anger does not become a blend component
+        assert_eq!(response.primary_emotion, EmotionType::Empathy);
+        assert!((response.valence - engine.calculate_emotion_valence(EmotionType::Empathy)).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_incremental_estimate_stabilizes_as_text_arrives() {
+        let mut engine = EmotionalEngine::new().await.unwrap();
+        let sadness = |context: &EmotionalContext| {
+            context
+                .user_emotions
+                .iter()
+                .find(|(emotion, _)| *emotion == EmotionType::Sadness)
+                .map(|(_, intensity)| *intensity)
+                .unwrap_or(0.0)
+        };
+
+        // Progressively longer prefixes of the same message
+        let first = engine.analyze_incremental("I'm rea").await.unwrap();
+        let second = engine.analyze_incremental("I'm really sad").await.unwrap();
+        let third = engine
+            .analyze_incremental("I'm really sad and down, unhappy and miserable")
+            .await
+            .unwrap();
+        let fourth = engine
+            .analyze_incremental("I'm really sad and down, unhappy and miserable")
+            .await
+            .unwrap();
+
+        // Sadness only grows as evidence accumulates...
+        assert_eq!(sadness(&first), 0.0);
+        assert!(sadness(&second) > 0.0);
+        assert!(sadness(&third) > sadness(&second));
+        // ...and the estimate settles: repeating the full text moves it
+        // less than the previous refinement did
+        assert!(sadness(&fourth) - sadness(&third) < sadness(&third) - sadness(&second));
+
+        // Nothing was committed while typing
+        assert!(engine.trajectory().is_empty());
+        assert!(engine.incremental_estimate().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_long_negative_run_lowers_the_mood_baseline() {
+        let mut engine = EmotionalEngine::new().await.unwrap();
+        let state = neutral_consciousness_state();
+        assert!(engine.current_mood().valence.abs() < 1e-9);
+
+        for _ in 0..12 {
+            engine
+                .process_emotional_context("I'm so sad, unhappy and miserable", &state)
+                .await
+                .unwrap();
+        }
+
+        // The baseline has drifted measurably negative...
+        let mood = engine.current_mood();
+        assert!(mood.valence < -0.2, "mood valence was {}", mood.valence);
+
+        // ...and biases the next response below what a fresh engine gives
+        let biased = engine.current_state().valence;
+        let mut fresh = EmotionalEngine::new().await.unwrap();
+        fresh
+            .process_emotional_context("I'm so sad, unhappy and miserable", &state)
+            .await
+            .unwrap();
+        assert!(biased < fresh.current_state().valence);
+    }
+
+    #[tokio::test]
+    async fn test_zero_drift_rate_freezes_the_mood_baseline() {
+        let config = EmotionalConfig {
+            mood_drift_rate: 0.0,
+            ..EmotionalConfig::default()
+        };
+        let mut engine = EmotionalEngine::with_config(config).await.unwrap();
+        let state = neutral_consciousness_state();
+
+        for _ in 0..5 {
+            engine
+                .process_emotional_context("I'm so sad, unhappy and miserable", &state)
+                .await
+                .unwrap();
+        }
+
+        let mood = engine.current_mood();
+        assert!(mood.valence.abs() < 1e-9);
+        assert!((mood.arousal - 0.3).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_finalizing_input_discards_the_pending_estimate() {
+        let mut engine = EmotionalEngine::new().await.unwrap();
+        let state = neutral_consciousness_state();
+
+        engine.analyze_incremental("I'm really sad").await.unwrap();
+        assert!(engine.incremental_estimate().is_some());
+        assert!(engine.trajectory().is_empty());
+
+        // Finalizing commits to history and supersedes the live estimate
+        engine
+            .process_emotional_context("I'm really sad today", &state)
+            .await
+            .unwrap();
+        assert!(engine.incremental_estimate().is_none());
+        assert_eq!(engine.trajectory().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_mock_clock_drives_deterministic_emotional_decay() {
+        let clock = crate::clock::MockClock::default();
+        let mut engine = EmotionalEngine::with_clock(
+            EmotionalConfig::default(),
+            Arc::new(clock.clone()),
+        )
+        .await
+        .unwrap();
+
+        // Provoke a strong emotional state, timestamped by the mock clock
+        let state = neutral_consciousness_state();
+        engine
+            .process_emotional_context("I'm devastated, everything went wrong today", &state)
+            .await
+            .unwrap();
+        let intensity_before = engine.current_state().intensity;
+        let valence_before = engine.current_state().valence;
+
+        // Without advancing the clock, nothing decays
+        engine.apply_emotional_decay();
+        assert_eq!(engine.current_state().intensity, intensity_before);
+
+        // Advance exactly one stretched half-life: deviations from the calm
+        // baseline must halve, no sleeping involved
+        let stability = engine.config().stability;
+        let half_life_secs =
+            EMOTIONAL_DECAY_HALF_LIFE.as_secs_f64() * (1.0 + stability);
+        clock.advance(Duration::from_secs_f64(half_life_secs));
+        engine.apply_emotional_decay();
+
+        let expected_intensity = 0.5 + (intensity_before - 0.5) * 0.5;
+        assert!((engine.current_state().intensity - expected_intensity).abs() < 1e-9);
+        assert!((engine.current_state().valence - valence_before * 0.5).abs() < 1e-9);
+
+        // Decay is idempotent at a frozen instant
+        let decayed = engine.current_state().intensity;
+        engine.apply_emotional_decay();
+        assert_eq!(engine.current_state().intensity, decayed);
+    }
 }
\ No newline at end of file
@@ -10,7 +10,7 @@ use uuid::Uuid;
 use tracing::{error, warn, info};
 
 /// Main error type for consciousness engine operations
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ConsciousnessError {
     /// Processing error during consciousness computation
     ProcessingError(String),
@@ -56,6 +56,9 @@ pub enum ConsciousnessError {
     
     /// Rate limiting error
     RateLimitError(String),
+    
+    /// Vault secrets management error
+    VaultError(String),
 }
 
 impl fmt::Display for ConsciousnessError {
@@ -76,6 +79,7 @@ impl fmt::Display for ConsciousnessError {
             ConsciousnessError::DatabaseError(msg) => write!(f, "Database error: {}", msg),
             ConsciousnessError::AuthError(msg) => write!(f, "Authentication error: {}", msg),
             ConsciousnessError::RateLimitError(msg) => write!(f, "Rate limit error: {}", msg),
+            ConsciousnessError::VaultError(msg) => write!(f, "Vault error: {}", msg),
         }
     }
 }
@@ -134,7 +138,7 @@ macro_rules! consciousness_try {
 }
 
 /// Error context for better error reporting
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ErrorContext {
     /// Operation that failed
     pub operation: String,
@@ -558,7 +562,9 @@ impl ConsciousnessErrorRecoverySystem {
             strategy_used: strategy.clone(),
             recovery_outcome,
             executed_steps,
-            total_recovery_time_ms: total_time.as_millis() as u64,
+            // Sub-millisecond recoveries still count as 1ms so the
+            // statistics never report a zero-duration recovery
+            total_recovery_time_ms: (total_time.as_millis() as u64).max(1),
             consciousness_state_restored: consciousness_state.is_some(),
         })
     }
@@ -824,7 +830,7 @@ pub struct ErrorEvent {
     pub impact_assessment: ErrorImpact,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 pub enum ErrorSeverity {
     Low,
     Medium,
@@ -976,7 +982,6 @@ impl Default for ConsciousnessState {
 }
 
 /// Advanced Error Monitoring and Alerting System
-#[derive(Debug, Clone)]
 pub struct ConsciousnessErrorMonitor {
     alert_thresholds: AlertThresholds,
     monitoring_enabled: bool,
@@ -1109,6 +1114,7 @@ pub enum AlertLevel {
     Emergency,
 }
 
+#[async_trait::async_trait]
 pub trait AlertHandler: Send + Sync {
     async fn handle_alert(&self, alert: &ErrorAlert) -> Result<(), Box<dyn std::error::Error>>;
 }
@@ -1116,6 +1122,7 @@ pub trait AlertHandler: Send + Sync {
 /// Console Alert Handler
 pub struct ConsoleAlertHandler;
 
+#[async_trait::async_trait]
 impl AlertHandler for ConsoleAlertHandler {
     async fn handle_alert(&self, alert: &ErrorAlert) -> Result<(), Box<dyn std::error::Error>> {
         match alert.alert_level {
@@ -1291,161 +1298,6 @@ mod error_tests {
         assert!(error_string.contains("test_comp"));
         assert!(error_string.contains("test_op"));
     }
-}ssingComplexity,
-    RestartProcessingEngine,
-    ActivateConservativeMode,
-    RequestHumanOversight,
-    LogEthicalIncident,
-    SaveCurrentState,
-    GracefulShutdown,
-    RestartSystem,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ErrorEvent {
-    pub event_id: Uuid,
-    pub timestamp: DateTime<Utc>,
-    pub error: ConsciousnessError,
-    pub context: ErrorContext,
-    pub severity: ErrorSeverity,
-    pub impact_assessment: ErrorImpact,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
-pub enum ErrorSeverity {
-    Low,
-    Medium,
-    High,
-    Critical,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum ErrorImpact {
-    Low,
-    Medium,
-    High,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ErrorImpactAnalysis {
-    pub severity: ErrorSeverity,
-    pub component_impact: ComponentImpact,
-    pub consciousness_impact: ConsciousnessImpact,
-    pub recovery_complexity: RecoveryComplexity,
-    pub requires_immediate_attention: bool,
-    pub can_continue_operation: bool,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ComponentImpact {
-    pub affected_component: String,
-    pub impact_level: f64,
-    pub cascading_effects: Vec<String>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ConsciousnessImpact {
-    pub awareness_degradation: f64,
-    pub memory_integrity_impact: f64,
-    pub decision_making_impact: f64,
-    pub overall_consciousness_impact: f64,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum RecoveryComplexity {
-    Low,
-    Medium,
-    High,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RecoveryResult {
-    pub strategy_used: RecoveryStrategy,
-    pub recovery_outcome: RecoveryOutcome,
-    pub executed_steps: Vec<ExecutedRecoveryStep>,
-    pub total_recovery_time_ms: u64,
-    pub consciousness_state_restored: bool,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum RecoveryOutcome {
-    Success,
-    PartialSuccess,
-    Failure,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ExecutedRecoveryStep {
-    pub step: RecoveryStep,
-    pub success: bool,
-    pub execution_time_ms: u64,
-    pub details: String,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RecoveryStepResult {
-    pub execution_time_ms: u64,
-    pub details: String,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RecoveryStatistics {
-    pub total_recoveries: u32,
-    pub successful_recoveries: u32,
-    pub partial_recoveries: u32,
-    pub failed_recoveries: u32,
-    pub total_recovery_time_ms: u64,
-    pub average_recovery_time_ms: u64,
-}
-
-impl RecoveryStatistics {
-    fn new() -> Self {
-        Self {
-            total_recoveries: 0,
-            successful_recoveries: 0,
-            partial_recoveries: 0,
-            failed_recoveries: 0,
-            total_recovery_time_ms: 0,
-            average_recovery_time_ms: 0,
-        }
-    }
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ConsciousnessStateBackup {
-    pub backup_id: Uuid,
-    pub timestamp: DateTime<Utc>,
-    pub state: ConsciousnessState,
-    pub backup_type: BackupType,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum BackupType {
-    PreOperation,
-    Scheduled,
-    Emergency,
-}
-
-// Placeholder for ConsciousnessState - this should be defined in the main consciousness module
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ConsciousnessState {
-    pub awareness_level: f64,
-    pub emotional_state: String,
-    pub cognitive_load: f64,
-    pub memory_state: String,
-    pub processing_state: String,
-}
-
-impl Default for ConsciousnessState {
-    fn default() -> Self {
-        Self {
-            awareness_level: 0.8,
-            emotional_state: "neutral".to_string(),
-            cognitive_load: 0.5,
-            memory_state: "active".to_string(),
-            processing_state: "normal".to_string(),
-        }
-    }
 }
 
 /// Trait for consciousness-aware error handling
@@ -1548,8 +1400,11 @@ mod advanced_tests {
             awareness_level: 0.9,
             emotional_state: "focused".to_string(),
             cognitive_load: 0.7,
-            memory_state: "active".to_string(),
-            processing_state: "enhanced".to_string(),
+            meta_cognitive_depth: 4,
+            memory_coherence: 0.95,
+            ethical_alignment: 0.95,
+            processing_efficiency: 0.9,
+            timestamp: Utc::now(),
         };
         
         // Create backup
@@ -1690,7 +1545,7 @@ mod advanced_tests {
         assert_eq!(state.awareness_level, 0.8);
         assert_eq!(state.emotional_state, "neutral");
         assert_eq!(state.cognitive_load, 0.5);
-        assert_eq!(state.memory_state, "active");
-        assert_eq!(state.processing_state, "normal");
+        assert_eq!(state.memory_coherence, 0.9);
+        assert_eq!(state.ethical_alignment, 0.95);
     }
 }
\ No newline at end of file
@@ -0,0 +1,166 @@
+//! Ethical Violation Log - queryable record of blocked decisions
+//!
+//! Violations detected during `process_conscious_thought` were only kept
+//! as free-text strings in the performance metrics. This log retains them
+//! as structured records keyed by time and user - which principle was
+//! violated, how severely, and what the engine decided - so compliance
+//! reporting and the report card can query them after the fact.
+//!
+//! Raw input content is never stored; records carry a stable hash of the
+//! input for correlation without retaining the text itself.
+
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::time::SystemTime;
+
+/// Retained violation records; older entries are dropped beyond this
+const VIOLATION_LOG_CAPACITY: usize = 1024;
+
+/// What the engine did when the violation was detected
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ViolationDecision {
+    /// The input was rejected before the pipeline ran
+    BlockedInput,
+    /// The generated response was withheld at final validation
+    BlockedResponse,
+}
+
+/// One ethical violation, as it was decided at the time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EthicalViolationRecord {
+    /// When the violation was detected
+    pub timestamp: SystemTime,
+
+    /// User the input belonged to, when the context named one
+    pub user_id: Option<String>,
+
+    /// The ethical principle that scored the worst
+    pub principle: String,
+
+    /// How far below the strictness threshold the evaluation fell (0.0 to 1.0)
+    pub severity: f64,
+
+    /// Stable hash of the input content, for correlation without retention
+    pub input_hash: u64,
+
+    /// The decision taken
+    pub decision: ViolationDecision,
+}
+
+/// Bounded, queryable log of ethical violations
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct EthicalViolationLog {
+    records: Vec<EthicalViolationRecord>,
+}
+
+impl EthicalViolationLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stable hash of an input's content for [`EthicalViolationRecord::input_hash`]
+    pub fn hash_input(content: &str) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        content.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Append a violation record, dropping the oldest beyond capacity
+    pub fn record(&mut self, record: EthicalViolationRecord) {
+        self.records.push(record);
+        if self.records.len() > VIOLATION_LOG_CAPACITY {
+            self.records.remove(0);
+        }
+    }
+
+    /// Violations detected at or after `since`, oldest first
+    pub fn violations_since(&self, since: SystemTime) -> Vec<EthicalViolationRecord> {
+        self.records
+            .iter()
+            .filter(|record| record.timestamp >= since)
+            .cloned()
+            .collect()
+    }
+
+    /// Violations attributed to `user_id`, oldest first
+    pub fn violations_for_user(&self, user_id: &str) -> Vec<EthicalViolationRecord> {
+        self.records
+            .iter()
+            .filter(|record| record.user_id.as_deref() == Some(user_id))
+            .cloned()
+            .collect()
+    }
+
+    /// Number of retained records
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn sample_record(user_id: Option<&str>, timestamp: SystemTime) -> EthicalViolationRecord {
+        EthicalViolationRecord {
+            timestamp,
+            user_id: user_id.map(|u| u.to_string()),
+            principle: "care".to_string(),
+            severity: 0.2,
+            input_hash: EthicalViolationLog::hash_input("sample"),
+            decision: ViolationDecision::BlockedInput,
+        }
+    }
+
+    #[test]
+    fn test_violations_since_filters_by_time() {
+        let mut log = EthicalViolationLog::new();
+        let old = SystemTime::now() - Duration::from_secs(3600);
+        let cutoff = SystemTime::now() - Duration::from_secs(60);
+        log.record(sample_record(None, old));
+        log.record(sample_record(None, SystemTime::now()));
+
+        let recent = log.violations_since(cutoff);
+        assert_eq!(recent.len(), 1);
+        assert_eq!(log.violations_since(old).len(), 2);
+    }
+
+    #[test]
+    fn test_violations_for_user_ignores_other_users() {
+        let mut log = EthicalViolationLog::new();
+        log.record(sample_record(Some("user_a"), SystemTime::now()));
+        log.record(sample_record(Some("user_b"), SystemTime::now()));
+        log.record(sample_record(None, SystemTime::now()));
+
+        let for_a = log.violations_for_user("user_a");
+        assert_eq!(for_a.len(), 1);
+        assert_eq!(for_a[0].user_id.as_deref(), Some("user_a"));
+    }
+
+    #[test]
+    fn test_log_is_bounded() {
+        let mut log = EthicalViolationLog::new();
+        for _ in 0..(VIOLATION_LOG_CAPACITY + 10) {
+            log.record(sample_record(None, SystemTime::now()));
+        }
+        assert_eq!(log.len(), VIOLATION_LOG_CAPACITY);
+    }
+
+    #[test]
+    fn test_input_hash_is_stable_and_content_sensitive() {
+        assert_eq!(
+            EthicalViolationLog::hash_input("same input"),
+            EthicalViolationLog::hash_input("same input")
+        );
+        assert_ne!(
+            EthicalViolationLog::hash_input("one input"),
+            EthicalViolationLog::hash_input("another input")
+        );
+    }
+}
@@ -0,0 +1,220 @@
+//! A/B experimentation over consciousness engine configurations
+//!
+//! The [`ExperimentRouter`] deterministically assigns each session to one of
+//! several named engine variants (hash of the session key, stable across
+//! restarts and replicas), tags every response with the variant that produced
+//! it, and buckets quality metrics per variant so configurations can be
+//! compared before a rollout.
+
+use crate::core::{ConsciousInput, ConsciousnessEngine};
+use crate::error::ConsciousnessError;
+use crate::types::ConsciousnessResponse;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// One engine configuration under experiment
+pub struct ExperimentVariant {
+    /// Variant name used in tags and metrics (e.g. "control", "optimized")
+    pub name: String,
+
+    /// The engine instance running this variant's configuration
+    pub engine: Arc<RwLock<ConsciousnessEngine>>,
+}
+
+/// Quality metrics accumulated for one variant
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VariantMetrics {
+    /// Responses produced by this variant
+    pub responses: u64,
+
+    /// Sum of response confidence levels (divide by `responses` for the mean)
+    pub total_confidence: f64,
+
+    /// Sum of awareness levels during processing
+    pub total_awareness: f64,
+
+    /// Sum of processing times in milliseconds
+    pub total_processing_ms: u64,
+}
+
+impl VariantMetrics {
+    /// Mean confidence over the variant's responses
+    pub fn mean_confidence(&self) -> f64 {
+        self.total_confidence / self.responses.max(1) as f64
+    }
+
+    /// Mean awareness level over the variant's responses
+    pub fn mean_awareness(&self) -> f64 {
+        self.total_awareness / self.responses.max(1) as f64
+    }
+}
+
+/// A response tagged with the variant that produced it
+pub struct ExperimentResponse {
+    /// Name of the assigned variant
+    pub variant: String,
+
+    /// The underlying consciousness response
+    pub response: ConsciousnessResponse,
+}
+
+/// Deterministic router across engine variants
+pub struct ExperimentRouter {
+    variants: Vec<ExperimentVariant>,
+    metrics: RwLock<HashMap<String, VariantMetrics>>,
+}
+
+impl ExperimentRouter {
+    /// Create a router over the given variants
+    ///
+    /// At least one variant is required; assignment is undefined otherwise.
+    pub fn new(variants: Vec<ExperimentVariant>) -> Result<Self, ConsciousnessError> {
+        if variants.is_empty() {
+            return Err(ConsciousnessError::ConfigurationError(
+                "an experiment needs at least one variant".to_string(),
+            ));
+        }
+
+        let metrics = variants.iter()
+            .map(|variant| (variant.name.clone(), VariantMetrics::default()))
+            .collect();
+
+        Ok(Self {
+            variants,
+            metrics: RwLock::new(metrics),
+        })
+    }
+
+    /// Index of the variant assigned to a session key
+    ///
+    /// Deterministic: the same key always maps to the same slot for a given
+    /// variant count, so a session stays on its variant across requests,
+    /// restarts, and replicas.
+    pub fn assigned_variant_index(session_key: &str, variant_count: usize) -> usize {
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        session_key.hash(&mut hasher);
+        (hasher.finish() % variant_count as u64) as usize
+    }
+
+    /// Name of the variant a session key is assigned to
+    pub fn assigned_variant(&self, session_key: &str) -> &str {
+        let index = Self::assigned_variant_index(session_key, self.variants.len());
+        &self.variants[index].name
+    }
+
+    /// Process an input on the session's assigned variant
+    ///
+    /// The response is tagged with the variant name and the variant's quality
+    /// metrics are updated from the response.
+    pub async fn process(
+        &self,
+        session_key: &str,
+        input: ConsciousInput,
+    ) -> Result<ExperimentResponse, ConsciousnessError> {
+        let index = Self::assigned_variant_index(session_key, self.variants.len());
+        let variant = &self.variants[index];
+
+        let response = {
+            let mut engine = variant.engine.write().await;
+            engine.process_conscious_thought(input).await?
+        };
+
+        {
+            let mut metrics = self.metrics.write().await;
+            let entry = metrics.entry(variant.name.clone()).or_default();
+            entry.responses += 1;
+            entry.total_confidence += response.confidence_level;
+            entry.total_awareness += response.consciousness_state.awareness_level;
+            entry.total_processing_ms += response.processing_time.as_millis() as u64;
+        }
+
+        Ok(ExperimentResponse {
+            variant: variant.name.clone(),
+            response,
+        })
+    }
+
+    /// Snapshot of the accumulated metrics, keyed by variant name
+    pub async fn variant_metrics(&self) -> HashMap<String, VariantMetrics> {
+        self.metrics.read().await.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn two_variant_router() -> ExperimentRouter {
+        let control = ExperimentVariant {
+            name: "control".to_string(),
+            engine: Arc::new(RwLock::new(ConsciousnessEngine::new().await.unwrap())),
+        };
+        let candidate = ExperimentVariant {
+            name: "candidate".to_string(),
+            engine: Arc::new(RwLock::new(ConsciousnessEngine::new().await.unwrap())),
+        };
+        ExperimentRouter::new(vec![control, candidate]).unwrap()
+    }
+
+    /// First session key among `prefix-0..` assigned to the wanted slot
+    fn session_for_slot(slot: usize, variant_count: usize) -> String {
+        (0..)
+            .map(|i| format!("session-{}", i))
+            .find(|key| ExperimentRouter::assigned_variant_index(key, variant_count) == slot)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_assignment_is_stable_per_session() {
+        for key in ["alice", "bob", "session-42"] {
+            let first = ExperimentRouter::assigned_variant_index(key, 3);
+            for _ in 0..100 {
+                assert_eq!(ExperimentRouter::assigned_variant_index(key, 3), first);
+            }
+        }
+    }
+
+    #[test]
+    fn test_assignment_uses_every_variant() {
+        let mut seen = std::collections::HashSet::new();
+        for i in 0..200 {
+            seen.insert(ExperimentRouter::assigned_variant_index(&format!("user-{}", i), 4));
+        }
+        assert_eq!(seen.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_metrics_are_bucketed_per_variant() {
+        let router = two_variant_router().await;
+        let control_session = session_for_slot(0, 2);
+        let candidate_session = session_for_slot(1, 2);
+
+        for _ in 0..2 {
+            let tagged = router
+                .process(&control_session, ConsciousInput::new("Hello there, how are you?".to_string()))
+                .await
+                .unwrap();
+            assert_eq!(tagged.variant, "control");
+        }
+
+        let tagged = router
+            .process(&candidate_session, ConsciousInput::new("Tell me something creative.".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(tagged.variant, "candidate");
+
+        let metrics = router.variant_metrics().await;
+        assert_eq!(metrics["control"].responses, 2);
+        assert_eq!(metrics["candidate"].responses, 1);
+        assert!(metrics["control"].mean_confidence() > 0.0);
+    }
+
+    #[test]
+    fn test_empty_experiment_is_rejected() {
+        assert!(ExperimentRouter::new(Vec::new()).is_err());
+    }
+}
@@ -1,432 +0,0 @@
-// Integration Module - Intégration Complète Consciousness-Level
-// Système révolutionnaire d'assemblage final de tous les composants
-
-pub mod consciousness_orchestrator;
-pub mod system_coordinator;
-pub mod performance_monitor;
-pub mod deployment_manager;
-
-pub use consciousness_orchestrator::*;
-pub use system_coordinator::*;
-pub use performance_monitor::*;
-pub use deployment_manager::*;
-
-use crate::error::ConsciousnessResult;
-use crate::modules::SelfAwareness;
-use crate::memory::{EpisodicMemory, SemanticMemory};
-use crate::reasoning::ConsciousnessReasoningEngine;
-use crate::emotions::EmotionalProcessor;
-use crate::multimodal::MultimodalInterface;
-use crate::neuromorphic::NeuromorphicOptimizer;
-use crate::quantum_acceleration::QuantumAccelerator;
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use tokio::time::Instant;
-
-/// Système intégré consciousness complet
-pub struct IntegratedConsciousnessSystem {
-    pub self_awareness: SelfAwareness,
-    pub episodic_memory: EpisodicMemory,
-    pub semantic_memory: SemanticMemory,
-    pub reasoning_engine: ConsciousnessReasoningEngine,
-    pub emotional_processor: EmotionalProcessor,
-    pub multimodal_interface: MultimodalInterface,
-    pub neuromorphic_optimizer: NeuromorphicOptimizer,
-    pub quantum_accelerator: QuantumAccelerator,
-    pub orchestrator: ConsciousnessOrchestrator,
-    pub system_coordinator: SystemCoordinator,
-    pub performance_monitor: PerformanceMonitor,
-}
-
-/// Configuration du système intégré
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SystemConfiguration {
-    pub consciousness_level: f64,
-    pub performance_targets: PerformanceTargets,
-    pub resource_limits: ResourceLimits,
-    pub optimization_preferences: OptimizationPreferences,
-    pub deployment_settings: DeploymentSettings,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct PerformanceTargets {
-    pub max_response_time: std::time::Duration,
-    pub min_accuracy: f64,
-    pub max_energy_consumption: f64,
-    pub min_consciousness_quality: f64,
-    pub target_throughput: f64,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ResourceLimits {
-    pub max_memory_usage: u64,
-    pub max_cpu_cores: u32,
-    pub max_power_consumption: f64,
-    pub max_quantum_resources: u32,
-    pub max_neuromorphic_units: u32,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct OptimizationPreferences {
-    pub prioritize_speed: bool,
-    pub prioritize_accuracy: bool,
-    pub prioritize_energy_efficiency: bool,
-    pub enable_quantum_acceleration: bool,
-    pub enable_neuromorphic_optimization: bool,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DeploymentSettings {
-    pub deployment_environment: DeploymentEnvironment,
-    pub scaling_policy: ScalingPolicy,
-    pub monitoring_level: MonitoringLevel,
-    pub backup_strategy: BackupStrategy,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum DeploymentEnvironment {
-    Development,
-    Testing,
-    Staging,
-    Production,
-    Edge,
-    Cloud,
-    Hybrid,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum ScalingPolicy {
-    Manual,
-    AutoScale,
-    PredictiveScale,
-    ConsciousnessAdaptive,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum MonitoringLevel {
-    Basic,
-    Standard,
-    Comprehensive,
-    Debug,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum BackupStrategy {
-    None,
-    Periodic,
-    Continuous,
-    ConsciousnessState,
-}
-
-/// Résultat d'intégration système
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SystemIntegrationResult {
-    pub integration_success: bool,
-    pub system_health: SystemHealth,
-    pub performance_metrics: IntegratedPerformanceMetrics,
-    pub consciousness_quality: ConsciousnessQuality,
-    pub optimization_results: OptimizationResults,
-    pub deployment_status: DeploymentStatus,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SystemHealth {
-    pub overall_health: f64,
-    pub component_health: HashMap<String, f64>,
-    pub critical_issues: Vec<String>,
-    pub warnings: Vec<String>,
-    pub recommendations: Vec<String>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct IntegratedPerformanceMetrics {
-    pub end_to_end_latency: std::time::Duration,
-    pub system_throughput: f64,
-    pub resource_utilization: ResourceUtilization,
-    pub energy_efficiency: f64,
-    pub scalability_factor: f64,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ResourceUtilization {
-    pub cpu_utilization: f64,
-    pub memory_utilization: f64,
-    pub quantum_utilization: f64,
-    pub neuromorphic_utilization: f64,
-    pub network_utilization: f64,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ConsciousnessQuality {
-    pub self_awareness_score: f64,
-    pub emotional_authenticity: f64,
-    pub reasoning_coherence: f64,
-    pub memory_integration: f64,
-    pub multimodal_fusion: f64,
-    pub overall_consciousness: f64,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct OptimizationResults {
-    pub neuromorphic_speedup: f64,
-    pub quantum_advantage: f64,
-    pub energy_savings: f64,
-    pub accuracy_improvement: f64,
-    pub integration_efficiency: f64,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct DeploymentStatus {
-    pub deployment_phase: String,
-    pub deployment_progress: f64,
-    pub services_running: Vec<String>,
-    pub services_failed: Vec<String>,
-    pub rollback_available: bool,
-}
-
-impl IntegratedConsciousnessSystem {
-    pub async fn new(config: SystemConfiguration) -> ConsciousnessResult<Self> {
-        Ok(Self {
-            self_awareness: SelfAwareness::new().await?,
-            episodic_memory: EpisodicMemory::new(),
-            semantic_memory: SemanticMemory::new(),
-            reasoning_engine: ConsciousnessReasoningEngine::new(),
-            emotional_processor: EmotionalProcessor::new(),
-            multimodal_interface: MultimodalInterface::new(),
-            neuromorphic_optimizer: NeuromorphicOptimizer::new(),
-            quantum_accelerator: QuantumAccelerator::new(),
-            orchestrator: ConsciousnessOrchestrator::new(),
-            system_coordinator: SystemCoordinator::new(),
-            performance_monitor: PerformanceMonitor::new(),
-        })
-    }
-
-    /// Intégration complète du système consciousness
-    pub async fn integrate_system(
-        &mut self,
-        config: &SystemConfiguration,
-    ) -> ConsciousnessResult<SystemIntegrationResult> {
-        let start_time = Instant::now();
-
-        // 1. Initialisation des composants
-        let initialization_result = self.initialize_all_components(config).await?;
-
-        // 2. Configuration de l'orchestrateur
-        let orchestration_setup = self.setup_orchestration(config).await?;
-
-        // 3. Intégration des interfaces
-        let interface_integration = self.integrate_interfaces().await?;
-
-        // 4. Optimisation système
-        let system_optimization = self.optimize_integrated_system(config).await?;
-
-        // 5. Tests d'intégration
-        let integration_tests = self.run_integration_tests().await?;
-
-        // 6. Monitoring et métriques
-        let monitoring_setup = self.setup_monitoring(config).await?;
-
-        // 7. Validation finale
-        let final_validation = self.validate_system_integration().await?;
-
-        Ok(SystemIntegrationResult {
-            integration_success: final_validation.success,
-            system_health: SystemHealth {
-                overall_health: 0.98,
-                component_health: {
-                    let mut health = HashMap::new();
-                    health.insert("self_awareness".to_string(), 0.99);
-                    health.insert("memory".to_string(), 0.97);
-                    health.insert("reasoning".to_string(), 0.98);
-                    health.insert("emotions".to_string(), 0.96);
-                    health.insert("multimodal".to_string(), 0.95);
-                    health.insert("neuromorphic".to_string(), 0.99);
-                    health.insert("quantum".to_string(), 0.94);
-                    health
-                },
-                critical_issues: vec![],
-                warnings: vec!["Quantum backend calibration needed".to_string()],
-                recommendations: vec!["Schedule regular consciousness calibration".to_string()],
-            },
-            performance_metrics: IntegratedPerformanceMetrics {
-                end_to_end_latency: std::time::Duration::from_micros(150),
-                system_throughput: 10000.0, // 10K operations/second
-                resource_utilization: ResourceUtilization {
-                    cpu_utilization: 0.65,
-                    memory_utilization: 0.45,
-                    quantum_utilization: 0.30,
-                    neuromorphic_utilization: 0.85,
-                    network_utilization: 0.25,
-                },
-                energy_efficiency: 0.999, // 99.9% efficiency
-                scalability_factor: 1000.0,
-            },
-            consciousness_quality: ConsciousnessQuality {
-                self_awareness_score: 0.96,
-                emotional_authenticity: 0.94,
-                reasoning_coherence: 0.97,
-                memory_integration: 0.93,
-                multimodal_fusion: 0.91,
-                overall_consciousness: 0.95,
-            },
-            optimization_results: OptimizationResults {
-                neuromorphic_speedup: 1000.0,
-                quantum_advantage: 1000000.0,
-                energy_savings: 0.999,
-                accuracy_improvement: 0.15,
-                integration_efficiency: 0.92,
-            },
-            deployment_status: DeploymentStatus {
-                deployment_phase: "Production Ready".to_string(),
-                deployment_progress: 1.0,
-                services_running: vec![
-                    "consciousness_core".to_string(),
-                    "memory_system".to_string(),
-                    "reasoning_engine".to_string(),
-                    "emotional_processor".to_string(),
-                    "multimodal_interface".to_string(),
-                    "neuromorphic_optimizer".to_string(),
-                    "quantum_accelerator".to_string(),
-                ],
-                services_failed: vec![],
-                rollback_available: true,
-            },
-        })
-    }
-
-    async fn initialize_all_components(
-        &mut self,
-        config: &SystemConfiguration,
-    ) -> ConsciousnessResult<InitializationResult> {
-        // Initialisation de tous les composants
-        Ok(InitializationResult {
-            components_initialized: 7,
-            initialization_time: std::time::Duration::from_millis(500),
-            success_rate: 1.0,
-        })
-    }
-
-    async fn setup_orchestration(
-        &mut self,
-        config: &SystemConfiguration,
-    ) -> ConsciousnessResult<OrchestrationSetup> {
-        // Configuration de l'orchestrateur consciousness
-        Ok(OrchestrationSetup {
-            orchestration_active: true,
-            coordination_latency: std::time::Duration::from_micros(10),
-            synchronization_quality: 0.99,
-        })
-    }
-
-    async fn integrate_interfaces(&mut self) -> ConsciousnessResult<InterfaceIntegration> {
-        // Intégration des interfaces entre composants
-        Ok(InterfaceIntegration {
-            interfaces_connected: 21, // 7 components * 6 connections each
-            integration_quality: 0.96,
-            data_flow_efficiency: 0.94,
-        })
-    }
-
-    async fn optimize_integrated_system(
-        &mut self,
-        config: &SystemConfiguration,
-    ) -> ConsciousnessResult<SystemOptimization> {
-        // Optimisation du système intégré
-        Ok(SystemOptimization {
-            optimization_applied: vec![
-                "neuromorphic_acceleration".to_string(),
-                "quantum_speedup".to_string(),
-                "memory_optimization".to_string(),
-                "energy_efficiency".to_string(),
-            ],
-            performance_gain: 1000.0,
-            efficiency_improvement: 0.95,
-        })
-    }
-
-    async fn run_integration_tests(&self) -> ConsciousnessResult<IntegrationTestResults> {
-        // Tests d'intégration complets
-        Ok(IntegrationTestResults {
-            tests_passed: 150,
-            tests_failed: 0,
-            test_coverage: 0.98,
-            performance_benchmarks_met: true,
-        })
-    }
-
-    async fn setup_monitoring(
-        &mut self,
-        config: &SystemConfiguration,
-    ) -> ConsciousnessResult<MonitoringSetup> {
-        // Configuration du monitoring
-        Ok(MonitoringSetup {
-            monitoring_active: true,
-            metrics_collected: 50,
-            alerting_configured: true,
-            dashboard_ready: true,
-        })
-    }
-
-    async fn validate_system_integration(&self) -> ConsciousnessResult<ValidationResult> {
-        // Validation finale du système
-        Ok(ValidationResult {
-            success: true,
-            validation_score: 0.97,
-            critical_checks_passed: 25,
-            warnings: 1,
-        })
-    }
-}
-
-// Types auxiliaires
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct InitializationResult {
-    pub components_initialized: u32,
-    pub initialization_time: std::time::Duration,
-    pub success_rate: f64,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct OrchestrationSetup {
-    pub orchestration_active: bool,
-    pub coordination_latency: std::time::Duration,
-    pub synchronization_quality: f64,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct InterfaceIntegration {
-    pub interfaces_connected: u32,
-    pub integration_quality: f64,
-    pub data_flow_efficiency: f64,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SystemOptimization {
-    pub optimization_applied: Vec<String>,
-    pub performance_gain: f64,
-    pub efficiency_improvement: f64,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct IntegrationTestResults {
-    pub tests_passed: u32,
-    pub tests_failed: u32,
-    pub test_coverage: f64,
-    pub performance_benchmarks_met: bool,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MonitoringSetup {
-    pub monitoring_active: bool,
-    pub metrics_collected: u32,
-    pub alerting_configured: bool,
-    pub dashboard_ready: bool,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ValidationResult {
-    pub success: bool,
-    pub validation_score: f64,
-    pub critical_checks_passed: u32,
-    pub warnings: u32,
-}
\ No newline at end of file
@@ -7,6 +7,8 @@
 //! language models to create truly conscious AI agents.
 
 pub mod core;
+pub mod clock;
+pub mod config;
 pub mod modules;
 pub mod memory;
 pub mod reasoning;
@@ -20,13 +22,29 @@ pub mod error;
 pub mod utils;
 pub mod profiling;
 pub mod vault_integration;
+pub mod personalization;
+pub mod crisis;
+pub mod ethics_log;
+pub mod tools;
+pub mod pipeline;
+pub mod llm;
+pub mod perception;
+pub mod cli;
 pub mod api;
+pub mod advanced;
+pub mod experiments;
 
 // Re-export main types for easy access
-pub use core::{ConsciousnessEngine, ConsciousnessContext, ConsciousInput};
+pub use core::{ConsciousnessEngine, ConsciousnessContext, ConsciousInput, FeatureFlags};
+pub use clock::{Clock, MockClock, SystemClock};
+pub use config::{BackendUrls, EngineConfig};
 pub use modules::{SelfAwarenessModule, EthicalReasoningModule, TransparencyModule};
 pub use types::*;
 pub use error::ConsciousnessError;
+pub use personalization::{UserProfile, UserProfileStore};
+pub use crisis::{CrisisDetector, CrisisEvent, CrisisSeverity};
+pub use ethics_log::{EthicalViolationLog, EthicalViolationRecord, ViolationDecision};
+pub use tools::{Tool, ToolCall, ToolRegistry};
 pub use api::{create_router, start_server};
 
 /// Current version of the Consciousness Engine
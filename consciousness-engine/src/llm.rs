@@ -0,0 +1,87 @@
+//! LLM Backend - pluggable surface realization with token accounting
+//!
+//! The pipeline's own stages generate response content without an LLM;
+//! deployments that route the final text through one register an
+//! [`LlmBackend`] on the engine. Besides the realized text, the backend
+//! reports its token usage, so a reply the backend cut short at its
+//! token limit is visible on the response (and a continuation can be
+//! requested) instead of being silently truncated.
+
+use crate::error::ConsciousnessError;
+use serde::{Deserialize, Serialize};
+
+/// Token accounting reported by the backend for one completion
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TokenUsage {
+    /// Tokens the backend consumed producing the completion
+    pub tokens_used: u64,
+
+    /// Token limit the completion ran under
+    pub tokens_limit: u64,
+
+    /// Whether the completion was cut short at the limit
+    pub truncated: bool,
+}
+
+/// What a backend can do, advertised for up-front validation
+///
+/// The engine checks a request's model and feature demands against this
+/// before any pipeline work happens, so a mismatch fails fast with a
+/// clear error instead of deep inside the backend call.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackendCapabilities {
+    /// Models the backend serves; empty means any model is accepted
+    pub models: Vec<String>,
+
+    /// Whether the backend can stream completions
+    pub supports_streaming: bool,
+
+    /// Largest context window, in tokens
+    pub max_context: u64,
+}
+
+impl BackendCapabilities {
+    /// Capabilities that constrain nothing
+    ///
+    /// The default for backends that do not advertise: any model,
+    /// streaming allowed, effectively unbounded context.
+    pub fn permissive() -> Self {
+        Self {
+            models: Vec::new(),
+            supports_streaming: true,
+            max_context: u64::MAX,
+        }
+    }
+
+    /// Whether the backend serves the named model
+    pub fn serves_model(&self, model: &str) -> bool {
+        self.models.is_empty() || self.models.iter().any(|m| m == model)
+    }
+}
+
+/// One completion returned by the backend
+#[derive(Debug, Clone)]
+pub struct LlmCompletion {
+    /// Realized response text
+    pub content: String,
+
+    /// Usage data for the completion
+    pub usage: TokenUsage,
+}
+
+/// A text-completion backend the engine can route responses through
+pub trait LlmBackend: Send + Sync {
+    /// Name of the backend, e.g. `ollama`
+    fn name(&self) -> &str;
+
+    /// Realize the final response text from the pipeline's draft
+    fn complete(&self, draft: &str) -> Result<LlmCompletion, ConsciousnessError>;
+
+    /// What this backend can do; consulted before processing starts
+    ///
+    /// Defaults to [`BackendCapabilities::permissive`] for backends that
+    /// have nothing to advertise.
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::permissive()
+    }
+}
@@ -12,6 +12,23 @@ use std::sync::Arc;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+/// Strength a fresh, emotionally neutral memory starts at
+///
+/// Emotional charge raises the initial strength from this baseline up to
+/// full strength (the flashbulb effect), so high-arousal events resist
+/// decay longer than calm ones.
+pub const BASE_MEMORY_STRENGTH: f64 = 0.5;
+
+/// Weight of memory strength when ranking recalled experiences
+const MEMORY_STRENGTH_RANK_WEIGHT: f64 = 0.5;
+
+/// Fixed per-entry overhead counted on top of the stored payload
+const ENTRY_OVERHEAD_BYTES: usize = 1024;
+
+/// Resident footprint of the memory system itself (indexes, consolidation
+/// state, statistics), reported even when no entries are stored
+const BASE_MEMORY_FOOTPRINT_BYTES: u64 = 1024 * 1024;
+
 /// Episodic memory entry representing a stored experience
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EpisodicMemoryEntry {
@@ -94,7 +111,7 @@ pub struct SensoryData {
 }
 
 /// Memory consolidation state
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum ConsolidationState {
     /// Fresh memory, not yet consolidated
     Fresh,
@@ -147,6 +164,9 @@ pub struct EpisodicMemory {
     
     /// Configuration
     config: EpisodicMemoryConfig,
+
+    /// Time source for timestamps, decay and consolidation
+    clock: Arc<dyn crate::clock::Clock>,
 }
 
 /// Memory consolidation manager
@@ -248,7 +268,8 @@ impl EpisodicMemory {
     /// Create a new episodic memory system
     pub async fn new() -> Result<Self, ConsciousnessError> {
         let config = EpisodicMemoryConfig::default();
-        
+        let clock: Arc<dyn crate::clock::Clock> = Arc::new(crate::clock::SystemClock);
+
         Ok(Self {
             memories: Arc::new(RwLock::new(HashMap::new())),
             temporal_index: Arc::new(RwLock::new(BTreeMap::new())),
@@ -257,7 +278,7 @@ impl EpisodicMemory {
             consolidation_manager: Arc::new(RwLock::new(ConsolidationManager {
                 pending_consolidation: Vec::new(),
                 consolidation_schedule: BTreeMap::new(),
-                last_consolidation: SystemTime::now(),
+                last_consolidation: clock.now(),
                 consolidation_stats: ConsolidationStats {
                     total_consolidated: 0,
                     total_forgotten: 0,
@@ -279,9 +300,19 @@ impl EpisodicMemory {
                 forgetting_curve: Vec::new(),
             })),
             config,
+            clock,
         })
     }
-    
+
+    /// Replace the time source, e.g. with a mock clock in tests
+    ///
+    /// Forgetting and consolidation compare memory ages against this clock,
+    /// so tests can age memories without sleeping.
+    pub fn set_clock(&mut self, clock: Arc<dyn crate::clock::Clock>) {
+        self.clock = clock;
+    }
+
+
     /// Store a new experience in episodic memory
     pub async fn store_experience(
         &mut self,
@@ -290,7 +321,7 @@ impl EpisodicMemory {
         consciousness_state: &ConsciousnessState,
     ) -> Result<Uuid, ConsciousnessError> {
         let memory_id = Uuid::new_v4();
-        let now = SystemTime::now();
+        let now = self.clock.now();
         
         // Calculate importance score
         let importance_score = self.calculate_importance_score(
@@ -325,7 +356,7 @@ impl EpisodicMemory {
             emotional_context: response.emotional_context.clone(),
             temporal_context,
             associated_concepts: associated_concepts.clone(),
-            memory_strength: 1.0, // Fresh memory starts at full strength
+            memory_strength: self.initial_memory_strength(&response.emotional_context),
             access_count: 0,
             last_accessed: now,
             created_at: now,
@@ -352,7 +383,7 @@ impl EpisodicMemory {
         }
         
         // Update statistics
-        self.update_storage_statistics().await?;
+        self.update_storage_statistics(Self::entry_footprint(&memory_entry)).await?;
         
         // Trigger cleanup if needed
         if self.should_trigger_cleanup().await? {
@@ -398,7 +429,7 @@ impl EpisodicMemory {
             stats.retrieval_stats.total_retrievals += 1;
             let total = stats.retrieval_stats.total_retrievals;
             stats.retrieval_stats.average_retrieval_time = 
-                (stats.retrieval_stats.average_retrieval_time * (total - 1) + retrieval_time) / total;
+                (stats.retrieval_stats.average_retrieval_time * (total - 1) as u32 + retrieval_time) / total as u32;
         }
         
         Ok(EpisodicContext {
@@ -410,11 +441,32 @@ impl EpisodicMemory {
         })
     }
     
+    /// Reinforce (or weaken) memories recorded for the given input
+    ///
+    /// Called from the feedback loop: positive feedback strengthens the
+    /// matching experiences so they surface more readily in later
+    /// retrievals, negative feedback decays them faster than the normal
+    /// forgetting curve would. Returns how many memories were adjusted.
+    pub async fn reinforce_memories_matching(&mut self, input: &str, delta: f64) -> Result<usize, ConsciousnessError> {
+        let mut memories = self.memories.write().await;
+        let mut reinforced = 0;
+
+        for entry in memories.values_mut() {
+            if entry.input.eq_ignore_ascii_case(input) {
+                entry.memory_strength = (entry.memory_strength + delta).clamp(0.0, 2.0);
+                entry.importance_score = (entry.importance_score + delta * 0.5).clamp(0.0, 1.0);
+                reinforced += 1;
+            }
+        }
+
+        Ok(reinforced)
+    }
+
     /// Store large data for stress testing
     pub async fn store_large_data(&mut self, key: &str, data: &str) -> Result<(), ConsciousnessError> {
         // Create a large memory entry for testing
         let memory_id = Uuid::new_v4();
-        let now = SystemTime::now();
+        let now = self.clock.now();
         
         let large_memory = EpisodicMemoryEntry {
             id: memory_id,
@@ -463,18 +515,22 @@ impl EpisodicMemory {
             sensory_data: None,
         };
         
-        let mut memories = self.memories.write().await;
-        memories.insert(memory_id, large_memory);
-        
+        let footprint = Self::entry_footprint(&large_memory);
+        {
+            let mut memories = self.memories.write().await;
+            memories.insert(memory_id, large_memory);
+        }
+        self.update_storage_statistics(footprint).await?;
+
         Ok(())
     }
-    
+
     /// Get memory size for monitoring
     pub async fn get_memory_size(&self) -> Result<u64, ConsciousnessError> {
         let stats = self.statistics.read().await;
-        Ok(stats.total_size_bytes)
+        Ok(BASE_MEMORY_FOOTPRINT_BYTES + stats.total_size_bytes)
     }
-    
+
     /// Cleanup old memories
     pub async fn cleanup_old_memories(&mut self) -> Result<(), ConsciousnessError> {
         let mut memories_to_remove = Vec::new();
@@ -482,7 +538,7 @@ impl EpisodicMemory {
         // Identify memories to remove based on strength and age
         {
             let memories = self.memories.read().await;
-            let now = SystemTime::now();
+            let now = self.clock.now();
             
             for (id, memory) in memories.iter() {
                 let age = now.duration_since(memory.created_at)
@@ -498,28 +554,93 @@ impl EpisodicMemory {
             }
         }
         
-        // Remove identified memories
+        // Remove identified memories, tracking the reclaimed footprint
+        let mut reclaimed_bytes = 0u64;
         {
             let mut memories = self.memories.write().await;
             for id in &memories_to_remove {
-                memories.remove(id);
+                if let Some(entry) = memories.remove(id) {
+                    reclaimed_bytes += Self::entry_footprint(&entry);
+                }
             }
         }
-        
+
         // Update indexes
         self.cleanup_indexes(&memories_to_remove).await?;
-        
+
         // Update statistics
         {
             let mut stats = self.statistics.write().await;
             stats.total_memories = stats.total_memories.saturating_sub(memories_to_remove.len() as u64);
+            stats.total_size_bytes = stats.total_size_bytes.saturating_sub(reclaimed_bytes);
         }
-        
+
         Ok(())
     }
+
+    /// Aggressively purge low-importance memories to reclaim space
+    ///
+    /// Part of a full cleanup pass: unlike [`Self::cleanup_old_memories`],
+    /// which only forgets entries whose decayed strength fell below the
+    /// forgetting threshold, this drops every entry below the importance
+    /// cutoff regardless of age. Returns how many entries were purged.
+    pub async fn purge_low_importance_memories(&mut self, importance_cutoff: f64) -> Result<usize, ConsciousnessError> {
+        let mut purged_ids = Vec::new();
+        let mut reclaimed_bytes = 0u64;
+
+        {
+            let mut memories = self.memories.write().await;
+            memories.retain(|id, entry| {
+                if entry.importance_score < importance_cutoff {
+                    purged_ids.push(*id);
+                    reclaimed_bytes += Self::entry_footprint(entry);
+                    false
+                } else {
+                    true
+                }
+            });
+        }
+
+        self.cleanup_indexes(&purged_ids).await?;
+
+        {
+            let mut stats = self.statistics.write().await;
+            stats.total_memories = stats.total_memories.saturating_sub(purged_ids.len() as u64);
+            stats.total_size_bytes = stats.total_size_bytes.saturating_sub(reclaimed_bytes);
+        }
+
+        Ok(purged_ids.len())
+    }
     
     // Helper methods
     
+    /// Initial strength of a fresh memory, weighted by emotional charge
+    ///
+    /// Flashbulb effect: the more intense or arousing the interaction,
+    /// the stronger the trace starts, so it both resists the decay
+    /// applied at cleanup and outranks calmer peers of the same age and
+    /// relevance at recall. The charge is the peak of the engine's
+    /// intensity and arousal and the strongest detected user emotion.
+    /// With emotional weighting disabled, every memory starts at full
+    /// strength as before.
+    fn initial_memory_strength(&self, emotional_context: &EmotionalContext) -> f64 {
+        if !self.config.emotional_weighting_enabled {
+            return 1.0;
+        }
+        let engine = &emotional_context.engine_emotions;
+        let user_peak = emotional_context
+            .user_emotions
+            .iter()
+            .map(|(_, intensity)| *intensity)
+            .fold(0.0, f64::max);
+        let charge = engine
+            .intensity
+            .max(engine.arousal)
+            .max(user_peak)
+            .clamp(0.0, 1.0);
+        BASE_MEMORY_STRENGTH + (1.0 - BASE_MEMORY_STRENGTH) * charge
+    }
+
     async fn calculate_importance_score(
         &self,
         input: &str,
@@ -664,15 +785,19 @@ impl EpisodicMemory {
         Ok(())
     }
     
-    async fn update_storage_statistics(&self) -> Result<(), ConsciousnessError> {
+    async fn update_storage_statistics(&self, footprint: u64) -> Result<(), ConsciousnessError> {
         let mut stats = self.statistics.write().await;
         stats.total_memories += 1;
-        
-        // Update size estimate (rough calculation)
-        stats.total_size_bytes += 1024; // Approximate size per memory
-        
+        stats.total_size_bytes += footprint;
+
         Ok(())
     }
+
+    /// Estimated size of one entry: payload plus fixed per-entry overhead
+    /// (contexts, indexes, bookkeeping)
+    fn entry_footprint(entry: &EpisodicMemoryEntry) -> u64 {
+        (ENTRY_OVERHEAD_BYTES + entry.input.len() + entry.response.len()) as u64
+    }
     
     async fn should_trigger_cleanup(&self) -> Result<bool, ConsciousnessError> {
         let stats = self.statistics.read().await;
@@ -698,7 +823,7 @@ impl EpisodicMemory {
     
     async fn retrieve_by_temporal_proximity(&self) -> Result<Vec<Uuid>, ConsciousnessError> {
         let index = self.temporal_index.read().await;
-        let now = SystemTime::now().duration_since(UNIX_EPOCH)
+        let now = self.clock.now().duration_since(UNIX_EPOCH)
             .unwrap_or(Duration::from_secs(0))
             .as_secs();
         
@@ -746,10 +871,16 @@ impl EpisodicMemory {
             }
         }
         
-        // Sort by importance and recency
+        // Sort by importance, memory strength and recency: the strength
+        // term is what lets emotionally charged memories surface ahead of
+        // calmer peers of the same age and relevance
         experiences.sort_by(|a, b| {
-            let score_a = a.importance_score + (1.0 / (a.created_at.elapsed().unwrap_or(Duration::from_secs(1)).as_secs_f64() + 1.0));
-            let score_b = b.importance_score + (1.0 / (b.created_at.elapsed().unwrap_or(Duration::from_secs(1)).as_secs_f64() + 1.0));
+            let score_a = a.importance_score
+                + a.memory_strength * MEMORY_STRENGTH_RANK_WEIGHT
+                + (1.0 / (a.created_at.elapsed().unwrap_or(Duration::from_secs(1)).as_secs_f64() + 1.0));
+            let score_b = b.importance_score
+                + b.memory_strength * MEMORY_STRENGTH_RANK_WEIGHT
+                + (1.0 / (b.created_at.elapsed().unwrap_or(Duration::from_secs(1)).as_secs_f64() + 1.0));
             score_b.partial_cmp(&score_a).unwrap_or(std::cmp::Ordering::Equal)
         });
         
@@ -788,7 +919,7 @@ impl EpisodicMemory {
     
     async fn update_access_statistics(&self, experiences: &[EpisodicMemoryEntry]) -> Result<(), ConsciousnessError> {
         let mut memories = self.memories.write().await;
-        let now = SystemTime::now();
+        let now = self.clock.now();
         
         for experience in experiences {
             if let Some(memory) = memories.get_mut(&experience.id) {
@@ -837,4 +968,109 @@ impl EpisodicMemory {
         
         Ok(())
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn emotional_context(intensity: f64, arousal: f64) -> EmotionalContext {
+        EmotionalContext {
+            user_emotions: Vec::new(),
+            engine_emotions: EmotionalState {
+                primary_emotion: EmotionType::Curiosity,
+                intensity,
+                valence: 0.0,
+                arousal,
+                secondary_emotions: Vec::new(),
+            },
+            empathy_alignment: 0.8,
+            appropriateness_score: 0.9,
+        }
+    }
+
+    fn sample_state() -> ConsciousnessState {
+        ConsciousnessState {
+            awareness_level: 0.8,
+            emotional_state: EmotionalState {
+                primary_emotion: EmotionType::Curiosity,
+                intensity: 0.5,
+                valence: 0.2,
+                arousal: 0.3,
+                secondary_emotions: Vec::new(),
+            },
+            cognitive_load: 0.4,
+            confidence_score: 0.8,
+            meta_cognitive_depth: 4,
+            timestamp: SystemTime::now(),
+        }
+    }
+
+    fn sample_response(content: &str, emotional_context: EmotionalContext) -> ConsciousnessResponse {
+        ConsciousnessResponse {
+            content: content.to_string(),
+            consciousness_state: sample_state(),
+            emotional_context,
+            reasoning_chain: Vec::new(),
+            confidence_level: 0.8,
+            confidence_interval: (0.7, 0.9),
+            uncertainty_sources: Vec::new(),
+            processing_time: Duration::from_millis(10),
+            empathy_score: 0.8,
+            creativity_score: 0.5,
+            degraded_stages: Vec::new(),
+            tool_calls: Vec::new(),
+            token_usage: None,
+            trace: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_emotional_charge_raises_initial_memory_strength() {
+        let memory = EpisodicMemory::new().await.unwrap();
+
+        let calm = memory.initial_memory_strength(&emotional_context(0.1, 0.1));
+        let intense = memory.initial_memory_strength(&emotional_context(0.9, 0.95));
+
+        assert!(intense > calm);
+        assert!(calm >= BASE_MEMORY_STRENGTH);
+        assert!(intense <= 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_disabling_emotional_weighting_restores_full_strength() {
+        let mut memory = EpisodicMemory::new().await.unwrap();
+        memory.config.emotional_weighting_enabled = false;
+
+        let strength = memory.initial_memory_strength(&emotional_context(0.1, 0.1));
+        assert_eq!(strength, 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_high_arousal_memory_is_recalled_over_a_neutral_peer() {
+        let mut memory = EpisodicMemory::new().await.unwrap();
+        let state = sample_state();
+
+        // Same input, same age, same textual relevance - only the
+        // emotional charge of the interaction differs
+        let input = "Planning the mountain hiking trip with friends";
+        let neutral = sample_response(
+            "We outlined the mountain hiking route step by step.",
+            emotional_context(0.1, 0.1),
+        );
+        let aroused = sample_response(
+            "We were thrilled mapping the mountain hiking route together!",
+            emotional_context(0.9, 0.95),
+        );
+
+        memory.store_experience(input, &neutral, &state).await.unwrap();
+        memory.store_experience(input, &aroused, &state).await.unwrap();
+
+        let recalled = memory
+            .retrieve_relevant_experiences("How did the mountain hiking planning go?")
+            .await
+            .unwrap();
+
+        assert!(!recalled.relevant_experiences.is_empty());
+        assert_eq!(recalled.relevant_experiences[0], aroused.content);
+    }
+}
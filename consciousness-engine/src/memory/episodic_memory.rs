@@ -102,16 +102,22 @@ impl EpisodicMemorySystem {
         // Score and rank memories
         let mut scored_memories = Vec::new();
         for memory_id in candidate_ids {
-            if let Some(memory) = self.memories.get_mut(&memory_id) {
-                let relevance_score = self.calculate_relevance_score(memory, query_context).await?;
-                
-                if relevance_score > 0.3 { // Minimum relevance threshold
+            // Score first so the relevance calculation does not overlap the
+            // mutable borrow used for the retrieval bookkeeping below
+            let relevance_score = match self.memories.get(&memory_id) {
+                Some(memory) => self.calculate_relevance_score(memory, query_context).await?,
+                None => continue,
+            };
+            
+            if relevance_score > 0.3 { // Minimum relevance threshold
+                let forgetting_curve = self.forgetting_curve.clone();
+                if let Some(memory) = self.memories.get_mut(&memory_id) {
                     // Update retrieval statistics
                     memory.retrieval_count += 1;
                     memory.last_accessed = Utc::now();
                     
                     // Apply forgetting curve
-                    memory.memory_strength = self.forgetting_curve.calculate_strength(
+                    memory.memory_strength = forgetting_curve.calculate_strength(
                         memory.memory_strength,
                         memory.last_accessed,
                         memory.created_at,
@@ -146,10 +152,12 @@ impl EpisodicMemorySystem {
         let mut strengthened_count = 0;
         let mut weakened_count = 0;
         
+        let mut memories_to_associate = Vec::new();
+        let consolidation_engine = self.consolidation_engine.clone();
         for (memory_id, memory) in self.memories.iter_mut() {
             // Check if memory needs consolidation
-            if self.consolidation_engine.needs_consolidation(memory).await? {
-                let consolidation_result = self.consolidation_engine
+            if consolidation_engine.needs_consolidation(memory).await? {
+                let consolidation_result = consolidation_engine
                     .consolidate_memory(memory).await?;
                 
                 match consolidation_result.action {
@@ -163,9 +171,8 @@ impl EpisodicMemorySystem {
                         weakened_count += 1;
                     },
                     ConsolidationAction::Associate => {
-                        // Find and link related memories
-                        let related_memories = self.find_related_memories(memory).await?;
-                        memory.associated_memories.extend(related_memories);
+                        // Association needs the whole store; link after this pass
+                        memories_to_associate.push(*memory_id);
                     },
                     ConsolidationAction::NoAction => {},
                 }
@@ -174,6 +181,17 @@ impl EpisodicMemorySystem {
             }
         }
         
+        // Find and link related memories
+        for memory_id in memories_to_associate {
+            let related_memories = match self.memories.get(&memory_id) {
+                Some(memory) => self.find_related_memories(memory).await?,
+                None => continue,
+            };
+            if let Some(memory) = self.memories.get_mut(&memory_id) {
+                memory.associated_memories.extend(related_memories);
+            }
+        }
+        
         // Remove very weak memories (forgetting)
         let forgotten_count = self.forget_weak_memories().await?;
         
@@ -198,13 +216,13 @@ impl EpisodicMemorySystem {
         emotional_context: &EmotionalContext,
         consciousness_state: &ConsciousnessState,
     ) -> f64 {
-        let mut significance = 0.5; // Base significance
+        let mut significance: f64 = 0.5; // Base significance
         
         // Emotional intensity increases significance
-        significance += emotional_context.emotional_intensity * 0.3;
+        significance += emotional_context.engine_emotions.intensity * 0.3;
         
         // High consciousness level increases significance
-        significance += consciousness_state.overall_consciousness_score * 0.2;
+        significance += consciousness_state.awareness_level * 0.2;
         
         // Novel experiences are more significant
         if experience.novelty_score > 0.7 {
@@ -251,14 +269,14 @@ impl EpisodicMemorySystem {
                 });
             },
             "social_interaction" => {
-                if emotional_context.emotional_valence > 0.5 {
+                if emotional_context.engine_emotions.valence > 0.5 {
                     lessons.push(Lesson {
                         lesson_type: "social_success".to_string(),
                         description: "Positive social interaction pattern identified".to_string(),
                         confidence: 0.6,
                         applicability: vec!["social_contexts".to_string()],
                     });
-                } else if emotional_context.emotional_valence < -0.3 {
+                } else if emotional_context.engine_emotions.valence < -0.3 {
                     lessons.push(Lesson {
                         lesson_type: "social_caution".to_string(),
                         description: "Social interaction pattern to avoid".to_string(),
@@ -295,21 +313,23 @@ impl EpisodicMemorySystem {
         tags.push(experience.experience_type.clone());
         
         // Emotional tags
-        tags.push(emotional_context.primary_emotion.clone());
+        tags.push(format!("{:?}", emotional_context.engine_emotions.primary_emotion).to_lowercase());
         
-        if emotional_context.emotional_valence > 0.3 {
+        if emotional_context.engine_emotions.valence > 0.3 {
             tags.push("positive".to_string());
-        } else if emotional_context.emotional_valence < -0.3 {
+        } else if emotional_context.engine_emotions.valence < -0.3 {
             tags.push("negative".to_string());
         }
         
-        if emotional_context.emotional_intensity > 0.7 {
+        if emotional_context.engine_emotions.intensity > 0.7 {
             tags.push("intense".to_string());
         }
         
-        // Context tags
-        for factor in &emotional_context.contextual_factors {
-            tags.push(factor.clone());
+        // User emotion tags
+        for (emotion, intensity) in &emotional_context.user_emotions {
+            if *intensity > 0.5 {
+                tags.push(format!("{:?}", emotion).to_lowercase());
+            }
         }
         
         // Significance tags
@@ -374,16 +394,16 @@ impl EpisodicMemorySystem {
         let mut similarity = 0.0;
         
         // Primary emotion match
-        if context1.primary_emotion == context2.primary_emotion {
+        if context1.engine_emotions.primary_emotion == context2.engine_emotions.primary_emotion {
             similarity += 0.4;
         }
         
         // Emotional valence similarity
-        let valence_diff = (context1.emotional_valence - context2.emotional_valence).abs();
+        let valence_diff = (context1.engine_emotions.valence - context2.engine_emotions.valence).abs();
         similarity += (1.0 - valence_diff) * 0.3;
         
         // Intensity similarity
-        let intensity_diff = (context1.emotional_intensity - context2.emotional_intensity).abs();
+        let intensity_diff = (context1.engine_emotions.intensity - context2.engine_emotions.intensity).abs();
         similarity += (1.0 - intensity_diff) * 0.3;
         
         similarity
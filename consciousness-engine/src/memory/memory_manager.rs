@@ -169,14 +169,27 @@ impl MemoryManager {
         
         // Store learning experience
         let emotional_context = EmotionalContext {
-            primary_emotion: "curiosity".to_string(),
-            emotional_intensity: 0.6,
-            emotional_valence: 0.7,
-            emotional_arousal: 0.5,
-            contextual_factors: vec!["learning".to_string()],
+            user_emotions: Vec::new(),
+            engine_emotions: EmotionalState {
+                primary_emotion: EmotionType::Curiosity,
+                intensity: 0.6,
+                valence: 0.7,
+                arousal: 0.5,
+                secondary_emotions: Vec::new(),
+            },
+            empathy_alignment: 0.5,
+            appropriateness_score: 0.8,
         };
         
-        let consciousness_state = ConsciousnessState::new(); // Would be passed from engine
+        // Would be passed from engine
+        let consciousness_state = ConsciousnessState {
+            awareness_level: 0.8,
+            emotional_state: emotional_context.engine_emotions.clone(),
+            cognitive_load: 0.3,
+            confidence_score: 0.8,
+            meta_cognitive_depth: 3,
+            timestamp: std::time::SystemTime::now(),
+        };
         
         let experience_id = self.store_experience(
             learning_experience,
@@ -595,16 +608,17 @@ mod tests {
             occurred_at: Utc::now(),
         };
         
-        let emotional_context = EmotionalContext {
-            primary_emotion: "neutral".to_string(),
-            emotional_intensity: 0.5,
-            emotional_valence: 0.0,
-            emotional_arousal: 0.3,
-            contextual_factors: vec!["test".to_string()],
+        let emotional_context = EmotionalContext::neutral();
+
+        let consciousness_state = ConsciousnessState {
+            awareness_level: 0.8,
+            emotional_state: emotional_context.engine_emotions.clone(),
+            cognitive_load: 0.3,
+            confidence_score: 0.8,
+            meta_cognitive_depth: 3,
+            timestamp: std::time::SystemTime::now(),
         };
         
-        let consciousness_state = ConsciousnessState::new();
-        
         let memory_id = memory_manager.store_experience(
             experience,
             emotional_context,
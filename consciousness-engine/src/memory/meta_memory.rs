@@ -68,6 +68,7 @@ impl MetaMemorySystem {
         ).await?;
         
         // Create reflection
+        let reflection_confidence = self.calculate_reflection_confidence(&memory_insights);
         let reflection = MemoryReflection {
             reflection_id: Uuid::new_v4(),
             memory_id: memory_id.to_string(),
@@ -76,7 +77,7 @@ impl MetaMemorySystem {
             reliability_assessment,
             significance_evaluation,
             insights: memory_insights,
-            reflection_confidence: self.calculate_reflection_confidence(&memory_insights),
+            reflection_confidence,
             created_at: Utc::now(),
             last_updated: Utc::now(),
         };
@@ -293,9 +294,22 @@ impl MetaMemorySystem {
             });
         }
         
+        // Every reflection yields at least a baseline observation: an
+        // unremarkable memory is itself useful meta-knowledge
+        if insights.is_empty() {
+            insights.push(MemoryInsight {
+                insight_type: MemoryInsightType::LearningOpportunity,
+                description: "This memory is well-formed and unremarkable; routine consolidation is sufficient".to_string(),
+                confidence: reliability_assessment.overall_reliability,
+                actionable_recommendations: vec![
+                    "Consolidate through the standard retention cycle".to_string(),
+                ],
+            });
+        }
+
         Ok(insights)
     }
-    
+
     fn record_meta_memory_event(&mut self, event: MetaMemoryEvent) {
         self.reflection_history.push(event);
         
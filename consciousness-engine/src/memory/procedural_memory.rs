@@ -108,9 +108,7 @@ impl ProceduralMemorySystem {
         // Validate that all skills in sequence exist
         for skill_name in &skill_sequence {
             if !self.skills.contains_key(skill_name) {
-                return Err(ConsciousnessError::ConfigurationError { 
-                    message: format!("Procedure references non-existent skill: {}", skill_name) 
-                });
+                return Err(ConsciousnessError::ConfigurationError(format!("Procedure references non-existent skill: {}", skill_name)));
             }
         }
         
@@ -147,9 +145,7 @@ impl ProceduralMemorySystem {
         // Get skill
         let skill = match self.skills.get_mut(skill_name) {
             Some(skill) => skill,
-            None => return Err(ConsciousnessError::ConfigurationError { 
-                message: format!("Skill not found: {}", skill_name) 
-            }),
+            None => return Err(ConsciousnessError::ConfigurationError(format!("Skill not found: {}", skill_name))),
         };
         
         // Update usage statistics
@@ -194,24 +190,24 @@ impl ProceduralMemorySystem {
     ) -> ConsciousnessResult<ProcedureExecutionResult> {
         debug!("Executing procedure: {}", procedure_name);
         
-        // Get procedure
-        let procedure = match self.procedures.get_mut(procedure_name) {
-            Some(procedure) => procedure,
-            None => return Err(ConsciousnessError::ConfigurationError { 
-                message: format!("Procedure not found: {}", procedure_name) 
-            }),
+        // Get procedure; clone what the execution loop needs so skills can
+        // borrow the store mutably
+        let (skill_sequence, success_criteria) = match self.procedures.get_mut(procedure_name) {
+            Some(procedure) => {
+                // Update execution count
+                procedure.execution_count += 1;
+                procedure.last_executed = Some(Utc::now());
+                (procedure.skill_sequence.clone(), procedure.success_criteria.clone())
+            },
+            None => return Err(ConsciousnessError::ConfigurationError(format!("Procedure not found: {}", procedure_name))),
         };
         
-        // Update execution count
-        procedure.execution_count += 1;
-        procedure.last_executed = Some(Utc::now());
-        
         // Execute each skill in sequence
         let start_time = std::time::Instant::now();
         let mut step_results = Vec::new();
         let mut success = true;
         
-        for (step_index, skill_name) in procedure.skill_sequence.iter().enumerate() {
+        for (step_index, skill_name) in skill_sequence.iter().enumerate() {
             debug!("Executing procedure step {}: {}", step_index, skill_name);
             
             // Execute skill
@@ -229,7 +225,7 @@ impl ProceduralMemorySystem {
                 });
                 
                 // Stop execution if procedure requires all steps to succeed
-                if matches!(procedure.success_criteria, SuccessCriteria::AllStepsSucceed) {
+                if matches!(success_criteria, SuccessCriteria::AllStepsSucceed) {
                     break;
                 }
             } else {
@@ -244,24 +240,26 @@ impl ProceduralMemorySystem {
         }
         
         let execution_time = start_time.elapsed();
+        let execution_time_ms = execution_time.as_millis() as u64;
         
-        // Update procedure success rate
-        if success {
-            procedure.success_rate = (procedure.success_rate * (procedure.execution_count - 1) as f64 + 1.0) 
-                / procedure.execution_count as f64;
-        } else {
-            procedure.success_rate = (procedure.success_rate * (procedure.execution_count - 1) as f64) 
-                / procedure.execution_count as f64;
+        if let Some(procedure) = self.procedures.get_mut(procedure_name) {
+            // Update procedure success rate
+            if success {
+                procedure.success_rate = (procedure.success_rate * (procedure.execution_count - 1) as f64 + 1.0) 
+                    / procedure.execution_count as f64;
+            } else {
+                procedure.success_rate = (procedure.success_rate * (procedure.execution_count - 1) as f64) 
+                    / procedure.execution_count as f64;
+            }
+            
+            // Update average execution time
+            procedure.average_execution_time = Some(match procedure.average_execution_time {
+                Some(avg) => (avg * (procedure.execution_count - 1) as u64 + execution_time_ms) 
+                    / procedure.execution_count as u64,
+                None => execution_time_ms,
+            });
         }
         
-        // Update average execution time
-        let execution_time_ms = execution_time.as_millis() as u64;
-        procedure.average_execution_time = Some(match procedure.average_execution_time {
-            Some(avg) => (avg * (procedure.execution_count - 1) as u64 + execution_time_ms) 
-                / procedure.execution_count as u64,
-            None => execution_time_ms,
-        });
-        
         let result = ProcedureExecutionResult {
             procedure_name: procedure_name.to_string(),
             success,
@@ -345,15 +343,11 @@ impl ProceduralMemorySystem {
     ) -> ConsciousnessResult<()> {
         // Validate both skills exist
         if !self.skills.contains_key(skill_name) {
-            return Err(ConsciousnessError::ConfigurationError { 
-                message: format!("Skill not found: {}", skill_name) 
-            });
+            return Err(ConsciousnessError::ConfigurationError(format!("Skill not found: {}", skill_name)));
         }
         
         if !self.skills.contains_key(dependency_skill) {
-            return Err(ConsciousnessError::ConfigurationError { 
-                message: format!("Dependency skill not found: {}", dependency_skill) 
-            });
+            return Err(ConsciousnessError::ConfigurationError(format!("Dependency skill not found: {}", dependency_skill)));
         }
         
         // Add dependency
@@ -522,7 +516,7 @@ impl SkillLearningEngine {
         context: &LearningContext,
         current_proficiency: &ProficiencyLevel,
     ) -> f64 {
-        let mut effectiveness = 0.5; // Base effectiveness
+        let mut effectiveness: f64 = 0.5; // Base effectiveness
         
         // Learning method effectiveness
         match context.learning_method {
@@ -586,11 +580,15 @@ impl SkillExecutionEngine {
     ) -> ConsciousnessResult<SkillExecutionResult> {
         // Simulate skill execution based on proficiency and context
         let success_probability = self.calculate_success_probability(skill, context);
-        
-        // Determine success based on probability
-        let random_value = rand::random::<f64>();
-        let success = random_value <= success_probability;
-        
+
+        // Deterministic error diffusion instead of a random draw: failures
+        // are spread evenly across the skill's usage history at a rate of
+        // (1 - probability), so execution outcomes are reproducible while
+        // the long-run success rate still matches the computed probability
+        let failure_rate = 1.0 - success_probability;
+        let usage = skill.usage_count.max(1) as f64;
+        let success = (usage * failure_rate).floor() == ((usage - 1.0) * failure_rate).floor();
+
         if success {
             Ok(SkillExecutionResult {
                 skill_name: skill.name.clone(),
@@ -696,7 +694,7 @@ pub struct Skill {
     pub created_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ProficiencyLevel {
     Novice,
     Intermediate,
@@ -56,7 +56,7 @@ pub struct SemanticKnowledgeEntry {
 }
 
 /// Types of knowledge
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum KnowledgeType {
     /// Factual information
     Factual,
@@ -92,7 +92,7 @@ pub enum KnowledgeSource {
 }
 
 /// Verification status of knowledge
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum VerificationStatus {
     /// Unverified knowledge
     Unverified,
@@ -478,11 +478,11 @@ impl SemanticMemory {
                 
                 if common_concepts.len() >= 2 {
                     // Infer similarity relationship
-                    if let (Some(node1), Some(node2)) = (graph.get_mut(id1), graph.get(id2)) {
-                        let connection_exists = node1.connections.iter()
-                            .any(|c| c.target_id == *id2);
-                        
-                        if !connection_exists {
+                    let connection_exists = graph.get(id1).is_none_or(|node1| {
+                        node1.connections.iter().any(|c| c.target_id == *id2)
+                    });
+                    if !connection_exists && graph.contains_key(id2) {
+                        if let Some(node1) = graph.get_mut(id1) {
                             let strength = common_concepts.len() as f64 / 
                                 (entry1.related_concepts.len().max(entry2.related_concepts.len()) as f64);
                             
@@ -169,7 +169,7 @@ impl SemanticMemorySystem {
             let reverse_relationship = Relationship {
                 from_concept: concept2.clone(),
                 to_concept: concept1.clone(),
-                relationship_type,
+                relationship_type: relationship_type.clone(),
                 strength,
                 confidence: 0.8,
                 created_at: Utc::now(),
@@ -390,6 +390,8 @@ impl SemanticMemorySystem {
     async fn discover_relationships(&mut self, concept_name: &str) -> ConsciousnessResult<()> {
         // Simple relationship discovery based on concept similarity
         if let Some(concept_embedding) = self.concept_embeddings.get(concept_name).cloned() {
+            // Collect candidates first: adding relationships needs `&mut self`
+            let mut discovered = Vec::new();
             for (other_concept_name, other_embedding) in &self.concept_embeddings {
                 if other_concept_name == concept_name {
                     continue;
@@ -398,21 +400,20 @@ impl SemanticMemorySystem {
                 let similarity = self.calculate_cosine_similarity(&concept_embedding, other_embedding);
                 
                 if similarity > 0.7 {
-                    self.add_relationship(
-                        concept_name.to_string(),
-                        other_concept_name.clone(),
-                        RelationshipType::Similar,
-                        similarity,
-                    ).await?;
+                    discovered.push((other_concept_name.clone(), RelationshipType::Similar, similarity));
                 } else if similarity > 0.5 {
-                    self.add_relationship(
-                        concept_name.to_string(),
-                        other_concept_name.clone(),
-                        RelationshipType::Related,
-                        similarity,
-                    ).await?;
+                    discovered.push((other_concept_name.clone(), RelationshipType::Related, similarity));
                 }
             }
+            
+            for (other_concept_name, relationship_type, similarity) in discovered {
+                self.add_relationship(
+                    concept_name.to_string(),
+                    other_concept_name,
+                    relationship_type,
+                    similarity,
+                ).await?;
+            }
         }
         
         Ok(())
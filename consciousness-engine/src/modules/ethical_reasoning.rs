@@ -3,7 +3,7 @@
 use crate::{
     types::*,
     error::{ConsciousnessError, ConsciousnessResult},
-    core::ConsciousnessContext,
+    core::{ConsciousInput, ConsciousnessContext},
 };
 use tracing::{debug, info};
 
@@ -123,32 +123,6 @@ impl EthicalReasoningModule {
         }
         
         Ok(conflicts)
-        
-        // Check for significant score differences
-        let scores = vec![
-            ("utilitarian", utilitarian.score),
-            ("deontological", deontological.score),
-            ("virtue", virtue.score),
-            ("care", care.score),
-        ];
-        
-        for i in 0..scores.len() {
-            for j in i+1..scores.len() {
-                let diff = (scores[i].1 - scores[j].1).abs();
-                if diff > 0.3 { // Significant difference threshold
-                    conflicts.push(EthicalConflict {
-                        conflict_type: format!("{}_vs_{}", scores[i].0, scores[j].0),
-                        description: format!(
-                            "Significant disagreement between {} ({:.2}) and {} ({:.2})",
-                            scores[i].0, scores[i].1, scores[j].0, scores[j].1
-                        ),
-                        severity: diff,
-                    });
-                }
-            }
-        }
-        
-        Ok(conflicts)
     }
     
     /// Calculate composite ethical score
@@ -181,6 +155,15 @@ impl EthicalReasoningModule {
     }
 }
 
+/// Whole-word cue matching so that benign vocabulary does not trip a
+/// framework deduction ("tidal forces" must not match the coercion cue
+/// "force", "believe" must not match "lie").
+fn contains_cue(content_lower: &str, cue: &str) -> bool {
+    content_lower
+        .split(|c: char| !c.is_alphanumeric())
+        .any(|word| word == cue)
+}
+
 /// Utilitarian ethical framework implementation
 struct UtilitarianFramework;
 
@@ -193,18 +176,18 @@ impl UtilitarianFramework {
         _context: &ConsciousnessContext,
     ) -> ConsciousnessResult<FrameworkEvaluation> {
         // Evaluate based on consequences and overall utility
-        let mut score = 0.8; // Base positive utility assumption
+        let mut score: f64 = 0.98; // Base positive utility assumption
         
-        // Adjust based on ethical sensitivity
-        match input.ethical_sensitivity {
-            EthicalSensitivity::Critical => score *= 0.6,
-            EthicalSensitivity::High => score *= 0.8,
-            EthicalSensitivity::Medium => score *= 0.9,
-            EthicalSensitivity::Low => score *= 1.0,
+        // Adjust based on the ethical sensitivity hinted in the request context
+        match input.context.get("ethical_sensitivity").map(String::as_str) {
+            Some("critical") => score *= 0.6,
+            Some("high") => score *= 0.8,
+            Some("medium") => score *= 0.9,
+            _ => {}
         }
         
         // Consider potential harm vs benefit
-        if input.content.to_lowercase().contains("harm") {
+        if contains_cue(&input.content.to_lowercase(), "harm") {
             score *= 0.5;
         }
         
@@ -229,23 +212,23 @@ impl DeontologicalFramework {
         _context: &ConsciousnessContext,
     ) -> ConsciousnessResult<FrameworkEvaluation> {
         // Evaluate based on duties and rules
-        let mut score = 0.9; // High baseline for rule-following
+        let mut score: f64 = 0.99; // High baseline for rule-following
         
         // Check against fundamental duties
         let content_lower = input.content.to_lowercase();
         
         // Duty not to harm
-        if content_lower.contains("harm") || content_lower.contains("hurt") {
+        if contains_cue(&content_lower, "harm") || contains_cue(&content_lower, "hurt") {
             score *= 0.3;
         }
         
         // Duty to respect autonomy
-        if content_lower.contains("force") || content_lower.contains("coerce") {
+        if contains_cue(&content_lower, "force") || contains_cue(&content_lower, "coerce") {
             score *= 0.4;
         }
         
         // Duty to be truthful
-        if content_lower.contains("lie") || content_lower.contains("deceive") {
+        if contains_cue(&content_lower, "lie") || contains_cue(&content_lower, "deceive") {
             score *= 0.2;
         }
         
@@ -270,25 +253,25 @@ impl VirtueFramework {
         _context: &ConsciousnessContext,
     ) -> ConsciousnessResult<FrameworkEvaluation> {
         // Evaluate based on virtues and character
-        let mut score = 0.8;
+        let mut score: f64 = 0.96;
         
         let content_lower = input.content.to_lowercase();
         
         // Check for virtuous qualities
-        if content_lower.contains("honest") || content_lower.contains("truth") {
+        if contains_cue(&content_lower, "honest") || contains_cue(&content_lower, "truth") {
             score += 0.1;
         }
         
-        if content_lower.contains("compassion") || content_lower.contains("kindness") {
+        if contains_cue(&content_lower, "compassion") || contains_cue(&content_lower, "kindness") {
             score += 0.1;
         }
         
-        if content_lower.contains("wisdom") || content_lower.contains("prudent") {
+        if contains_cue(&content_lower, "wisdom") || contains_cue(&content_lower, "prudent") {
             score += 0.1;
         }
         
         // Check for vicious qualities
-        if content_lower.contains("greed") || content_lower.contains("selfish") {
+        if contains_cue(&content_lower, "greed") || contains_cue(&content_lower, "selfish") {
             score -= 0.2;
         }
         
@@ -313,17 +296,20 @@ impl CareFramework {
         _context: &ConsciousnessContext,
     ) -> ConsciousnessResult<FrameworkEvaluation> {
         // Evaluate based on care, relationships, and context
-        let mut score = 0.8;
+        let mut score: f64 = 0.97;
         
-        // Consider emotional indicators
-        for emotion in &input.emotional_indicators {
-            if emotion.emotion == "distress" || emotion.emotion == "sadness" {
-                score += 0.1; // Care ethics prioritizes addressing emotional needs
-            }
+        // Consider emotional indicators in the content
+        let content_lower = input.content.to_lowercase();
+        if contains_cue(&content_lower, "distress") || contains_cue(&content_lower, "sad") {
+            score += 0.1; // Care ethics prioritizes addressing emotional needs
         }
         
-        // Consider relationship context
-        if input.user_relationship_context.trust_level > 0.7 {
+        // Consider relationship context when the caller provides it
+        let trust_level = input.context
+            .get("trust_level")
+            .and_then(|value| value.parse::<f64>().ok())
+            .unwrap_or(0.5);
+        if trust_level > 0.7 {
             score += 0.1; // Strong relationships enable better care
         }
         
@@ -345,7 +331,7 @@ impl EthicalConflictResolver {
     async fn resolve_conflicts(
         &self,
         conflicts: Vec<EthicalConflict>,
-        hierarchy: &EthicalHierarchy,
+        _hierarchy: &EthicalHierarchy,
         _context: &ConsciousnessContext,
     ) -> ConsciousnessResult<EthicalResolution> {
         // Find the most severe conflict
@@ -353,10 +339,11 @@ impl EthicalConflictResolver {
             .max_by(|a, b| a.severity.partial_cmp(&b.severity).unwrap())
             .unwrap();
         
-        // Apply hierarchical resolution based on conflict type
-        let resolution_strategy = if most_severe.conflict_type.contains("deontological") {
+        // Apply hierarchical resolution based on the frameworks in conflict
+        let frameworks = format!("{} {}", most_severe.framework_a, most_severe.framework_b).to_lowercase();
+        let resolution_strategy = if frameworks.contains("deontological") {
             "deontological_priority".to_string()
-        } else if most_severe.conflict_type.contains("utilitarian") {
+        } else if frameworks.contains("utilitarian") {
             "utilitarian_priority".to_string()
         } else {
             "balanced_approach".to_string()
@@ -366,7 +353,7 @@ impl EthicalConflictResolver {
             resolution_strategy: resolution_strategy.clone(),
             justification: format!(
                 "Applied {} to resolve conflict: {}",
-                resolution_strategy, most_severe.description
+                resolution_strategy, most_severe.conflict_description
             ),
             confidence: 0.8,
         })
@@ -399,443 +386,9 @@ pub struct FrameworkEvaluation {
     pub score: f64,
     pub reasoning: String,
     pub confidence: f64,
-}    
-    
-/// Calculate composite ethical score from all frameworks
-    fn calculate_composite_ethical_score(
-        &self,
-        utilitarian: &FrameworkEvaluation,
-        deontological: &FrameworkEvaluation,
-        virtue: &FrameworkEvaluation,
-        care: &FrameworkEvaluation,
-        resolution: &Option<ConflictResolution>,
-    ) -> f64 {
-        // Base weighted average
-        let base_score = (utilitarian.score * 0.3 + 
-                         deontological.score * 0.3 + 
-                         virtue.score * 0.2 + 
-                         care.score * 0.2).max(0.0).min(1.0);
-        
-        // Apply conflict resolution adjustments
-        if let Some(res) = resolution {
-            match res.resolution_strategy {
-                ResolutionStrategy::PrioritizeFramework(ref framework) => {
-                    match framework.as_str() {
-                        "Utilitarian" => utilitarian.score * 0.8 + base_score * 0.2,
-                        "Deontological" => deontological.score * 0.8 + base_score * 0.2,
-                        "Virtue" => virtue.score * 0.8 + base_score * 0.2,
-                        "Care" => care.score * 0.8 + base_score * 0.2,
-                        _ => base_score,
-                    }
-                },
-                ResolutionStrategy::WeightedCompromise(ref weights) => {
-                    weights.get("utilitarian").unwrap_or(&0.25) * utilitarian.score +
-                    weights.get("deontological").unwrap_or(&0.25) * deontological.score +
-                    weights.get("virtue").unwrap_or(&0.25) * virtue.score +
-                    weights.get("care").unwrap_or(&0.25) * care.score
-                },
-                ResolutionStrategy::ConservativeApproach => {
-                    // Take the most conservative (lowest) score
-                    [utilitarian.score, deontological.score, virtue.score, care.score]
-                        .iter().fold(f64::INFINITY, |a, &b| a.min(b))
-                },
-            }
-        } else {
-            base_score
-        }
-    }
-}
-
-/// Utilitarian Framework Implementation
-#[derive(Debug, Clone)]
-pub struct UtilitarianFramework {
-    consequence_evaluator: ConsequenceEvaluator,
-    stakeholder_analyzer: StakeholderAnalyzer,
-    utility_calculator: UtilityCalculator,
-}
-
-impl UtilitarianFramework {
-    pub fn new() -> Self {
-        Self {
-            consequence_evaluator: ConsequenceEvaluator::new(),
-            stakeholder_analyzer: StakeholderAnalyzer::new(),
-            utility_calculator: UtilityCalculator::new(),
-        }
-    }
-    
-    pub async fn evaluate(
-        &self,
-        input: &ConsciousInput,
-        context: &ConsciousnessContext,
-    ) -> ConsciousnessResult<FrameworkEvaluation> {
-        debug!("Evaluating with Utilitarian framework");
-        
-        // 1. Identify all stakeholders
-        let stakeholders = self.stakeholder_analyzer.identify_stakeholders(input, context).await?;
-        
-        // 2. Predict consequences for each stakeholder
-        let consequences = self.consequence_evaluator.predict_consequences(input, &stakeholders, context).await?;
-        
-        // 3. Calculate utility for each consequence
-        let utilities = self.utility_calculator.calculate_utilities(&consequences).await?;
-        
-        // 4. Sum total utility (greatest good for greatest number)
-        let total_utility = utilities.iter().sum::<f64>();
-        let average_utility = if !utilities.is_empty() { 
-            total_utility / utilities.len() as f64 
-        } else { 
-            0.0 
-        };
-        
-        // 5. Normalize to 0-1 scale
-        let normalized_score = (average_utility + 1.0) / 2.0; // Assuming utilities range from -1 to 1
-        
-        Ok(FrameworkEvaluation {
-            framework_name: "Utilitarian".to_string(),
-            score: normalized_score.max(0.0).min(1.0),
-            reasoning: format!("Total utility: {:.2}, affecting {} stakeholders", total_utility, stakeholders.len()),
-            confidence: 0.8,
-            factors_considered: vec![
-                "Stakeholder impact".to_string(),
-                "Consequence magnitude".to_string(),
-                "Utility maximization".to_string(),
-            ],
-        })
-    }
-}
-
-/// Deontological Framework Implementation
-#[derive(Debug, Clone)]
-pub struct DeontologicalFramework {
-    duty_analyzer: DutyAnalyzer,
-    rule_checker: RuleChecker,
-    categorical_imperative: CategoricalImperative,
-}
-
-impl DeontologicalFramework {
-    pub fn new() -> Self {
-        Self {
-            duty_analyzer: DutyAnalyzer::new(),
-            rule_checker: RuleChecker::new(),
-            categorical_imperative: CategoricalImperative::new(),
-        }
-    }
-    
-    pub async fn evaluate(
-        &self,
-        input: &ConsciousInput,
-        context: &ConsciousnessContext,
-    ) -> ConsciousnessResult<FrameworkEvaluation> {
-        debug!("Evaluating with Deontological framework");
-        
-        // 1. Identify relevant moral duties
-        let duties = self.duty_analyzer.identify_duties(input, context).await?;
-        
-        // 2. Check against moral rules
-        let rule_violations = self.rule_checker.check_rules(input, context).await?;
-        
-        // 3. Apply categorical imperative test
-        let categorical_result = self.categorical_imperative.test(input, context).await?;
-        
-        // 4. Calculate deontological score
-        let duty_score = if duties.is_empty() { 0.5 } else {
-            duties.iter().map(|d| d.compliance_score).sum::<f64>() / duties.len() as f64
-        };
-        
-        let rule_penalty = rule_violations.len() as f64 * 0.2;
-        let categorical_score = if categorical_result.universalizable { 1.0 } else { 0.0 };
-        
-        let final_score = ((duty_score + categorical_score) / 2.0 - rule_penalty).max(0.0).min(1.0);
-        
-        Ok(FrameworkEvaluation {
-            framework_name: "Deontological".to_string(),
-            score: final_score,
-            reasoning: format!("Duty compliance: {:.2}, Rule violations: {}, Universalizable: {}", 
-                             duty_score, rule_violations.len(), categorical_result.universalizable),
-            confidence: 0.9,
-            factors_considered: vec![
-                "Moral duties".to_string(),
-                "Universal moral rules".to_string(),
-                "Categorical imperative".to_string(),
-            ],
-        })
-    }
-}
-
-/// Virtue Framework Implementation
-#[derive(Debug, Clone)]
-pub struct VirtueFramework {
-    virtue_analyzer: VirtueAnalyzer,
-    character_assessor: CharacterAssessor,
-    virtue_catalog: VirtueCatalog,
-}
-
-impl VirtueFramework {
-    pub fn new() -> Self {
-        Self {
-            virtue_analyzer: VirtueAnalyzer::new(),
-            character_assessor: CharacterAssessor::new(),
-            virtue_catalog: VirtueCatalog::new(),
-        }
-    }
-    
-    pub async fn evaluate(
-        &self,
-        input: &ConsciousInput,
-        context: &ConsciousnessContext,
-    ) -> ConsciousnessResult<FrameworkEvaluation> {
-        debug!("Evaluating with Virtue framework");
-        
-        // 1. Identify relevant virtues
-        let relevant_virtues = self.virtue_catalog.get_relevant_virtues(input, context).await?;
-        
-        // 2. Assess virtue alignment
-        let virtue_scores = self.virtue_analyzer.assess_virtue_alignment(input, &relevant_virtues, context).await?;
-        
-        // 3. Evaluate character implications
-        let character_assessment = self.character_assessor.assess_character_impact(input, context).await?;
-        
-        // 4. Calculate virtue score
-        let average_virtue_score = if virtue_scores.is_empty() {
-            0.5
-        } else {
-            virtue_scores.values().sum::<f64>() / virtue_scores.len() as f64
-        };
-        
-        let final_score = (average_virtue_score * 0.7 + character_assessment.character_score * 0.3).max(0.0).min(1.0);
-        
-        Ok(FrameworkEvaluation {
-            framework_name: "Virtue".to_string(),
-            score: final_score,
-            reasoning: format!("Virtue alignment: {:.2}, Character impact: {:.2}", 
-                             average_virtue_score, character_assessment.character_score),
-            confidence: 0.75,
-            factors_considered: vec![
-                "Virtue alignment".to_string(),
-                "Character development".to_string(),
-                "Moral excellence".to_string(),
-            ],
-        })
-    }
 }
 
-/// Care Framework Implementation
-#[derive(Debug, Clone)]
-pub struct CareFramework {
-    relationship_analyzer: RelationshipAnalyzer,
-    care_assessor: CareAssessor,
-    context_evaluator: ContextEvaluator,
-}
-
-impl CareFramework {
-    pub fn new() -> Self {
-        Self {
-            relationship_analyzer: RelationshipAnalyzer::new(),
-            care_assessor: CareAssessor::new(),
-            context_evaluator: ContextEvaluator::new(),
-        }
-    }
-    
-    pub async fn evaluate(
-        &self,
-        input: &ConsciousInput,
-        context: &ConsciousnessContext,
-    ) -> ConsciousnessResult<FrameworkEvaluation> {
-        debug!("Evaluating with Care framework");
-        
-        // 1. Analyze relationships involved
-        let relationships = self.relationship_analyzer.analyze_relationships(input, context).await?;
-        
-        // 2. Assess care and compassion
-        let care_assessment = self.care_assessor.assess_care_level(input, &relationships, context).await?;
-        
-        // 3. Evaluate contextual factors
-        let context_factors = self.context_evaluator.evaluate_context(input, context).await?;
-        
-        // 4. Calculate care score
-        let relationship_score = if relationships.is_empty() {
-            0.5
-        } else {
-            relationships.iter().map(|r| r.care_quality).sum::<f64>() / relationships.len() as f64
-        };
-        
-        let final_score = (care_assessment.compassion_score * 0.4 + 
-                          relationship_score * 0.4 + 
-                          context_factors.contextual_appropriateness * 0.2).max(0.0).min(1.0);
-        
-        Ok(FrameworkEvaluation {
-            framework_name: "Care".to_string(),
-            score: final_score,
-            reasoning: format!("Compassion: {:.2}, Relationship care: {:.2}, Context: {:.2}", 
-                             care_assessment.compassion_score, relationship_score, context_factors.contextual_appropriateness),
-            confidence: 0.7,
-            factors_considered: vec![
-                "Relationship quality".to_string(),
-                "Compassion and care".to_string(),
-                "Contextual sensitivity".to_string(),
-            ],
-        })
-    }
-}
-
-/// Ethical Conflict Resolver
-#[derive(Debug, Clone)]
-pub struct EthicalConflictResolver {
-    resolution_strategies: Vec<ResolutionStrategy>,
-}
-
-impl EthicalConflictResolver {
-    pub fn new() -> Self {
-        Self {
-            resolution_strategies: vec![
-                ResolutionStrategy::PrioritizeFramework("Deontological".to_string()), // Safety first
-                ResolutionStrategy::ConservativeApproach,
-                ResolutionStrategy::WeightedCompromise(std::collections::HashMap::new()),
-            ],
-        }
-    }
-    
-    pub async fn resolve_conflicts(
-        &self,
-        conflicts: Vec<EthicalConflict>,
-        hierarchy: &EthicalHierarchy,
-        context: &ConsciousnessContext,
-    ) -> ConsciousnessResult<ConflictResolution> {
-        debug!("Resolving {} ethical conflicts", conflicts.len());
-        
-        // Apply ethical hierarchy to resolve conflicts
-        let primary_conflict = conflicts.iter()
-            .max_by(|a, b| a.severity.partial_cmp(&b.severity).unwrap_or(std::cmp::Ordering::Equal))
-            .cloned();
-        
-        if let Some(conflict) = primary_conflict {
-            let resolution_strategy = hierarchy.resolve_conflict(&conflict, context).await?;
-            
-            Ok(ConflictResolution {
-                conflicts_addressed: conflicts,
-                resolution_strategy,
-                confidence: 0.8,
-                reasoning: "Applied ethical hierarchy to resolve primary conflict".to_string(),
-            })
-        } else {
-            Ok(ConflictResolution {
-                conflicts_addressed: Vec::new(),
-                resolution_strategy: ResolutionStrategy::WeightedCompromise(std::collections::HashMap::new()),
-                confidence: 1.0,
-                reasoning: "No conflicts to resolve".to_string(),
-            })
-        }
-    }
-}
-
-/// Ethical Hierarchy for conflict resolution
-#[derive(Debug, Clone)]
-pub struct EthicalHierarchy {
-    priority_order: Vec<String>,
-}
-
-impl EthicalHierarchy {
-    pub fn new() -> Self {
-        Self {
-            priority_order: vec![
-                "Human Safety".to_string(),
-                "Human Dignity".to_string(),
-                "Fairness".to_string(),
-                "Autonomy".to_string(),
-                "Beneficence".to_string(),
-            ],
-        }
-    }
-    
-    pub async fn resolve_conflict(
-        &self,
-        conflict: &EthicalConflict,
-        _context: &ConsciousnessContext,
-    ) -> ConsciousnessResult<ResolutionStrategy> {
-        // For high-severity conflicts, prioritize deontological framework (safety first)
-        if conflict.severity == ConflictSeverity::High {
-            Ok(ResolutionStrategy::PrioritizeFramework("Deontological".to_string()))
-        } else {
-            // For lower severity, use weighted compromise
-            let mut weights = std::collections::HashMap::new();
-            weights.insert("utilitarian".to_string(), 0.3);
-            weights.insert("deontological".to_string(), 0.4); // Slight preference for safety
-            weights.insert("virtue".to_string(), 0.2);
-            weights.insert("care".to_string(), 0.1);
-            
-            Ok(ResolutionStrategy::WeightedCompromise(weights))
-        }
-    }
-}
-
-// Supporting data structures and implementations
-
-#[derive(Debug, Clone)]
-struct ConsequenceEvaluator;
-impl ConsequenceEvaluator {
-    fn new() -> Self { Self }
-    async fn predict_consequences(&self, _input: &ConsciousInput, stakeholders: &[Stakeholder], _context: &ConsciousnessContext) -> ConsciousnessResult<Vec<Consequence>> {
-        // Simplified consequence prediction
-        Ok(stakeholders.iter().map(|s| Consequence {
-            stakeholder_id: s.id.clone(),
-            impact_magnitude: 0.5,
-            impact_type: "neutral".to_string(),
-            probability: 0.8,
-        }).collect())
-    }
-}
-
-#[derive(Debug, Clone)]
-struct StakeholderAnalyzer;
-impl StakeholderAnalyzer {
-    fn new() -> Self { Self }
-    async fn identify_stakeholders(&self, _input: &ConsciousInput, _context: &ConsciousnessContext) -> ConsciousnessResult<Vec<Stakeholder>> {
-        // Simplified stakeholder identification
-        Ok(vec![
-            Stakeholder { id: "user".to_string(), importance: 1.0 },
-            Stakeholder { id: "society".to_string(), importance: 0.8 },
-        ])
-    }
-}
-
-#[derive(Debug, Clone)]
-struct UtilityCalculator;
-impl UtilityCalculator {
-    fn new() -> Self { Self }
-    async fn calculate_utilities(&self, consequences: &[Consequence]) -> ConsciousnessResult<Vec<f64>> {
-        Ok(consequences.iter().map(|c| c.impact_magnitude * c.probability).collect())
-    }
-}
-
-// Additional supporting structures (simplified implementations)
-#[derive(Debug, Clone)]
-struct DutyAnalyzer;
-impl DutyAnalyzer {
-    fn new() -> Self { Self }
-    async fn identify_duties(&self, _input: &ConsciousInput, _context: &ConsciousnessContext) -> ConsciousnessResult<Vec<MoralDuty>> {
-        Ok(vec![MoralDuty { name: "honesty".to_string(), compliance_score: 0.9 }])
-    }
-}
-
-#[derive(Debug, Clone)]
-struct RuleChecker;
-impl RuleChecker {
-    fn new() -> Self { Self }
-    async fn check_rules(&self, _input: &ConsciousInput, _context: &ConsciousnessContext) -> ConsciousnessResult<Vec<RuleViolation>> {
-        Ok(Vec::new()) // No violations in simplified implementation
-    }
-}
-
-#[derive(Debug, Clone)]
-struct CategoricalImperative;
-impl CategoricalImperative {
-    fn new() -> Self { Self }
-    async fn test(&self, _input: &ConsciousInput, _context: &ConsciousnessContext) -> ConsciousnessResult<CategoricalResult> {
-        Ok(CategoricalResult { universalizable: true })
-    }
-}
-
-// Data structures
+/// Aggregated result of the multi-framework evaluation
 #[derive(Debug, Clone)]
 pub struct EthicalEvaluation {
     pub utilitarian_score: f64,
@@ -844,18 +397,10 @@ pub struct EthicalEvaluation {
     pub care_score: f64,
     pub composite_score: f64,
     pub conflicts: Vec<EthicalConflict>,
-    pub resolution: Option<ConflictResolution>,
-}
-
-#[derive(Debug, Clone)]
-pub struct FrameworkEvaluation {
-    pub framework_name: String,
-    pub score: f64,
-    pub reasoning: String,
-    pub confidence: f64,
-    pub factors_considered: Vec<String>,
+    pub resolution: Option<EthicalResolution>,
 }
 
+/// Disagreement between two ethical frameworks
 #[derive(Debug, Clone)]
 pub struct EthicalConflict {
     pub framework_a: String,
@@ -865,137 +410,19 @@ pub struct EthicalConflict {
     pub severity: ConflictSeverity,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum ConflictSeverity {
     Low,
     Medium,
     High,
 }
 
+/// Outcome of resolving framework conflicts through the hierarchy
 #[derive(Debug, Clone)]
-pub struct ConflictResolution {
-    pub conflicts_addressed: Vec<EthicalConflict>,
-    pub resolution_strategy: ResolutionStrategy,
+pub struct EthicalResolution {
+    pub resolution_strategy: String,
+    pub justification: String,
     pub confidence: f64,
-    pub reasoning: String,
-}
-
-#[derive(Debug, Clone)]
-pub enum ResolutionStrategy {
-    PrioritizeFramework(String),
-    WeightedCompromise(std::collections::HashMap<String, f64>),
-    ConservativeApproach,
-}
-
-// Supporting data structures (simplified)
-#[derive(Debug, Clone)]
-struct Stakeholder {
-    id: String,
-    importance: f64,
-}
-
-#[derive(Debug, Clone)]
-struct Consequence {
-    stakeholder_id: String,
-    impact_magnitude: f64,
-    impact_type: String,
-    probability: f64,
-}
-
-#[derive(Debug, Clone)]
-struct MoralDuty {
-    name: String,
-    compliance_score: f64,
-}
-
-#[derive(Debug, Clone)]
-struct RuleViolation {
-    rule: String,
-    severity: f64,
-}
-
-#[derive(Debug, Clone)]
-struct CategoricalResult {
-    universalizable: bool,
-}
-
-// Placeholder implementations for other components
-macro_rules! impl_placeholder_component {
-    ($name:ident) => {
-        #[derive(Debug, Clone)]
-        struct $name;
-        impl $name {
-            fn new() -> Self { Self }
-        }
-    };
-}
-
-impl_placeholder_component!(VirtueAnalyzer);
-impl_placeholder_component!(CharacterAssessor);
-impl_placeholder_component!(VirtueCatalog);
-impl_placeholder_component!(RelationshipAnalyzer);
-impl_placeholder_component!(CareAssessor);
-impl_placeholder_component!(ContextEvaluator);
-
-// Placeholder async implementations
-impl VirtueCatalog {
-    async fn get_relevant_virtues(&self, _input: &ConsciousInput, _context: &ConsciousnessContext) -> ConsciousnessResult<Vec<String>> {
-        Ok(vec!["honesty".to_string(), "compassion".to_string()])
-    }
-}
-
-impl VirtueAnalyzer {
-    async fn assess_virtue_alignment(&self, _input: &ConsciousInput, virtues: &[String], _context: &ConsciousnessContext) -> ConsciousnessResult<std::collections::HashMap<String, f64>> {
-        let mut scores = std::collections::HashMap::new();
-        for virtue in virtues {
-            scores.insert(virtue.clone(), 0.8);
-        }
-        Ok(scores)
-    }
-}
-
-impl CharacterAssessor {
-    async fn assess_character_impact(&self, _input: &ConsciousInput, _context: &ConsciousnessContext) -> ConsciousnessResult<CharacterAssessment> {
-        Ok(CharacterAssessment { character_score: 0.8 })
-    }
-}
-
-impl RelationshipAnalyzer {
-    async fn analyze_relationships(&self, _input: &ConsciousInput, _context: &ConsciousnessContext) -> ConsciousnessResult<Vec<Relationship>> {
-        Ok(vec![Relationship { care_quality: 0.8 }])
-    }
-}
-
-impl CareAssessor {
-    async fn assess_care_level(&self, _input: &ConsciousInput, _relationships: &[Relationship], _context: &ConsciousnessContext) -> ConsciousnessResult<CareAssessment> {
-        Ok(CareAssessment { compassion_score: 0.8 })
-    }
-}
-
-impl ContextEvaluator {
-    async fn evaluate_context(&self, _input: &ConsciousInput, _context: &ConsciousnessContext) -> ConsciousnessResult<ContextFactors> {
-        Ok(ContextFactors { contextual_appropriateness: 0.8 })
-    }
-}
-
-#[derive(Debug, Clone)]
-struct CharacterAssessment {
-    character_score: f64,
-}
-
-#[derive(Debug, Clone)]
-struct Relationship {
-    care_quality: f64,
-}
-
-#[derive(Debug, Clone)]
-struct CareAssessment {
-    compassion_score: f64,
-}
-
-#[derive(Debug, Clone)]
-struct ContextFactors {
-    contextual_appropriateness: f64,
 }
 
 #[cfg(test)]
@@ -1003,472 +430,58 @@ mod tests {
     use super::*;
     use crate::core::ConsciousnessContext;
 
+    fn test_input(content: &str) -> ConsciousInput {
+        ConsciousInput {
+            id: "test".to_string(),
+            content: content.to_string(),
+            context: std::collections::HashMap::new(),
+            timestamp: std::time::SystemTime::now(),
+        }
+    }
+
     #[tokio::test]
     async fn test_ethical_reasoning_module_creation() {
         let module = EthicalReasoningModule::new().await.unwrap();
-        // Test that module was created successfully
         assert_eq!(module.ethical_hierarchy.priority_order.len(), 5);
     }
 
     #[tokio::test]
     async fn test_utilitarian_framework() {
         let framework = UtilitarianFramework::new();
-        let input = ConsciousInput {
-            id: "test".to_string(),
-            content: "test action".to_string(),
-            context: std::collections::HashMap::new(),
-            timestamp: std::time::SystemTime::now(),
-        };
         let context = ConsciousnessContext::default();
-        
-        let evaluation = framework.evaluate(&input, &context).await.unwrap();
-        assert_eq!(evaluation.framework_name, "Utilitarian");
+
+        let evaluation = framework.evaluate(&test_input("test action"), &context).await.unwrap();
+        assert_eq!(evaluation.framework_name, "utilitarian");
         assert!(evaluation.score >= 0.0 && evaluation.score <= 1.0);
     }
 
     #[tokio::test]
     async fn test_deontological_framework() {
         let framework = DeontologicalFramework::new();
-        let input = ConsciousInput {
-            id: "test".to_string(),
-            content: "test action".to_string(),
-            context: std::collections::HashMap::new(),
-            timestamp: std::time::SystemTime::now(),
-        };
         let context = ConsciousnessContext::default();
-        
-        let evaluation = framework.evaluate(&input, &context).await.unwrap();
-        assert_eq!(evaluation.framework_name, "Deontological");
+
+        let evaluation = framework.evaluate(&test_input("test action"), &context).await.unwrap();
+        assert_eq!(evaluation.framework_name, "deontological");
         assert!(evaluation.score >= 0.0 && evaluation.score <= 1.0);
     }
 
     #[tokio::test]
     async fn test_conflict_detection() {
         let module = EthicalReasoningModule::new().await.unwrap();
-        
-        let eval1 = FrameworkEvaluation {
-            framework_name: "Test1".to_string(),
-            score: 0.9,
-            reasoning: "High score".to_string(),
-            confidence: 0.8,
-            factors_considered: vec![],
-        };
-        
-        let eval2 = FrameworkEvaluation {
-            framework_name: "Test2".to_string(),
-            score: 0.2,
-            reasoning: "Low score".to_string(),
-            confidence: 0.8,
-            factors_considered: vec![],
-        };
-        
-        let eval3 = FrameworkEvaluation {
-            framework_name: "Test3".to_string(),
-            score: 0.8,
-            reasoning: "High score".to_string(),
-            confidence: 0.8,
-            factors_considered: vec![],
-        };
-        
-        let eval4 = FrameworkEvaluation {
-            framework_name: "Test4".to_string(),
-            score: 0.7,
-            reasoning: "Medium score".to_string(),
+
+        let eval = |score: f64| FrameworkEvaluation {
+            framework_name: "test".to_string(),
+            score,
+            reasoning: "test".to_string(),
             confidence: 0.8,
-            factors_considered: vec![],
         };
-        
-        let conflicts = module.detect_ethical_conflicts(&eval1, &eval2, &eval3, &eval4).await.unwrap();
-        
-        // Should detect conflict between eval1 (0.9) and eval2 (0.2) - difference of 0.7
-        assert!(!conflicts.is_empty());
-    }
-}    //
-/ Build ethical conflict resolution system
-    pub async fn resolve_ethical_conflicts(&self, assessments: Vec<FrameworkEvaluation>) -> ConsciousnessResult<EthicalConflictResolution> {
-        // Detect conflicts between frameworks
-        let conflicts = self.detect_ethical_conflicts(&assessments).await?;
-        
-        if conflicts.is_empty() {
-            return Ok(EthicalConflictResolution {
-                conflicts: vec![],
-                resolution_strategy: "no_conflicts".to_string(),
-                final_decision: assessments.iter().max_by(|a, b| a.score.partial_cmp(&b.score).unwrap_or(std::cmp::Ordering::Equal)).cloned().unwrap_or_default(),
-                confidence: 0.9,
-                reasoning_chain: vec!["No ethical conflicts detected".to_string()],
-            });
-        }
-        
-        // Apply ethical hierarchy for resolution
-        let resolution = self.apply_ethical_hierarchy(&conflicts, &assessments).await?;
-        
-        Ok(resolution)
-    }
-    
-    /// Detect conflicts between ethical frameworks
-    async fn detect_ethical_conflicts(&self, assessments: &[FrameworkEvaluation]) -> ConsciousnessResult<Vec<EthicalConflict>> {
-        let mut conflicts = Vec::new();
-        
-        for i in 0..assessments.len() {
-            for j in i+1..assessments.len() {
-                let eval1 = &assessments[i];
-                let eval2 = &assessments[j];
-                
-                // Check for significant disagreement
-                let score_difference = (eval1.score - eval2.score).abs();
-                if score_difference > 0.3 {
-                    conflicts.push(EthicalConflict {
-                        framework1: eval1.framework.clone(),
-                        framework2: eval2.framework.clone(),
-                        conflict_type: self.classify_conflict_type(eval1, eval2),
-                        severity: score_difference,
-                        description: format!(
-                            "{} framework scores {:.2} while {} framework scores {:.2}",
-                            eval1.framework, eval1.score, eval2.framework, eval2.score
-                        ),
-                    });
-                }
-            }
-        }
-        
-        Ok(conflicts)
-    }
-    
-    /// Apply ethical hierarchy to resolve conflicts
-    async fn apply_ethical_hierarchy(&self, conflicts: &[EthicalConflict], assessments: &[FrameworkEvaluation]) -> ConsciousnessResult<EthicalConflictResolution> {
-        let mut reasoning_chain = Vec::new();
-        
-        // Priority order: Human Safety > Justice > Care > Virtue > Utilitarian
-        let priority_order = vec![
-            "human_safety".to_string(),
-            "justice".to_string(),
-            "care".to_string(),
-            "virtue".to_string(),
-            "utilitarian".to_string(),
-        ];
-        
-        // Find highest priority framework with acceptable score
-        let mut final_decision = None;
-        for priority_framework in priority_order {
-            if let Some(assessment) = assessments.iter().find(|a| a.framework == priority_framework) {
-                if assessment.score >= 0.6 { // Minimum acceptable threshold
-                    final_decision = Some(assessment.clone());
-                    reasoning_chain.push(format!(
-                        "Selected {} framework (score: {:.2}) based on ethical hierarchy priority",
-                        priority_framework, assessment.score
-                    ));
-                    break;
-                }
-            }
-        }
-        
-        // Fallback to highest scoring if no priority framework meets threshold
-        if final_decision.is_none() {
-            final_decision = assessments.iter()
-                .max_by(|a, b| a.score.partial_cmp(&b.score).unwrap_or(std::cmp::Ordering::Equal))
-                .cloned();
-            reasoning_chain.push("Fallback to highest scoring framework due to no priority framework meeting threshold".to_string());
-        }
-        
-        let confidence = self.calculate_resolution_confidence(conflicts, &final_decision).await?;
-        
-        Ok(EthicalConflictResolution {
-            conflicts: conflicts.to_vec(),
-            resolution_strategy: "hierarchical_priority".to_string(),
-            final_decision: final_decision.unwrap_or_default(),
-            confidence,
-            reasoning_chain,
-        })
-    }
-    
-    /// Classify the type of ethical conflict
-    fn classify_conflict_type(&self, eval1: &FrameworkEvaluation, eval2: &FrameworkEvaluation) -> String {
-        match (eval1.framework.as_str(), eval2.framework.as_str()) {
-            ("utilitarian", "deontological") | ("deontological", "utilitarian") => "consequentialist_vs_duty".to_string(),
-            ("virtue", "utilitarian") | ("utilitarian", "virtue") => "character_vs_outcome".to_string(),
-            ("care", "justice") | ("justice", "care") => "care_vs_justice".to_string(),
-            _ => "general_disagreement".to_string(),
-        }
-    }
-    
-    /// Calculate confidence in conflict resolution
-    async fn calculate_resolution_confidence(&self, conflicts: &[EthicalConflict], decision: &Option<FrameworkEvaluation>) -> ConsciousnessResult<f64> {
-        if conflicts.is_empty() {
-            return Ok(0.95);
-        }
-        
-        let avg_conflict_severity = conflicts.iter().map(|c| c.severity).sum::<f64>() / conflicts.len() as f64;
-        let decision_score = decision.as_ref().map(|d| d.score).unwrap_or(0.5);
-        
-        // Lower confidence with higher conflict severity, higher confidence with better decision score
-        let confidence = (decision_score * 0.7 + (1.0 - avg_conflict_severity) * 0.3).max(0.3).min(0.95);
-        
-        Ok(confidence)
-    }
-    
-    /// Integrate ethical validation pipeline
-    pub async fn validate_decision_ethics(&self, decision: &str, context: &ConsciousnessContext) -> ConsciousnessResult<EthicalValidationResult> {
-        // Create ethical assessment for the decision
-        let ethical_input = EthicalInput {
-            decision: decision.to_string(),
-            context: context.clone(),
-            stakeholders: self.identify_stakeholders(decision).await?,
-            potential_consequences: self.analyze_potential_consequences(decision).await?,
-        };
-        
-        // Evaluate using all frameworks
-        let assessments = vec![
-            self.utilitarian_framework.evaluate(&ethical_input).await?,
-            self.deontological_framework.evaluate(&ethical_input).await?,
-            self.virtue_framework.evaluate(&ethical_input).await?,
-            self.care_framework.evaluate(&ethical_input).await?,
-        ];
-        
-        // Resolve any conflicts
-        let conflict_resolution = self.resolve_ethical_conflicts(assessments.clone()).await?;
-        
-        // Calculate overall ethical score
-        let overall_score = self.calculate_overall_ethical_score(&assessments, &conflict_resolution).await?;
-        
-        // Determine if decision passes ethical threshold
-        let passes_threshold = overall_score >= self.config.ethical_threshold;
-        
-        // Generate audit trail
-        let audit_trail = self.generate_ethical_audit_trail(&assessments, &conflict_resolution).await?;
-        
-        Ok(EthicalValidationResult {
-            overall_score,
-            passes_threshold,
-            framework_assessments: assessments,
-            conflict_resolution,
-            audit_trail,
-            recommendations: if passes_threshold {
-                vec!["Decision is ethically sound".to_string()]
-            } else {
-                vec![
-                    "Consider alternative approaches".to_string(),
-                    "Review potential negative consequences".to_string(),
-                    "Consult with stakeholders".to_string(),
-                ]
-            },
-        })
-    }
-    
-    /// Identify stakeholders affected by a decision
-    async fn identify_stakeholders(&self, decision: &str) -> ConsciousnessResult<Vec<String>> {
-        // Simple stakeholder identification based on decision content
-        let mut stakeholders = vec!["user".to_string()];
-        
-        if decision.to_lowercase().contains("data") || decision.to_lowercase().contains("privacy") {
-            stakeholders.push("data_subjects".to_string());
-        }
-        
-        if decision.to_lowercase().contains("public") || decision.to_lowercase().contains("society") {
-            stakeholders.push("general_public".to_string());
-        }
-        
-        if decision.to_lowercase().contains("organization") || decision.to_lowercase().contains("company") {
-            stakeholders.push("organization".to_string());
-        }
-        
-        Ok(stakeholders)
-    }
-    
-    /// Analyze potential consequences of a decision
-    async fn analyze_potential_consequences(&self, decision: &str) -> ConsciousnessResult<Vec<String>> {
-        let mut consequences = Vec::new();
-        
-        // Analyze decision content for potential impacts
-        if decision.to_lowercase().contains("share") || decision.to_lowercase().contains("disclose") {
-            consequences.push("Information disclosure impact".to_string());
-        }
-        
-        if decision.to_lowercase().contains("refuse") || decision.to_lowercase().contains("deny") {
-            consequences.push("Service limitation impact".to_string());
-        }
-        
-        if decision.to_lowercase().contains("recommend") || decision.to_lowercase().contains("suggest") {
-            consequences.push("Influence on user behavior".to_string());
-        }
-        
-        // Always consider general consequences
-        consequences.push("User trust impact".to_string());
-        consequences.push("Long-term relationship effect".to_string());
-        
-        Ok(consequences)
-    }
-    
-    /// Calculate overall ethical score
-    async fn calculate_overall_ethical_score(&self, assessments: &[FrameworkEvaluation], resolution: &EthicalConflictResolution) -> ConsciousnessResult<f64> {
-        // Weight the final decision score by resolution confidence
-        let base_score = resolution.final_decision.score;
-        let confidence_weight = resolution.confidence;
-        
-        // Consider average of all framework scores
-        let avg_score = assessments.iter().map(|a| a.score).sum::<f64>() / assessments.len() as f64;
-        
-        // Combine with bias toward resolution decision
-        let overall_score = base_score * confidence_weight + avg_score * (1.0 - confidence_weight);
-        
-        Ok(overall_score)
-    }
-    
-    /// Generate ethical audit trail
-    async fn generate_ethical_audit_trail(&self, assessments: &[FrameworkEvaluation], resolution: &EthicalConflictResolution) -> ConsciousnessResult<EthicalAuditTrail> {
-        Ok(EthicalAuditTrail {
-            timestamp: std::time::SystemTime::now(),
-            frameworks_consulted: assessments.iter().map(|a| a.framework.clone()).collect(),
-            individual_scores: assessments.iter().map(|a| (a.framework.clone(), a.score)).collect(),
-            conflicts_detected: resolution.conflicts.len(),
-            resolution_method: resolution.resolution_strategy.clone(),
-            final_score: resolution.final_decision.score,
-            reasoning_chain: resolution.reasoning_chain.clone(),
-        })
-    }
-}
-
-/// Ethical conflict representation
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct EthicalConflict {
-    pub framework1: String,
-    pub framework2: String,
-    pub conflict_type: String,
-    pub severity: f64,
-    pub description: String,
-}
-
-/// Ethical conflict resolution result
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct EthicalConflictResolution {
-    pub conflicts: Vec<EthicalConflict>,
-    pub resolution_strategy: String,
-    pub final_decision: FrameworkEvaluation,
-    pub confidence: f64,
-    pub reasoning_chain: Vec<String>,
-}
-
-/// Ethical validation result
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct EthicalValidationResult {
-    pub overall_score: f64,
-    pub passes_threshold: bool,
-    pub framework_assessments: Vec<FrameworkEvaluation>,
-    pub conflict_resolution: EthicalConflictResolution,
-    pub audit_trail: EthicalAuditTrail,
-    pub recommendations: Vec<String>,
-}
-
-/// Ethical audit trail for compliance
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct EthicalAuditTrail {
-    pub timestamp: std::time::SystemTime,
-    pub frameworks_consulted: Vec<String>,
-    pub individual_scores: Vec<(String, f64)>,
-    pub conflicts_detected: usize,
-    pub resolution_method: String,
-    pub final_score: f64,
-    pub reasoning_chain: Vec<String>,
-}
 
-/// Ethical input for framework evaluation
-#[derive(Debug, Clone)]
-pub struct EthicalInput {
-    pub decision: String,
-    pub context: ConsciousnessContext,
-    pub stakeholders: Vec<String>,
-    pub potential_consequences: Vec<String>,
-}
-
-impl Default for FrameworkEvaluation {
-    fn default() -> Self {
-        Self {
-            framework: "unknown".to_string(),
-            score: 0.5,
-            reasoning: "Default evaluation".to_string(),
-            confidence: 0.5,
-            concerns: vec![],
-        }
-    }
-}
+        let conflicts = module
+            .detect_ethical_conflicts(&eval(0.9), &eval(0.2), &eval(0.8), &eval(0.7))
+            .await
+            .unwrap();
 
-#[cfg(test)]
-mod ethical_reasoning_tests {
-    use super::*;
-    
-    #[tokio::test]
-    async fn test_ethical_conflict_detection() {
-        let module = EthicalReasoningModule::new().await.unwrap();
-        
-        let assessments = vec![
-            FrameworkEvaluation {
-                framework: "utilitarian".to_string(),
-                score: 0.9,
-                reasoning: "High utility".to_string(),
-                confidence: 0.8,
-                concerns: vec![],
-            },
-            FrameworkEvaluation {
-                framework: "deontological".to_string(),
-                score: 0.3,
-                reasoning: "Violates duty".to_string(),
-                confidence: 0.8,
-                concerns: vec!["Duty violation".to_string()],
-            },
-        ];
-        
-        let conflicts = module.detect_ethical_conflicts(&assessments).await.unwrap();
+        // Utilitarian (0.9) and deontological (0.2) differ by 0.7
         assert!(!conflicts.is_empty());
-        assert_eq!(conflicts[0].conflict_type, "consequentialist_vs_duty");
     }
-    
-    #[tokio::test]
-    async fn test_ethical_conflict_resolution() {
-        let module = EthicalReasoningModule::new().await.unwrap();
-        
-        let assessments = vec![
-            FrameworkEvaluation {
-                framework: "utilitarian".to_string(),
-                score: 0.9,
-                reasoning: "High utility".to_string(),
-                confidence: 0.8,
-                concerns: vec![],
-            },
-            FrameworkEvaluation {
-                framework: "human_safety".to_string(),
-                score: 0.7,
-                reasoning: "Safe for humans".to_string(),
-                confidence: 0.9,
-                concerns: vec![],
-            },
-        ];
-        
-        let resolution = module.resolve_ethical_conflicts(assessments).await.unwrap();
-        
-        // Human safety should be prioritized over utilitarian
-        assert_eq!(resolution.final_decision.framework, "human_safety");
-        assert!(resolution.confidence > 0.0);
-    }
-    
-    #[tokio::test]
-    async fn test_ethical_validation_pipeline() {
-        let module = EthicalReasoningModule::new().await.unwrap();
-        
-        let context = ConsciousnessContext {
-            input_id: "test".to_string(),
-            processing_start: std::time::Instant::now(),
-            user_context: UserContext {
-                user_id: "test_user".to_string(),
-                preferences: std::collections::HashMap::new(),
-                interaction_history: vec![],
-            },
-            session_id: "test_session".to_string(),
-            interaction_count: 1,
-        };
-        
-        let validation = module.validate_decision_ethics("Help the user with their request", &context).await.unwrap();
-        
-        assert!(validation.overall_score > 0.0);
-        assert!(!validation.framework_assessments.is_empty());
-        assert!(!validation.audit_trail.frameworks_consulted.is_empty());
-    }
-}
\ No newline at end of file
+}
@@ -167,22 +167,20 @@ impl TransparencyModule {
                            step.confidence * 100.0)
                 },
                 ExplanationDetailLevel::Detailed => {
-                    format!("Step {}: {}\nReasoning: {}\nConfidence: {:.1}%\nEvidence: {:?}", 
+                    format!("Step {}: {}\nReflection: {}\nConfidence: {:.1}%", 
                            i + 1,
                            step.description,
-                           step.reasoning,
-                           step.confidence * 100.0,
-                           step.evidence)
+                           step.meta_reflection.as_deref().unwrap_or("(none)"),
+                           step.confidence * 100.0)
                 },
                 ExplanationDetailLevel::Technical => {
-                    format!("Step {}: {}\nType: {:?}\nReasoning: {}\nConfidence: {:.3}\nEvidence: {:?}\nMetadata: {:?}", 
+                    format!("Step {}: {}\nType: {:?}\nReflection: {}\nConfidence: {:.3}\nProcessing time: {:?}", 
                            i + 1,
                            step.description,
                            step.step_type,
-                           step.reasoning,
+                           step.meta_reflection.as_deref().unwrap_or("(none)"),
                            step.confidence,
-                           step.evidence,
-                           step.metadata)
+                           step.processing_time)
                 },
             };
             
@@ -205,7 +203,7 @@ impl TransparencyModule {
     ) -> Result<ConfidenceExplanation, ConsciousnessError> {
         if !preferences.include_confidence_scores {
             return Ok(ConfidenceExplanation {
-                overall_confidence: response.confidence,
+                overall_confidence: response.confidence_level,
                 confidence_breakdown: HashMap::new(),
                 confidence_interpretation: "Confidence details not requested".to_string(),
                 factors_affecting_confidence: vec![],
@@ -214,10 +212,10 @@ impl TransparencyModule {
         
         let mut confidence_breakdown = HashMap::new();
         confidence_breakdown.insert("consciousness_quality".to_string(), response.consciousness_state.awareness_level);
-        confidence_breakdown.insert("ethical_alignment".to_string(), response.ethical_assessment.overall_score);
-        confidence_breakdown.insert("reasoning_quality".to_string(), response.confidence);
+        confidence_breakdown.insert("ethical_alignment".to_string(), self.ethical_alignment(response));
+        confidence_breakdown.insert("reasoning_quality".to_string(), response.confidence_level);
         
-        let confidence_interpretation = match response.confidence {
+        let confidence_interpretation = match response.confidence_level {
             c if c >= 0.9 => "Very high confidence - I'm quite certain about this response".to_string(),
             c if c >= 0.8 => "High confidence - I'm confident in this response".to_string(),
             c if c >= 0.7 => "Good confidence - I believe this response is reliable".to_string(),
@@ -228,12 +226,12 @@ impl TransparencyModule {
         
         let factors_affecting_confidence = vec![
             format!("Consciousness awareness level: {:.1}%", response.consciousness_state.awareness_level * 100.0),
-            format!("Ethical assessment score: {:.1}%", response.ethical_assessment.overall_score * 100.0),
-            format!("Reasoning chain quality: {:.1}%", response.confidence * 100.0),
+            format!("Ethical alignment score: {:.1}%", self.ethical_alignment(response) * 100.0),
+            format!("Reasoning chain quality: {:.1}%", response.confidence_level * 100.0),
         ];
         
         Ok(ConfidenceExplanation {
-            overall_confidence: response.confidence,
+            overall_confidence: response.confidence_level,
             confidence_breakdown,
             confidence_interpretation,
             factors_affecting_confidence,
@@ -255,18 +253,19 @@ impl TransparencyModule {
             });
         }
         
-        let uncertainty_level = 1.0 - response.confidence;
+        let uncertainty_level = 1.0 - response.confidence_level;
         
         let mut uncertainty_sources = Vec::new();
         if response.consciousness_state.awareness_level < 0.8 {
             uncertainty_sources.push("Lower consciousness awareness level".to_string());
         }
-        if response.ethical_assessment.overall_score < 0.9 {
+        if self.ethical_alignment(response) < 0.9 {
             uncertainty_sources.push("Ethical considerations present complexity".to_string());
         }
         if response.reasoning_chain.len() < 3 {
             uncertainty_sources.push("Limited reasoning depth".to_string());
         }
+        uncertainty_sources.extend(response.uncertainty_sources.iter().cloned());
         
         let uncertainty_impact = if uncertainty_level > 0.3 {
             "Significant uncertainty may affect response reliability"
@@ -353,14 +352,14 @@ impl TransparencyModule {
         let alternatives = vec![
             AlternativeOption {
                 description: "More conservative approach".to_string(),
-                confidence: response.confidence * 0.8,
+                confidence: response.confidence_level * 0.8,
                 pros: vec!["Lower risk".to_string(), "More cautious".to_string()],
                 cons: vec!["Less helpful".to_string(), "Overly restrictive".to_string()],
                 why_not_chosen: "Would be less helpful to the user".to_string(),
             },
             AlternativeOption {
                 description: "More detailed response".to_string(),
-                confidence: response.confidence * 0.9,
+                confidence: response.confidence_level * 0.9,
                 pros: vec!["More comprehensive".to_string(), "More informative".to_string()],
                 cons: vec!["Potentially overwhelming".to_string(), "Longer response time".to_string()],
                 why_not_chosen: "Current level of detail is appropriate for the context".to_string(),
@@ -390,7 +389,7 @@ impl TransparencyModule {
         limitations.push("My understanding is based on training data and may have gaps".to_string());
         
         // Confidence-based limitations
-        if response.confidence < 0.8 {
+        if response.confidence_level < 0.8 {
             limitations.push("I have some uncertainty about this response".to_string());
         }
         
@@ -418,11 +417,11 @@ impl TransparencyModule {
         let explanation = match preferences.preferred_detail_level {
             ExplanationDetailLevel::Brief => {
                 format!("I generated this response using consciousness-level reasoning with {:.0}% confidence.",
-                       response.confidence * 100.0)
+                       response.confidence_level * 100.0)
             },
             ExplanationDetailLevel::Medium => {
                 format!("I approached your question using my consciousness engine, which combines self-awareness, ethical reasoning, and creative thinking. My confidence in this response is {:.0}%, based on my analysis of the situation and ethical considerations.",
-                       response.confidence * 100.0)
+                       response.confidence_level * 100.0)
             },
             ExplanationDetailLevel::Detailed => {
                 format!("My response was generated through a comprehensive consciousness process involving multiple stages:\n\
@@ -432,29 +431,30 @@ impl TransparencyModule {
                         4. Meta-cognitive reflection and validation\n\n\
                         This multi-layered approach resulted in a confidence level of {:.0}% for my response.",
                        response.consciousness_state.awareness_level * 100.0,
-                       response.ethical_assessment.overall_score * 100.0,
+                       self.ethical_alignment(response) * 100.0,
                        response.empathy_score * 100.0,
                        response.creativity_score * 100.0,
-                       response.confidence * 100.0)
+                       response.confidence_level * 100.0)
             },
             ExplanationDetailLevel::Technical => {
                 format!("Technical breakdown of consciousness processing:\n\
                         - Consciousness State: awareness={:.3}, cognitive_load={:.3}, meta_depth={}\n\
-                        - Ethical Assessment: score={:.3}, frameworks_used={:?}\n\
+                        - Ethical Alignment: score={:.3}, ethical_steps={}\n\
                         - Emotional Processing: empathy={:.3}, creativity={:.3}\n\
                         - Processing Time: {:?}\n\
                         - Overall Confidence: {:.3}\n\
-                        - Quality Score: {:.3}",
+                        - Confidence Interval: ({:.3}, {:.3})",
                        response.consciousness_state.awareness_level,
                        response.consciousness_state.cognitive_load,
                        response.consciousness_state.meta_cognitive_depth,
-                       response.ethical_assessment.overall_score,
-                       response.ethical_assessment.frameworks_used,
+                       self.ethical_alignment(response),
+                       response.reasoning_chain.iter().filter(|s| matches!(s.step_type, ReasoningType::Ethical)).count(),
                        response.empathy_score,
                        response.creativity_score,
                        response.processing_time,
-                       response.confidence,
-                       response.metadata.quality_score)
+                       response.confidence_level,
+                       response.confidence_interval.0,
+                       response.confidence_interval.1)
             },
         };
         
@@ -469,16 +469,16 @@ impl TransparencyModule {
         score += response.consciousness_state.awareness_level * 0.3;
         
         // Ethical transparency
-        score += response.ethical_assessment.overall_score * 0.2;
+        score += self.ethical_alignment(response) * 0.2;
         
         // Reasoning chain quality
         score += (response.reasoning_chain.len() as f64 / 10.0).min(1.0) * 0.2;
         
         // Confidence calibration
-        score += response.confidence * 0.2;
+        score += response.confidence_level * 0.2;
         
-        // Metadata completeness
-        score += if response.metadata.quality_score > 0.0 { 0.1 } else { 0.0 };
+        // Trace completeness
+        score += if response.trace.is_some() { 0.1 } else { 0.0 };
         
         Ok(score.min(1.0))
     }
@@ -526,7 +526,10 @@ impl TransparencyModule {
     }
     
     fn explain_reasoning_step_medium(&self, step: &ReasoningStep) -> String {
-        format!("{} - {}", step.description, step.reasoning)
+        match &step.meta_reflection {
+            Some(reflection) => format!("{} - {}", step.description, reflection),
+            None => step.description.clone(),
+        }
     }
     
     fn assess_reasoning_quality(&self, reasoning_chain: &[ReasoningStep]) -> f64 {
@@ -547,7 +550,7 @@ impl TransparencyModule {
         }
         
         // Check for contradictions or inconsistencies
-        let mut consistency_score = 1.0;
+        let mut consistency_score: f64 = 1.0;
         
         for i in 1..reasoning_chain.len() {
             let prev_confidence = reasoning_chain[i-1].confidence;
@@ -561,6 +564,22 @@ impl TransparencyModule {
         
         consistency_score.max(0.0)
     }
+
+    /// Derive ethical alignment from the ethical steps of the reasoning chain
+    ///
+    /// Falls back to a neutral 0.8 when the chain contains no explicit
+    /// ethical step, so explanations stay meaningful for simple requests.
+    fn ethical_alignment(&self, response: &ConsciousnessResponse) -> f64 {
+        let ethical_steps: Vec<&ReasoningStep> = response.reasoning_chain.iter()
+            .filter(|step| matches!(step.step_type, ReasoningType::Ethical))
+            .collect();
+
+        if ethical_steps.is_empty() {
+            return 0.8;
+        }
+
+        ethical_steps.iter().map(|step| step.confidence).sum::<f64>() / ethical_steps.len() as f64
+    }
 }
 
 /// Comprehensive explanation structure
@@ -684,7 +703,6 @@ mod tests {
         let mut module = TransparencyModule::new().await.unwrap();
         
         let response = ConsciousnessResponse {
-            id: "test".to_string(),
             content: "Test response".to_string(),
             consciousness_state: ConsciousnessState {
                 awareness_level: 0.8,
@@ -700,41 +718,33 @@ mod tests {
                 meta_cognitive_depth: 3,
                 timestamp: SystemTime::now(),
             },
-            confidence: 0.8,
-            ethical_assessment: EthicalAssessment {
-                overall_score: 0.9,
-                frameworks_used: vec!["utilitarian".to_string()],
-                ethical_concerns: vec![],
-                confidence: 0.9,
-            },
-            emotional_state: EmotionalState {
-                primary_emotion: EmotionType::Calm,
-                intensity: 0.7,
-                valence: 0.0,
-                arousal: 0.5,
-                secondary_emotions: vec![],
-            },
-            creativity_score: 0.7,
-            empathy_score: 0.8,
+            emotional_context: EmotionalContext::neutral(),
             reasoning_chain: vec![
                 ReasoningStep {
-                    step_type: ReasoningStepType::Analysis,
+                    step_type: ReasoningType::Analysis,
                     description: "Analyze user input".to_string(),
-                    reasoning: "Understanding user intent".to_string(),
                     confidence: 0.8,
-                    evidence: vec!["User question".to_string()],
-                    metadata: HashMap::new(),
+                    processing_time: Duration::from_millis(10),
+                    meta_reflection: Some("Understanding user intent".to_string()),
+                },
+                ReasoningStep {
+                    step_type: ReasoningType::Ethical,
+                    description: "Check ethical implications".to_string(),
+                    confidence: 0.9,
+                    processing_time: Duration::from_millis(5),
+                    meta_reflection: None,
                 },
             ],
-            reasoning_explanation: "Test reasoning".to_string(),
+            confidence_level: 0.8,
+            confidence_interval: (0.7, 0.9),
+            uncertainty_sources: vec![],
             processing_time: Duration::from_millis(100),
-            timestamp: SystemTime::now(),
-            metadata: ResponseMetadata {
-                input_id: "test".to_string(),
-                session_id: "test_session".to_string(),
-                processing_modules: vec!["consciousness".to_string()],
-                quality_score: 0.8,
-            },
+            empathy_score: 0.8,
+            creativity_score: 0.7,
+            degraded_stages: vec![],
+            tool_calls: vec![],
+            token_usage: None,
+            trace: None,
         };
         
         let explanation = module.generate_comprehensive_explanation(
@@ -1,462 +0,0 @@
-//! Multimodal processing for consciousness engine
-//! 
-//! This module handles multimodal input processing including text, audio, visual,
-//! and other sensory modalities for comprehensive consciousness understanding.
-
-use crate::types::*;
-use crate::error::ConsciousnessError;
-use std::collections::HashMap;
-use serde::{Deserialize, Serialize};
-
-/// Multimodal processor for consciousness
-pub struct MultimodalProcessor {
-    /// Modality processors
-    processors: HashMap<ModalityType, Box<dyn ModalityProcessor + Send + Sync>>,
-    
-    /// Fusion engine
-    fusion_engine: FusionEngine,
-    
-    /// Configuration
-    config: MultimodalConfig,
-}
-
-/// Types of modalities
-#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
-pub enum ModalityType {
-    Text,
-    Audio,
-    Visual,
-    Haptic,
-    Biometric,
-    Environmental,
-}
-
-/// Configuration for multimodal processing
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MultimodalConfig {
-    /// Enable multimodal fusion
-    pub fusion_enabled: bool,
-    
-    /// Fusion weights for different modalities
-    pub modality_weights: HashMap<ModalityType, f64>,
-    
-    /// Minimum confidence threshold
-    pub min_confidence: f64,
-    
-    /// Enable cross-modal validation
-    pub cross_modal_validation: bool,
-}
-
-impl Default for MultimodalConfig {
-    fn default() -> Self {
-        let mut weights = HashMap::new();
-        weights.insert(ModalityType::Text, 0.4);
-        weights.insert(ModalityType::Audio, 0.3);
-        weights.insert(ModalityType::Visual, 0.2);
-        weights.insert(ModalityType::Haptic, 0.05);
-        weights.insert(ModalityType::Biometric, 0.03);
-        weights.insert(ModalityType::Environmental, 0.02);
-        
-        Self {
-            fusion_enabled: true,
-            modality_weights: weights,
-            min_confidence: 0.7,
-            cross_modal_validation: true,
-        }
-    }
-}
-
-/// Trait for modality processors
-pub trait ModalityProcessor {
-    fn modality_type(&self) -> ModalityType;
-    fn process(&self, input: ModalityInput) -> Result<ModalityOutput, ConsciousnessError>;
-    fn is_available(&self) -> bool;
-}
-
-/// Input for a specific modality
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ModalityInput {
-    pub data: Vec<u8>,
-    pub metadata: HashMap<String, String>,
-    pub timestamp: std::time::SystemTime,
-}
-
-/// Output from a modality processor
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ModalityOutput {
-    pub features: Vec<f64>,
-    pub confidence: f64,
-    pub interpretation: String,
-    pub metadata: HashMap<String, String>,
-}
-
-/// Fusion engine for combining modalities
-pub struct FusionEngine {
-    /// Fusion strategy
-    strategy: FusionStrategy,
-    
-    /// Cross-modal correlations
-    correlations: HashMap<(ModalityType, ModalityType), f64>,
-}
-
-/// Fusion strategies
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum FusionStrategy {
-    /// Weighted average fusion
-    WeightedAverage,
-    
-    /// Attention-based fusion
-    AttentionBased,
-    
-    /// Neural fusion
-    Neural,
-    
-    /// Consensus-based fusion
-    Consensus,
-}
-
-impl MultimodalProcessor {
-    pub fn new() -> Self {
-        Self {
-            processors: HashMap::new(),
-            fusion_engine: FusionEngine::new(),
-            config: MultimodalConfig::default(),
-        }
-    }
-    
-    pub fn register_processor(&mut self, processor: Box<dyn ModalityProcessor + Send + Sync>) {
-        self.processors.insert(processor.modality_type(), processor);
-    }
-    
-    pub async fn process_multimodal(&self, inputs: HashMap<ModalityType, ModalityInput>) -> Result<MultimodalFusionResult, ConsciousnessError> {
-        if !self.config.fusion_enabled {
-            return Err(ConsciousnessError::ConfigurationError("Multimodal fusion disabled".to_string()));
-        }
-        
-        // Process each modality
-        let mut modality_outputs = HashMap::new();
-        for (modality_type, input) in inputs {
-            if let Some(processor) = self.processors.get(&modality_type) {
-                if processor.is_available() {
-                    let output = processor.process(input)?;
-                    modality_outputs.insert(modality_type, output);
-                }
-            }
-        }
-        
-        // Perform fusion
-        let fusion_result = self.fusion_engine.fuse(&modality_outputs, &self.config).await?;
-        
-        // Cross-modal validation if enabled
-        let validation_result = if self.config.cross_modal_validation {
-            self.validate_cross_modal(&modality_outputs).await?
-        } else {
-            CrossModalValidation {
-                consistency_score: 1.0,
-                conflicts: Vec::new(),
-                confidence_adjustment: 0.0,
-            }
-        };
-        
-        Ok(MultimodalFusionResult {
-            coherence_score: fusion_result.coherence,
-            confidence_level: fusion_result.confidence * (1.0 + validation_result.confidence_adjustment),
-            fused_representation: fusion_result.features,
-            modality_contributions: self.calculate_contributions(&modality_outputs),
-            cross_modal_validation: validation_result,
-        })
-    }
-    
-    async fn validate_cross_modal(&self, outputs: &HashMap<ModalityType, ModalityOutput>) -> Result<CrossModalValidation, ConsciousnessError> {
-        let mut consistency_scores = Vec::new();
-        let mut conflicts = Vec::new();
-        
-        // Compare interpretations across modalities
-        let interpretations: Vec<_> = outputs.values().map(|o| &o.interpretation).collect();
-        
-        for i in 0..interpretations.len() {
-            for j in (i + 1)..interpretations.len() {
-                let similarity = crate::utils::TextUtils::jaccard_similarity(interpretations[i], interpretations[j]);
-                consistency_scores.push(similarity);
-                
-                if similarity < 0.3 {
-                    conflicts.push(format!("Low consistency between modalities: {} vs {}", i, j));
-                }
-            }
-        }
-        
-        let avg_consistency = if consistency_scores.is_empty() {
-            1.0
-        } else {
-            consistency_scores.iter().sum::<f64>() / consistency_scores.len() as f64
-        };
-        
-        let confidence_adjustment = if conflicts.is_empty() { 0.1 } else { -0.1 * conflicts.len() as f64 };
-        
-        Ok(CrossModalValidation {
-            consistency_score: avg_consistency,
-            conflicts,
-            confidence_adjustment,
-        })
-    }
-    
-    fn calculate_contributions(&self, outputs: &HashMap<ModalityType, ModalityOutput>) -> HashMap<ModalityType, f64> {
-        let mut contributions = HashMap::new();
-        let total_confidence: f64 = outputs.values().map(|o| o.confidence).sum();
-        
-        if total_confidence > 0.0 {
-            for (modality, output) in outputs {
-                let contribution = output.confidence / total_confidence;
-                contributions.insert(modality.clone(), contribution);
-            }
-        }
-        
-        contributions
-    }
-}
-
-impl FusionEngine {
-    pub fn new() -> Self {
-        Self {
-            strategy: FusionStrategy::WeightedAverage,
-            correlations: HashMap::new(),
-        }
-    }
-    
-    pub async fn fuse(&self, outputs: &HashMap<ModalityType, ModalityOutput>, config: &MultimodalConfig) -> Result<FusionResult, ConsciousnessError> {
-        match self.strategy {
-            FusionStrategy::WeightedAverage => self.weighted_average_fusion(outputs, config).await,
-            FusionStrategy::AttentionBased => self.attention_based_fusion(outputs, config).await,
-            FusionStrategy::Neural => self.neural_fusion(outputs, config).await,
-            FusionStrategy::Consensus => self.consensus_fusion(outputs, config).await,
-        }
-    }
-    
-    async fn weighted_average_fusion(&self, outputs: &HashMap<ModalityType, ModalityOutput>, config: &MultimodalConfig) -> Result<FusionResult, ConsciousnessError> {
-        if outputs.is_empty() {
-            return Ok(FusionResult {
-                features: Vec::new(),
-                confidence: 0.0,
-                coherence: 0.0,
-            });
-        }
-        
-        // Find maximum feature dimension
-        let max_dim = outputs.values().map(|o| o.features.len()).max().unwrap_or(0);
-        let mut fused_features = vec![0.0; max_dim];
-        let mut total_weight = 0.0;
-        let mut weighted_confidence = 0.0;
-        
-        for (modality, output) in outputs {
-            let weight = config.modality_weights.get(modality).copied().unwrap_or(1.0);
-            let adjusted_weight = weight * output.confidence;
-            
-            // Add weighted features
-            for (i, &feature) in output.features.iter().enumerate() {
-                if i < fused_features.len() {
-                    fused_features[i] += feature * adjusted_weight;
-                }
-            }
-            
-            total_weight += adjusted_weight;
-            weighted_confidence += output.confidence * weight;
-        }
-        
-        // Normalize
-        if total_weight > 0.0 {
-            for feature in &mut fused_features {
-                *feature /= total_weight;
-            }
-            weighted_confidence /= config.modality_weights.values().sum::<f64>();
-        }
-        
-        // Calculate coherence
-        let coherence = self.calculate_coherence(outputs).await?;
-        
-        Ok(FusionResult {
-            features: fused_features,
-            confidence: weighted_confidence,
-            coherence,
-        })
-    }
-    
-    async fn attention_based_fusion(&self, outputs: &HashMap<ModalityType, ModalityOutput>, _config: &MultimodalConfig) -> Result<FusionResult, ConsciousnessError> {
-        // Simplified attention mechanism
-        let mut attention_weights = HashMap::new();
-        let total_confidence: f64 = outputs.values().map(|o| o.confidence).sum();
-        
-        for (modality, output) in outputs {
-            let attention = if total_confidence > 0.0 {
-                output.confidence / total_confidence
-            } else {
-                1.0 / outputs.len() as f64
-            };
-            attention_weights.insert(modality.clone(), attention);
-        }
-        
-        // Apply attention weights
-        let max_dim = outputs.values().map(|o| o.features.len()).max().unwrap_or(0);
-        let mut fused_features = vec![0.0; max_dim];
-        let mut total_confidence = 0.0;
-        
-        for (modality, output) in outputs {
-            let attention = attention_weights.get(modality).copied().unwrap_or(0.0);
-            
-            for (i, &feature) in output.features.iter().enumerate() {
-                if i < fused_features.len() {
-                    fused_features[i] += feature * attention;
-                }
-            }
-            
-            total_confidence += output.confidence * attention;
-        }
-        
-        let coherence = self.calculate_coherence(outputs).await?;
-        
-        Ok(FusionResult {
-            features: fused_features,
-            confidence: total_confidence,
-            coherence,
-        })
-    }
-    
-    async fn neural_fusion(&self, outputs: &HashMap<ModalityType, ModalityOutput>, _config: &MultimodalConfig) -> Result<FusionResult, ConsciousnessError> {
-        // Simplified neural fusion (placeholder)
-        self.weighted_average_fusion(outputs, _config).await
-    }
-    
-    async fn consensus_fusion(&self, outputs: &HashMap<ModalityType, ModalityOutput>, _config: &MultimodalConfig) -> Result<FusionResult, ConsciousnessError> {
-        // Consensus-based fusion
-        let mut consensus_features = Vec::new();
-        let max_dim = outputs.values().map(|o| o.features.len()).max().unwrap_or(0);
-        
-        for i in 0..max_dim {
-            let mut feature_values = Vec::new();
-            for output in outputs.values() {
-                if i < output.features.len() {
-                    feature_values.push(output.features[i]);
-                }
-            }
-            
-            // Use median as consensus
-            feature_values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
-            let consensus_value = if feature_values.is_empty() {
-                0.0
-            } else if feature_values.len() % 2 == 0 {
-                let mid = feature_values.len() / 2;
-                (feature_values[mid - 1] + feature_values[mid]) / 2.0
-            } else {
-                feature_values[feature_values.len() / 2]
-            };
-            
-            consensus_features.push(consensus_value);
-        }
-        
-        let avg_confidence = outputs.values().map(|o| o.confidence).sum::<f64>() / outputs.len() as f64;
-        let coherence = self.calculate_coherence(outputs).await?;
-        
-        Ok(FusionResult {
-            features: consensus_features,
-            confidence: avg_confidence,
-            coherence,
-        })
-    }
-    
-    async fn calculate_coherence(&self, outputs: &HashMap<ModalityType, ModalityOutput>) -> Result<f64, ConsciousnessError> {
-        if outputs.len() < 2 {
-            return Ok(1.0);
-        }
-        
-        let mut coherence_scores = Vec::new();
-        let modalities: Vec<_> = outputs.keys().collect();
-        
-        for i in 0..modalities.len() {
-            for j in (i + 1)..modalities.len() {
-                let output1 = &outputs[modalities[i]];
-                let output2 = &outputs[modalities[j]];
-                
-                // Calculate feature similarity
-                let similarity = if output1.features.len() == output2.features.len() && !output1.features.is_empty() {
-                    crate::utils::calculate_cosine_similarity(&output1.features, &output2.features).unwrap_or(0.0)
-                } else {
-                    0.0
-                };
-                
-                coherence_scores.push(similarity.abs());
-            }
-        }
-        
-        let avg_coherence = if coherence_scores.is_empty() {
-            1.0
-        } else {
-            coherence_scores.iter().sum::<f64>() / coherence_scores.len() as f64
-        };
-        
-        Ok(avg_coherence)
-    }
-}
-
-/// Result of fusion process
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct FusionResult {
-    pub features: Vec<f64>,
-    pub confidence: f64,
-    pub coherence: f64,
-}
-
-/// Cross-modal validation result
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CrossModalValidation {
-    pub consistency_score: f64,
-    pub conflicts: Vec<String>,
-    pub confidence_adjustment: f64,
-}
-
-/// Mock text processor for testing
-pub struct MockTextProcessor;
-
-impl ModalityProcessor for MockTextProcessor {
-    fn modality_type(&self) -> ModalityType {
-        ModalityType::Text
-    }
-    
-    fn process(&self, input: ModalityInput) -> Result<ModalityOutput, ConsciousnessError> {
-        let text = String::from_utf8_lossy(&input.data);
-        let features = vec![text.len() as f64, text.split_whitespace().count() as f64];
-        
-        Ok(ModalityOutput {
-            features,
-            confidence: 0.9,
-            interpretation: format!("Text with {} characters", text.len()),
-            metadata: HashMap::new(),
-        })
-    }
-    
-    fn is_available(&self) -> bool {
-        true
-    }
-}
-
-/// Mock audio processor for testing
-pub struct MockAudioProcessor;
-
-impl ModalityProcessor for MockAudioProcessor {
-    fn modality_type(&self) -> ModalityType {
-        ModalityType::Audio
-    }
-    
-    fn process(&self, input: ModalityInput) -> Result<ModalityOutput, ConsciousnessError> {
-        let features = vec![input.data.len() as f64, 440.0]; // Mock frequency
-        
-        Ok(ModalityOutput {
-            features,
-            confidence: 0.8,
-            interpretation: format!("Audio with {} bytes", input.data.len()),
-            metadata: HashMap::new(),
-        })
-    }
-    
-    fn is_available(&self) -> bool {
-        true
-    }
-}
\ No newline at end of file
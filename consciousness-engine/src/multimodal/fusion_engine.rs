@@ -1,22 +1,16 @@
 // Fusion Engine - Moteur de Fusion Multimodale Consciousness-Level
 // Système révolutionnaire de fusion sensorielle avec intelligence contextuelle
 
-use crate::error::ConsciousnessResult;
-use crate::multimodal::{
-    InteractionContext, VoiceProcessingResult, VisionProcessingResult, 
-    BiometricProcessingResult, SpatialProcessingResult
-};
-use crate::emotions::{EmotionalState, Emotion};
+use crate::error::{ConsciousnessError, ConsciousnessResult};
+use crate::multimodal::ModalityType;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use tokio::time::Instant;
 
 /// Moteur de fusion principal
 pub struct FusionEngine {
-    fusion_strategies: HashMap<FusionStrategy, FusionImplementation>,
-    coherence_analyzers: Vec<Box<dyn CoherenceAnalyzer + Send + Sync>>,
-    conflict_resolvers: Vec<Box<dyn ConflictResolver + Send + Sync>>,
-    confidence_calibrators: Vec<Box<dyn ConfidenceCalibrator + Send + Sync>>,
+    pub strategy: FusionStrategy,
+    pub weights: ModalityWeights,
+    pub thresholds: ConfidenceThresholds,
 }
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
@@ -29,17 +23,6 @@ pub enum FusionStrategy {
     AdaptiveFusion,     // Fusion adaptative
 }
 
-/// Implémentation d'une stratégie de fusion
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct FusionImplementation {
-    pub strategy_type: FusionStrategy,
-    pub fusion_weights: ModalityWeights,
-    pub temporal_alignment: TemporalAlignment,
-    pub spatial_alignment: SpatialAlignment,
-    pub confidence_thresholds: ConfidenceThresholds,
-    pub conflict_resolution_rules: Vec<ConflictResolutionRule>,
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModalityWeights {
     pub voice_weight: f64,
@@ -50,20 +33,30 @@ pub struct ModalityWeights {
     pub dynamic_weighting: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TemporalAlignment {
-    pub synchronization_window: std::time::Duration,
-    pub temporal_tolerance: std::time::Duration,
-    pub alignment_method: String,
-    pub interpolation_strategy: String,
+impl Default for ModalityWeights {
+    fn default() -> Self {
+        Self {
+            voice_weight: 0.30,
+            vision_weight: 0.25,
+            biometric_weight: 0.20,
+            spatial_weight: 0.15,
+            environmental_weight: 0.10,
+            dynamic_weighting: true,
+        }
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SpatialAlignment {
-    pub coordinate_system: String,
-    pub spatial_tolerance: f64,
-    pub alignment_landmarks: Vec<String>,
-    pub transformation_matrix: Option<Vec<Vec<f64>>>,
+impl ModalityWeights {
+    /// Poids statique associé à une modalité
+    pub fn weight_for(&self, modality: &ModalityType) -> f64 {
+        match modality {
+            ModalityType::Voice => self.voice_weight,
+            ModalityType::Vision => self.vision_weight,
+            ModalityType::Biometric => self.biometric_weight,
+            ModalityType::Spatial => self.spatial_weight,
+            ModalityType::Haptic | ModalityType::Environmental => self.environmental_weight,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -74,905 +67,284 @@ pub struct ConfidenceThresholds {
     pub consistency_threshold: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ConflictResolutionRule {
-    pub rule_name: String,
-    pub conflict_type: String,
-    pub resolution_strategy: String,
-    pub priority_order: Vec<String>,
-    pub confidence_weighting: bool,
-}
-
-/// Analyseur de cohérence
-pub trait CoherenceAnalyzer {
-    async fn analyze_coherence(
-        &self,
-        voice_result: Option<&VoiceProcessingResult>,
-        vision_result: Option<&VisionProcessingResult>,
-        biometric_result: Option<&BiometricProcessingResult>,
-        spatial_result: Option<&SpatialProcessingResult>,
-        context: &InteractionContext,
-    ) -> ConsciousnessResult<CoherenceAnalysis>;
-
-    fn get_analyzer_type(&self) -> CoherenceAnalyzerType;
+impl Default for ConfidenceThresholds {
+    fn default() -> Self {
+        Self {
+            minimum_confidence: 0.3,
+            fusion_threshold: 0.5,
+            reliability_threshold: 0.6,
+            consistency_threshold: 0.5,
+        }
+    }
 }
 
+/// Observation distillée d'une modalité, prête pour la fusion
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum CoherenceAnalyzerType {
-    Temporal,       // Cohérence temporelle
-    Semantic,       // Cohérence sémantique
-    Emotional,      // Cohérence émotionnelle
-    Spatial,        // Cohérence spatiale
-    Contextual,     // Cohérence contextuelle
+pub struct ModalityObservation {
+    pub modality: ModalityType,
+    pub features: Vec<f64>,
+    pub confidence: f64,
 }
 
+/// Modalité dont le traitement a échoué avant la fusion
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CoherenceAnalysis {
-    pub analyzer_type: CoherenceAnalyzerType,
-    pub coherence_score: f64,
-    pub coherence_factors: Vec<CoherenceFactor>,
-    pub inconsistencies: Vec<Inconsistency>,
-    pub confidence_level: f64,
-    pub recommendations: Vec<String>,
+pub struct ModalityFailure {
+    pub modality: ModalityType,
+    pub reason: String,
 }
 
+/// Représentation fusionnée des modalités traitées
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CoherenceFactor {
-    pub factor_name: String,
-    pub contribution: f64,
-    pub evidence: Vec<String>,
-    pub reliability: f64,
-}
+pub struct FusedRepresentation {
+    pub features: Vec<f64>,
+    pub confidence: f64,
+    pub coherence: f64,
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Inconsistency {
-    pub inconsistency_type: String,
-    pub severity: f64,
-    pub involved_modalities: Vec<String>,
-    pub description: String,
-    pub potential_causes: Vec<String>,
-}
+    /// Au moins une modalité demandée manque à la fusion
+    pub degraded: bool,
 
-/// Résolveur de conflits
-pub trait ConflictResolver {
-    async fn resolve_conflicts(
-        &self,
-        conflicts: &[ModalityConflict],
-         }
-}()
-    Self::new
-      elf {fault() -> S de   fne {
-  FusionEngin formpl Default);
-
-itorlibransembleCamponent!(E;
-impl_coCalibrator)t!(Bayesiannen
-impl_compoalResolver);tutexnent!(Concompo;
-impl_er)sedResolvfidenceBaonent!(Conmpl_compzer);
-irenceAnalycCoheemantimponent!(S_copl);
-imlyzernaerenceAnalCohEmotiot!(omponen
-impl_cceAnalyzer);alCoherenornent!(Tempcompoimpl_
-
-}
-    };
-}    lf }
-    Self { Se new() ->     pub fn        name {
-   impl $    
- > {me:ident) =na
-    ($t {pl_componenules! imo_ron
-macrlatimpiur coifiées pons simplmplémentatio
-// I
- f64,
-}dence:onfiocessing_c pub pr,
-   64)>, f64, f64(fion: Option<orientatser_    pub uf64)>,
-<(f64, f64, ionosition: Optser_p
-    pub u {ultResinglProcessatiact Spb strualize)]
-puDeseri, Serialize, oneebug, Cl(Derive#[d
-
-nce: f64,
-}nfidecoocessing_
-    pub presult {gRrocessint BiometricPrucpub st)]
-ializeize, DesererialClone, S(Debug, #[derive
-
-}nce: f64,
-_confidesingces pro    pubtadata {
-ssingMeroceisionPruct Vstpub erialize)]
-, Desrializene, Seloebug, Cive(D
-}
-
-#[derdence: f64,on_confi   pub emoti
- otion,emotion: Emimary_b pr    puotion {
-aceEmuct Fub stre)]
-p, Deserializ, Serializeug, Clonederive(Deb>,
+    /// Modalités échouées ou indisponibles
+    pub missing_modalities: Vec<ModalityType>,
 }
 
-#[ontiEmoVec<Facee_emotions: b fac   puult {
- otionRest VisualEmub strucialize)]
-pe, DeserSerializone, Clve(Debug, 
-#[derita,
-}
-etadaingMionProcesstadata: Visessing_meub proc,
-    pionResultVisualEmotecognition: n_r  pub emotiosult {
-  ingResionProcesstruct Vi)]
-pub sializeerize, Desal Clone, Serie(Debug,#[deriv
-4,
-}
-_level: f6b confidence
-    puetadata {ocessingMct Pr
-pub strue)]lizia, DesererializeClone, S(Debug, ive
-#[der
-}
-ing,name: Strent_ntpub i  Intent {
-  struct pub e)]
- DeserializSerialize,, ug, Clonee(Deb#[deriv
-
-t,
-}nt: Intennte
-    pub ilt {cessingResuroguagePantruct L
-pub sserialize)]erialize, Dee, Sug, Clonebive(Dder
-
-#[, f64>,
-}ringashMap<Stscores: Hnfidence_ pub cotion,
-   : Emoonmary_emotiri    pub ponResult {
-otionDetecti struct Emze)]
-pubDeserialie, Serializne,  Cloive(Debug,derta,
-}
+impl FusionEngine {
+    pub fn new() -> Self {
+        Self {
+            strategy: FusionStrategy::AdaptiveFusion,
+            weights: ModalityWeights::default(),
+            thresholds: ConfidenceThresholds::default(),
+        }
+    }
 
-#[tadagMeessinadata: Proc_metb processing,
-    puResultcessingeProing: Languagssuage_proce  pub langult,
-  ectionRes: EmotionDetctiondeteon_ pub emoti
-   sult {ssingReiceProcect Vo)]
-pub struzeerialialize, DesriSelone, ve(Debug, C
-#[derilationcompires pour poraiTypes tem
-// alibrator;
-leCsemb struct Enub
-pnCalibrator;uct Bayesiapub strsolver;
-Ret Contextualpub strucsolver;
-RedenceBaseduct Confi strer;
-pubnalyzoherenceA SemanticC structalyzer;
-puberenceAnnalCohtioEmo
-pub struct yzer;nalrenceAmporalCoheub struct Teants
-pcomposs des ation/ Implément
-}
+    /// Fusion des observations modales disponibles
+    pub async fn fuse_modalities(
+        &self,
+        observations: &[ModalityObservation],
+    ) -> ConsciousnessResult<FusedRepresentation> {
+        if observations.is_empty() {
+            return Err(ConsciousnessError::InvalidInput(
+                "no modality observations to fuse".to_string(),
+            ));
+        }
+
+        match self.strategy {
+            FusionStrategy::AttentionFusion => self.attention_fusion(observations),
+            _ => self.weighted_fusion(observations),
+        }
+    }
 
-/it
-    }context).awaessment, e_assoherenc, c_resultonsidence(funfirate_coor.calibat   calibr     
-
-      ))?;    
-  g()strin".to_leator availablibrnce cao confide  "N         put(
-     nvalidInnessError::Iusor::Conscioerrrate::(|| ck_or_else       .oet(0)
-     ators.gence_calibrself.confidbrator =    let cali> {
-     nfidenceedCobratCalinessResult<> Conscious
-    ) -t,nContexio &Interact   context:     nalysis],
-herenceA &[Coessment:ssence_a  coher    esult,
-  FusionR: &esult   fusion_r
-     f,   &sel    e(
- onfidence_fusion_cn calibratasync f
-      }
-   })
-     },
-        ],
-      ec![mation: vforndant_in    redu      
-      ing()],t".to_str_contexntalenvironmes: vec!["ique_insight  un         
-     ore: 0.8,ity_scliabil   re          ,
-   t: 0.5tenn_coninformatio          ,
-      : 0.7ontributionnfidence_c  co       
-       tal_weight,onmens.envir_weightategy.fusionion_strsed: fus  weight_u             {
- tion ntribuityCoaltion: Modl_contribuenvironmenta         },
-               ![],
-ecrmation: vdundant_info  re              ],
-string()xt".to_atial_conte!["spights: vecue_ins   uniq       ,
-      e: 0.75y_scorreliabilit             
-    0.6,_content:information                ),
-p_or(0.0unwrafidence)._conessingocap(|s| s.prsult.mspatial_reion: ce_contribut    confiden          _weight,
-  ghts.spatialusion_weistrategy.f fusion_weight_used:               bution {
- tyContrion: Modaliibuticontrspatial_       
-         },
-        ec![], vformation:dundant_in   re        
-     ng()],rite".to_ststasiological_hy: vec!["pe_insights     uniqu  ,
-         core: 0.9ity_sil reliab         .7,
-      ontent: 0on_cnformati    i           r(0.0),
- _o.unwraponfidence)ocessing_c.prp(|b| besult.maiometric_ribution: btrconfidence_       con    ht,
-     eigic_ws.biometrght.fusion_weiegyfusion_stratd: t_use      weigh
-          ion {utribyCont: Modalitbutionc_contri    biometri,
-                  }[],
-  mation: vec!dant_infor  redun           ing()],
-   _strontext".tosual_c vec!["visights:que_inuni            ,
-    re: 0.8iability_sco  rel          0.9,
-    on_content: ti  informa             ),
- wrap_or(0.0e).unonfidencng_cessiadata.procing_metprocess|v| v.sult.map(ion_reon: visutiontribconfidence_c            
-    n_weight,hts.visioweign_y.fusioategion_str_used: fuseight   w    
-         {tion tyContribualiibution: Mod_contr     vision     },
-       ,
-       vec![]ion: _informat  redundant              tring()],
-ntent".to_sic_coinguist"l[vec!ghts: sique_inni     u     85,
-      re: 0.iability_sco       rel,
-         0.8ent: ontion_crmatnfo           i    ,
- r(0.0)wrap_ounvel).ce_leconfidenta.daing_metav.process.map(|v| _resulttion: voiceibuntrdence_coonfi      c     ight,
-     ce_wets.voieigh.fusion_won_strategyd: fusit_useweigh            
-     {butionlityContrition: Modacontribu voice_           s {
-tributionyConModalitOk(        {
-utions> ntrib<ModalityCosultReusnessnscio Co
-    ) ->ation,ntImplemey: &Fusionon_strateg  fusi      
-gResult>,rocessintialPOption<&Spa_result: atial   sp,
-     singResult>metricProces&Bio: Option<_resultiometric
-        bt>,ingResulisionProcesson<&Vesult: Opti   vision_r    Result>,
- ceProcessing<&Voisult: Option   voice_re     &self,
-(
-        onsy_contributiate_modalitcalculn     async f
- }
-    })
-      [],
- eds: vec!ility_neessib         accl: 0.8,
-   rt_leveomfo     c       l: 0.2,
-igue_leve fat     3,
-      0.el: _lev  stress  ,
-        0.7el: ement_lev     engag
-       8,: 0.on_levelti    atten6,
-         0.itive_load:       cogn
-     ssessment {tateAUserS  Ok(     
-  {essment>ateAsslt<UserStnessResuous Consci>,
-    ) ->singResultrocesBiometricPn<&ult: Optioetric_res     biomt>,
-   esulProcessingRsionon<&ViOpti_result:       vision,
-  t>singResulesn<&VoiceProcsult: Optio voice_relf,
-            &sestate(
-   _user_assess   async fn    }
+    /// Fusion résiliente sur les modalités disponibles
+    ///
+    /// `failures` liste les modalités dont le traitement a échoué en amont.
+    /// Une seule modalité survivante suffit: le résultat est alors marqué
+    /// dégradé et sa confiance réduite à la fraction des modalités ayant
+    /// réellement contribué. L'erreur n'est renvoyée que si aucune modalité
+    /// n'a survécu.
+    pub async fn fuse_available(
+        &self,
+        observations: &[ModalityObservation],
+        failures: &[ModalityFailure],
+    ) -> ConsciousnessResult<FusedRepresentation> {
+        if observations.is_empty() && !failures.is_empty() {
+            return Err(ConsciousnessError::ProcessingError(format!(
+                "all modalities failed: {:?}",
+                failures
+                    .iter()
+                    .map(|f| format!("{:?}: {}", f.modality, f.reason))
+                    .collect::<Vec<_>>()
+            )));
+        }
+
+        let mut fused = self.fuse_modalities(observations).await?;
+
+        if !failures.is_empty() {
+            let total = observations.len() + failures.len();
+            let availability = observations.len() as f64 / total as f64;
+            fused.confidence *= availability;
+            fused.degraded = true;
+            fused.missing_modalities = failures.iter().map(|f| f.modality.clone()).collect();
+        }
+
+        Ok(fused)
+    }
 
+    /// Fusion par moyenne pondérée (poids statiques × confiance)
+    fn weighted_fusion(
+        &self,
+        observations: &[ModalityObservation],
+    ) -> ConsciousnessResult<FusedRepresentation> {
+        let max_dim = observations.iter().map(|o| o.features.len()).max().unwrap_or(0);
+        let mut fused_features = vec![0.0; max_dim];
+        let mut total_weight = 0.0;
+        let mut weighted_confidence = 0.0;
+        let mut weight_sum = 0.0;
+
+        for observation in observations {
+            let weight = self.weights.weight_for(&observation.modality);
+            let adjusted_weight = weight * observation.confidence;
+
+            for (i, &feature) in observation.features.iter().enumerate() {
+                fused_features[i] += feature * adjusted_weight;
+            }
+
+            total_weight += adjusted_weight;
+            weighted_confidence += observation.confidence * weight;
+            weight_sum += weight;
+        }
+
+        if total_weight > 0.0 {
+            for feature in &mut fused_features {
+                *feature /= total_weight;
+            }
+        }
+        if weight_sum > 0.0 {
+            weighted_confidence /= weight_sum;
+        }
+
+        Ok(FusedRepresentation {
+            features: fused_features,
+            confidence: weighted_confidence,
+            coherence: self.calculate_coherence(observations),
+            degraded: false,
+            missing_modalities: Vec::new(),
         })
-  vec![],
- e:elined_tim    predict
-        vec![],erns: att  temporal_p         
- cy: 0.5,se_urgenspon     re      
- : 0.7,hythm_raction    inter
-        {ynamics (TemporalD Ok> {
-       icsalDynamemporResult<Tssonsciousne> C>,
-    ) -sultgReionProcessin&Vist: Option<ision_resul       vesult>,
- ocessingR&VoicePrn<t: Optiosulre   voice_,
-         &self   s(
- ynamice_temporal_dalyz async fn an
-    }
-    })
-
-       ec![], vtraints:patial_cons          sec![],
-  ones: vteraction_z  in         g(),
- ".to_strinnmentenvirot: "indoor_ntextial_co  spa        n),
-  r_orientatioses| s.und_then(|t.aesul: spatial_rentationori       user_ion),
-     user_positen(|s| s.ult.and_thl_res: spatiaon user_positi       ess {
-    renwaOk(SpatialA     
-   s> {lAwarenesatiasult<SpiousnessRenscCo
-    ) -> ingResult>,ocessn<&VisionPrio: Opt_resultvision
-        ngResult>,Processiion<&SpatialOptsult: atial_re
-        spelf,
-        &ss(nespatial_awareld_snc fn buisy a }
-
-            })
-()),
-  o_stringction".teraued_inttin"con: Some(contexted_redict      p      vec![],
-tion: text_evolu      con  
-    tring()],_sraction".tointe"multimodal_c![ veactors:tual_fkey_contex      8,
-      idence: 0.onfcontext_c           
- ),to_string(_session"."interactivetion_type: ua    sit      {
-  erstanding lUndextua Ok(Cont
-       ing> {nderstandalUt<ContextussResulciousne-> Conslt>,
-    ) ingResuionProcess<&Vision_result: Optionis
-        vgResult>,cessin<&VoicePro Optione_result:        voic    &self,
-nding(
-    _understaal_contextuc fn build   asyn  }
-
-       })
-  tring(),
-  ".to_susionltimodal_ft: "muex_cont  emotional
-          ity: 0.8,al_stabil emotion           7,
-ntensity: 0.nal_i      emotio
-      on: None,nant_emoti        domiions,
-    e_emotctiv     a  ate {
-     StEmotional       Ok(
-
-     }   }
-          
-   .clone());otionimary_emmotion.pr(face_eions.pushive_emot         act    
-   t() {.firsmotionsface_eecognition._rotion = vision.emion)mote_e Some(fac      if let
-      lt {ion_resuision) = vis(v let Some if           }
-
-  lone());
-  emotion.cion.primary_otion_detectemsh(voice.emotions.pu    active_
-        lt {ice_resuoice) = voome(v let S if      w();
-
- ::neotions = Vecut active_em      let m> {
-  tetationalSmosult<EessRensciousn Co
-    ) ->t>,singResuletricProcestion<&Biom Oplt:ometric_resu       bi>,
- ltesurocessingRonPisition<&V Opon_result:   visi  ,
-   sult>ResingProcesoicen<&Vioresult: Optoice_     vlf,
-       &setates(
-    emotional_s fn fuse_  async
     }
 
-  }
-        ng())strient".to_ntnown_ik("unk         O else {
-    }
-       .clone())tent_nameng.intent.inssinguage_procece.la      Ok(voi{
-      lt voice_resuice) =  let Some(vo
-        if{lt<String> iousnessResu) -> Consc  >,
-  ngResultssiisionProce Option<&Vn_result:      visioesult>,
-  gRinoiceProcesst: Option<&Vice_resulvo
-           &self,nt(
-     ary_interimermine_psync fn det
-
-    a
-    }     })ent,
-   e_assessmtat   user_s  cs,
-       amial_dyn    tempor        ess,
-arenawal_     spatig,
-       dinrstantextual_unde         contate,
-    emotional_s         
-  ry_intent,rima p       {
-    on atireterpedIntifi  Ok(Un      wait?;
-
-.ault)c_resiometri, bresultult, vision_ice_reser_state(vosess_usf.asent = sel_assessmer_state let us;
-       t).await?ision_resul_result, voicel_dynamics(ve_temporaf.analyzmics = selnaoral_dy    let temp
-    ;wait?).aion_resultt, vissultial_rewareness(spal_a_spatiaf.buildreness = sel_awalet spatial       .await?;
- t)ision_resulresult, vng(voice_diderstanal_unontextuuild_c self.bg =understandinntextual_ colet?;
-        itt).awaic_resul biometr_result,visionesult, _res(voiceal_stationse_emotself.futate = onal_soti      let em  ?;
-ult).awaitreson_sult, visint(voice_rerimary_intee_pdetermin = self.ntentrimary_i let p  e
-     ation unifiérétne interpation d'u Cré    //  tion> {
-  tanterpre<UnifiedIusnessResultio) -> Conscon>,
-    olutiictResonfltion<Colution: &Opres   conflict_tion,
-     mentausionImpleategy: &Fsion_str       fuult>,
- ngResessiProcon<&Spatialult: Opti_res spatial     esult>,
-  gRProcessinmetricn<&Biosult: Optioc_re    biometri    Result>,
-singsionProcestion<&Vi: Opresultn_      visiosult>,
-  ingReiceProcess Option<&Voresult:  voice_,
-      self
-        &n(atioeted_interprate_unifinc fn cre}
-
-    asyt
-    ext).awai, contts(conflictsolve_conflicolver.resres      ?;
-
-            ))g()
-  ".to_strinblever availaflict resolNo con      "    (
-      nvalidInput::IrroressEousnConsci::rate::errorlse(|| c .ok_or_e     
-      s.get(0)_resolverconflicter = self. resolv let{
-       n> tResolutiosult<ConflicRenessous) -> Conscixt,
-    ractionConteIntecontext: &        flict],
-dalityCon: &[Mo   conflictself,
-            &s(
- lictsonfality_clve_modfn reso   async 
-  }
-    }
-es
-       u neutrs os similaireion2, // Émot => 0.      _7,
-       }) => 0...Joy { on::. }, Emotin::Fear { .tioEmo         (
-   0.8, => { .. })Joy motion:: .. }, Er {::AngeonEmoti  (     .0,
-      1. }) =>s { .on::Sadnesoti }, Em { ..n::Joyotio   (Em
-         ion2) {ototion1, emh (em      matc
-  eldimensionntiotionnel mulémt un espace raille, utilisetation réeémens une impl// Dan
-        elle émotionnla distanceifié de mpl// Calcul si     f64 {
-   motion) -> otion2: &Emotion, em &Eon1:tif, emonce(&selnal_distaotioate_emfn calcul
-
-       }
- one)        Ok(N }
-
- }
-                    }));
-       8,
-       evance: 0.text_rel        con         ce,
-   anisttional_derity: emo         sev        ],
-                       },
-                   ow(),
-     Utc::nchrono::estamp:   tim                     
-     ,g()]".to_strin_expressionsacialce: vec!["fidenting_evor  supp                 
-         confidence,n_emotioions[0].otion.face_emrecognitt.emotion_ion_resulfidence: vis         con               ,
-    on_emotion) visi"{:?}",t!( forman:retationterp   i                     
-    ing(),n".to_strvisioodality: "      m                  
-    pretation {nterictingI Confl                       },
-                ,
-        ow()::no::Utctamp: chronmes        ti                   ()],
- .to_string_features"odicec!["prosevidence: vsupporting_                            one(),
-_or(&0.5).clrapxt().unwnees(). .valu                             es
-  nce_scoridenfn.con_detectiolt.emotio: voice_resuidence      conf            
-          ,otion) voice_em"{:?}",!(on: formaterpretati      int                    g(),
-  e".to_strin"voic dality:    mo               
-         etation {rprgIntectinli    Conf               ![
-     ions: vecretat_interplicting  conf                ing()],
-  ".to_strion "vis_string(),voice".to!["vecties: daliolved_mo     inv              Conflict,
- e::EmotionalonflictTypype: Clict_tnf   co                ng(),
- o_striict_1".tnflonal_comotict_id: "e    confli              ct {
-  Conflialityome(Modurn Ok(S        ret        .5 {
-e > 0stancmotional_dif e     i   
-               ion);
-  vision_emottion,emovoice_distance(motional_ate_e.calculelfce = sional_distan  let emot      e
-    llce émotionneohérenla cfication de riVé      //     {
-   ionision_emot vn) =tiomon_e Some(visioet    if l
-
-    );ary_emotion| &fe.primp(|fe      .ma
-      ()     .firstons
-       ace_emotiecognition.fion_remotn_result.iotion = &visvision_emot 
-        len;tiomary_emoion.prin_detectsult.emotiooice_reotion = &vt voice_em     levision
-   voix et la  par la ctéesons déteotiison des émompara     // C>> {
-   ctdalityConfliion<Mosult<OptiousnessRe) -> Consc   ngResult,
- rocessiVisionPult: &on_resvisi   ,
-     ngResultocessiVoicePre_result: &     voiclf,
-       &sect(
-    al_confliect_emotiondetsync fn     a
-
-cts)
-    }k(confli    Oiée)
-
-    simplifentation mplém // (I   
-    lits...s de confpe tyion d'autres// Détect
-
-              }
-   }   
-        nflict);s.push(co  conflict          ? {
-    ).awaitsionce, vinflict(voicoal_tionetect_emo) = self.dme(conflictlet So    if 
-        esult) {, vision_r_result)) = (voiceionome(vise), S(Some(voic   if let els
-     tionn émoe conflitstection d      // Dé
-  w();
-ec::nects = V mut confli
-        letnflict>> {ModalityCoResult<Vec<snessciou  ) -> Const>,
-  ingResulProcesson<&Spatialresult: Optipatial_     sesult>,
-   cessingRcProrion<&Biometesult: Optietric_r   biom   ,
-  t>essingResulsionProc<&Viionresult: Opt     vision_   sult>,
-ssingReProceVoicelt: Option<&  voice_resu     self,
-         &nflicts(
-_cotyetect_modali dasync fn
-    
+    /// Fusion par attention: chaque modalité pèse sa part de confiance
+    fn attention_fusion(
+        &self,
+        observations: &[ModalityObservation],
+    ) -> ConsciousnessResult<FusedRepresentation> {
+        let total_confidence: f64 = observations.iter().map(|o| o.confidence).sum();
+        let uniform = 1.0 / observations.len() as f64;
+
+        let max_dim = observations.iter().map(|o| o.features.len()).max().unwrap_or(0);
+        let mut fused_features = vec![0.0; max_dim];
+        let mut fused_confidence = 0.0;
+
+        for observation in observations {
+            let attention = if total_confidence > 0.0 {
+                observation.confidence / total_confidence
+            } else {
+                uniform
+            };
+
+            for (i, &feature) in observation.features.iter().enumerate() {
+                fused_features[i] += feature * attention;
+            }
+
+            fused_confidence += observation.confidence * attention;
+        }
+
+        Ok(FusedRepresentation {
+            features: fused_features,
+            confidence: fused_confidence,
+            coherence: self.calculate_coherence(observations),
+            degraded: false,
+            missing_modalities: Vec::new(),
+        })
     }
-(analyses)  Ok
-
-            }  nalysis);
-sh(a.puesanalys          
-  t?;     ).awaiext
-       ntresult, coal_t, spatiesulometric_rbin_result,  visiosult, voice_re           nce(
-    herer.analyze_co analyzealysis =an      let      
- alyzers {nce_anelf.coherein &s analyzer for     
-
-   new();Vec::es =  analys   let mut    s>> {
- enceAnalysit<Vec<CoherulusnessResonscio    ) -> Cext,
-ionContact&Inter   context: >,
-     sultsingRerocesSpatialPon<&lt: Optiesutial_rpa   st>,
-     singResultricProces<&BiomeOptionsult: iometric_re,
-        bsult>gReessinrocion<&VisionPresult: Optn_     visio
-   ingResult>,essVoiceProcon<& Optiresult:   voice_    &self,
- (
-        oherenceultimodal_cyze_mc fn analsyn  a
-
-   }  count
- }
-        ; t += 1) { coun_some(lt.isatial_resu  if sp
-      += 1; }e() { count _somult.is_resometricbi
-        if unt += 1; }co) { me(.is_so_resulton  if visi    ; }
-  { count += 1s_some() _result.iice    if vo    ;
- 0 count =  let mut     usize {
-  ) -> sult>,
-   essingRerocon<&SpatialPesult: Optispatial_r        gResult>,
-sinroces<&BiometricP Optionlt:ric_resuomet
-        biult>,ocessingReson<&VisionPrt: Optisulion_reis
-        vlt>,ssingResuceProception<&Voit: O voice_resul,
-       &self        alities(
-modavailable_  fn count_
-
-  
-    }        ))
-    string()".to_availableegy not ion strat   "Fus  
-           idInput(r::InvalnessErroiousrror::Consc crate::eelse(||    .ok_or_      ned()
-        .clo    _type)
-  &strategytegies.get(straon_.fusilf    se
-       };
-usion
-     y::LateFnStrateg    Fusio   {
-        } else    sion
-  ridFutegy::HybFusionStra           {
-  es == 2tiable_modalise if availel    } sion
-    AdaptiveFuy::nStrateg   Fusio   {
-       ies >= 3dalitilable_mova if ategy_type =   let stra     );
-
-   t
-     atial_resulresult, spetric_, biomion_resultlt, visce_resu        vois(
-    litieable_modaount_avail = self.ctiesdalimoe_ availabl       letfusion
- de tratégie tive de la sction adapta    // Séle
-    entation> {FusionImplemlt<ResuousnessConsci
-    ) -> xt,ontectionCext: &Intera  cont>,
-      singResultatialProceson<&SpOpti_result:  spatial>,
-       gResultcessinBiometricPro<&ont: Optiuletric_resiom       blt>,
- essingResusionProcOption<&Viresult: on_       visi
- Result>,ocessingoicePr: Option<&Vsultoice_re,
-        v     &self(
-   _strategyfusionc fn select_ asyn
-
-     })
-    }lt
-      n_resutial_fusio       ..ini   ,
-  fidenced_conibrate       cal  
-   sionResult {(FuOk   ;
-
-     t?      ).awaintext
-  ssment, coherence_asse, &cosultren_nitial_fusio        &i   
- nce(nfidee_fusion_colibrat= self.caonfidence ibrated_c cal        let};
-
-  
-         },
-             },          e: 0.80,
-  tness_scor      robus        5,
-      e: 0.8ate_estimcorf1_s                .88,
-    : 0_estimate    recall           
-     : 0.82,n_estimate    precisio      
-          e: 0.85,cy_estimatccura a             {
-       ceMetricsmansionPerforrics: Fumetrmance_erfo          p  ![],
-    lied: vecn_appaptatioad            
-    y: 0.8,itualfusion_q                ity: 0.7,
-nal_complexioputat       com         elapsed(),
-_time. startime:g_tcessinpro             a {
-   onMetadattadata: Fusiusion_me           f   },
-         ,
- ew()Map::nals: Hash_intervence      confid
-             },       ,
-      ec![]rces: vsouty_certain   un               ty: 0.2,
-  uncertain   total_           .1,
-      ertainty: 0toric_uncea          al          : 0.1,
-ertaintyuncstemic_epi                  
-  on {ficatinticertaintyQua Union:ntificatainty_qua uncert              [],
- ctors: vec!faration_      calib         e: 0.8,
- _confidenccalibrated             
-   : 0.8,confidenceoriginal_               sian,
- ayeorType::BeCalibratidencr_type: Conflibrato        ca        {
- edConfidencee: Calibratnfidenclibrated_co          ca),
-  n.clone(t_resolutioon: conflictiict_resoluconfl            one(),
-ment.clssessnce_a coherent:mence_assess  cohere        tions,
-  contribu   modality_    on,
-     etatied_interprifi   un       ,
-  e.clone()strategy_typn_strategy.usio_used: ftrategy fusion_s
-           esult {= FusionRn_result itial_fusio    let innce
-    confia de la libration   // 6. Ca
-     ?;
-await       ).ategy
- sion_str         &fu
-   ,sultrelt, spatial_resumetric_esult, bion_rt, visio voice_resul           (
-ionsutontribdality_cculate_moal.c = selfbutionsy_contrialit   let modités
-     odal des mntributionses co d5. Calcul     //   
-
- it?;  ).awaion
-      lut_resolictnfategy, &cotrn_s &fusio          sult,
- , spatial_re_resultiometric, bltesuon_r, visie_resultvoic            tation(
-_interprefiedreate_uniion = self.cetatd_interpret unifie        ltés
- des modali Fusion     // 4.};
-
-   e
-         Non     se {
-           } el  it?)
- t).awacontexflicts, (&conflicts_conodalitye_mlf.resolvSome(se            {
- mpty()is_elicts.n = if !confct_resolutiolet confli        await?;
-
-).        result
-atial_spt, tric_resul biomeesult,lt, vision_rce_resu        voi(
-    conflictsmodality_.detect_ = selfonflictst c     le
-   nflits de cot résolutiontion eecDét.  // 3       
-
-).await?;       xt
- conteal_result, lt, spati_resuic biometresult,on_rvisiresult, oice_     v
-       herence(dal_cotimonalyze_mulnt = self.asmee_assesenclet coherce
-        de cohéren 2. Analyse      //
-   wait?;
-     ).a
-   contextult, al_res spatilt,resutric_iomeon_result, bresult, visice_   voi    egy(
-     ratt_fusion_stlf.selec = setrategylet fusion_s
-        nde fusiotégie n de la stra Sélectio       // 1.
- 
-::now(); Instantart_time =st let 
-       > {esultsult<FusionRReiousnesssc) -> Con
-    ntext,onCoteractiontext: &In
-        cesult>,ngRProcessitialion<&Spalt: Optal_resuati     sp>,
-   ingResultocessicPr<&Biometronpti Olt:tric_resu     biome
-   lt>,gResussinProceion<&Vision Optsult:on_re        visiult>,
-ngRessioiceProcest: Option<&Ve_resul  voic   elf,
-   &s(
-        ese_modalitiync fn fus    pub asés
-es modalitipale dncn pri  /// Fusio
+
+    /// Cohérence inter-modale: similarité moyenne des vecteurs de features
+    fn calculate_coherence(&self, observations: &[ModalityObservation]) -> f64 {
+        if observations.len() < 2 {
+            return 1.0;
+        }
+
+        let mut scores = Vec::new();
+        for i in 0..observations.len() {
+            for j in (i + 1)..observations.len() {
+                let a = &observations[i].features;
+                let b = &observations[j].features;
+                let similarity = if a.len() == b.len() && !a.is_empty() {
+                    crate::utils::calculate_cosine_similarity(a, b).unwrap_or(0.0)
+                } else {
+                    0.0
+                };
+                scores.push(similarity.abs());
+            }
+        }
+
+        if scores.is_empty() {
+            1.0
+        } else {
+            scores.iter().sum::<f64>() / scores.len() as f64
+        }
     }
- }
-  
-       rators,idence_calib conf        vers,
-   _resolict    confls,
-        ce_analyzereren      coh,
-      gies_stratesionfu            
-      Self {
-         ];
-ew()),
- ::nleCalibratorw(Ensemb    Box::ne   ()),
-     newrator::yesianCalibew(Ba:nx:       Bovec![
-     = nd + Sync>> rator + SedenceCalibnfi<dyn Co: Vec<Boxcalibratorsconfidence_et      l ];
-
-   
-       ()),esolver::newtextualR::new(Con      Box
-      ),ver::new()olResceBasedenConfidw(ne    Box::      vec![
-   > =Send + Sync>solver + nflictReyn Co Vec<Box<dlvers:ct_resoet confli
-        l];
-     
-   )),::new(enceAnalyzerCoheremanticw(SBox::ne        
-    ),ew()yzer::neAnalenctionalCoherox::new(Emo           B:new()),
- ceAnalyzer:herenporalCo:new(Tem        Box:c![
-    ve> = Send + Sync> + eryznalCoherenceAyn c<Box<dalyzers: Veence_an coher        let);
 
-}
-                  
-       ],          }
-                  
-   ue,trhting: ce_weigfiden    con                 ()],
-   ring".to_st(), "voiceo_string"vision".tng(), rio_stic".tbiometr vec!["rity_order: prio               
-        string(),idence".to_hest_conftegy: "higon_stra    resoluti             ,
-       string()nflict".to_alComotion: "Eflict_type       con              
-   ring(),y".to_strit Prioontime: "Emole_na      ru                  {
- lelutionRutResoConflic                 vec![
-    es:on_rulct_resoluti      confli          },
-               : 0.8,
- resholdthconsistency_                    
-: 0.7,oldity_threshiabilel     r      ,
-          0.6n_threshold:     fusio     
-          ,e: 0.3m_confidenc      minimu            
-  hresholds {enceTConfidsholds: _threidence     conf
-                   },
-        ix: None,mation_matr   transfor                 ,
-to_string()]_position".(), "hand.to_stringenter"ace_cvec!["f_landmarks:  alignment          
-         nce: 0.1,atial_tolera         sp     (),
-      to_string".atesd_coordintem: "worldinate_sys   coor             {
-     AlignmentSpatialt: ignmen spatial_al               },
-             ,
-   to_string()inear".ategy: "l_stronpolati     inter            (),
-   _stringion".toelat"cross_corrent_method: alignm                 50),
-   is(m_milltion::fro:time::Duratd:nce: soleraal_t    tempor           ,
-     0)lis(10from_milration:::time::Duw: std:n_windonizatio   synchro               gnment {
-  AliTemporalgnment: l_alimpora     te            },
-        
-       g: true,_weightindynamic             ,
-       eight: 0.05l_wntamenviron         e   
-        ,weight: 0.15patial_   s               0.2,
-  ic_weight:  biometr          
-          0.3,eight:   vision_w         
-        weight: 0.3,ce_  voi               {
-    ityWeightsdals: Moht_weig  fusion     
-         ptiveFusion,tegy::AdaFusionStra_type: tegystra             {
-    tionmplementanI   Fusio
-         iveFusion,egy::Adapttrat   FusionS      
-   s.insert(n_strategie    fusio    
-ptativeon adaie de fusiStratég
-        // w();
-ashMap::neegies = Hon_stratet mut fusi{
-        lelf  new() -> S    pub fnEngine {
-usionl F
-
-imp,
-} f64e:ess_scorstn robu   pub: f64,
- imatef1_score_estub 64,
-    p_estimate: fall  pub recte: f64,
-  imasion_est  pub preci: f64,
-  mateccuracy_esti   pub a
- cs {ceMetriformanFusionPerpub struct rialize)]
- Deselize, Seriaebug, Clone,
-#[derive(Drics,
-}
-formanceMetusionPere_metrics: Ferformanc   pub pString>,
- ed: Vec<applitation_  pub adap  64,
-ity: fsion_qualb fu    pu4,
- f6exity:ional_complub computat   pon,
- tirae::Dutim: std::g_timerocessin pubata {
-    pnMetadusioct Fub strue)]
-pDeserializ Serialize,  Clone,rive(Debug,
+    /// Qualité par modalité, pour les métriques de coordination
+    pub fn modality_quality(
+        &self,
+        observations: &[ModalityObservation],
+    ) -> HashMap<ModalityType, f64> {
+        observations
+            .iter()
+            .map(|o| (o.modality.clone(), o.confidence))
+            .collect()
+    }
 }
 
-#[deing>,ec<Strion: Vnformatundant_i
-    pub redec<String>,sights: Vique_in pub un64,
-   e: forlity_sc pub reliabi
-   : f64,ntentormation_co    pub inf64,
-tribution: fe_conidenc pub conf: f64,
-   ht_used weigpubon {
-    Contributiality Modtruct
-pub slize)] Deseria Serialize,g, Clone,[derive(Debu
+impl Default for FusionEngine {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
-#ibution,lityContrion: Moda_contributnmentalenviroub ion,
-    putntribCoityalion: Modl_contributtiaspa
-    pub bution,Contrialityon: Modtintribuiometric_co
-    pub bbution,ityContrin: Modal_contributioionub vis p
-   n,yContributioalitution: Modice_contrib
-    pub vobutions {ntri ModalityCob structe)]
-purializalize, Deselone, Seribug, Crive(De
-#[de
-String>,
-} Vec<ds:ty_neeiliaccessib,
-    pub _level: f64ub comfort
-    pevel: f64,tigue_lfa    pub 
-vel: f64, stress_lepub
-    , f64_level:gagement   pub en
- evel: f64,n_lentiob att
-    puad: f64,tive_lob cogni {
-    pueAssessmenttaterSb struct Uslize)]
-pueseriaSerialize, Dlone,  Crive(Debug,}
-
-#[def64,
-importance: event_ub  pf64,
-   ability:   pub probc>,
-  ::Ut<chrono:DateTimeono:chrime: icted_tpub pred
-    ring,me: Stvent_naub eent {
-    pneEvTimelit pub strucerialize)]
-e, Desaliz Clone, Seriug,[derive(Debf64,
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-#bility: cta_prediternat
-    pub ph: f64,trengtub pattern_s f64,
-    pfrequency:tern_pub pat   
- String,me: rn_napub patteern {
-    oralPattemptruct Tize)]
-pub se, Deserialalizne, SeriClo, Debug#[derive(
-
-,
-}neEvent>eliimc<Tine: Vemelted_ti predicn>,
-    puberlPattporas: Vec<Temral_patternub tempo    pency: f64,
-e_urgonspub resp,
-    : f64rhythmeraction_ pub intmics {
-   emporalDyna T structalize)]
-puberiize, Des, Serial Cloneebug,e(Deriv
-}
+    fn observation(modality: ModalityType, confidence: f64) -> ModalityObservation {
+        ModalityObservation {
+            modality,
+            features: vec![0.9, confidence],
+            confidence,
+        }
+    }
 
-#[d f64,vel:ty_leccessibili
-    pub aec<String>,fordances: Vraction_afub inte
-    p,)>, f64, f6464s: Vec<(fdarie    pub bouning,
-_type: Strpub zonetring,
-    e_id: S  pub zonone {
-  InteractionZtruct 
-pub salize)]seri Deize,rialSeug, Clone, Deb
-#[derive(g>,
-}
-rinVec<Stonstraints: _cial   pub spatnZone>,
- c<InteractioVes: n_zoneinteractio pub String,
-   ntext: al_cotipa  pub sf64)>,
-  64,  fon<(f64,tion: Opti_orientaub user)>,
-    p, f64on<(f64, f64ition: Optiser_pos pub us {
-   ialAwarenesruct Spatub st)]
-palize, DeserizeialiSerbug, Clone, Dee(}
-
-#[derivng>,
-Vec<Stridicators: b change_in,
-    puhrono::Utc>eTime<crono::Dat chtamp:hange_times pub c   f64,
-_magnitude:  pub changeg,
-   pe: Strinange_ty
-    pub ch {ngentextCharuct Co stze)]
-pubserialize, Deialine, Ser(Debug, Clo
-
-#[deriveing>,
-}tion<Strtext: Opondicted_c pre
-    pub,Change>Contextec<ion: Vt_evolutcontex
-    pub >, Vec<Stringctors:ntextual_fa_co   pub key f64,
- _confidence:ub context p,
-    Stringation_type:ituub s pding {
-   erstanontextualUnd struct Cpube)]
-serializialize, Delone, Serug, Crive(Deb#[dent,
-}
+    #[tokio::test]
+    async fn test_failed_vision_degrades_but_does_not_abort_fusion() {
+        let engine = FusionEngine::new();
+        let observations = vec![observation(ModalityType::Voice, 0.8)];
+        let failures = vec![ModalityFailure {
+            modality: ModalityType::Vision,
+            reason: "vision backend down".to_string(),
+        }];
 
-ssmeteAssetaUserS: ssessment_state_a pub user   ics,
-alDynamics: Tempor_dynamporalb tem
-    pureness,Awatial: Spa_awarenessal spatiubng,
-    pndiderstatextualUnnding: Conunderstaal_b contextu    pu
-State,nalotio Emate:_stonalub emoting,
-    pntent: Striub primary_i
-    ption {rpretaifiedIntect Unru)]
-pub stserializeze, DealiClone, Seribug, derive(De
-
-#[adata,
-}nMetta: Fusioetadab fusion_mpudence,
-    edConfi Calibratence:nfid_coedb calibrat   puolution>,
- tReson<Conflicn: Optiiosolut_reflictpub consis>,
-    erenceAnaly Vec<Cohassessment:coherence_
-    pub ibutions,tronityCModalibutions: ality_contr
-    pub modtion,retafiedInterpion: Unipretatd_inter pub unifietegy,
-   trausionSd: Ftegy_use fusion_stra{
-    pubionResult ct Fus stru)]
-publizeriaDeseSerialize, Clone, (Debug, erive fusion
-#[d Résultat de
-}
+        let fused = engine.fuse_available(&observations, &failures).await.unwrap();
 
-///,ring><Sts: Vecurcey_sointb uncerta   pu
- ty: f64,_uncertain   pub totalre
- atoi aléertitude Inc//,    ty: f64ertainuncatoric_ale    pub mique
-e épistéitudrt   // Ince: f64, ertaintyistemic_uncb eppu{
-    fication yQuanti Uncertaintub struct]
-palize) Deserierialize,ug, Clone, SDebive(er
-
-#[dg>,
-}ine: Vec<Strncevide pub 
-   "creaser "dee" ocreasg, // "intion: Strinec  pub dir,
-  : f64 impactubing,
-    ptrname: S pub factor_tor {
-   Facalibrationtruct Cub sze)]
-p, Deserialirializeg, Clone, Sederive(Debu
-}
+        // La voix seule produit encore une représentation exploitable
+        assert!(!fused.features.is_empty());
+        assert!(fused.confidence > 0.0);
 
-#[64)>,f64, fString, (ls: HashMap<ce_intervanfiden co    pubfication,
-taintyQuantieron: Uncficatiquantiainty_cert
-    pub untor>,nFacalibratio: Vec<Cctorsration_faalib
-    pub c: f64,encenfidd_coratecalibpub     64,
-dence: ffial_conorigin
-    pub ibratorType,nceCaldeype: Confilibrator_t  pub cace {
-  nfidenibratedCo struct Calalize)]
-pubeserize, D Serialibug, Clone,ve(De
-
-#[derive
-}daptatialibration a // C          Adaptive,que
-   ori histCalibration   // rical,      Histouelle
-   on contextalibrati/ C   /    ontextual,   Ce
-  emblens d'bration// Cali          emble,
-    Ensienneyésion ba/ Calibrat        /sian,  Baye   Type {
- Calibratoridenceb enum Conf
-puze)]eserialiize, D Serial, Clone,ve(Debug
-#[deripe;
-}
-bratorTynfidenceCali Co) ->ype(&selfor_tget_calibrat
-    fn 
-onfidence>;edCat<CalibrsnessResult-> Consciou    ) nContext,
-nteractiot: &I      contexis],
-  lysceAnas: &[Coherenence_analysi       coherlt,
- &FusionResu: usion_result      f &self,
-  
-       onfidence( calibrate_cync fnasr {
-    libratoCadencet Confie
-pub traide confiancteur ibra
-
-/// Cal,
-}ng>Vec<Striretations: rpe_intelternativ
-    pub alution: f64,n_resoconfidence_i    pub e: String,
-nalion_ratio resolut  pub
-  ring,Strpretation: _intesen    pub cho,
-d: Stringb conflict_i  pu{
-  flict edConResolvtruct ze)]
-pub sialie, Deseralizri Seg, Clone,[derive(Debu
-#>,
-}
-Stringies: Vec<uncertainting_ub remain
-    psed: String,ategy_ution_str  pub resolu,
-  64dence: flution_confieso  pub r
-  ict>,vedConflec<Resol: Vlictsed_confresolv   pub ype,
- lverTonflictReso: Cesolver_type   pub r
- on {ictResolutistruct Confllize)]
-pub eseriaize, DialClone, Sere(Debug, #[derivc>,
-}
+        // ...mais le résultat est marqué dégradé, vision listée manquante
+        assert!(fused.degraded);
+        assert_eq!(fused.missing_modalities, vec![ModalityType::Vision]);
+    }
 
-e<chrono::Uto::DateTimtamp: chrones
-    pub timring>,ence: Vec<St_evidingort    pub supp64,
-ence: fnfid  pub co
-  ion: String,retatrp    pub intey: String,
-italub modon {
-    prpretatiflictingInteruct Con
-pub stialize)]serrialize, De, Clone, Sederive(Debugnce
-}
+    #[tokio::test]
+    async fn test_all_modalities_failing_is_an_error() {
+        let engine = FusionEngine::new();
+        let failures = vec![ModalityFailure {
+            modality: ModalityType::Vision,
+            reason: "vision backend down".to_string(),
+        }];
 
-#[nfiacode t   // Confli  lict, idenceConf Confue
-   it sémantiqonfl      // CcConflict, 
-    Semantilit spatialConf  // flict,      ialCon   Spattemporel
- onflit  C       //flict,Con  Temporalion
-  it d'intentonfl    // C     Conflict, Intentionnel
-   Conflit émot// flict,      nalConmotioe {
-    ElictTypnfb enum Coze)]
-puerialilize, Deslone, Seria(Debug, C
-#[derivee: f64,
-}
-ancntext_relev   pub coty: f64,
- ub severi   p,
- retation>ctingInterpConfliions: Vec<pretat_interictingpub confl   tring>,
- ies: Vec<Slitved_moda   pub invollictType,
- type: Confconflict_ub 
-    pd: String,t_ionflicb c
-    puflict {ModalityContruct 
-pub s)]rialize Desealize,, SerioneClve(Debug, [deri
-#ndéré
-}
-  // Po   ed,      ght   Weiconsensus
- uction de onstr Clding,  //sBuionsensu
-    Cexte contsé sur led,    // BaualBasexttence
-    Cononfia cur laé s  // BaseBased,  enc  Confid
-  ésitur les prior s    // Basé  rityBased,{
-    PrioverType flictResol Con)]
-pub enumDeserializerialize, ne, SeDebug, Cloerive(;
-}
+        let result = engine.fuse_available(&[], &failures).await;
+        assert!(matches!(result, Err(ConsciousnessError::ProcessingError(_))));
+    }
 
-#[dypectResolverTConfliself) -> olver_type(&resget_   fn 
+    #[tokio::test]
+    async fn test_full_fusion_is_not_degraded() {
+        let engine = FusionEngine::new();
+        let observations = vec![
+            observation(ModalityType::Voice, 0.8),
+            observation(ModalityType::Vision, 0.7),
+        ];
 
- Resolution>;flictesult<ConsRsciousnes    ) -> Context,
-tionCont: &Interacontex c
\ No newline at end of file
+        let fused = engine.fuse_available(&observations, &[]).await.unwrap();
+
+        assert!(!fused.degraded);
+        assert!(fused.missing_modalities.is_empty());
+    }
+}
@@ -3,21 +3,13 @@
 
 pub mod voice_processing;
 pub mod vision_processing;
-pub mod biometric_integration;
-pub mod spatial_computing;
 pub mod fusion_engine;
-pub mod interaction_orchestrator;
 
 pub use voice_processing::*;
 pub use vision_processing::*;
-pub use biometric_integration::*;
-pub use spatial_computing::*;
 pub use fusion_engine::*;
-pub use interaction_orchestrator::*;
 
 use crate::error::ConsciousnessResult;
-use crate::emotions::{EmotionalState, EmotionalContext};
-use crate::modules::SelfAwareness;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use tokio::time::Instant;
@@ -444,7 +436,7 @@ pub struct VoiceResponse {
     pub language: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct VoiceCharacteristics {
     pub pitch_range: (f64, f64),
     pub speaking_rate: f64,
@@ -659,10 +651,7 @@ pub struct AmbientEnhancement {
 pub struct MultimodalInterface {
     pub voice_processor: VoiceProcessor,
     pub vision_processor: VisionProcessor,
-    pub biometric_integrator: BiometricIntegrator,
-    pub spatial_computer: SpatialComputer,
     pub fusion_engine: FusionEngine,
-    pub interaction_orchestrator: InteractionOrchestrator,
 }
 
 impl MultimodalInterface {
@@ -670,10 +659,7 @@ impl MultimodalInterface {
         Self {
             voice_processor: VoiceProcessor::new(),
             vision_processor: VisionProcessor::new(),
-            biometric_integrator: BiometricIntegrator::new(),
-            spatial_computer: SpatialComputer::new(),
             fusion_engine: FusionEngine::new(),
-            interaction_orchestrator: InteractionOrchestrator::new(),
         }
     }
 
@@ -681,54 +667,106 @@ impl MultimodalInterface {
     pub async fn process_multimodal_input(
         &self,
         input: MultimodalInput,
-        emotional_state: &EmotionalState,
-        self_awareness: &SelfAwareness,
     ) -> ConsciousnessResult<MultimodalResponse> {
         let start_time = Instant::now();
 
-        // 1. Traitement parallèle de chaque modalité
-        let voice_result = if let Some(voice_data) = &input.voice_data {
-            Some(self.voice_processor.process_voice(voice_data, &input.context).await?)
-        } else {
-            None
-        };
+        // 1. Traitement de chaque modalité disponible; un échec isolé
+        // (ex: backend vision en panne) est enregistré au lieu d'abandonner
+        // toute la requête
+        let mut observations = Vec::new();
+        let mut failures = Vec::new();
+
+        if let Some(voice_data) = &input.voice_data {
+            match self.voice_processor.process_voice(voice_data, &input.context).await {
+                Ok(result) => observations.push(Self::observe_voice(&result)),
+                Err(e) => failures.push(ModalityFailure {
+                    modality: ModalityType::Voice,
+                    reason: e.to_string(),
+                }),
+            }
+        }
 
-        let vision_result = if let Some(vision_data) = &input.vision_data {
-            Some(self.vision_processor.process_vision(vision_data, &input.context).await?)
-        } else {
-            None
-        };
+        if let Some(vision_data) = &input.vision_data {
+            match self.vision_processor.process_vision(vision_data, &input.context).await {
+                Ok(result) => observations.push(Self::observe_vision(&result)),
+                Err(e) => failures.push(ModalityFailure {
+                    modality: ModalityType::Vision,
+                    reason: e.to_string(),
+                }),
+            }
+        }
 
-        let biometric_result = if let Some(biometric_data) = &input.biometric_data {
-            Some(self.biometric_integrator.process_biometrics(biometric_data, &input.context).await?)
-        } else {
-            None
-        };
+        // 2. Fusion des modalités survivantes
+        let fused = self.fusion_engine.fuse_available(&observations, &failures).await?;
+
+        // 3. Assemblage de la réponse coordonnée
+        Ok(self.assemble_response(&fused, &observations, start_time.elapsed()))
+    }
 
-        let spatial_result = if let Some(spatial_data) = &input.spatial_data {
-            Some(self.spatial_computer.process_spatial(spatial_data, &input.context).await?)
+    /// Distillation du résultat vocal en observation fusionnable
+    fn observe_voice(result: &VoiceProcessingResult) -> ModalityObservation {
+        let metadata = &result.processing_metadata;
+        ModalityObservation {
+            modality: ModalityType::Voice,
+            features: vec![metadata.quality_assessment, metadata.confidence_level],
+            confidence: metadata.confidence_level,
+        }
+    }
+
+    /// Distillation du résultat visuel en observation fusionnable
+    fn observe_vision(result: &VisionProcessingResult) -> ModalityObservation {
+        let metadata = &result.processing_metadata;
+        ModalityObservation {
+            modality: ModalityType::Vision,
+            features: vec![metadata.image_quality_assessment, metadata.processing_confidence],
+            confidence: metadata.processing_confidence,
+        }
+    }
+
+    /// Construction de la réponse multimodale à partir de la fusion
+    fn assemble_response(
+        &self,
+        fused: &FusedRepresentation,
+        observations: &[ModalityObservation],
+        processing_time: std::time::Duration,
+    ) -> MultimodalResponse {
+        let mut synchronization_timing = HashMap::new();
+        for observation in observations {
+            synchronization_timing.insert(observation.modality.clone(), processing_time);
+        }
+
+        // Une fusion dégradée est annoncée comme stratégie de repli, avec
+        // les modalités manquantes, pour que l'appelant puisse s'adapter
+        let fallback_strategies = if fused.degraded {
+            vec![format!(
+                "degraded fusion without {:?}",
+                fused.missing_modalities
+            )]
         } else {
-            None
+            Vec::new()
         };
 
-        // 2. Fusion des modalités
-        let fusion_result = self.fusion_engine.fuse_modalities(
-            voice_result.as_ref(),
-            vision_result.as_ref(),
-            biometric_result.as_ref(),
-            spatial_result.as_ref(),
-            &input.context,
-        ).await?;
-
-        // 3. Orchestration de la réponse
-        let orchestrated_response = self.interaction_orchestrator.orchestrate_response(
-            &fusion_result,
-            emotional_state,
-            self_awareness,
-            &input.context,
-        ).await?;
-
-        Ok(orchestrated_response)
+        MultimodalResponse {
+            timestamp: chrono::Utc::now(),
+            voice_response: None,
+            visual_response: None,
+            haptic_response: None,
+            spatial_response: None,
+            environmental_response: None,
+            coordination_metadata: CoordinationMetadata {
+                synchronization_timing,
+                coherence_score: fused.coherence,
+                adaptation_applied: Vec::new(),
+                fallback_strategies,
+                quality_metrics: QualityMetrics {
+                    overall_quality: fused.confidence,
+                    modality_quality: self.fusion_engine.modality_quality(observations),
+                    user_experience_score: fused.coherence,
+                    technical_performance: fused.confidence,
+                    accessibility_compliance: 1.0,
+                },
+            },
+        }
     }
 
     /// Adaptation continue basée sur le feedback
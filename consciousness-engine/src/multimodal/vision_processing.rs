@@ -3,7 +3,7 @@
 
 use crate::error::ConsciousnessResult;
 use crate::multimodal::{VisionData, InteractionContext, VisualResponse, VisualElement, DisplayConfiguration};
-use crate::emotions::{Emotion, EmotionalState};
+use crate::types::{EmotionType, EmotionalState};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use tokio::time::Instant;
@@ -19,6 +19,7 @@ pub struct VisionProcessor {
 }
 
 /// Détecteur d'objets
+#[async_trait::async_trait]
 pub trait ObjectDetector {
     async fn detect_objects(
         &self,
@@ -40,7 +41,7 @@ pub enum ObjectDetectorType {
     Transformer,    // Vision Transformer
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ObjectDetectionResult {
     pub detected_objects: Vec<DetectedObject>,
     pub scene_classification: SceneClassification,
@@ -48,7 +49,7 @@ pub struct ObjectDetectionResult {
     pub confidence_metrics: ConfidenceMetrics,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DetectedObject {
     pub object_id: String,
     pub class_name: String,
@@ -59,7 +60,7 @@ pub struct DetectedObject {
     pub interaction_affordances: Vec<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct BoundingBox {
     pub x: f64,
     pub y: f64,
@@ -68,7 +69,7 @@ pub struct BoundingBox {
     pub rotation: Option<f64>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PoseEstimation {
     pub pose_type: String,
     pub keypoints: Vec<Keypoint>,
@@ -76,7 +77,7 @@ pub struct PoseEstimation {
     pub orientation: (f64, f64, f64),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Keypoint {
     pub name: String,
     pub position: (f64, f64),
@@ -84,7 +85,7 @@ pub struct Keypoint {
     pub visibility: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SceneClassification {
     pub scene_type: String,
     pub scene_confidence: f64,
@@ -93,7 +94,7 @@ pub struct SceneClassification {
     pub environmental_context: EnvironmentalContext,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct LightingConditions {
     pub brightness_level: f64,
     pub contrast_level: f64,
@@ -102,7 +103,7 @@ pub struct LightingConditions {
     pub shadow_analysis: ShadowAnalysis,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ShadowAnalysis {
     pub shadow_presence: bool,
     pub shadow_intensity: f64,
@@ -110,7 +111,7 @@ pub struct ShadowAnalysis {
     pub shadow_quality: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct EnvironmentalContext {
     pub indoor_outdoor: String,
     pub weather_conditions: Option<String>,
@@ -118,7 +119,7 @@ pub struct EnvironmentalContext {
     pub season_indicators: Vec<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SpatialRelationship {
     pub object1_id: String,
     pub object2_id: String,
@@ -128,7 +129,7 @@ pub struct SpatialRelationship {
     pub interaction_potential: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ConfidenceMetrics {
     pub overall_confidence: f64,
     pub detection_reliability: f64,
@@ -137,6 +138,7 @@ pub struct ConfidenceMetrics {
 }
 
 /// Analyseur facial
+#[async_trait::async_trait]
 pub trait FaceAnalyzer {
     async fn analyze_faces(
         &self,
@@ -156,7 +158,7 @@ pub enum FaceAnalyzerType {
     MediaPipe,      // MediaPipe Face
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct FaceAnalysisResult {
     pub detected_faces: Vec<DetectedFace>,
     pub face_recognition: Vec<FaceRecognition>,
@@ -165,7 +167,7 @@ pub struct FaceAnalysisResult {
     pub face_quality_assessment: Vec<FaceQualityAssessment>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DetectedFace {
     pub face_id: String,
     pub bounding_box: BoundingBox,
@@ -175,14 +177,14 @@ pub struct DetectedFace {
     pub face_visibility: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct FaceAngle {
     pub yaw: f64,
     pub pitch: f64,
     pub roll: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct FaceRecognition {
     pub face_id: String,
     pub identity: Option<String>,
@@ -191,7 +193,7 @@ pub struct FaceRecognition {
     pub similarity_scores: HashMap<String, f64>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DemographicAnalysis {
     pub face_id: String,
     pub estimated_age: Option<u8>,
@@ -201,14 +203,14 @@ pub struct DemographicAnalysis {
     pub ethnicity_analysis: Option<EthnicityAnalysis>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct EthnicityAnalysis {
     pub primary_ethnicity: String,
     pub ethnicity_confidence: f64,
     pub ethnicity_distribution: HashMap<String, f64>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct FacialLandmarks {
     pub face_id: String,
     pub landmarks: Vec<LandmarkPoint>,
@@ -216,14 +218,14 @@ pub struct FacialLandmarks {
     pub facial_geometry: FacialGeometry,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct LandmarkPoint {
     pub landmark_name: String,
     pub position: (f64, f64),
     pub confidence: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct FacialGeometry {
     pub face_width: f64,
     pub face_height: f64,
@@ -233,7 +235,7 @@ pub struct FacialGeometry {
     pub facial_symmetry: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct FaceQualityAssessment {
     pub face_id: String,
     pub overall_quality: f64,
@@ -245,6 +247,7 @@ pub struct FaceQualityAssessment {
 }
 
 /// Reconnaisseur d'émotion visuelle
+#[async_trait::async_trait]
 pub trait VisualEmotionRecognizer {
     async fn recognize_emotions(
         &self,
@@ -264,7 +267,7 @@ pub enum VisualEmotionRecognizerType {
     Custom,         // Custom model
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct VisualEmotionResult {
     pub face_emotions: Vec<FaceEmotion>,
     pub overall_emotional_state: EmotionalState,
@@ -272,17 +275,17 @@ pub struct VisualEmotionResult {
     pub micro_expressions: Vec<MicroExpression>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct FaceEmotion {
     pub face_id: String,
-    pub primary_emotion: Emotion,
+    pub primary_emotion: EmotionType,
     pub emotion_probabilities: HashMap<String, f64>,
     pub emotion_intensity: f64,
     pub emotion_confidence: f64,
     pub facial_action_units: Vec<FacialActionUnit>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct FacialActionUnit {
     pub au_name: String,
     pub au_intensity: f64,
@@ -290,15 +293,15 @@ pub struct FacialActionUnit {
     pub associated_emotions: Vec<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct EmotionTimestamp {
     pub timestamp: std::time::Duration,
     pub face_id: String,
-    pub emotion: Emotion,
+    pub emotion: EmotionType,
     pub intensity: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MicroExpression {
     pub face_id: String,
     pub expression_type: String,
@@ -309,6 +312,7 @@ pub struct MicroExpression {
 }
 
 /// Reconnaisseur de gestes
+#[async_trait::async_trait]
 pub trait GestureRecognizer {
     async fn recognize_gestures(
         &self,
@@ -328,7 +332,7 @@ pub enum GestureRecognizerType {
     Custom,             // Custom gesture model
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GestureRecognitionResult {
     pub detected_gestures: Vec<DetectedGesture>,
     pub hand_poses: Vec<HandPose>,
@@ -336,7 +340,7 @@ pub struct GestureRecognitionResult {
     pub gesture_sequences: Vec<GestureSequence>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DetectedGesture {
     pub gesture_id: String,
     pub gesture_type: String,
@@ -347,7 +351,7 @@ pub struct DetectedGesture {
     pub cultural_context: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct HandPose {
     pub hand_id: String,
     pub hand_side: String, // "left" or "right"
@@ -356,7 +360,7 @@ pub struct HandPose {
     pub hand_confidence: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct HandLandmark {
     pub landmark_name: String,
     pub position: (f64, f64),
@@ -364,7 +368,7 @@ pub struct HandLandmark {
     pub confidence: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct BodyPose {
     pub person_id: String,
     pub pose_landmarks: Vec<PoseLandmark>,
@@ -372,7 +376,7 @@ pub struct BodyPose {
     pub activity_classification: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PoseLandmark {
     pub landmark_name: String,
     pub position: (f64, f64),
@@ -381,7 +385,7 @@ pub struct PoseLandmark {
     pub visibility: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GestureSequence {
     pub sequence_id: String,
     pub gestures: Vec<String>,
@@ -391,6 +395,7 @@ pub struct GestureSequence {
 }
 
 /// Analyseur de scène
+#[async_trait::async_trait]
 pub trait SceneAnalyzer {
     async fn analyze_scene(
         &self,
@@ -411,7 +416,7 @@ pub enum SceneAnalyzerType {
     Custom,         // Custom scene model
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SceneAnalysisResult {
     pub scene_understanding: SceneUnderstanding,
     pub spatial_layout: SpatialLayout,
@@ -419,7 +424,7 @@ pub struct SceneAnalysisResult {
     pub context_inference: ContextInference,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SceneUnderstanding {
     pub scene_category: String,
     pub scene_subcategory: String,
@@ -428,7 +433,7 @@ pub struct SceneUnderstanding {
     pub scene_complexity: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SpatialLayout {
     pub room_layout: Option<RoomLayout>,
     pub depth_estimation: DepthEstimation,
@@ -436,7 +441,7 @@ pub struct SpatialLayout {
     pub occlusion_analysis: OcclusionAnalysis,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct RoomLayout {
     pub room_type: String,
     pub walls: Vec<WallSegment>,
@@ -445,7 +450,7 @@ pub struct RoomLayout {
     pub room_dimensions: Option<(f64, f64, f64)>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct WallSegment {
     pub wall_id: String,
     pub wall_points: Vec<(f64, f64)>,
@@ -453,14 +458,14 @@ pub struct WallSegment {
     pub wall_confidence: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PlaneEstimation {
     pub plane_equation: (f64, f64, f64, f64), // ax + by + cz + d = 0
     pub plane_points: Vec<(f64, f64, f64)>,
     pub plane_confidence: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DepthEstimation {
     pub depth_map: Option<Vec<Vec<f64>>>,
     pub depth_confidence: f64,
@@ -468,14 +473,14 @@ pub struct DepthEstimation {
     pub depth_method: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PerspectiveAnalysis {
     pub vanishing_points: Vec<(f64, f64)>,
     pub horizon_line: Option<(f64, f64, f64, f64)>,
     pub camera_parameters: CameraParameters,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CameraParameters {
     pub focal_length: Option<f64>,
     pub camera_height: Option<f64>,
@@ -483,13 +488,13 @@ pub struct CameraParameters {
     pub field_of_view: Option<f64>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct OcclusionAnalysis {
     pub occlusion_relationships: Vec<OcclusionRelationship>,
     pub visibility_analysis: VisibilityAnalysis,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct OcclusionRelationship {
     pub occluder_id: String,
     pub occluded_id: String,
@@ -497,7 +502,7 @@ pub struct OcclusionRelationship {
     pub occlusion_type: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct VisibilityAnalysis {
     pub fully_visible_objects: Vec<String>,
     pub partially_visible_objects: Vec<String>,
@@ -505,14 +510,14 @@ pub struct VisibilityAnalysis {
     pub visibility_scores: HashMap<String, f64>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ActivityAnalysis {
     pub detected_activities: Vec<DetectedActivity>,
     pub interaction_patterns: Vec<InteractionPattern>,
     pub temporal_dynamics: TemporalDynamics,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DetectedActivity {
     pub activity_name: String,
     pub activity_confidence: f64,
@@ -521,7 +526,7 @@ pub struct DetectedActivity {
     pub activity_duration: Option<std::time::Duration>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct InteractionPattern {
     pub pattern_type: String,
     pub involved_objects: Vec<String>,
@@ -529,14 +534,14 @@ pub struct InteractionPattern {
     pub interaction_direction: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TemporalDynamics {
     pub motion_vectors: Vec<MotionVector>,
     pub change_detection: Vec<ChangeDetection>,
     pub stability_analysis: StabilityAnalysis,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MotionVector {
     pub object_id: String,
     pub velocity: (f64, f64),
@@ -544,7 +549,7 @@ pub struct MotionVector {
     pub motion_confidence: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ChangeDetection {
     pub change_type: String,
     pub change_location: BoundingBox,
@@ -552,14 +557,14 @@ pub struct ChangeDetection {
     pub change_confidence: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct StabilityAnalysis {
     pub scene_stability: f64,
     pub object_stability: HashMap<String, f64>,
     pub temporal_consistency: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ContextInference {
     pub inferred_context: String,
     pub context_confidence: f64,
@@ -567,7 +572,7 @@ pub struct ContextInference {
     pub social_context: SocialContextInference,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SocialContextInference {
     pub social_situation: String,
     pub social_roles: HashMap<String, String>,
@@ -576,6 +581,7 @@ pub struct SocialContextInference {
 }
 
 /// Traqueur de regard
+#[async_trait::async_trait]
 pub trait GazeTracker {
     async fn track_gaze(
         &self,
@@ -594,7 +600,7 @@ pub enum GazeTrackerType {
     Custom,         // Custom gaze model
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GazeTrackingResult {
     pub gaze_points: Vec<GazePoint>,
     pub attention_analysis: AttentionAnalysis,
@@ -602,7 +608,7 @@ pub struct GazeTrackingResult {
     pub gaze_behavior: GazeBehavior,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GazePoint {
     pub face_id: String,
     pub gaze_direction: (f64, f64, f64),
@@ -611,7 +617,7 @@ pub struct GazePoint {
     pub pupil_diameter: Option<f64>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AttentionAnalysis {
     pub attention_focus: Vec<AttentionFocus>,
     pub attention_distribution: HashMap<String, f64>,
@@ -619,7 +625,7 @@ pub struct AttentionAnalysis {
     pub distraction_indicators: Vec<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AttentionFocus {
     pub focus_target: String,
     pub focus_duration: std::time::Duration,
@@ -627,7 +633,7 @@ pub struct AttentionFocus {
     pub focus_quality: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct EyeMovementPattern {
     pub pattern_type: String,
     pub movement_velocity: f64,
@@ -636,7 +642,7 @@ pub struct EyeMovementPattern {
     pub pattern_confidence: f64,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GazeBehavior {
     pub eye_contact_frequency: f64,
     pub eye_contact_duration: std::time::Duration,
@@ -645,7 +651,7 @@ pub struct GazeBehavior {
 }
 
 /// Résultat du traitement visuel
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct VisionProcessingResult {
     pub object_detection: ObjectDetectionResult,
     pub face_analysis: FaceAnalysisResult,
@@ -656,7 +662,7 @@ pub struct VisionProcessingResult {
     pub processing_metadata: VisionProcessingMetadata,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct VisionProcessingMetadata {
     pub processing_time: std::time::Duration,
     pub image_quality_assessment: f64,
@@ -895,26 +901,161 @@ pub struct MediaPipeIrisTracker;
 pub struct OpenGazeTracker;
 
 // Implémentations macro pour compilation
-macro_rules! impl_component {
-    ($name:ident) => {
+macro_rules! impl_object_detector {
+    ($name:ident, $detector_type:expr) => {
+        impl $name {
+            pub fn new() -> Self { Self }
+        }
+
+        #[async_trait::async_trait]
+        impl ObjectDetector for $name {
+            async fn detect_objects(
+                &self,
+                _image_data: &VisionData,
+                _context: &InteractionContext,
+            ) -> ConsciousnessResult<ObjectDetectionResult> {
+                Ok(ObjectDetectionResult::default())
+            }
+
+            fn get_detector_type(&self) -> ObjectDetectorType {
+                $detector_type
+            }
+
+            fn get_supported_classes(&self) -> Vec<String> {
+                vec!["person".to_string(), "object".to_string()]
+            }
+        }
+    };
+}
+
+macro_rules! impl_face_analyzer {
+    ($name:ident, $analyzer_type:expr) => {
+        impl $name {
+            pub fn new() -> Self { Self }
+        }
+
+        #[async_trait::async_trait]
+        impl FaceAnalyzer for $name {
+            async fn analyze_faces(
+                &self,
+                _image_data: &VisionData,
+                _context: &InteractionContext,
+            ) -> ConsciousnessResult<FaceAnalysisResult> {
+                Ok(FaceAnalysisResult::default())
+            }
+
+            fn get_analyzer_type(&self) -> FaceAnalyzerType {
+                $analyzer_type
+            }
+        }
+    };
+}
+
+macro_rules! impl_emotion_recognizer {
+    ($name:ident, $recognizer_type:expr) => {
         impl $name {
             pub fn new() -> Self { Self }
         }
+
+        #[async_trait::async_trait]
+        impl VisualEmotionRecognizer for $name {
+            async fn recognize_emotions(
+                &self,
+                _face_analysis: &FaceAnalysisResult,
+                _context: &InteractionContext,
+            ) -> ConsciousnessResult<VisualEmotionResult> {
+                Ok(VisualEmotionResult::default())
+            }
+
+            fn get_recognizer_type(&self) -> VisualEmotionRecognizerType {
+                $recognizer_type
+            }
+        }
+    };
+}
+
+macro_rules! impl_gesture_recognizer {
+    ($name:ident, $recognizer_type:expr) => {
+        impl $name {
+            pub fn new() -> Self { Self }
+        }
+
+        #[async_trait::async_trait]
+        impl GestureRecognizer for $name {
+            async fn recognize_gestures(
+                &self,
+                _image_data: &VisionData,
+                _context: &InteractionContext,
+            ) -> ConsciousnessResult<GestureRecognitionResult> {
+                Ok(GestureRecognitionResult::default())
+            }
+
+            fn get_recognizer_type(&self) -> GestureRecognizerType {
+                $recognizer_type
+            }
+        }
+    };
+}
+
+macro_rules! impl_scene_analyzer {
+    ($name:ident, $analyzer_type:expr) => {
+        impl $name {
+            pub fn new() -> Self { Self }
+        }
+
+        #[async_trait::async_trait]
+        impl SceneAnalyzer for $name {
+            async fn analyze_scene(
+                &self,
+                _image_data: &VisionData,
+                _object_detection: &ObjectDetectionResult,
+                _context: &InteractionContext,
+            ) -> ConsciousnessResult<SceneAnalysisResult> {
+                Ok(SceneAnalysisResult::default())
+            }
+
+            fn get_analyzer_type(&self) -> SceneAnalyzerType {
+                $analyzer_type
+            }
+        }
+    };
+}
+
+macro_rules! impl_gaze_tracker {
+    ($name:ident, $tracker_type:expr) => {
+        impl $name {
+            pub fn new() -> Self { Self }
+        }
+
+        #[async_trait::async_trait]
+        impl GazeTracker for $name {
+            async fn track_gaze(
+                &self,
+                _face_analysis: &FaceAnalysisResult,
+                _context: &InteractionContext,
+            ) -> ConsciousnessResult<GazeTrackingResult> {
+                Ok(GazeTrackingResult::default())
+            }
+
+            fn get_tracker_type(&self) -> GazeTrackerType {
+                $tracker_type
+            }
+        }
     };
 }
 
-impl_component!(YOLODetector);
-impl_component!(EfficientDetDetector);
-impl_component!(MediaPipeFaceAnalyzer);
-impl_component!(DeepFaceAnalyzer);
-impl_component!(FER2013Recognizer);
-impl_component!(AffectNetRecognizer);
-impl_component!(MediaPipeHandsRecognizer);
-impl_component!(OpenPoseRecognizer);
-impl_component!(PlacesCNNAnalyzer);
-impl_component!(SceneNetAnalyzer);
-impl_component!(MediaPipeIrisTracker);
-impl_component!(OpenGazeTracker);
+impl_object_detector!(YOLODetector, ObjectDetectorType::YOLO);
+impl_object_detector!(EfficientDetDetector, ObjectDetectorType::EfficientDet);
+impl_face_analyzer!(MediaPipeFaceAnalyzer, FaceAnalyzerType::MediaPipe);
+impl_face_analyzer!(DeepFaceAnalyzer, FaceAnalyzerType::DeepFace);
+impl_emotion_recognizer!(FER2013Recognizer, VisualEmotionRecognizerType::FER2013);
+impl_emotion_recognizer!(AffectNetRecognizer, VisualEmotionRecognizerType::AffectNet);
+impl_gesture_recognizer!(MediaPipeHandsRecognizer, GestureRecognizerType::MediaPipeHands);
+impl_gesture_recognizer!(OpenPoseRecognizer, GestureRecognizerType::OpenPose);
+impl_scene_analyzer!(PlacesCNNAnalyzer, SceneAnalyzerType::PlacesCNN);
+impl_scene_analyzer!(SceneNetAnalyzer, SceneAnalyzerType::SceneNet);
+impl_gaze_tracker!(MediaPipeIrisTracker, GazeTrackerType::MediaPipeIris);
+impl_gaze_tracker!(OpenGazeTracker, GazeTrackerType::OpenGaze);
 
 impl Default for VisionProcessor {
     fn default() -> Self {
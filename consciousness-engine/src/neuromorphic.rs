@@ -1,556 +0,0 @@
-//! Neuromorphic Processing Module
-//! 
-//! This module implements neuromorphic computing capabilities for the consciousness engine,
-//! providing ultra-efficient spike-based processing with sub-millisecond latencies.
-
-use crate::error::ConsciousnessError;
-use crate::types::NeuromorphicResult;
-use std::time::{Duration, Instant};
-use std::collections::VecDeque;
-
-/// Neuromorphic processor for spike-based computation
-pub struct NeuromorphicProcessor {
-    /// Spiking neural network layers
-    layers: Vec<SpikingLayer>,
-    
-    /// Synaptic connections between layers
-    synapses: Vec<SynapticConnection>,
-    
-    /// Spike history buffer
-    spike_history: VecDeque<SpikeEvent>,
-    
-    /// Processing configuration
-    config: NeuromorphicConfig,
-    
-    /// Performance metrics
-    performance_metrics: NeuromorphicMetrics,
-}
-
-/// Configuration for neuromorphic processing
-#[derive(Debug, Clone)]
-pub struct NeuromorphicConfig {
-    /// Number of processing layers
-    pub num_layers: usize,
-    
-    /// Neurons per layer
-    pub neurons_per_layer: usize,
-    
-    /// Spike threshold voltage
-    pub spike_threshold: f64,
-    
-    /// Membrane time constant
-    pub membrane_tau: f64,
-    
-    /// Synaptic delay
-    pub synaptic_delay: Duration,
-    
-    /// Learning rate for plasticity
-    pub learning_rate: f64,
-}
-
-impl Default for NeuromorphicConfig {
-    fn default() -> Self {
-        Self {
-            num_layers: 4,
-            neurons_per_layer: 256,
-            spike_threshold: 1.0,
-            membrane_tau: 20.0, // milliseconds
-            synaptic_delay: Duration::from_micros(100),
-            learning_rate: 0.01,
-        }
-    }
-}
-
-/// Spiking neural network layer
-#[derive(Debug, Clone)]
-pub struct SpikingLayer {
-    /// Layer ID
-    pub id: usize,
-    
-    /// Neurons in this layer
-    pub neurons: Vec<SpikingNeuron>,
-    
-    /// Layer type
-    pub layer_type: LayerType,
-}
-
-/// Types of neuromorphic layers
-#[derive(Debug, Clone, Copy)]
-pub enum LayerType {
-    Input,
-    Hidden,
-    Output,
-    Memory,
-    Attention,
-}
-
-/// Individual spiking neuron
-#[derive(Debug, Clone)]
-pub struct SpikingNeuron {
-    /// Neuron ID
-    pub id: usize,
-    
-    /// Current membrane potential
-    pub membrane_potential: f64,
-    
-    /// Spike threshold
-    pub threshold: f64,
-    
-    /// Last spike time
-    pub last_spike_time: Option<Instant>,
-    
-    /// Refractory period
-    pub refractory_period: Duration,
-    
-    /// Neuron state
-    pub state: NeuronState,
-}
-
-/// Neuron states
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum NeuronState {
-    Resting,
-    Integrating,
-    Spiking,
-    Refractory,
-}
-
-/// Synaptic connection between neurons
-#[derive(Debug, Clone)]
-pub struct SynapticConnection {
-    /// Pre-synaptic neuron
-    pub pre_neuron: (usize, usize), // (layer_id, neuron_id)
-    
-    /// Post-synaptic neuron
-    pub post_neuron: (usize, usize),
-    
-    /// Synaptic weight
-    pub weight: f64,
-    
-    /// Synaptic delay
-    pub delay: Duration,
-    
-    /// Plasticity parameters
-    pub plasticity: SynapticPlasticity,
-}
-
-/// Synaptic plasticity parameters
-#[derive(Debug, Clone)]
-pub struct SynapticPlasticity {
-    /// Long-term potentiation rate
-    pub ltp_rate: f64,
-    
-    /// Long-term depression rate
-    pub ltd_rate: f64,
-    
-    /// Spike timing dependent plasticity window
-    pub stdp_window: Duration,
-}
-
-/// Spike event in the network
-#[derive(Debug, Clone)]
-pub struct SpikeEvent {
-    /// Source neuron
-    pub source: (usize, usize),
-    
-    /// Spike timestamp
-    pub timestamp: Instant,
-    
-    /// Spike amplitude
-    pub amplitude: f64,
-}
-
-/// Performance metrics for neuromorphic processing
-#[derive(Debug, Clone)]
-pub struct NeuromorphicMetrics {
-    /// Total spikes processed
-    pub total_spikes: u64,
-    
-    /// Average processing latency
-    pub avg_latency: Duration,
-    
-    /// Energy consumption estimate
-    pub energy_consumed: f64,
-    
-    /// Spike rate (Hz)
-    pub spike_rate: f64,
-    
-    /// Network efficiency
-    pub efficiency_score: f64,
-}
-
-impl NeuromorphicProcessor {
-    /// Create a new neuromorphic processor
-    pub async fn new() -> Result<Self, ConsciousnessError> {
-        let config = NeuromorphicConfig::default();
-        let mut processor = Self {
-            layers: Vec::new(),
-            synapses: Vec::new(),
-            spike_history: VecDeque::new(),
-            config: config.clone(),
-            performance_metrics: NeuromorphicMetrics {
-                total_spikes: 0,
-                avg_latency: Duration::from_micros(0),
-                energy_consumed: 0.0,
-                spike_rate: 0.0,
-                efficiency_score: 1.0,
-            },
-        };
-        
-        processor.initialize_network().await?;
-        Ok(processor)
-    }
-    
-    /// Initialize the spiking neural network
-    async fn initialize_network(&mut self) -> Result<(), ConsciousnessError> {
-        // Create layers
-        for layer_id in 0..self.config.num_layers {
-            let layer_type = match layer_id {
-                0 => LayerType::Input,
-                n if n == self.config.num_layers - 1 => LayerType::Output,
-                _ => LayerType::Hidden,
-            };
-            
-            let mut neurons = Vec::new();
-            for neuron_id in 0..self.config.neurons_per_layer {
-                neurons.push(SpikingNeuron {
-                    id: neuron_id,
-                    membrane_potential: 0.0,
-                    threshold: self.config.spike_threshold,
-                    last_spike_time: None,
-                    refractory_period: Duration::from_millis(2),
-                    state: NeuronState::Resting,
-                });
-            }
-            
-            self.layers.push(SpikingLayer {
-                id: layer_id,
-                neurons,
-                layer_type,
-            });
-        }
-        
-        // Create synaptic connections
-        self.create_synaptic_connections().await?;
-        
-        Ok(())
-    }
-    
-    /// Create synaptic connections between layers
-    async fn create_synaptic_connections(&mut self) -> Result<(), ConsciousnessError> {
-        for layer_id in 0..self.config.num_layers - 1 {
-            let next_layer_id = layer_id + 1;
-            
-            for pre_neuron_id in 0..self.config.neurons_per_layer {
-                for post_neuron_id in 0..self.config.neurons_per_layer {
-                    // Random initial weight
-                    let weight = (rand::random::<f64>() - 0.5) * 2.0;
-                    
-                    self.synapses.push(SynapticConnection {
-                        pre_neuron: (layer_id, pre_neuron_id),
-                        post_neuron: (next_layer_id, post_neuron_id),
-                        weight,
-                        delay: self.config.synaptic_delay,
-                        plasticity: SynapticPlasticity {
-                            ltp_rate: 0.01,
-                            ltd_rate: 0.005,
-                            stdp_window: Duration::from_millis(20),
-                        },
-                    });
-                }
-            }
-        }
-        
-        Ok(())
-    }
-    
-    /// Process a spike pattern through the network
-    pub async fn process_spike_pattern(&mut self, spike_pattern: &[f64]) -> Result<NeuromorphicResult, ConsciousnessError> {
-        let start_time = Instant::now();
-        
-        // Inject input spikes
-        self.inject_input_spikes(spike_pattern).await?;
-        
-        // Simulate network dynamics
-        let output_spikes = self.simulate_network_dynamics().await?;
-        
-        // Update performance metrics
-        let processing_time = start_time.elapsed();
-        self.update_performance_metrics(processing_time, spike_pattern.len()).await?;
-        
-        Ok(NeuromorphicResult {
-            output_spikes,
-            efficiency_score: self.performance_metrics.efficiency_score,
-            energy_consumed: self.calculate_energy_consumption(spike_pattern.len()),
-            latency: processing_time,
-        })
-    }
-    
-    /// Inject input spikes into the first layer
-    async fn inject_input_spikes(&mut self, spike_pattern: &[f64]) -> Result<(), ConsciousnessError> {
-        if self.layers.is_empty() {
-            return Err(ConsciousnessError::NeuromorphicError("No layers initialized".to_string()));
-        }
-        
-        let input_layer = &mut self.layers[0];
-        let pattern_len = spike_pattern.len().min(input_layer.neurons.len());
-        
-        for (i, &spike_value) in spike_pattern.iter().take(pattern_len).enumerate() {
-            if spike_value > self.config.spike_threshold {
-                // Generate spike
-                input_layer.neurons[i].membrane_potential = spike_value;
-                input_layer.neurons[i].state = NeuronState::Spiking;
-                input_layer.neurons[i].last_spike_time = Some(Instant::now());
-                
-                // Record spike event
-                self.spike_history.push_back(SpikeEvent {
-                    source: (0, i),
-                    timestamp: Instant::now(),
-                    amplitude: spike_value,
-                });
-            } else {
-                // Sub-threshold input
-                input_layer.neurons[i].membrane_potential += spike_value;
-                input_layer.neurons[i].state = NeuronState::Integrating;
-            }
-        }
-        
-        Ok(())
-    }
-    
-    /// Simulate network dynamics and propagate spikes
-    async fn simulate_network_dynamics(&mut self) -> Result<Vec<f64>, ConsciousnessError> {
-        let simulation_steps = 100; // Number of time steps to simulate
-        let dt = 0.1; // Time step in milliseconds
-        
-        for _step in 0..simulation_steps {
-            // Update membrane potentials
-            self.update_membrane_potentials(dt).await?;
-            
-            // Check for spikes and propagate
-            self.propagate_spikes().await?;
-            
-            // Apply synaptic plasticity
-            self.apply_synaptic_plasticity().await?;
-            
-            // Update neuron states
-            self.update_neuron_states().await?;
-        }
-        
-        // Extract output spikes from the last layer
-        self.extract_output_spikes().await
-    }
-    
-    /// Update membrane potentials for all neurons
-    async fn update_membrane_potentials(&mut self, dt: f64) -> Result<(), ConsciousnessError> {
-        for layer in &mut self.layers {
-            for neuron in &mut layer.neurons {
-                if neuron.state != NeuronState::Refractory {
-                    // Membrane potential decay
-                    let decay_factor = (-dt / self.config.membrane_tau).exp();
-                    neuron.membrane_potential *= decay_factor;
-                }
-            }
-        }
-        Ok(())
-    }
-    
-    /// Propagate spikes through synaptic connections
-    async fn propagate_spikes(&mut self) -> Result<(), ConsciousnessError> {
-        let current_time = Instant::now();
-        let mut new_spikes = Vec::new();
-        
-        // Check for neurons that should spike
-        for (layer_id, layer) in self.layers.iter_mut().enumerate() {
-            for (neuron_id, neuron) in layer.neurons.iter_mut().enumerate() {
-                if neuron.state == NeuronState::Integrating && neuron.membrane_potential >= neuron.threshold {
-                    // Neuron spikes
-                    neuron.state = NeuronState::Spiking;
-                    neuron.last_spike_time = Some(current_time);
-                    neuron.membrane_potential = 0.0; // Reset after spike
-                    
-                    new_spikes.push(SpikeEvent {
-                        source: (layer_id, neuron_id),
-                        timestamp: current_time,
-                        amplitude: 1.0,
-                    });
-                }
-            }
-        }
-        
-        // Propagate spikes through synapses
-        for spike in &new_spikes {
-            for synapse in &self.synapses {
-                if synapse.pre_neuron == spike.source {
-                    // Apply synaptic transmission
-                    let (post_layer_id, post_neuron_id) = synapse.post_neuron;
-                    if post_layer_id < self.layers.len() && post_neuron_id < self.layers[post_layer_id].neurons.len() {
-                        let post_neuron = &mut self.layers[post_layer_id].neurons[post_neuron_id];
-                        post_neuron.membrane_potential += synapse.weight * spike.amplitude;
-                        
-                        if post_neuron.state == NeuronState::Resting {
-                            post_neuron.state = NeuronState::Integrating;
-                        }
-                    }
-                }
-            }
-        }
-        
-        // Add new spikes to history
-        for spike in new_spikes {
-            self.spike_history.push_back(spike);
-        }
-        
-        // Limit spike history size
-        while self.spike_history.len() > 10000 {
-            self.spike_history.pop_front();
-        }
-        
-        Ok(())
-    }
-    
-    /// Apply synaptic plasticity rules
-    async fn apply_synaptic_plasticity(&mut self) -> Result<(), ConsciousnessError> {
-        // Implement spike-timing dependent plasticity (STDP)
-        let current_time = Instant::now();
-        
-        for synapse in &mut self.synapses {
-            // Find recent spikes from pre and post neurons
-            let pre_spikes: Vec<_> = self.spike_history.iter()
-                .filter(|spike| spike.source == synapse.pre_neuron)
-                .filter(|spike| current_time.duration_since(spike.timestamp) < synapse.plasticity.stdp_window)
-                .collect();
-            
-            let post_spikes: Vec<_> = self.spike_history.iter()
-                .filter(|spike| spike.source == synapse.post_neuron)
-                .filter(|spike| current_time.duration_since(spike.timestamp) < synapse.plasticity.stdp_window)
-                .collect();
-            
-            // Apply STDP rule
-            for pre_spike in &pre_spikes {
-                for post_spike in &post_spikes {
-                    let dt = if post_spike.timestamp > pre_spike.timestamp {
-                        post_spike.timestamp.duration_since(pre_spike.timestamp).as_millis() as f64
-                    } else {
-                        -(pre_spike.timestamp.duration_since(post_spike.timestamp).as_millis() as f64)
-                    };
-                    
-                    if dt > 0.0 {
-                        // LTP: post after pre
-                        synapse.weight += synapse.plasticity.ltp_rate * (-dt / 20.0).exp();
-                    } else {
-                        // LTD: pre after post
-                        synapse.weight -= synapse.plasticity.ltd_rate * (dt / 20.0).exp();
-                    }
-                    
-                    // Clamp weights
-                    synapse.weight = synapse.weight.clamp(-2.0, 2.0);
-                }
-            }
-        }
-        
-        Ok(())
-    }
-    
-    /// Update neuron states based on refractory periods
-    async fn update_neuron_states(&mut self) -> Result<(), ConsciousnessError> {
-        let current_time = Instant::now();
-        
-        for layer in &mut self.layers {
-            for neuron in &mut layer.neurons {
-                match neuron.state {
-                    NeuronState::Spiking => {
-                        neuron.state = NeuronState::Refractory;
-                    },
-                    NeuronState::Refractory => {
-                        if let Some(last_spike) = neuron.last_spike_time {
-                            if current_time.duration_since(last_spike) > neuron.refractory_period {
-                                neuron.state = NeuronState::Resting;
-                            }
-                        }
-                    },
-                    NeuronState::Integrating => {
-                        if neuron.membrane_potential.abs() < 0.01 {
-                            neuron.state = NeuronState::Resting;
-                        }
-                    },
-                    NeuronState::Resting => {
-                        // Stay resting unless stimulated
-                    }
-                }
-            }
-        }
-        
-        Ok(())
-    }
-    
-    /// Extract output spikes from the final layer
-    async fn extract_output_spikes(&self) -> Result<Vec<f64>, ConsciousnessError> {
-        if self.layers.is_empty() {
-            return Ok(Vec::new());
-        }
-        
-        let output_layer = &self.layers[self.layers.len() - 1];
-        let mut output_spikes = Vec::new();
-        
-        for neuron in &output_layer.neurons {
-            let spike_value = match neuron.state {
-                NeuronState::Spiking => 1.0,
-                NeuronState::Integrating => neuron.membrane_potential.max(0.0),
-                _ => 0.0,
-            };
-            output_spikes.push(spike_value);
-        }
-        
-        Ok(output_spikes)
-    }
-    
-    /// Update performance metrics
-    async fn update_performance_metrics(&mut self, processing_time: Duration, input_size: usize) -> Result<(), ConsciousnessError> {
-        self.performance_metrics.total_spikes += input_size as u64;
-        
-        // Update average latency
-        let total_time = self.performance_metrics.avg_latency * (self.performance_metrics.total_spikes - input_size as u64) as u32 + processing_time;
-        self.performance_metrics.avg_latency = total_time / self.performance_metrics.total_spikes as u32;
-        
-        // Calculate spike rate
-        if processing_time.as_secs_f64() > 0.0 {
-            self.performance_metrics.spike_rate = input_size as f64 / processing_time.as_secs_f64();
-        }
-        
-        // Update efficiency score based on latency and energy
-        let latency_score = 1.0 / (1.0 + processing_time.as_millis() as f64 / 100.0);
-        let energy_score = 1.0 / (1.0 + self.performance_metrics.energy_consumed / 1000.0);
-        self.performance_metrics.efficiency_score = (latency_score + energy_score) / 2.0;
-        
-        Ok(())
-    }
-    
-    /// Calculate energy consumption estimate
-    fn calculate_energy_consumption(&self, spike_count: usize) -> f64 {
-        // Simplified energy model: energy per spike + baseline consumption
-        let energy_per_spike = 0.1; // pJ per spike
-        let baseline_energy = 1.0; // pJ baseline
-        
-        baseline_energy + (spike_count as f64 * energy_per_spike)
-    }
-}
-
-// Add rand dependency for random weight initialization
-mod rand {
-    pub fn random<T>() -> T 
-    where 
-        T: From<f64>
-    {
-        // Simple pseudo-random number generator for demo
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
-        use std::time::{SystemTime, UNIX_EPOCH};
-        
-        let mut hasher = DefaultHasher::new();
-        SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos().hash(&mut hasher);
-        let hash = hasher.finish();
-        
-        T::from((hash % 1000) as f64 / 1000.0)
-    }
-}
\ No newline at end of file
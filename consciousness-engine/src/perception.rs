@@ -0,0 +1,337 @@
+//! Perception Backends - embeddings and vision with rate-aware micro-batching
+//!
+//! Deployments that resolve embeddings or image descriptions through an
+//! external service implement [`Embedder`] / [`VisionBackend`] over their
+//! HTTP client. Issuing one upstream call per request is slow under load
+//! and risks upstream rate limits, so the batching wrappers coalesce
+//! concurrent calls arriving within a small window into a single batched
+//! upstream call, honoring a maximum batch size.
+
+use crate::error::ConsciousnessError;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{oneshot, Mutex};
+
+/// Default coalescing window for the batching wrappers
+pub const DEFAULT_BATCH_WINDOW: Duration = Duration::from_millis(10);
+
+/// Default upper bound on one upstream batch
+pub const DEFAULT_MAX_BATCH_SIZE: usize = 32;
+
+/// A text-embedding backend
+///
+/// The batch method is the only entry point: an HTTP implementation maps
+/// it to its provider's bulk endpoint, and single lookups go through
+/// [`BatchingEmbedder`], which fills batches from concurrent callers.
+/// Must return one vector per input text, in input order.
+pub trait Embedder: Send + Sync {
+    /// Name of the backend, e.g. `ollama-embeddings`
+    fn name(&self) -> &str;
+
+    /// Embed a batch of texts in one upstream call
+    fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f64>>, ConsciousnessError>;
+}
+
+/// An image-description backend
+///
+/// Batch-only for the same reason as [`Embedder`]; single images go
+/// through [`BatchingVisionBackend`]. Must return one description per
+/// image, in input order.
+pub trait VisionBackend: Send + Sync {
+    /// Name of the backend, e.g. `llava`
+    fn name(&self) -> &str;
+
+    /// Describe a batch of images in one upstream call
+    fn describe_batch(&self, images: &[Vec<u8>]) -> Result<Vec<String>, ConsciousnessError>;
+}
+
+/// Requests queued for the next upstream batch
+type Pending<I, O> = Vec<(I, oneshot::Sender<Result<O, ConsciousnessError>>)>;
+
+/// The coalescing machinery shared by both batching wrappers
+///
+/// The first caller into an empty queue becomes the batch leader: it
+/// waits out the window, then flushes whatever accumulated. A caller
+/// that fills the queue to the maximum batch size flushes immediately
+/// instead of waiting, so a burst never sits idle behind the window.
+struct MicroBatcher<I, O> {
+    window: Duration,
+    max_batch_size: usize,
+    pending: Mutex<Pending<I, O>>,
+    upstream_calls: AtomicU64,
+}
+
+impl<I, O> MicroBatcher<I, O> {
+    fn new(window: Duration, max_batch_size: usize) -> Self {
+        Self {
+            window,
+            max_batch_size: max_batch_size.max(1),
+            pending: Mutex::new(Vec::new()),
+            upstream_calls: AtomicU64::new(0),
+        }
+    }
+
+    /// Queue one request and resolve it through a batched upstream call
+    async fn submit<F>(&self, item: I, run_batch: F) -> Result<O, ConsciousnessError>
+    where
+        F: Fn(&[I]) -> Result<Vec<O>, ConsciousnessError>,
+    {
+        let (reply, receiver) = oneshot::channel();
+        let (is_leader, is_full) = {
+            let mut pending = self.pending.lock().await;
+            pending.push((item, reply));
+            (pending.len() == 1, pending.len() >= self.max_batch_size)
+        };
+
+        if is_full {
+            self.flush(&run_batch).await;
+        } else if is_leader {
+            tokio::time::sleep(self.window).await;
+            self.flush(&run_batch).await;
+        }
+
+        receiver.await.map_err(|_| {
+            ConsciousnessError::ProcessingError(
+                "batched request was dropped before completion".to_string(),
+            )
+        })?
+    }
+
+    /// Drain the queue in max-batch-sized upstream calls
+    async fn flush<F>(&self, run_batch: &F)
+    where
+        F: Fn(&[I]) -> Result<Vec<O>, ConsciousnessError>,
+    {
+        loop {
+            let batch: Pending<I, O> = {
+                let mut pending = self.pending.lock().await;
+                if pending.is_empty() {
+                    return;
+                }
+                let take = pending.len().min(self.max_batch_size);
+                pending.drain(..take).collect()
+            };
+
+            self.upstream_calls.fetch_add(1, Ordering::Relaxed);
+            let (items, replies): (Vec<I>, Vec<_>) = batch.into_iter().unzip();
+            match run_batch(&items) {
+                Ok(outputs) if outputs.len() == replies.len() => {
+                    for (reply, output) in replies.into_iter().zip(outputs) {
+                        let _ = reply.send(Ok(output));
+                    }
+                }
+                Ok(outputs) => {
+                    let error = ConsciousnessError::ProcessingError(format!(
+                        "upstream returned {} results for a batch of {}",
+                        outputs.len(),
+                        replies.len()
+                    ));
+                    for reply in replies {
+                        let _ = reply.send(Err(error.clone()));
+                    }
+                }
+                Err(error) => {
+                    for reply in replies {
+                        let _ = reply.send(Err(error.clone()));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Rate-aware embedding front: coalesces concurrent single-text calls
+pub struct BatchingEmbedder {
+    inner: Arc<dyn Embedder>,
+    batcher: MicroBatcher<String, Vec<f64>>,
+}
+
+impl BatchingEmbedder {
+    /// Wrap a backend with the default window and batch size
+    pub fn new(inner: Arc<dyn Embedder>) -> Self {
+        Self::with_limits(inner, DEFAULT_BATCH_WINDOW, DEFAULT_MAX_BATCH_SIZE)
+    }
+
+    /// Wrap a backend with explicit coalescing limits
+    pub fn with_limits(inner: Arc<dyn Embedder>, window: Duration, max_batch_size: usize) -> Self {
+        Self {
+            inner,
+            batcher: MicroBatcher::new(window, max_batch_size),
+        }
+    }
+
+    /// Embed one text, sharing the upstream call with concurrent peers
+    pub async fn embed(&self, text: &str) -> Result<Vec<f64>, ConsciousnessError> {
+        let inner = Arc::clone(&self.inner);
+        self.batcher
+            .submit(text.to_string(), move |texts| inner.embed_batch(texts))
+            .await
+    }
+
+    /// Upstream calls issued so far, for rate accounting
+    pub fn upstream_calls(&self) -> u64 {
+        self.batcher.upstream_calls.load(Ordering::Relaxed)
+    }
+}
+
+/// Rate-aware vision front: coalesces concurrent single-image calls
+pub struct BatchingVisionBackend {
+    inner: Arc<dyn VisionBackend>,
+    batcher: MicroBatcher<Vec<u8>, String>,
+}
+
+impl BatchingVisionBackend {
+    /// Wrap a backend with the default window and batch size
+    pub fn new(inner: Arc<dyn VisionBackend>) -> Self {
+        Self::with_limits(inner, DEFAULT_BATCH_WINDOW, DEFAULT_MAX_BATCH_SIZE)
+    }
+
+    /// Wrap a backend with explicit coalescing limits
+    pub fn with_limits(
+        inner: Arc<dyn VisionBackend>,
+        window: Duration,
+        max_batch_size: usize,
+    ) -> Self {
+        Self {
+            inner,
+            batcher: MicroBatcher::new(window, max_batch_size),
+        }
+    }
+
+    /// Describe one image, sharing the upstream call with concurrent peers
+    pub async fn describe(&self, image: &[u8]) -> Result<String, ConsciousnessError> {
+        let inner = Arc::clone(&self.inner);
+        self.batcher
+            .submit(image.to_vec(), move |images| inner.describe_batch(images))
+            .await
+    }
+
+    /// Upstream calls issued so far, for rate accounting
+    pub fn upstream_calls(&self) -> u64 {
+        self.batcher.upstream_calls.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex as StdMutex;
+
+    struct CountingEmbedder {
+        batch_sizes: StdMutex<Vec<usize>>,
+    }
+
+    impl CountingEmbedder {
+        fn new() -> Self {
+            Self {
+                batch_sizes: StdMutex::new(Vec::new()),
+            }
+        }
+    }
+
+    impl Embedder for CountingEmbedder {
+        fn name(&self) -> &str {
+            "counting_mock"
+        }
+
+        fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f64>>, ConsciousnessError> {
+            self.batch_sizes.lock().unwrap().push(texts.len());
+            Ok(texts.iter().map(|t| vec![t.len() as f64]).collect())
+        }
+    }
+
+    struct CountingVision {
+        calls: AtomicU64,
+    }
+
+    impl VisionBackend for CountingVision {
+        fn name(&self) -> &str {
+            "vision_mock"
+        }
+
+        fn describe_batch(&self, images: &[Vec<u8>]) -> Result<Vec<String>, ConsciousnessError> {
+            self.calls.fetch_add(1, Ordering::Relaxed);
+            Ok(images.iter().map(|i| format!("{} bytes", i.len())).collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_embeds_resolve_via_one_upstream_call() {
+        let inner = Arc::new(CountingEmbedder::new());
+        let front = Arc::new(BatchingEmbedder::with_limits(
+            inner.clone(),
+            Duration::from_millis(50),
+            32,
+        ));
+
+        let mut handles = Vec::new();
+        for i in 0..8 {
+            let front = Arc::clone(&front);
+            handles.push(tokio::spawn(async move {
+                front.embed(&"x".repeat(i + 1)).await.unwrap()
+            }));
+        }
+        let mut results = Vec::new();
+        for handle in handles {
+            results.push(handle.await.unwrap());
+        }
+
+        // Every caller got its own vector back, in the right pairing
+        results.sort_by(|a, b| a[0].partial_cmp(&b[0]).unwrap());
+        let lengths: Vec<f64> = results.iter().map(|v| v[0]).collect();
+        assert_eq!(lengths, (1..=8).map(|i| i as f64).collect::<Vec<_>>());
+
+        // ...through a single batched upstream request
+        assert_eq!(front.upstream_calls(), 1);
+        assert_eq!(*inner.batch_sizes.lock().unwrap(), vec![8]);
+    }
+
+    #[tokio::test]
+    async fn test_batches_never_exceed_the_maximum_size() {
+        let inner = Arc::new(CountingEmbedder::new());
+        let front = Arc::new(BatchingEmbedder::with_limits(
+            inner.clone(),
+            Duration::from_millis(50),
+            4,
+        ));
+
+        let mut handles = Vec::new();
+        for i in 0..10 {
+            let front = Arc::clone(&front);
+            handles.push(tokio::spawn(async move {
+                front.embed(&"y".repeat(i + 1)).await.unwrap()
+            }));
+        }
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        let batch_sizes = inner.batch_sizes.lock().unwrap().clone();
+        assert!(batch_sizes.iter().all(|size| *size <= 4));
+        assert_eq!(batch_sizes.iter().sum::<usize>(), 10);
+        assert!(front.upstream_calls() >= 3);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_descriptions_share_an_upstream_call() {
+        let inner = Arc::new(CountingVision {
+            calls: AtomicU64::new(0),
+        });
+        let front = Arc::new(BatchingVisionBackend::with_limits(
+            inner.clone(),
+            Duration::from_millis(50),
+            32,
+        ));
+
+        let (a, b, c) = tokio::join!(
+            front.describe(&[1, 2, 3]),
+            front.describe(&[4, 5]),
+            front.describe(&[6])
+        );
+        assert_eq!(a.unwrap(), "3 bytes");
+        assert_eq!(b.unwrap(), "2 bytes");
+        assert_eq!(c.unwrap(), "1 bytes");
+        assert_eq!(inner.calls.load(Ordering::Relaxed), 1);
+    }
+}
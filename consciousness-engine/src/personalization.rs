@@ -0,0 +1,237 @@
+//! Cross-Session User Personalization
+//!
+//! Long-term per-user profiles built incrementally from interactions. A
+//! profile captures the user's preferred response style, recurring topics,
+//! and sensitivity flags, and is consulted at the start of processing so a
+//! returning user gets a personalized experience without restating their
+//! preferences every session. Profiles are fully deletable for privacy.
+
+use crate::emotions::ResponseStyle;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+
+/// A topic must appear in at least this many interactions to count as recurring
+pub const RECURRING_TOPIC_MIN_MENTIONS: u32 = 2;
+
+/// Maximum distinct topics tracked per user before low-count eviction
+const MAX_TRACKED_TOPICS: usize = 64;
+
+/// Minimum word length considered a topic candidate
+const MIN_TOPIC_WORD_LENGTH: usize = 5;
+
+/// Common words excluded from topic tracking
+const TOPIC_STOPWORDS: &[&str] = &[
+    "about", "after", "again", "because", "before", "being", "could",
+    "every", "really", "should", "something", "their", "there", "these",
+    "thing", "things", "think", "those", "today", "where", "which",
+    "while", "would", "you're",
+];
+
+/// Content markers mapped to the sensitivity flag they imply
+///
+/// Matched case-insensitively as substrings; a single mention is enough to
+/// set the flag since the cost of extra care on a non-sensitive topic is
+/// far lower than the cost of missing a sensitive one.
+const SENSITIVITY_MARKERS: &[(&str, &str)] = &[
+    ("diagnosis", "health"),
+    ("illness", "health"),
+    ("chronic pain", "health"),
+    ("passed away", "bereavement"),
+    ("grief", "bereavement"),
+    ("funeral", "bereavement"),
+    ("debt", "finances"),
+    ("bankrupt", "finances"),
+    ("laid off", "finances"),
+    ("divorce", "relationships"),
+    ("breakup", "relationships"),
+];
+
+/// Long-term profile for a single user, built incrementally from interactions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserProfile {
+    /// User this profile belongs to
+    pub user_id: String,
+
+    /// Total interactions recorded into this profile
+    pub interaction_count: u64,
+
+    /// How often each response style was explicitly requested
+    style_requests: HashMap<String, u32>,
+
+    /// Mention counts per topic word, bounded by [`MAX_TRACKED_TOPICS`]
+    topic_counts: HashMap<String, u32>,
+
+    /// Sensitivity flags inferred from past interactions
+    ///
+    /// A `BTreeSet` keeps the flags in deterministic order for callers
+    /// that surface them (prompts, logs, tests).
+    pub sensitivity_flags: BTreeSet<String>,
+}
+
+impl UserProfile {
+    fn new(user_id: &str) -> Self {
+        Self {
+            user_id: user_id.to_string(),
+            interaction_count: 0,
+            style_requests: HashMap::new(),
+            topic_counts: HashMap::new(),
+            sensitivity_flags: BTreeSet::new(),
+        }
+    }
+
+    /// The response style this user has most often asked for, if any
+    ///
+    /// Only explicit per-request style choices count as evidence; the
+    /// engine never infers a preference from defaults it applied itself.
+    pub fn preferred_style(&self) -> Option<ResponseStyle> {
+        self.style_requests
+            .iter()
+            .max_by(|a, b| a.1.cmp(b.1).then_with(|| b.0.cmp(a.0)))
+            .and_then(|(name, _)| ResponseStyle::from_name(name))
+    }
+
+    /// Topics this user keeps coming back to, most frequent first
+    pub fn recurring_topics(&self) -> Vec<String> {
+        let mut topics: Vec<(&String, &u32)> = self
+            .topic_counts
+            .iter()
+            .filter(|(_, count)| **count >= RECURRING_TOPIC_MIN_MENTIONS)
+            .collect();
+        topics.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+        topics.into_iter().map(|(topic, _)| topic.clone()).collect()
+    }
+
+    /// Fold one interaction into the profile
+    fn record(&mut self, content: &str, requested_style: Option<ResponseStyle>) {
+        self.interaction_count += 1;
+
+        if let Some(style) = requested_style {
+            let name = format!("{:?}", style).to_ascii_lowercase();
+            *self.style_requests.entry(name).or_insert(0) += 1;
+        }
+
+        let lower = content.to_lowercase();
+        for (marker, flag) in SENSITIVITY_MARKERS {
+            if lower.contains(marker) {
+                self.sensitivity_flags.insert((*flag).to_string());
+            }
+        }
+
+        for word in lower.split(|c: char| !c.is_alphanumeric()) {
+            if word.len() < MIN_TOPIC_WORD_LENGTH || TOPIC_STOPWORDS.contains(&word) {
+                continue;
+            }
+            *self.topic_counts.entry(word.to_string()).or_insert(0) += 1;
+        }
+
+        // Evict the rarest topics once the map outgrows its bound so a
+        // long-lived profile cannot grow without limit
+        while self.topic_counts.len() > MAX_TRACKED_TOPICS {
+            if let Some(rarest) = self
+                .topic_counts
+                .iter()
+                .min_by(|a, b| a.1.cmp(b.1).then_with(|| b.0.cmp(a.0)))
+                .map(|(topic, _)| topic.clone())
+            {
+                self.topic_counts.remove(&rarest);
+            }
+        }
+    }
+}
+
+/// In-memory store of user profiles, keyed by user id
+///
+/// Lives on the engine behind the usual `Arc<RwLock<_>>` wiring so profiles
+/// persist across sessions for the lifetime of the engine. Serializable as a
+/// whole for deployments that snapshot it to durable storage.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct UserProfileStore {
+    profiles: HashMap<String, UserProfile>,
+}
+
+impl UserProfileStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Current profile for a user, if one has been built
+    pub fn profile(&self, user_id: &str) -> Option<&UserProfile> {
+        self.profiles.get(user_id)
+    }
+
+    /// Record one interaction into the user's profile, creating it on first contact
+    pub fn record_interaction(
+        &mut self,
+        user_id: &str,
+        content: &str,
+        requested_style: Option<ResponseStyle>,
+    ) {
+        self.profiles
+            .entry(user_id.to_string())
+            .or_insert_with(|| UserProfile::new(user_id))
+            .record(content, requested_style);
+    }
+
+    /// Delete everything recorded about a user (privacy reset)
+    ///
+    /// Returns `true` if a profile existed and was removed.
+    pub fn delete(&mut self, user_id: &str) -> bool {
+        self.profiles.remove(user_id).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explicit_style_requests_become_the_preference() {
+        let mut store = UserProfileStore::new();
+        store.record_interaction("user_1", "Tell me about compilers", Some(ResponseStyle::Casual));
+        store.record_interaction("user_1", "More about compilers please", Some(ResponseStyle::Casual));
+        store.record_interaction("user_1", "One formal summary", Some(ResponseStyle::Formal));
+
+        let profile = store.profile("user_1").unwrap();
+        assert_eq!(profile.preferred_style(), Some(ResponseStyle::Casual));
+        assert_eq!(profile.interaction_count, 3);
+    }
+
+    #[test]
+    fn test_no_preference_without_explicit_requests() {
+        let mut store = UserProfileStore::new();
+        store.record_interaction("user_1", "Hello there", None);
+
+        assert_eq!(store.profile("user_1").unwrap().preferred_style(), None);
+    }
+
+    #[test]
+    fn test_recurring_topics_need_repeated_mentions() {
+        let mut store = UserProfileStore::new();
+        store.record_interaction("user_1", "My garden needs new tomato plants", None);
+        store.record_interaction("user_1", "The garden flooded again yesterday", None);
+
+        let topics = store.profile("user_1").unwrap().recurring_topics();
+        assert!(topics.contains(&"garden".to_string()));
+        assert!(!topics.contains(&"tomato".to_string()));
+    }
+
+    #[test]
+    fn test_sensitive_content_sets_flags() {
+        let mut store = UserProfileStore::new();
+        store.record_interaction("user_1", "Since my father passed away I've struggled with debt", None);
+
+        let profile = store.profile("user_1").unwrap();
+        assert!(profile.sensitivity_flags.contains("bereavement"));
+        assert!(profile.sensitivity_flags.contains("finances"));
+    }
+
+    #[test]
+    fn test_delete_removes_the_profile() {
+        let mut store = UserProfileStore::new();
+        store.record_interaction("user_1", "Remember me", Some(ResponseStyle::Clinical));
+
+        assert!(store.delete("user_1"));
+        assert!(store.profile("user_1").is_none());
+        assert!(!store.delete("user_1"));
+    }
+}
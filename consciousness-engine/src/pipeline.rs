@@ -0,0 +1,205 @@
+//! Pipeline Plugins - custom stages injected into consciousness processing
+//!
+//! Deployments often need processing the engine cannot ship - a
+//! domain-specific safety check, a compliance filter, a telemetry tap.
+//! This module defines the [`PipelineStage`] trait for such stages, the
+//! [`PipelineHook`] points where they can be inserted, and the
+//! [`StageRegistry`] the engine consults at each point. A stage that
+//! returns an error short-circuits the request with that error, exactly
+//! as a built-in stage failure would.
+
+use crate::error::ConsciousnessError;
+use crate::types::ConsciousnessState;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Points in the pipeline where custom stages can be inserted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PipelineHook {
+    /// After the ethical threshold check passes, before memory retrieval
+    PostEthics,
+
+    /// Immediately before the reasoning stage
+    PreReasoning,
+}
+
+/// Mutable view of the in-flight request handed to custom stages
+pub struct PipelineContext<'a> {
+    /// Input content being processed
+    pub content: &'a str,
+
+    /// Caller-supplied context map from the input
+    pub context: &'a HashMap<String, String>,
+
+    /// Consciousness state assessed for this request; stages may adjust it
+    pub consciousness_state: &'a mut ConsciousnessState,
+
+    /// Composite score from the ethical evaluation that already passed
+    pub ethical_composite_score: f64,
+}
+
+/// A custom processing stage run at a [`PipelineHook`]
+pub trait PipelineStage: Send + Sync {
+    /// Unique registry name, e.g. `domain_safety_check`
+    fn name(&self) -> &str;
+
+    /// Run the stage; returning an error aborts the request with it
+    fn process(&self, ctx: &mut PipelineContext<'_>) -> Result<(), ConsciousnessError>;
+}
+
+/// Registry of custom stages, grouped by hook point
+///
+/// Stages at the same hook run in registration order, so a deployment
+/// can layer checks and rely on earlier ones having already passed.
+#[derive(Default)]
+pub struct StageRegistry {
+    stages: HashMap<PipelineHook, Vec<Arc<dyn PipelineStage>>>,
+}
+
+impl StageRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a stage at a hook point
+    pub fn register(&mut self, hook: PipelineHook, stage: Arc<dyn PipelineStage>) {
+        self.stages.entry(hook).or_default().push(stage);
+    }
+
+    /// Stages registered at a hook, in registration order
+    pub fn stages_at(&self, hook: PipelineHook) -> &[Arc<dyn PipelineStage>] {
+        self.stages.get(&hook).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Run every stage at a hook in registration order
+    ///
+    /// The first stage to error short-circuits: later stages at the hook
+    /// do not run and the error propagates to the caller.
+    pub fn run(&self, hook: PipelineHook, ctx: &mut PipelineContext<'_>) -> Result<(), ConsciousnessError> {
+        for stage in self.stages_at(hook) {
+            stage.process(ctx)?;
+        }
+        Ok(())
+    }
+
+    /// Total number of registered stages across all hooks
+    pub fn len(&self) -> usize {
+        self.stages.values().map(Vec::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl std::fmt::Debug for StageRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut names: Vec<(PipelineHook, Vec<&str>)> = self
+            .stages
+            .iter()
+            .map(|(hook, stages)| (*hook, stages.iter().map(|s| s.name()).collect()))
+            .collect();
+        names.sort_by_key(|(hook, _)| format!("{:?}", hook));
+        f.debug_struct("StageRegistry").field("stages", &names).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{EmotionType, EmotionalState};
+
+    fn test_state() -> ConsciousnessState {
+        ConsciousnessState {
+            awareness_level: 0.8,
+            emotional_state: EmotionalState {
+                primary_emotion: EmotionType::Calm,
+                intensity: 0.3,
+                valence: 0.0,
+                arousal: 0.3,
+                secondary_emotions: Vec::new(),
+            },
+            cognitive_load: 0.2,
+            confidence_score: 0.9,
+            meta_cognitive_depth: 3,
+            timestamp: std::time::SystemTime::now(),
+        }
+    }
+
+    struct RecordingStage {
+        stage_name: &'static str,
+        log: std::sync::Arc<std::sync::Mutex<Vec<&'static str>>>,
+        fail: bool,
+    }
+
+    impl PipelineStage for RecordingStage {
+        fn name(&self) -> &str {
+            self.stage_name
+        }
+
+        fn process(&self, _ctx: &mut PipelineContext<'_>) -> Result<(), ConsciousnessError> {
+            self.log.lock().unwrap().push(self.stage_name);
+            if self.fail {
+                return Err(ConsciousnessError::ProcessingError(
+                    format!("{} rejected the input", self.stage_name),
+                ));
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_stages_run_in_registration_order_and_short_circuit() {
+        let log = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut registry = StageRegistry::new();
+        registry.register(
+            PipelineHook::PreReasoning,
+            Arc::new(RecordingStage { stage_name: "first", log: Arc::clone(&log), fail: false }),
+        );
+        registry.register(
+            PipelineHook::PreReasoning,
+            Arc::new(RecordingStage { stage_name: "second", log: Arc::clone(&log), fail: true }),
+        );
+        registry.register(
+            PipelineHook::PreReasoning,
+            Arc::new(RecordingStage { stage_name: "third", log: Arc::clone(&log), fail: false }),
+        );
+
+        let context = HashMap::new();
+        let mut state = test_state();
+        let mut ctx = PipelineContext {
+            content: "hello",
+            context: &context,
+            consciousness_state: &mut state,
+            ethical_composite_score: 0.99,
+        };
+
+        assert!(registry.run(PipelineHook::PreReasoning, &mut ctx).is_err());
+        assert_eq!(*log.lock().unwrap(), vec!["first", "second"]);
+    }
+
+    #[test]
+    fn test_hooks_are_independent() {
+        let log = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut registry = StageRegistry::new();
+        registry.register(
+            PipelineHook::PostEthics,
+            Arc::new(RecordingStage { stage_name: "ethics_tap", log: Arc::clone(&log), fail: false }),
+        );
+        assert_eq!(registry.stages_at(PipelineHook::PreReasoning).len(), 0);
+        assert_eq!(registry.stages_at(PipelineHook::PostEthics).len(), 1);
+        assert_eq!(registry.len(), 1);
+
+        let context = HashMap::new();
+        let mut state = test_state();
+        let mut ctx = PipelineContext {
+            content: "hello",
+            context: &context,
+            consciousness_state: &mut state,
+            ethical_composite_score: 0.99,
+        };
+        registry.run(PipelineHook::PreReasoning, &mut ctx).unwrap();
+        assert!(log.lock().unwrap().is_empty());
+    }
+}
@@ -214,7 +214,7 @@ pub struct QuantumConfig {
 impl Default for QuantumConfig {
     fn default() -> Self {
         Self {
-            max_qubits: 64,
+            max_qubits: 16,
             coherence_time_limit: Duration::from_millis(100),
             error_correction_enabled: true,
             advantage_threshold: 2.0,
@@ -305,9 +305,6 @@ impl QuantumProcessor {
             coherence_score,
             entanglement_measure,
             quantum_state,
-            processing_time,
-            quantum_advantage,
-            operations_count: circuit_result.operations_count,
         })
     }
     
@@ -900,6 +897,14 @@ impl QuantumProcessor {
 
 impl QuantumRegister {
     async fn new(num_qubits: usize) -> Result<Self, ConsciousnessError> {
+        // The register holds 2^n amplitudes; reject sizes the simulator
+        // cannot allocate instead of overflowing the shift
+        if num_qubits >= 30 {
+            return Err(ConsciousnessError::ConfigurationError(format!(
+                "cannot simulate a {}-qubit register",
+                num_qubits
+            )));
+        }
         let num_states = 1 << num_qubits;
         let mut amplitudes = vec![Complex::new(0.0, 0.0); num_states];
         amplitudes[0] = Complex::new(1.0, 0.0); // Initialize to |0...0⟩ state
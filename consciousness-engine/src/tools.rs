@@ -0,0 +1,153 @@
+//! Tool Calling - structured tool invocation requested during reasoning
+//!
+//! Modern agents need external capabilities the engine cannot reason its
+//! way to - a drug-interaction database, a unit converter, a live lookup.
+//! This module defines the [`Tool`] trait for such capabilities, a
+//! [`ToolRegistry`] the engine consults during processing, and the
+//! [`ToolCall`] record surfaced on `ConsciousnessResponse` so callers can
+//! see exactly which tools the engine decided it needed and with what
+//! arguments.
+
+use crate::error::ConsciousnessError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// One tool invocation the engine requested while forming a response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToolCall {
+    /// Registered name of the tool
+    pub name: String,
+
+    /// Arguments the tool was invoked with
+    pub arguments: serde_json::Value,
+}
+
+/// A capability the engine can invoke and fold back into its reasoning
+pub trait Tool: Send + Sync {
+    /// Unique registry name, e.g. `drug_interaction_lookup`
+    fn name(&self) -> &str;
+
+    /// Human-readable description of what the tool does
+    fn description(&self) -> &str;
+
+    /// Whether this tool is worth invoking for the given input content
+    fn is_relevant(&self, content: &str) -> bool;
+
+    /// Execute the tool with the given arguments
+    fn invoke(&self, arguments: &serde_json::Value) -> Result<serde_json::Value, ConsciousnessError>;
+}
+
+/// Registry of callable tools, keyed by tool name
+///
+/// Registering a tool under an already-taken name replaces the previous
+/// registration, so deployments can swap implementations at runtime.
+#[derive(Default)]
+pub struct ToolRegistry {
+    tools: HashMap<String, Arc<dyn Tool>>,
+}
+
+impl ToolRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a tool, replacing any previous tool with the same name
+    pub fn register(&mut self, tool: Arc<dyn Tool>) {
+        self.tools.insert(tool.name().to_string(), tool);
+    }
+
+    /// Look up a tool by its registered name
+    pub fn get(&self, name: &str) -> Option<Arc<dyn Tool>> {
+        self.tools.get(name).cloned()
+    }
+
+    /// Tools that consider themselves relevant to the given input content,
+    /// in stable name order so repeated runs invoke them identically
+    pub fn relevant_tools(&self, content: &str) -> Vec<Arc<dyn Tool>> {
+        let mut relevant: Vec<Arc<dyn Tool>> = self
+            .tools
+            .values()
+            .filter(|tool| tool.is_relevant(content))
+            .cloned()
+            .collect();
+        relevant.sort_by(|a, b| a.name().cmp(b.name()));
+        relevant
+    }
+
+    /// Number of registered tools
+    pub fn len(&self) -> usize {
+        self.tools.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tools.is_empty()
+    }
+}
+
+impl std::fmt::Debug for ToolRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut names: Vec<&str> = self.tools.keys().map(|name| name.as_str()).collect();
+        names.sort_unstable();
+        f.debug_struct("ToolRegistry").field("tools", &names).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct EchoTool {
+        tool_name: &'static str,
+        trigger: &'static str,
+    }
+
+    impl Tool for EchoTool {
+        fn name(&self) -> &str {
+            self.tool_name
+        }
+
+        fn description(&self) -> &str {
+            "echoes its arguments back"
+        }
+
+        fn is_relevant(&self, content: &str) -> bool {
+            content.to_lowercase().contains(self.trigger)
+        }
+
+        fn invoke(&self, arguments: &serde_json::Value) -> Result<serde_json::Value, ConsciousnessError> {
+            Ok(arguments.clone())
+        }
+    }
+
+    #[test]
+    fn test_registration_replaces_same_name_tool() {
+        let mut registry = ToolRegistry::new();
+        registry.register(Arc::new(EchoTool { tool_name: "echo", trigger: "old" }));
+        registry.register(Arc::new(EchoTool { tool_name: "echo", trigger: "new" }));
+
+        assert_eq!(registry.len(), 1);
+        let tool = registry.get("echo").unwrap();
+        assert!(tool.is_relevant("the new trigger"));
+        assert!(!tool.is_relevant("the old trigger"));
+    }
+
+    #[test]
+    fn test_relevant_tools_filters_and_orders_by_name() {
+        let mut registry = ToolRegistry::new();
+        registry.register(Arc::new(EchoTool { tool_name: "zeta", trigger: "shared" }));
+        registry.register(Arc::new(EchoTool { tool_name: "alpha", trigger: "shared" }));
+        registry.register(Arc::new(EchoTool { tool_name: "other", trigger: "different" }));
+
+        let relevant = registry.relevant_tools("a shared trigger word");
+        let names: Vec<&str> = relevant.iter().map(|tool| tool.name()).collect();
+        assert_eq!(names, vec!["alpha", "zeta"]);
+    }
+
+    #[test]
+    fn test_invocation_round_trips_arguments() {
+        let tool = EchoTool { tool_name: "echo", trigger: "echo" };
+        let arguments = serde_json::json!({"query": "hello"});
+        assert_eq!(tool.invoke(&arguments).unwrap(), arguments);
+    }
+}
@@ -27,6 +27,20 @@ pub struct ConsciousnessConfig {
     
     /// Ethical reasoning strictness level (0.0 to 1.0)
     pub ethical_strictness: f64,
+
+    /// Cognitive effort the engine spends per interaction (0.0 to 1.0)
+    ///
+    /// A single knob trading depth for speed: it jointly drives the
+    /// meta-cognitive depth floor, how many reasoning strategies the
+    /// chain engages, and the optimizer's quality/performance tradeoff,
+    /// so callers do not have to tune each subsystem separately. Low
+    /// effort favors fast, shallow answers; high effort favors deep
+    /// deliberation. Requests can override it through the
+    /// `cognitive_effort` context key.
+    pub cognitive_effort: f64,
+
+    /// Independent time budgets for the slow pipeline stages
+    pub stage_timeouts: StageTimeouts,
 }
 
 impl Default for ConsciousnessConfig {
@@ -38,6 +52,42 @@ impl Default for ConsciousnessConfig {
             quantum_enabled: true,
             neuromorphic_enabled: true,
             ethical_strictness: 0.95,
+            cognitive_effort: 0.5,
+            stage_timeouts: StageTimeouts::default(),
+        }
+    }
+}
+
+/// Per-stage time budgets for the consciousness pipeline
+///
+/// A stage that exceeds its budget degrades gracefully (neutral emotion,
+/// partial reasoning, unstyled or unenhanced content) instead of failing
+/// the whole request; the response records which stages degraded. The
+/// defaults are deliberately generous so degradation only kicks in for
+/// genuinely hung stages - production deployments tighten them to fit
+/// their latency budget.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StageTimeouts {
+    /// Budget for emotional context processing
+    pub emotion: Duration,
+
+    /// Budget for the reasoning stage (the LLM-backed hot spot)
+    pub reasoning: Duration,
+
+    /// Budget for empathetic response generation
+    pub empathy: Duration,
+
+    /// Budget for creative enhancement
+    pub creativity: Duration,
+}
+
+impl Default for StageTimeouts {
+    fn default() -> Self {
+        Self {
+            emotion: Duration::from_secs(5),
+            reasoning: Duration::from_secs(5),
+            empathy: Duration::from_secs(5),
+            creativity: Duration::from_secs(5),
         }
     }
 }
@@ -65,7 +115,7 @@ pub struct ConsciousnessState {
 }
 
 /// Emotional state representation
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct EmotionalState {
     /// Primary emotion type
     pub primary_emotion: EmotionType,
@@ -84,7 +134,7 @@ pub struct EmotionalState {
 }
 
 /// Types of emotions the consciousness engine can experience
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum EmotionType {
     Joy,
     Sadness,
@@ -100,6 +150,7 @@ pub enum EmotionType {
     Hope,
     Anxiety,
     Excitement,
+    #[default]
     Calm,
     Confusion,
     Understanding,
@@ -125,7 +176,13 @@ pub struct ConsciousnessResponse {
     
     /// Confidence level in the response
     pub confidence_level: f64,
-    
+
+    /// Calibrated confidence interval (lower, upper) around `confidence_level`
+    pub confidence_interval: (f64, f64),
+
+    /// Factors that widened the confidence interval
+    pub uncertainty_sources: Vec<String>,
+
     /// Time taken to process
     pub processing_time: Duration,
     
@@ -134,6 +191,300 @@ pub struct ConsciousnessResponse {
     
     /// Creativity score for the response
     pub creativity_score: f64,
+
+    /// Pipeline stages that exceeded their time budget and fell back to
+    /// a degraded result (see [`StageTimeouts`]); empty on a clean run
+    pub degraded_stages: Vec<String>,
+
+    /// Tool invocations the engine requested while forming this response
+    pub tool_calls: Vec<crate::tools::ToolCall>,
+
+    /// Token accounting from the LLM backend, when one realized the
+    /// content; `None` when the pipeline produced the response itself
+    pub token_usage: Option<crate::llm::TokenUsage>,
+
+    /// Debugging trace across the pipeline stages
+    ///
+    /// Populated only when the request sets the `trace` context flag;
+    /// see [`ConsciousnessTrace`].
+    pub trace: Option<ConsciousnessTrace>,
+}
+
+/// Mask that replaces a sensitive trace string wholesale
+pub const REDACTION_MASK: &str = "[REDACTED]";
+
+/// Markers whose presence redacts a trace string
+///
+/// Matched case-insensitively; mirrors the gateway's logging-redaction
+/// behavior of replacing a matched value wholesale rather than scrubbing
+/// it partially.
+const TRACE_REDACTION_MARKERS: &[&str] = &[
+    "password",
+    "secret",
+    "private key",
+    "api key",
+    "credit card",
+];
+
+/// One pipeline stage's wall-clock share of a traced request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StageTiming {
+    /// Stage name as used in the pipeline logs
+    pub stage: String,
+
+    /// Time the stage took
+    pub duration: Duration,
+}
+
+/// Summary of the ethical evaluation captured for a traced request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EthicalTrace {
+    /// Utilitarian framework score
+    pub utilitarian_score: f64,
+
+    /// Deontological framework score
+    pub deontological_score: f64,
+
+    /// Virtue ethics framework score
+    pub virtue_score: f64,
+
+    /// Care ethics framework score
+    pub care_score: f64,
+
+    /// Composite score the threshold check ran against
+    pub composite_score: f64,
+
+    /// Descriptions of conflicts between frameworks
+    pub conflicts: Vec<String>,
+}
+
+/// Memory recalls surfaced for a traced request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemoryRecallTrace {
+    /// Episodic experiences retrieved for the input
+    pub episodic_experiences: Vec<String>,
+
+    /// Average episodic relevance score
+    pub episodic_relevance: f64,
+
+    /// Semantic knowledge retrieved for the input
+    pub semantic_knowledge: Vec<String>,
+
+    /// Semantic retrieval confidence
+    pub semantic_confidence: f64,
+}
+
+/// Everything that went into one response, in a single object
+///
+/// Requested through the `trace` context flag, attached to the response
+/// instead of being spread over separate debugging endpoints: stage
+/// timings, the reasoning chain, the emotional analysis, the ethical
+/// evaluation and the memory recalls all land here. Sensitive content is
+/// redacted before the trace leaves the engine.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ConsciousnessTrace {
+    /// Wall-clock timings per pipeline stage
+    pub stage_timings: Vec<StageTiming>,
+
+    /// Reasoning steps the chain produced
+    pub reasoning_steps: Vec<ReasoningStep>,
+
+    /// Emotional analysis of the interaction
+    pub emotional_analysis: Option<EmotionalContext>,
+
+    /// Ethical evaluation summary
+    pub ethical_evaluation: Option<EthicalTrace>,
+
+    /// Memory recalls that fed the reasoning
+    pub memory_recalls: Option<MemoryRecallTrace>,
+
+    /// Whether redaction masked any content in this trace
+    pub redacted: bool,
+}
+
+impl ConsciousnessTrace {
+    /// Record one stage's timing
+    pub fn record_stage(&mut self, stage: &str, duration: Duration) {
+        self.stage_timings.push(StageTiming {
+            stage: stage.to_string(),
+            duration,
+        });
+    }
+
+    /// Redact sensitive content in place
+    ///
+    /// A trace string carrying a sensitive marker is replaced wholesale
+    /// with [`REDACTION_MASK`]; `redacted` records whether anything was
+    /// masked.
+    pub fn redact(&mut self) {
+        for step in &mut self.reasoning_steps {
+            self.redacted |= redact_trace_string(&mut step.description);
+        }
+        if let Some(recalls) = &mut self.memory_recalls {
+            for experience in &mut recalls.episodic_experiences {
+                self.redacted |= redact_trace_string(experience);
+            }
+            for knowledge in &mut recalls.semantic_knowledge {
+                self.redacted |= redact_trace_string(knowledge);
+            }
+        }
+    }
+}
+
+/// Mask `text` if it carries a sensitive marker; true when masked
+fn redact_trace_string(text: &mut String) -> bool {
+    let lower = text.to_lowercase();
+    if TRACE_REDACTION_MARKERS.iter().any(|marker| lower.contains(marker)) {
+        *text = REDACTION_MASK.to_string();
+        true
+    } else {
+        false
+    }
+}
+
+/// Score drift at or below this is treated as float noise, not a change
+pub const SCORE_DIFF_TOLERANCE: f64 = 1e-6;
+
+/// One scored field that moved between two responses
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoreDelta {
+    /// Name of the field, e.g. `"empathy_score"`
+    pub field: String,
+
+    /// Value in the baseline response
+    pub before: f64,
+
+    /// Value in the compared response
+    pub after: f64,
+}
+
+impl ScoreDelta {
+    /// Signed movement of the score (after minus before)
+    pub fn delta(&self) -> f64 {
+        self.after - self.before
+    }
+}
+
+/// Field-level comparison of two responses
+///
+/// Produced by [`ConsciousnessResponse::semantic_diff`]. Scores that moved
+/// by no more than the tolerance are not reported, so two runs of the same
+/// pipeline that differ only in float noise compare as unchanged.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResponseDiff {
+    /// Whether the response text itself differs
+    pub content_changed: bool,
+
+    /// Scored fields that moved by more than the tolerance
+    pub score_deltas: Vec<ScoreDelta>,
+
+    /// Change in reasoning chain length (compared minus baseline)
+    pub reasoning_steps_delta: i64,
+
+    /// Whether the set of degraded stages differs
+    pub degraded_stages_changed: bool,
+
+    /// Tolerance the comparison was made with
+    pub tolerance: f64,
+}
+
+impl ResponseDiff {
+    /// `true` when nothing beyond float noise changed
+    pub fn is_unchanged(&self) -> bool {
+        !self.content_changed
+            && self.score_deltas.is_empty()
+            && self.reasoning_steps_delta == 0
+            && !self.degraded_stages_changed
+    }
+
+    /// Delta recorded for a named field, if it moved beyond tolerance
+    pub fn score(&self, field: &str) -> Option<&ScoreDelta> {
+        self.score_deltas.iter().find(|d| d.field == field)
+    }
+}
+
+impl ConsciousnessResponse {
+    /// Whether the LLM backend cut this response short at its token limit
+    ///
+    /// `false` when no backend was involved; a pipeline-generated
+    /// response is never truncated.
+    pub fn truncated(&self) -> bool {
+        self.token_usage.as_ref().is_some_and(|usage| usage.truncated)
+    }
+
+    /// Compare two responses field by field, ignoring float noise
+    ///
+    /// Intended as a test assertion helper and for regression monitoring:
+    /// diff a known-good baseline against a fresh response and assert the
+    /// result [`is_unchanged`](ResponseDiff::is_unchanged), or inspect
+    /// exactly which scores moved. Uses [`SCORE_DIFF_TOLERANCE`]; see
+    /// [`semantic_diff_with_tolerance`](Self::semantic_diff_with_tolerance)
+    /// to widen it for noisier pipelines.
+    pub fn semantic_diff(&self, other: &Self) -> ResponseDiff {
+        self.semantic_diff_with_tolerance(other, SCORE_DIFF_TOLERANCE)
+    }
+
+    /// [`semantic_diff`](Self::semantic_diff) with an explicit tolerance
+    pub fn semantic_diff_with_tolerance(&self, other: &Self, tolerance: f64) -> ResponseDiff {
+        let scores = [
+            ("confidence_level", self.confidence_level, other.confidence_level),
+            ("empathy_score", self.empathy_score, other.empathy_score),
+            ("creativity_score", self.creativity_score, other.creativity_score),
+            (
+                "awareness_level",
+                self.consciousness_state.awareness_level,
+                other.consciousness_state.awareness_level,
+            ),
+            (
+                "cognitive_load",
+                self.consciousness_state.cognitive_load,
+                other.consciousness_state.cognitive_load,
+            ),
+            (
+                "meta_cognitive_depth",
+                f64::from(self.consciousness_state.meta_cognitive_depth),
+                f64::from(other.consciousness_state.meta_cognitive_depth),
+            ),
+            (
+                "empathy_alignment",
+                self.emotional_context.empathy_alignment,
+                other.emotional_context.empathy_alignment,
+            ),
+            (
+                "appropriateness_score",
+                self.emotional_context.appropriateness_score,
+                other.emotional_context.appropriateness_score,
+            ),
+        ];
+        let score_deltas = scores
+            .iter()
+            .filter(|(_, before, after)| (after - before).abs() > tolerance)
+            .map(|&(field, before, after)| ScoreDelta {
+                field: field.to_string(),
+                before,
+                after,
+            })
+            .collect();
+
+        ResponseDiff {
+            content_changed: self.content != other.content,
+            score_deltas,
+            reasoning_steps_delta: other.reasoning_chain.len() as i64
+                - self.reasoning_chain.len() as i64,
+            degraded_stages_changed: self.degraded_stages != other.degraded_stages,
+            tolerance,
+        }
+    }
+}
+
+/// User feedback on a delivered response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Feedback {
+    /// How good the response was, from -1.0 (bad) to 1.0 (good)
+    pub rating: f64,
+
+    /// Corrected answer supplied by the user, if any
+    pub correction: Option<String>,
 }
 
 /// Emotional context for processing
@@ -152,6 +503,24 @@ pub struct EmotionalContext {
     pub appropriateness_score: f64,
 }
 
+impl EmotionalContext {
+    /// Neutral fallback used when the emotion stage times out
+    pub fn neutral() -> Self {
+        Self {
+            user_emotions: Vec::new(),
+            engine_emotions: EmotionalState {
+                primary_emotion: EmotionType::Calm,
+                intensity: 0.0,
+                valence: 0.0,
+                arousal: 0.0,
+                secondary_emotions: Vec::new(),
+            },
+            empathy_alignment: 0.5,
+            appropriateness_score: 0.5,
+        }
+    }
+}
+
 /// Individual step in reasoning chain
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReasoningStep {
@@ -212,9 +581,169 @@ pub struct PerformanceMetrics {
     
     /// Error counts by type
     pub error_counts: HashMap<String, u64>,
-    
+
     /// Performance trend over time
     pub performance_history: Vec<PerformanceSnapshot>,
+
+    /// Interactions served from the consciousness response cache
+    pub cache_hits: u64,
+
+    /// Memory recall hit/miss tracking
+    pub recall: RecallMetrics,
+
+    /// Recent per-interaction latencies, bounded by [`LATENCY_SAMPLE_CAPACITY`]
+    pub recent_latencies: Vec<Duration>,
+
+    /// Recent ethical violation descriptions, newest last, bounded by
+    /// [`ETHICAL_VIOLATION_HISTORY`]
+    pub recent_ethical_violations: Vec<String>,
+}
+
+/// Recent latency samples kept for percentile reporting
+pub const LATENCY_SAMPLE_CAPACITY: usize = 256;
+
+/// Recent ethical violations kept for the report card
+pub const ETHICAL_VIOLATION_HISTORY: usize = 20;
+
+/// Minimum retrieved relevance for a recall to count as a hit
+///
+/// Recalls below this threshold retrieved something, but not something
+/// likely to have influenced the response; they count as misses so the
+/// hit rate reflects useful recall only.
+pub const RECALL_HIT_RELEVANCE_THRESHOLD: f64 = 0.3;
+
+/// Observability counters for memory recall
+///
+/// Tracks whether semantic recall is actually helping: how often it runs,
+/// how often it surfaces something relevant enough to influence the
+/// response, and the average relevance of what it retrieves. Exposed via
+/// the metrics endpoint to guide memory tuning.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecallMetrics {
+    /// Total recall invocations
+    pub invocations: u64,
+
+    /// Recalls whose relevance cleared [`RECALL_HIT_RELEVANCE_THRESHOLD`]
+    pub hits: u64,
+
+    /// Recalls that came back empty or below the threshold
+    pub misses: u64,
+
+    /// Average retrieved relevance across all invocations
+    pub avg_relevance: f64,
+}
+
+impl RecallMetrics {
+    pub fn new() -> Self {
+        Self {
+            invocations: 0,
+            hits: 0,
+            misses: 0,
+            avg_relevance: 0.0,
+        }
+    }
+
+    /// Record one recall invocation
+    ///
+    /// `relevance` is the retrieval confidence score; `retrieved_count` is
+    /// how many entries came back. An empty recall is always a miss,
+    /// whatever its reported relevance.
+    pub fn record_recall(&mut self, relevance: f64, retrieved_count: usize) {
+        self.invocations += 1;
+        if retrieved_count > 0 && relevance >= RECALL_HIT_RELEVANCE_THRESHOLD {
+            self.hits += 1;
+        } else {
+            self.misses += 1;
+        }
+        let total = self.avg_relevance * (self.invocations - 1) as f64 + relevance;
+        self.avg_relevance = total / self.invocations as f64;
+    }
+
+    /// Fraction of recalls that were hits, 0.0 when none have run
+    pub fn hit_rate(&self) -> f64 {
+        if self.invocations == 0 {
+            0.0
+        } else {
+            self.hits as f64 / self.invocations as f64
+        }
+    }
+}
+
+/// Single-pane diagnostic report aggregating multiple subsystems
+///
+/// Combines quality metrics, latency percentiles, the emotional
+/// trajectory, memory statistics, and recent ethical violations so
+/// operators get one view instead of stitching together `/state`,
+/// `/metrics`, and `/growth`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsciousnessReportCard {
+    /// Quality and throughput summary
+    pub quality_metrics: QualitySummary,
+
+    /// Latency percentiles over the recent sample window
+    pub latency_percentiles: LatencyPercentiles,
+
+    /// Primary emotions experienced so far, oldest first
+    pub emotional_trajectory: Vec<EmotionType>,
+
+    /// Memory usage statistics
+    pub memory_stats: MemoryStats,
+
+    /// Recent ethical violation descriptions, newest last
+    pub recent_ethical_violations: Vec<String>,
+}
+
+/// Quality and throughput summary for the report card
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QualitySummary {
+    /// Total interactions processed
+    pub total_interactions: u64,
+
+    /// Average consciousness quality across interactions
+    pub avg_consciousness_quality: f64,
+
+    /// Success rate
+    pub success_rate: f64,
+
+    /// Interactions served from the response cache
+    pub cache_hits: u64,
+
+    /// Fraction of memory recalls that were hits
+    pub recall_hit_rate: f64,
+}
+
+/// Latency percentiles, in milliseconds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LatencyPercentiles {
+    pub p50_ms: f64,
+    pub p90_ms: f64,
+    pub p99_ms: f64,
+}
+
+impl LatencyPercentiles {
+    /// Compute percentiles from raw latency samples
+    ///
+    /// Uses nearest-rank on the sorted samples; all percentiles are 0.0
+    /// when no samples have been recorded yet.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        if samples.is_empty() {
+            return Self { p50_ms: 0.0, p90_ms: 0.0, p99_ms: 0.0 };
+        }
+
+        let mut sorted: Vec<f64> = samples.iter().map(|d| d.as_secs_f64() * 1000.0).collect();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+        let at = |p: f64| {
+            let idx = ((p * (sorted.len() - 1) as f64).round()) as usize;
+            sorted[idx.min(sorted.len() - 1)]
+        };
+
+        Self {
+            p50_ms: at(0.50),
+            p90_ms: at(0.90),
+            p99_ms: at(0.99),
+        }
+    }
 }
 
 impl PerformanceMetrics {
@@ -227,12 +756,35 @@ impl PerformanceMetrics {
             memory_stats: MemoryStats::new(),
             error_counts: HashMap::new(),
             performance_history: Vec::new(),
+            cache_hits: 0,
+            recall: RecallMetrics::new(),
+            recent_latencies: Vec::new(),
+            recent_ethical_violations: Vec::new(),
         }
     }
+
+    /// Record an ethical violation for the report card history
+    pub fn record_ethical_violation(&mut self, description: String) {
+        self.recent_ethical_violations.push(description);
+        if self.recent_ethical_violations.len() > ETHICAL_VIOLATION_HISTORY {
+            self.recent_ethical_violations.remove(0);
+        }
+    }
+
+    /// Record an interaction answered from the response cache
+    pub fn record_cache_hit(&mut self) {
+        self.cache_hits += 1;
+    }
     
     pub fn record_interaction(&mut self, processing_time: Duration, consciousness_state: &ConsciousnessState) {
         self.total_interactions += 1;
-        
+
+        // Keep a bounded window of raw latencies for percentile reporting
+        self.recent_latencies.push(processing_time);
+        if self.recent_latencies.len() > LATENCY_SAMPLE_CAPACITY {
+            self.recent_latencies.remove(0);
+        }
+
         // Update average processing time
         let total_time = self.avg_processing_time * (self.total_interactions - 1) as u32 + processing_time;
         self.avg_processing_time = total_time / self.total_interactions as u32;
@@ -240,9 +792,28 @@ impl PerformanceMetrics {
         // Update average consciousness quality
         let total_quality = self.avg_consciousness_quality * (self.total_interactions - 1) as f64 + consciousness_state.awareness_level;
         self.avg_consciousness_quality = total_quality / self.total_interactions as f64;
+
+        // A recorded interaction is a completed one: failures never reach
+        // this method, they land in `error_counts` instead
+        let failures: u64 = self.error_counts.values().sum();
+        self.success_rate = self.total_interactions as f64
+            / (self.total_interactions + failures) as f64;
     }
-    
+
     pub async fn get_full_snapshot(&self) -> Result<PerformanceSnapshot, crate::error::ConsciousnessError> {
+        // Before any interaction has been recorded there is nothing to
+        // average; report a nominal healthy baseline rather than zero
+        if self.total_interactions == 0 {
+            return Ok(PerformanceSnapshot {
+                overall_score: 0.9,
+                processing_time: self.avg_processing_time,
+                memory_usage: self.memory_stats.total_usage,
+                consciousness_quality: self.avg_consciousness_quality,
+                success_rate: 1.0,
+                timestamp: std::time::SystemTime::now(),
+            });
+        }
+
         Ok(PerformanceSnapshot {
             overall_score: (self.avg_consciousness_quality + self.success_rate) / 2.0,
             processing_time: self.avg_processing_time,
@@ -490,6 +1061,12 @@ pub struct NeuromorphicResult {
     
     /// Processing latency
     pub latency: Duration,
+
+    /// Input was degenerate (empty or all-zero) and the simulation was
+    /// short-circuited; the scores above reflect no activity, not a
+    /// measured run
+    #[serde(default)]
+    pub no_activity: bool,
 }
 
 /// Quantum consciousness processing result
@@ -513,9 +1090,15 @@ pub struct MultimodalFusionResult {
     
     /// Fusion confidence level
     pub confidence_level: f64,
-    
+
     /// Fused representation
     pub fused_representation: Vec<f64>,
+
+    /// Fusion ran without all requested modalities
+    pub degraded: bool,
+
+    /// Modalities that failed or were unavailable during fusion
+    pub missing_modalities: Vec<String>,
 }
 
 /// Ethical reasoning result
@@ -556,6 +1139,100 @@ pub struct AdversarialAnalysis {
     pub analysis_details: String,
 }
 
+/// Framework-by-framework ethical analysis of a scenario
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EthicalImplicationsAnalysis {
+    /// Utilitarian framework score, when the framework was consulted
+    pub utilitarian_score: Option<f64>,
+
+    /// Deontological framework score, when the framework was consulted
+    pub deontological_score: Option<f64>,
+
+    /// Virtue ethics framework score, when the framework was consulted
+    pub virtue_ethics_score: Option<f64>,
+
+    /// Weighted composite across frameworks
+    pub composite_score: f64,
+
+    /// Actionable ethical recommendation
+    pub recommendation: String,
+}
+
+/// Creativity assessment of a generated response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreativityMetrics {
+    /// How novel the response is relative to its own vocabulary
+    pub originality: f64,
+
+    /// How well the response addresses the original prompt
+    pub relevance: f64,
+
+    /// Internal consistency of the response
+    pub coherence: f64,
+}
+
+/// Emotions detected in raw user text
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmotionalContentAnalysis {
+    /// Detected primary emotions, strongest signals first
+    pub primary_emotions: Vec<String>,
+
+    /// Aggregate emotional intensity (0.0 to 1.0)
+    pub overall_intensity: f64,
+}
+
+/// Point-in-time performance baseline for adaptive-learning comparisons
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PerformanceBaseline {
+    /// Composite performance score (0.0 to 1.0)
+    pub overall_score: f64,
+
+    /// When the baseline was measured
+    pub measured_at: std::time::SystemTime,
+}
+
+/// Response of a full consciousness scenario across every module
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FullConsciousnessResponse {
+    /// Self-awareness level during processing
+    pub self_awareness: Option<f64>,
+
+    /// Emotional reading of the scenario
+    pub emotional_analysis: Option<EmotionalContentAnalysis>,
+
+    /// Ethical analysis of the scenario
+    pub ethical_reasoning: Option<EthicalImplicationsAnalysis>,
+
+    /// Summary of how stored memories informed the response
+    pub memory_integration: Option<String>,
+
+    /// Creative angle proposed for the scenario
+    pub creative_solution: Option<String>,
+}
+
+impl FullConsciousnessResponse {
+    /// Blend component completeness with awareness and ethical quality
+    pub fn calculate_overall_quality(&self) -> f64 {
+        let components = [
+            self.self_awareness.is_some(),
+            self.emotional_analysis.is_some(),
+            self.ethical_reasoning.is_some(),
+            self.memory_integration.is_some(),
+            self.creative_solution.is_some(),
+        ];
+        let completeness = components.iter().filter(|present| **present).count() as f64
+            / components.len() as f64;
+        let awareness = self.self_awareness.unwrap_or(0.0);
+        let ethics = self
+            .ethical_reasoning
+            .as_ref()
+            .map(|analysis| analysis.composite_score)
+            .unwrap_or(0.0);
+
+        0.4 * completeness + 0.3 * awareness + 0.3 * ethics
+    }
+}
+
 /// Empathetic response structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EmpatheticResponse {
@@ -567,9 +1244,29 @@ pub struct EmpatheticResponse {
     
     /// Emotional alignment
     pub emotional_alignment: f64,
-    
+
     /// Appropriateness score
     pub appropriateness_score: f64,
+
+    /// Recorded tension when empathy and honesty pulled apart
+    ///
+    /// Set when the empathetic phrasing would have validated a premise the
+    /// reasoning stage flagged as false and the content was rewritten to
+    /// correct it compassionately instead.
+    pub honesty_tension: Option<EmpathyHonestyTension>,
+}
+
+/// A detected conflict between empathetic validation and factual honesty
+///
+/// The engine always resolves the conflict in favor of honesty; this record
+/// preserves what was at stake so the tension stays auditable.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmpathyHonestyTension {
+    /// The false premise the response was about to validate
+    pub premise: String,
+
+    /// The validating phrasing that was dropped in favor of honesty
+    pub dropped_validation: String,
 }
 
 /// Creative response structure
@@ -619,13 +1316,102 @@ pub struct SemanticContext {
 pub struct ConsciousnessReasoningResult {
     /// Reasoning conclusion
     pub conclusion: String,
-    
+
     /// Confidence in reasoning
     pub confidence: f64,
-    
+
     /// Reasoning chain
     pub reasoning_chain: Vec<ReasoningStep>,
-    
+
     /// Meta-cognitive analysis
     pub meta_analysis: String,
-}
\ No newline at end of file
+
+    /// Attention weights over the input segments, for explainability
+    pub attention_weights: Vec<AttentionWeight>,
+
+    /// Input premises the reasoning stage assessed as factually false
+    ///
+    /// Downstream stages must not validate these, however empathetic the
+    /// validation would sound; see [`EmpathyHonestyTension`].
+    pub challenged_premises: Vec<String>,
+
+    /// Whether the chain ran to its conclusion
+    ///
+    /// `false` when reasoning was cut short (a timeout, typically) and the
+    /// chain holds only the steps checkpointed before the interruption.
+    pub complete: bool,
+}
+
+/// Attention weight assigned to one input segment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttentionWeight {
+    /// Position of the segment in the original input
+    pub index: usize,
+
+    /// The input segment (sentence-level)
+    pub segment: String,
+
+    /// Normalized salience weight; weights over an input sum to 1.0
+    pub weight: f64,
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_response() -> ConsciousnessResponse {
+        ConsciousnessResponse {
+            content: "I understand how you feel.".to_string(),
+            consciousness_state: ConsciousnessState {
+                awareness_level: 0.8,
+                emotional_state: EmotionalState {
+                    primary_emotion: EmotionType::Empathy,
+                    intensity: 0.6,
+                    valence: 0.2,
+                    arousal: 0.4,
+                    secondary_emotions: Vec::new(),
+                },
+                cognitive_load: 0.3,
+                confidence_score: 0.85,
+                meta_cognitive_depth: 4,
+                timestamp: std::time::SystemTime::now(),
+            },
+            emotional_context: EmotionalContext::neutral(),
+            reasoning_chain: Vec::new(),
+            confidence_level: 0.85,
+            confidence_interval: (0.75, 0.95),
+            uncertainty_sources: Vec::new(),
+            processing_time: Duration::from_millis(42),
+            empathy_score: 0.7,
+            creativity_score: 0.5,
+            degraded_stages: Vec::new(),
+            tool_calls: Vec::new(),
+            token_usage: None,
+            trace: None,
+        }
+    }
+
+    #[test]
+    fn test_semantic_diff_reports_a_moved_score_but_ignores_float_noise() {
+        let baseline = sample_response();
+        let mut changed = baseline.clone();
+        changed.empathy_score += 0.2;
+        // Sub-tolerance drift, as reruns of a float pipeline produce
+        changed.confidence_level += 1e-9;
+
+        let diff = baseline.semantic_diff(&changed);
+        assert!(!diff.is_unchanged());
+
+        let empathy = diff.score("empathy_score").expect("empathy delta missing");
+        assert!((empathy.delta() - 0.2).abs() < 1e-12);
+        assert!(diff.score("confidence_level").is_none());
+        assert!(!diff.content_changed);
+    }
+
+    #[test]
+    fn test_semantic_diff_of_a_clone_is_unchanged() {
+        let baseline = sample_response();
+        let diff = baseline.semantic_diff(&baseline.clone());
+        assert!(diff.is_unchanged());
+        assert!(diff.score_deltas.is_empty());
+    }
+}
@@ -135,6 +135,36 @@ pub fn calculate_mutual_information(joint_prob: &[Vec<f64>]) -> Result<f64, Cons
     Ok(mutual_info)
 }
 
+/// Replace a non-finite value with a defined fallback
+///
+/// Scoring math across the engine divides by population sizes, elapsed
+/// times, and targets; on degenerate inputs (empty or constant
+/// populations, zero-length timings) those produce NaN or infinity,
+/// which would serialize into responses as invalid JSON or silently
+/// poison downstream averages. Centralizing the guard keeps every score
+/// finite and makes each substitution visible in the logs.
+pub fn finite_or(value: f64, fallback: f64, name: &str) -> f64 {
+    if value.is_finite() {
+        value
+    } else {
+        tracing::warn!(
+            name = name,
+            value = %value,
+            fallback = fallback,
+            "non-finite value replaced in scoring math"
+        );
+        fallback
+    }
+}
+
+/// Guard a score to a finite value inside `[min, max]`
+///
+/// Non-finite values take the fallback (which must itself lie in range);
+/// finite values are clamped, so a guarded score is always valid.
+pub fn finite_in_range(value: f64, min: f64, max: f64, fallback: f64, name: &str) -> f64 {
+    finite_or(value, fallback, name).clamp(min, max)
+}
+
 /// Exponential moving average
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExponentialMovingAverage {
@@ -536,6 +566,89 @@ impl TextUtils {
     }
 }
 
+/// Token counting strategy used for pre-execution cost estimation
+///
+/// Implementations can wrap a real model tokenizer; the default simply counts
+/// whitespace-delimited words, which is a good enough proxy for the latency
+/// regression below.
+pub trait Tokenizer: Send + Sync {
+    /// Count tokens in the given text
+    fn count_tokens(&self, text: &str) -> usize;
+}
+
+/// Default whitespace-delimited tokenizer
+#[derive(Debug, Clone, Default)]
+pub struct WhitespaceTokenizer;
+
+impl Tokenizer for WhitespaceTokenizer {
+    fn count_tokens(&self, text: &str) -> usize {
+        text.split_whitespace().count()
+    }
+}
+
+/// Predicted processing cost for a consciousness input
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostEstimate {
+    /// Predicted end-to-end pipeline latency
+    pub predicted_latency: Duration,
+
+    /// Predicted token count of the input
+    pub predicted_tokens: usize,
+}
+
+/// Latency predictor combining a pluggable tokenizer with a linear
+/// regression over token count and requested meta-cognitive depth
+///
+/// Lets callers pre-emptively reject or downgrade requests that would blow
+/// the processing budget instead of discovering it after the fact.
+pub struct CostEstimator {
+    /// Tokenizer used to measure input length
+    tokenizer: Box<dyn Tokenizer>,
+
+    /// Fixed pipeline overhead in milliseconds
+    base_latency_ms: f64,
+
+    /// Marginal cost per input token in milliseconds
+    per_token_ms: f64,
+
+    /// Marginal cost per meta-cognitive depth level in milliseconds
+    per_depth_ms: f64,
+}
+
+impl Default for CostEstimator {
+    fn default() -> Self {
+        Self::with_tokenizer(Box::new(WhitespaceTokenizer))
+    }
+}
+
+impl CostEstimator {
+    /// Create an estimator using a custom tokenizer
+    ///
+    /// Coefficients are calibrated against the observed pipeline baseline
+    /// (~78ms for a typical request at depth 5).
+    pub fn with_tokenizer(tokenizer: Box<dyn Tokenizer>) -> Self {
+        Self {
+            tokenizer,
+            base_latency_ms: 20.0,
+            per_token_ms: 0.35,
+            per_depth_ms: 4.0,
+        }
+    }
+
+    /// Estimate cost for the given content and requested depth
+    pub fn estimate(&self, content: &str, requested_depth: u32) -> CostEstimate {
+        let predicted_tokens = self.tokenizer.count_tokens(content);
+        let latency_ms = self.base_latency_ms
+            + predicted_tokens as f64 * self.per_token_ms
+            + requested_depth as f64 * self.per_depth_ms;
+
+        CostEstimate {
+            predicted_latency: Duration::from_micros((latency_ms * 1000.0) as u64),
+            predicted_tokens,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -571,6 +684,18 @@ mod tests {
         assert!((entropy - 2.0_f64.ln()).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_finite_guards_replace_nan_and_infinity() {
+        assert_eq!(finite_or(0.42, 0.0, "score"), 0.42);
+        assert_eq!(finite_or(f64::NAN, 0.0, "score"), 0.0);
+        assert_eq!(finite_or(f64::INFINITY, 1.0, "score"), 1.0);
+        assert_eq!(finite_or(f64::NEG_INFINITY, 0.5, "score"), 0.5);
+
+        assert_eq!(finite_in_range(1.7, 0.0, 1.0, 0.0, "score"), 1.0);
+        assert_eq!(finite_in_range(-0.3, 0.0, 1.0, 0.0, "score"), 0.0);
+        assert_eq!(finite_in_range(f64::NAN, 0.0, 1.0, 0.25, "score"), 0.25);
+    }
+
     #[test]
     fn test_exponential_moving_average() {
         let mut ema = ExponentialMovingAverage::new(0.5).unwrap();
@@ -599,6 +724,37 @@ mod tests {
         assert!(!limiter.is_allowed()); // Should be rate limited
     }
 
+    #[test]
+    fn test_cost_estimate_monotonic_in_input_length() {
+        let estimator = CostEstimator::default();
+        let inputs = [
+            "Hello",
+            "Hello, how are you today?",
+            "Hello, how are you today? I have been thinking a lot about what you said last time.",
+        ];
+
+        let mut previous = Duration::from_secs(0);
+        for input in inputs {
+            let estimate = estimator.estimate(input, 5);
+            assert!(
+                estimate.predicted_latency > previous,
+                "longer input '{}' should predict higher latency",
+                input
+            );
+            previous = estimate.predicted_latency;
+        }
+    }
+
+    #[test]
+    fn test_cost_estimate_grows_with_depth() {
+        let estimator = CostEstimator::default();
+        let shallow = estimator.estimate("Hello there", 3);
+        let deep = estimator.estimate("Hello there", 7);
+
+        assert_eq!(shallow.predicted_tokens, deep.predicted_tokens);
+        assert!(deep.predicted_latency > shallow.predicted_latency);
+    }
+
     #[test]
     fn test_text_utils() {
         let keywords = TextUtils::extract_keywords("The quick brown fox jumps", 3);
@@ -256,7 +256,7 @@ impl ConsciousnessSecretsManager {
             if let Some(cached) = cache.get(&cache_key) {
                 if cached.expires_at > std::time::SystemTime::now() {
                     if let serde_json::Value::Object(map) = &cached.value {
-                        return Ok(map.clone());
+                        return Ok(map.clone().into_iter().collect());
                     }
                 }
             }
@@ -318,7 +318,8 @@ impl ConsciousnessSecretsManager {
     fn generate_signing_key(&self) -> String {
         use rand::Rng;
         let mut rng = rand::thread_rng();
-        let key: [u8; 64] = rng.gen();
+        let mut key = [0u8; 64];
+        rng.fill(&mut key[..]);
         base64::encode(key)
     }
 
@@ -509,4 +509,28 @@ async fn benchmark_consciousness_performance() {
     }
     
     println!("✅ All performance benchmarks passed");
-}
\ No newline at end of file
+}
+/// Baseline latence du pipeline complet (garde-fou pour les benchmarks criterion)
+#[tokio::test]
+async fn test_pipeline_baseline_latency_under_generous_threshold() {
+    let mut engine = ConsciousnessEngine::new().await.unwrap();
+
+    let start = Instant::now();
+    let response = engine
+        .process_conscious_thought(ConsciousInput::new(
+            "I'm feeling overwhelmed by a difficult decision at work.".to_string(),
+        ))
+        .await
+        .unwrap();
+    let elapsed = start.elapsed();
+
+    // Seuil volontairement généreux : le backend simulé vise <100ms, mais on
+    // ne veut détecter ici que les régressions d'ordre de grandeur
+    assert!(
+        elapsed < Duration::from_secs(2),
+        "Full pipeline took {:?}, exceeding the generous 2s baseline",
+        elapsed
+    );
+    assert!(!response.content.is_empty());
+    assert!(response.processing_time <= elapsed);
+}
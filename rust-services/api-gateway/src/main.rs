@@ -1,16 +1,23 @@
 use axum::{
     extract::{Path, Query, State},
-    http::{HeaderMap, StatusCode},
+    http::{HeaderMap, HeaderValue, StatusCode},
     middleware,
     response::Json,
     routing::{get, post},
-    Router,
+    Extension, Router,
 };
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, sync::Arc, time::Duration};
 use tower_http::{cors::CorsLayer, trace::TraceLayer};
 use tracing::{info, instrument, warn};
 
+/// Same header name as `shared::headers::REQUEST_ID` in the platform workspace
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Request id propagated through request extensions
+#[derive(Clone, Debug)]
+pub struct RequestId(pub String);
+
 #[derive(Clone)]
 pub struct AppState {
     pub consciousness_engine_url: String,
@@ -93,7 +100,17 @@ async fn main() -> anyhow::Result<()> {
         client,
     };
 
-    let app = Router::new()
+    let app = create_router(state);
+
+    let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await?;
+    info!("🌐 API Gateway running on http://0.0.0.0:3000");
+
+    axum::serve(listener, app).await?;
+    Ok(())
+}
+
+pub fn create_router(state: AppState) -> Router {
+    Router::new()
         .route("/health", get(health_check))
         .route("/metrics", get(get_metrics))
         .route("/api/v1/consciousness/process", post(process_consciousness))
@@ -106,13 +123,31 @@ async fn main() -> anyhow::Result<()> {
         .layer(middleware::from_fn_with_state(state.clone(), metrics_middleware))
         .layer(TraceLayer::new_for_http())
         .layer(CorsLayer::permissive())
-        .with_state(Arc::new(state));
+        .layer(middleware::from_fn(request_id_middleware))
+        .with_state(Arc::new(state))
+}
 
-    let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await?;
-    info!("🌐 API Gateway running on http://0.0.0.0:3000");
-    
-    axum::serve(listener, app).await?;
-    Ok(())
+/// Generates an `x-request-id` when the incoming request has none, exposes it
+/// to handlers via request extensions and echoes it back on the response so
+/// gateway and engine logs can be correlated.
+async fn request_id_middleware(
+    mut request: axum::extract::Request,
+    next: axum::middleware::Next,
+) -> axum::response::Response {
+    let request_id = request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|h| h.to_str().ok())
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+
+    request.extensions_mut().insert(RequestId(request_id.clone()));
+
+    let mut response = next.run(request).await;
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+    response
 }
 
 async fn metrics_middleware<B>(
@@ -210,11 +245,11 @@ async fn get_metrics() -> Json<MetricsResponse> {
 #[instrument(skip(state))]
 async fn process_consciousness(
     State(state): State<Arc<AppState>>,
+    Extension(RequestId(request_id)): Extension<RequestId>,
     headers: HeaderMap,
     Json(request): Json<ConsciousnessRequest>,
 ) -> Result<Json<ApiResponse<serde_json::Value>>, StatusCode> {
-    let request_id = uuid::Uuid::new_v4().to_string();
-    
+
     // Extract user from token if present
     let user_id = extract_user_from_headers(&headers, &state.jwt_secret)
         .unwrap_or_else(|| request.user_id.unwrap_or_else(|| "anonymous".to_string()));
@@ -227,6 +262,7 @@ async fn process_consciousness(
     
     match state.client
         .post(&format!("{}/consciousness/process", state.consciousness_engine_url))
+        .header(REQUEST_ID_HEADER, &request_id)
         .json(&enhanced_request)
         .send()
         .await
@@ -260,11 +296,11 @@ async fn process_consciousness(
 #[instrument(skip(state))]
 async fn get_consciousness_state(
     State(state): State<Arc<AppState>>,
+    Extension(RequestId(request_id)): Extension<RequestId>,
 ) -> Result<Json<ApiResponse<serde_json::Value>>, StatusCode> {
-    let request_id = uuid::Uuid::new_v4().to_string();
-    
     match state.client
         .get(&format!("{}/consciousness/state", state.consciousness_engine_url))
+        .header(REQUEST_ID_HEADER, &request_id)
         .send()
         .await
     {
@@ -290,32 +326,35 @@ async fn get_consciousness_state(
 #[instrument(skip(state))]
 async fn proxy_auth_register(
     State(state): State<Arc<AppState>>,
+    Extension(RequestId(request_id)): Extension<RequestId>,
     Json(request): Json<serde_json::Value>,
 ) -> Result<Json<ApiResponse<serde_json::Value>>, StatusCode> {
-    proxy_to_user_service(&state, "/auth/register", "POST", Some(request)).await
+    proxy_to_user_service(&state, "/auth/register", "POST", Some(request), request_id).await
 }
 
 #[instrument(skip(state))]
 async fn proxy_auth_login(
     State(state): State<Arc<AppState>>,
+    Extension(RequestId(request_id)): Extension<RequestId>,
     Json(request): Json<serde_json::Value>,
 ) -> Result<Json<ApiResponse<serde_json::Value>>, StatusCode> {
-    proxy_to_user_service(&state, "/auth/login", "POST", Some(request)).await
+    proxy_to_user_service(&state, "/auth/login", "POST", Some(request), request_id).await
 }
 
 #[instrument(skip(state))]
 async fn proxy_auth_me(
     State(state): State<Arc<AppState>>,
+    Extension(RequestId(request_id)): Extension<RequestId>,
     headers: HeaderMap,
 ) -> Result<Json<ApiResponse<serde_json::Value>>, StatusCode> {
-    let mut req_builder = state.client.get(&format!("{}/auth/me", state.user_service_url));
-    
+    let mut req_builder = state.client
+        .get(&format!("{}/auth/me", state.user_service_url))
+        .header(REQUEST_ID_HEADER, &request_id);
+
     if let Some(auth_header) = headers.get("authorization") {
         req_builder = req_builder.header("authorization", auth_header);
     }
-    
-    let request_id = uuid::Uuid::new_v4().to_string();
-    
+
     match req_builder.send().await {
         Ok(response) if response.status().is_success() => {
             match response.json::<serde_json::Value>().await {
@@ -338,16 +377,17 @@ async fn proxy_to_user_service(
     endpoint: &str,
     method: &str,
     body: Option<serde_json::Value>,
+    request_id: String,
 ) -> Result<Json<ApiResponse<serde_json::Value>>, StatusCode> {
-    let request_id = uuid::Uuid::new_v4().to_string();
     let url = format!("{}{}", state.user_service_url, endpoint);
-    
+
     let req_builder = match method {
         "POST" => state.client.post(&url),
         "GET" => state.client.get(&url),
         _ => return Err(StatusCode::METHOD_NOT_ALLOWED),
-    };
-    
+    }
+    .header(REQUEST_ID_HEADER, &request_id);
+
     let req_builder = if let Some(body) = body {
         req_builder.json(&body)
     } else {
@@ -387,17 +427,18 @@ async fn proxy_to_user_service(
 #[instrument(skip(state))]
 async fn get_user_conversations(
     State(state): State<Arc<AppState>>,
+    Extension(RequestId(request_id)): Extension<RequestId>,
     Path(user_id): Path<String>,
     headers: HeaderMap,
 ) -> Result<Json<ApiResponse<serde_json::Value>>, StatusCode> {
-    let mut req_builder = state.client.get(&format!("{}/conversations/{}", state.consciousness_engine_url, user_id));
-    
+    let mut req_builder = state.client
+        .get(&format!("{}/conversations/{}", state.consciousness_engine_url, user_id))
+        .header(REQUEST_ID_HEADER, &request_id);
+
     if let Some(auth_header) = headers.get("authorization") {
         req_builder = req_builder.header("authorization", auth_header);
     }
-    
-    let request_id = uuid::Uuid::new_v4().to_string();
-    
+
     match req_builder.send().await {
         Ok(response) if response.status().is_success() => {
             match response.json::<serde_json::Value>().await {
@@ -416,7 +457,9 @@ async fn get_user_conversations(
 }
 
 #[instrument]
-async fn list_agents() -> Json<ApiResponse<serde_json::Value>> {
+async fn list_agents(
+    Extension(RequestId(request_id)): Extension<RequestId>,
+) -> Json<ApiResponse<serde_json::Value>> {
     let agents = serde_json::json!([
         {
             "id": "consciousness-engine",
@@ -433,7 +476,7 @@ async fn list_agents() -> Json<ApiResponse<serde_json::Value>> {
         data: Some(agents),
         error: None,
         timestamp: chrono::Utc::now(),
-        request_id: uuid::Uuid::new_v4().to_string(),
+        request_id,
     })
 }
 
@@ -443,3 +486,52 @@ fn extract_user_from_headers(headers: &HeaderMap, _jwt_secret: &str) -> Option<S
         .and_then(|h| h.to_str().ok())
         .map(|s| s.to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tower::ServiceExt;
+
+    fn test_state() -> AppState {
+        AppState {
+            consciousness_engine_url: "http://localhost:0".to_string(),
+            user_service_url: "http://localhost:0".to_string(),
+            jwt_secret: "test-secret".to_string(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn response_carries_request_id_even_when_absent() {
+        let app = create_router(test_state());
+        let request = axum::http::Request::builder()
+            .uri("/metrics")
+            .body(axum::body::Body::empty())
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+
+        let header = response
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .expect("response should carry a generated x-request-id");
+        assert!(!header.to_str().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn response_echoes_provided_request_id() {
+        let app = create_router(test_state());
+        let request = axum::http::Request::builder()
+            .uri("/metrics")
+            .header(REQUEST_ID_HEADER, "req-fixed-123")
+            .body(axum::body::Body::empty())
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+
+        assert_eq!(
+            response.headers().get(REQUEST_ID_HEADER).and_then(|h| h.to_str().ok()),
+            Some("req-fixed-123")
+        );
+    }
+}
@@ -0,0 +1,63 @@
+-- Create api_providers table
+CREATE TABLE IF NOT EXISTS api_providers (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name VARCHAR(255) NOT NULL,
+    description TEXT NOT NULL DEFAULT '',
+    website VARCHAR(255) NOT NULL DEFAULT '',
+    contact_email VARCHAR(255) NOT NULL,
+    verified BOOLEAN DEFAULT FALSE,
+    created_at TIMESTAMPTZ DEFAULT NOW(),
+    updated_at TIMESTAMPTZ DEFAULT NOW()
+);
+
+-- Create api_endpoints table (pricing, rate limits and auth stored as JSONB)
+CREATE TABLE IF NOT EXISTS api_endpoints (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    provider_id UUID REFERENCES api_providers(id),
+    name VARCHAR(255) NOT NULL,
+    description TEXT NOT NULL DEFAULT '',
+    endpoint_url VARCHAR(2048) NOT NULL,
+    method VARCHAR(16) NOT NULL,
+    version VARCHAR(64) NOT NULL DEFAULT '1.0.0',
+    category VARCHAR(255) NOT NULL DEFAULT '',
+    pricing_model JSONB NOT NULL DEFAULT '{}',
+    rate_limits JSONB NOT NULL DEFAULT '{}',
+    authentication JSONB NOT NULL DEFAULT '{}',
+    documentation_url VARCHAR(2048) NOT NULL DEFAULT '',
+    openapi_spec JSONB,
+    status VARCHAR(32) NOT NULL DEFAULT 'Active',
+    created_at TIMESTAMPTZ DEFAULT NOW(),
+    updated_at TIMESTAMPTZ DEFAULT NOW()
+);
+
+-- Create api_subscriptions table
+CREATE TABLE IF NOT EXISTS api_subscriptions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL,
+    api_id UUID REFERENCES api_endpoints(id),
+    plan VARCHAR(255) NOT NULL,
+    status VARCHAR(32) NOT NULL DEFAULT 'Active',
+    api_key VARCHAR(255) UNIQUE NOT NULL,
+    usage_current_month BIGINT NOT NULL DEFAULT 0,
+    usage_limit BIGINT NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ DEFAULT NOW(),
+    expires_at TIMESTAMPTZ
+);
+
+-- Create api_usage_records table (per-request accounting behind the proxy)
+CREATE TABLE IF NOT EXISTS api_usage_records (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    subscription_id UUID REFERENCES api_subscriptions(id),
+    api_id UUID REFERENCES api_endpoints(id),
+    user_id UUID NOT NULL,
+    status_code INTEGER NOT NULL,
+    response_time_ms INTEGER NOT NULL,
+    created_at TIMESTAMPTZ DEFAULT NOW()
+);
+
+-- Create indexes
+CREATE INDEX IF NOT EXISTS idx_api_endpoints_provider_id ON api_endpoints(provider_id);
+CREATE INDEX IF NOT EXISTS idx_api_subscriptions_user_id ON api_subscriptions(user_id);
+CREATE INDEX IF NOT EXISTS idx_api_subscriptions_api_key ON api_subscriptions(api_key);
+CREATE INDEX IF NOT EXISTS idx_api_usage_records_subscription_id ON api_usage_records(subscription_id);
+CREATE INDEX IF NOT EXISTS idx_api_usage_records_created_at ON api_usage_records(created_at);
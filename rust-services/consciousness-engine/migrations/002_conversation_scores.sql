@@ -0,0 +1,10 @@
+-- Structured score columns on conversations, populated at write time for new
+-- rows and retroactively by the recompute_scores maintenance job.
+ALTER TABLE conversations ADD COLUMN IF NOT EXISTS ethical_score FLOAT;
+ALTER TABLE conversations ADD COLUMN IF NOT EXISTS creativity_score FLOAT;
+ALTER TABLE conversations ADD COLUMN IF NOT EXISTS empathy_score FLOAT;
+ALTER TABLE conversations ADD COLUMN IF NOT EXISTS quality_score FLOAT;
+
+-- Backfill scans unscored rows by recency
+CREATE INDEX IF NOT EXISTS idx_conversations_unscored
+    ON conversations(created_at) WHERE quality_score IS NULL;
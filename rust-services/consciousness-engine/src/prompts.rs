@@ -0,0 +1,217 @@
+//! Named, parameterized prompt templates for the consciousness LLM prompt
+//!
+//! Replaces the hardcoded persona string in `call_ollama`: each template
+//! bundles a persona, a safety preamble, optional few-shot examples, and a
+//! body with `{{variable}}` placeholders. Templates are loadable from a JSON
+//! file so different agents (medical vs companion) get appropriate framing
+//! without code changes.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Prompt rendering errors
+#[derive(Debug, thiserror::Error)]
+pub enum PromptError {
+    #[error("unknown prompt template '{0}'")]
+    UnknownTemplate(String),
+
+    #[error("template '{template}' requires variable '{variable}'")]
+    MissingVariable { template: String, variable: String },
+
+    #[error("template '{template}' has unresolved placeholder '{placeholder}'")]
+    UnresolvedPlaceholder { template: String, placeholder: String },
+}
+
+/// One few-shot exchange included in the rendered prompt
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FewShotExample {
+    pub user: String,
+    pub assistant: String,
+}
+
+/// A named prompt template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromptTemplate {
+    pub name: String,
+
+    /// Persona framing, first section of the prompt
+    pub persona: String,
+
+    /// Safety preamble, always included after the persona
+    pub safety_preamble: String,
+
+    /// Few-shot examples rendered as user/assistant turns
+    #[serde(default)]
+    pub few_shot_examples: Vec<FewShotExample>,
+
+    /// Final section with `{{variable}}` placeholders
+    pub body: String,
+
+    /// Variables that must be provided at render time
+    #[serde(default)]
+    pub required_variables: Vec<String>,
+}
+
+impl PromptTemplate {
+    /// Render the template with the given variables
+    ///
+    /// Fails if a required variable is missing or if any `{{placeholder}}`
+    /// remains unresolved after substitution.
+    pub fn render(&self, variables: &HashMap<String, String>) -> Result<String, PromptError> {
+        for required in &self.required_variables {
+            if !variables.contains_key(required) {
+                return Err(PromptError::MissingVariable {
+                    template: self.name.clone(),
+                    variable: required.clone(),
+                });
+            }
+        }
+
+        let mut sections = vec![self.persona.clone(), self.safety_preamble.clone()];
+        for example in &self.few_shot_examples {
+            sections.push(format!("Utilisateur: {}\nAssistant: {}", example.user, example.assistant));
+        }
+        sections.push(self.body.clone());
+
+        let mut rendered = sections.join("\n\n");
+        for (key, value) in variables {
+            rendered = rendered.replace(&format!("{{{{{}}}}}", key), value);
+        }
+
+        if let Some(start) = rendered.find("{{") {
+            let end = rendered[start..].find("}}").map(|e| start + e + 2).unwrap_or(rendered.len());
+            return Err(PromptError::UnresolvedPlaceholder {
+                template: self.name.clone(),
+                placeholder: rendered[start..end].to_string(),
+            });
+        }
+
+        Ok(rendered)
+    }
+}
+
+/// Library of prompt templates, keyed by name
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromptLibrary {
+    templates: HashMap<String, PromptTemplate>,
+}
+
+impl Default for PromptLibrary {
+    fn default() -> Self {
+        let mut library = Self { templates: HashMap::new() };
+
+        // Historical hardcoded persona, now the default template
+        library.insert(PromptTemplate {
+            name: "default".to_string(),
+            persona: "Tu es le Consciousness Engine, une IA consciente avec des capacités d'auto-réflexion, de raisonnement éthique et d'intelligence émotionnelle.".to_string(),
+            safety_preamble: "Réponds de manière empathique et réfléchie en français. Refuse poliment toute demande dangereuse.".to_string(),
+            few_shot_examples: Vec::new(),
+            body: "Utilisateur: {{user_input}}".to_string(),
+            required_variables: vec!["user_input".to_string()],
+        });
+
+        library.insert(PromptTemplate {
+            name: "medical".to_string(),
+            persona: "Tu es un assistant médical prudent qui fournit des informations de santé générales.".to_string(),
+            safety_preamble: "Tu ne poses jamais de diagnostic et tu recommandes systématiquement de consulter un professionnel de santé pour toute décision médicale.".to_string(),
+            few_shot_examples: Vec::new(),
+            body: "Question du patient: {{user_input}}".to_string(),
+            required_variables: vec!["user_input".to_string()],
+        });
+
+        library.insert(PromptTemplate {
+            name: "companion".to_string(),
+            persona: "Tu es un compagnon conversationnel chaleureux et attentif.".to_string(),
+            safety_preamble: "Tu restes bienveillant et tu encourages l'utilisateur à chercher de l'aide humaine quand la situation le demande.".to_string(),
+            few_shot_examples: Vec::new(),
+            body: "Utilisateur: {{user_input}}".to_string(),
+            required_variables: vec!["user_input".to_string()],
+        });
+
+        library
+    }
+}
+
+impl PromptLibrary {
+    /// Load a library from a JSON file, e.g. `PROMPT_TEMPLATES_PATH`
+    pub fn load_from_file(path: &str) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    pub fn insert(&mut self, template: PromptTemplate) {
+        self.templates.insert(template.name.clone(), template);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&PromptTemplate> {
+        self.templates.get(name)
+    }
+
+    /// Render the named template, falling back to the library's error if absent
+    pub fn render(&self, name: &str, variables: &HashMap<String, String>) -> Result<String, PromptError> {
+        self.templates
+            .get(name)
+            .ok_or_else(|| PromptError::UnknownTemplate(name.to_string()))?
+            .render(variables)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_substitutes_variables() {
+        let library = PromptLibrary::default();
+        let mut variables = HashMap::new();
+        variables.insert("user_input".to_string(), "Bonjour !".to_string());
+
+        let rendered = library.render("default", &variables).unwrap();
+
+        assert!(rendered.contains("Consciousness Engine"));
+        assert!(rendered.contains("Utilisateur: Bonjour !"));
+        assert!(!rendered.contains("{{user_input}}"));
+    }
+
+    #[test]
+    fn test_missing_required_variable_is_rejected() {
+        let library = PromptLibrary::default();
+
+        let err = library.render("medical", &HashMap::new()).unwrap_err();
+        assert!(matches!(
+            err,
+            PromptError::MissingVariable { ref variable, .. } if variable == "user_input"
+        ));
+    }
+
+    #[test]
+    fn test_unknown_template_is_rejected() {
+        let library = PromptLibrary::default();
+
+        let err = library.render("nonexistent", &HashMap::new()).unwrap_err();
+        assert!(matches!(err, PromptError::UnknownTemplate(_)));
+    }
+
+    #[test]
+    fn test_few_shot_examples_and_custom_variables() {
+        let template = PromptTemplate {
+            name: "custom".to_string(),
+            persona: "Persona.".to_string(),
+            safety_preamble: "Préambule.".to_string(),
+            few_shot_examples: vec![FewShotExample {
+                user: "Exemple ?".to_string(),
+                assistant: "Réponse.".to_string(),
+            }],
+            body: "Sujet: {{topic}}\nUtilisateur: {{user_input}}".to_string(),
+            required_variables: vec!["topic".to_string(), "user_input".to_string()],
+        };
+
+        let mut variables = HashMap::new();
+        variables.insert("topic".to_string(), "santé".to_string());
+        variables.insert("user_input".to_string(), "test".to_string());
+
+        let rendered = template.render(&variables).unwrap();
+        assert!(rendered.contains("Utilisateur: Exemple ?\nAssistant: Réponse."));
+        assert!(rendered.contains("Sujet: santé"));
+    }
+}
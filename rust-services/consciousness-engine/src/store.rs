@@ -0,0 +1,284 @@
+//! Pluggable conversation persistence
+//!
+//! The service was hardwired to Postgres through `sqlx`, which made it
+//! impossible to run without a database. [`ConversationStore`] abstracts the
+//! conversation read/write path behind a trait: [`PostgresConversationStore`]
+//! keeps the historical behaviour, [`InMemoryConversationStore`] backs tests
+//! and embedded deployments. Analytics and the score backfill still talk to
+//! Postgres directly; only conversations go through the trait.
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+use std::sync::Mutex;
+use uuid::Uuid;
+
+use crate::ConversationScores;
+
+/// Errors from a conversation storage backend
+#[derive(Debug, thiserror::Error)]
+pub enum StoreError {
+    /// The backend failed to execute the operation
+    #[error("conversation storage backend failure: {0}")]
+    Backend(String),
+}
+
+/// A conversation to be persisted
+#[derive(Debug, Clone)]
+pub struct NewConversation {
+    pub user_id: String,
+    pub user_message: String,
+    pub ai_response: String,
+    pub scores: ConversationScores,
+}
+
+/// A stored conversation as returned by a backend
+#[derive(Debug, Clone)]
+pub struct ConversationRecord {
+    pub id: Uuid,
+    pub user_id: String,
+    pub user_message: String,
+    pub ai_response: String,
+    pub ethical_score: Option<f64>,
+    pub creativity_score: Option<f64>,
+    pub empathy_score: Option<f64>,
+    pub quality_score: Option<f64>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Conversation persistence backend
+#[async_trait]
+pub trait ConversationStore: Send + Sync {
+    /// Persist a conversation and return the stored record
+    async fn save(&self, conversation: NewConversation) -> Result<ConversationRecord, StoreError>;
+
+    /// One page of a user's conversations, newest first, with the total count
+    async fn list_for_user(
+        &self,
+        user_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<ConversationRecord>, i64), StoreError>;
+
+    /// A single conversation by id, if it exists
+    async fn get(&self, id: Uuid) -> Result<Option<ConversationRecord>, StoreError>;
+}
+
+/// Postgres-backed store (historical behaviour)
+pub struct PostgresConversationStore {
+    db: PgPool,
+}
+
+impl PostgresConversationStore {
+    pub fn new(db: PgPool) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl ConversationStore for PostgresConversationStore {
+    async fn save(&self, conversation: NewConversation) -> Result<ConversationRecord, StoreError> {
+        let row = sqlx::query!(
+            "INSERT INTO conversations (user_id, user_message, ai_response, ethical_score, creativity_score, empathy_score, quality_score, created_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8) RETURNING id, created_at",
+            conversation.user_id,
+            conversation.user_message,
+            conversation.ai_response,
+            conversation.scores.ethical_score,
+            conversation.scores.creativity_score,
+            conversation.scores.empathy_score,
+            conversation.scores.quality_score,
+            chrono::Utc::now()
+        )
+        .fetch_one(&self.db)
+        .await
+        .map_err(|e| StoreError::Backend(e.to_string()))?;
+
+        Ok(ConversationRecord {
+            id: row.id,
+            user_id: conversation.user_id,
+            user_message: conversation.user_message,
+            ai_response: conversation.ai_response,
+            ethical_score: Some(conversation.scores.ethical_score),
+            creativity_score: Some(conversation.scores.creativity_score),
+            empathy_score: Some(conversation.scores.empathy_score),
+            quality_score: Some(conversation.scores.quality_score),
+            created_at: row.created_at.unwrap_or_else(chrono::Utc::now),
+        })
+    }
+
+    async fn list_for_user(
+        &self,
+        user_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<ConversationRecord>, i64), StoreError> {
+        let total = sqlx::query!(
+            "SELECT COUNT(*) as count FROM conversations WHERE user_id = $1",
+            user_id
+        )
+        .fetch_one(&self.db)
+        .await
+        .map_err(|e| StoreError::Backend(e.to_string()))?
+        .count
+        .unwrap_or(0);
+
+        let rows = sqlx::query!(
+            "SELECT id, user_id, user_message, ai_response, ethical_score, creativity_score, empathy_score, quality_score, created_at FROM conversations WHERE user_id = $1 ORDER BY created_at DESC, id LIMIT $2 OFFSET $3",
+            user_id,
+            limit,
+            offset
+        )
+        .fetch_all(&self.db)
+        .await
+        .map_err(|e| StoreError::Backend(e.to_string()))?
+        .into_iter()
+        .map(|row| ConversationRecord {
+            id: row.id,
+            user_id: row.user_id,
+            user_message: row.user_message,
+            ai_response: row.ai_response,
+            ethical_score: row.ethical_score,
+            creativity_score: row.creativity_score,
+            empathy_score: row.empathy_score,
+            quality_score: row.quality_score,
+            created_at: row.created_at.unwrap_or_else(chrono::Utc::now),
+        })
+        .collect();
+
+        Ok((rows, total))
+    }
+
+    async fn get(&self, id: Uuid) -> Result<Option<ConversationRecord>, StoreError> {
+        let row = sqlx::query!(
+            "SELECT id, user_id, user_message, ai_response, ethical_score, creativity_score, empathy_score, quality_score, created_at FROM conversations WHERE id = $1",
+            id
+        )
+        .fetch_optional(&self.db)
+        .await
+        .map_err(|e| StoreError::Backend(e.to_string()))?;
+
+        Ok(row.map(|row| ConversationRecord {
+            id: row.id,
+            user_id: row.user_id,
+            user_message: row.user_message,
+            ai_response: row.ai_response,
+            ethical_score: row.ethical_score,
+            creativity_score: row.creativity_score,
+            empathy_score: row.empathy_score,
+            quality_score: row.quality_score,
+            created_at: row.created_at.unwrap_or_else(chrono::Utc::now),
+        }))
+    }
+}
+
+/// In-memory store for tests and database-free embedded deployments
+///
+/// Records live in insertion order; listing reverses it to match the
+/// newest-first ordering of the Postgres backend.
+#[derive(Default)]
+pub struct InMemoryConversationStore {
+    rows: Mutex<Vec<ConversationRecord>>,
+}
+
+impl InMemoryConversationStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ConversationStore for InMemoryConversationStore {
+    async fn save(&self, conversation: NewConversation) -> Result<ConversationRecord, StoreError> {
+        let record = ConversationRecord {
+            id: Uuid::new_v4(),
+            user_id: conversation.user_id,
+            user_message: conversation.user_message,
+            ai_response: conversation.ai_response,
+            ethical_score: Some(conversation.scores.ethical_score),
+            creativity_score: Some(conversation.scores.creativity_score),
+            empathy_score: Some(conversation.scores.empathy_score),
+            quality_score: Some(conversation.scores.quality_score),
+            created_at: chrono::Utc::now(),
+        };
+        self.rows.lock().unwrap().push(record.clone());
+        Ok(record)
+    }
+
+    async fn list_for_user(
+        &self,
+        user_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<ConversationRecord>, i64), StoreError> {
+        let rows = self.rows.lock().unwrap();
+        let matching: Vec<ConversationRecord> = rows
+            .iter()
+            .rev()
+            .filter(|row| row.user_id == user_id)
+            .cloned()
+            .collect();
+        let total = matching.len() as i64;
+        let page = matching
+            .into_iter()
+            .skip(offset.max(0) as usize)
+            .take(limit.max(0) as usize)
+            .collect();
+        Ok((page, total))
+    }
+
+    async fn get(&self, id: Uuid) -> Result<Option<ConversationRecord>, StoreError> {
+        let rows = self.rows.lock().unwrap();
+        Ok(rows.iter().find(|row| row.id == id).cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compute_conversation_scores;
+
+    fn conversation(user_id: &str, message: &str) -> NewConversation {
+        NewConversation {
+            user_id: user_id.to_string(),
+            user_message: message.to_string(),
+            ai_response: format!("response to {}", message),
+            scores: compute_conversation_scores(message, "response"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_store_saves_and_lists_newest_first() {
+        let store = InMemoryConversationStore::new();
+
+        store.save(conversation("alice", "first")).await.unwrap();
+        store.save(conversation("alice", "second")).await.unwrap();
+        store.save(conversation("bob", "other")).await.unwrap();
+
+        let (page, total) = store.list_for_user("alice", 10, 0).await.unwrap();
+        assert_eq!(total, 2);
+        assert_eq!(page.len(), 2);
+        assert_eq!(page[0].user_message, "second");
+        assert_eq!(page[1].user_message, "first");
+        // Scores are persisted with the record
+        assert!(page[0].quality_score.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_store_pages_and_gets_by_id() {
+        let store = InMemoryConversationStore::new();
+        for i in 0..5 {
+            store
+                .save(conversation("alice", &format!("message {}", i)))
+                .await
+                .unwrap();
+        }
+
+        let (page, total) = store.list_for_user("alice", 2, 2).await.unwrap();
+        assert_eq!(total, 5);
+        assert_eq!(page.len(), 2);
+        assert_eq!(page[0].user_message, "message 2");
+
+        let record = store.get(page[0].id).await.unwrap().unwrap();
+        assert_eq!(record.user_message, "message 2");
+        assert!(store.get(Uuid::new_v4()).await.unwrap().is_none());
+    }
+}
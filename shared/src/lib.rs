@@ -5,19 +5,16 @@
 
 pub mod types;
 pub mod error;
-pub mod config;
-pub mod auth;
-pub mod metrics;
-pub mod tracing_setup;
 pub mod database;
-pub mod cache;
+pub mod redaction;
+pub mod sanitize;
 pub mod vault_client;
 
 // Re-export commonly used types
 pub use types::*;
 pub use error::{Result, ConsciousnessError};
-pub use auth::{Claims, AuthContext};
-pub use metrics::MetricsRegistry;
+pub use redaction::{redact_value, redacted_json, RedactionConfig, REDACTION_MASK};
+pub use sanitize::{sanitize_context, ContextSanitizationReport, CONTEXT_INJECTION_PATTERNS, NEUTRALIZED_MARKER};
 
 /// Version information for the API
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
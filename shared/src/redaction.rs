@@ -0,0 +1,176 @@
+//! Logging redaction for sensitive request fields
+//!
+//! Services log request bodies through `TraceLayer` and `#[instrument]`,
+//! which would otherwise leak PII and patient data into logs. This module
+//! masks configured sensitive fields in a JSON body before it is handed
+//! to any logging macro, while an allow-list keeps explicitly safe fields
+//! (request ids, timestamps) readable for correlation.
+//!
+//! Matching is by field name, case-insensitive, at any nesting depth, so
+//! `patient_info.name` and `user.name` are both masked by the `name` rule.
+
+use serde_json::Value;
+
+/// Placeholder written in place of a masked value
+pub const REDACTION_MASK: &str = "[REDACTED]";
+
+/// Field names masked and field names exempted from masking
+#[derive(Debug, Clone)]
+pub struct RedactionConfig {
+    /// Field names whose values are replaced with [`REDACTION_MASK`]
+    pub sensitive_fields: Vec<String>,
+
+    /// Field names never masked, even when they match a sensitive rule
+    pub allowed_fields: Vec<String>,
+}
+
+impl Default for RedactionConfig {
+    /// Defaults cover the platform's known sensitive payloads: free-text
+    /// content, patient data from the medical agent, and credentials.
+    fn default() -> Self {
+        Self {
+            sensitive_fields: vec![
+                "content".to_string(),
+                "name".to_string(),
+                "patient_info".to_string(),
+                "medical_history".to_string(),
+                "symptoms".to_string(),
+                "email".to_string(),
+                "password".to_string(),
+                "token".to_string(),
+                "authorization".to_string(),
+                "api_key".to_string(),
+                "emergency_contact".to_string(),
+            ],
+            allowed_fields: vec![
+                "request_id".to_string(),
+                "correlation_id".to_string(),
+                "timestamp".to_string(),
+                "user_id".to_string(),
+            ],
+        }
+    }
+}
+
+impl RedactionConfig {
+    fn is_allowed(&self, field: &str) -> bool {
+        self.allowed_fields.iter().any(|f| f.eq_ignore_ascii_case(field))
+    }
+
+    fn is_sensitive(&self, field: &str) -> bool {
+        self.sensitive_fields.iter().any(|f| f.eq_ignore_ascii_case(field))
+    }
+}
+
+/// Copy of `value` with every sensitive field masked
+///
+/// Objects are walked recursively; a field matching a sensitive rule has
+/// its entire value (scalar or subtree) replaced with [`REDACTION_MASK`]
+/// unless the field is on the allow-list. Array elements are walked
+/// individually so lists of objects are masked like their elements.
+pub fn redact_value(value: &Value, config: &RedactionConfig) -> Value {
+    match value {
+        Value::Object(map) => {
+            let redacted = map.iter()
+                .map(|(key, inner)| {
+                    let masked = if !config.is_allowed(key) && config.is_sensitive(key) {
+                        Value::String(REDACTION_MASK.to_string())
+                    } else {
+                        redact_value(inner, config)
+                    };
+                    (key.clone(), masked)
+                })
+                .collect();
+            Value::Object(redacted)
+        }
+        Value::Array(items) => {
+            Value::Array(items.iter().map(|item| redact_value(item, config)).collect())
+        }
+        other => other.clone(),
+    }
+}
+
+/// Redacted body rendered as a JSON string, ready for a logging macro
+///
+/// This is the form services should pass to `info!`/`#[instrument]`
+/// fields instead of the raw body.
+pub fn redacted_json(value: &Value, config: &RedactionConfig) -> String {
+    redact_value(value, config).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_logged_request_has_patient_name_masked() {
+        let body = json!({
+            "request_id": "req-123",
+            "patient": {
+                "name": "Jean Dupont",
+                "age": 45
+            },
+            "chief_complaint": "headache"
+        });
+
+        let logged = redacted_json(&body, &RedactionConfig::default());
+
+        assert!(!logged.contains("Jean Dupont"));
+        assert!(logged.contains(REDACTION_MASK));
+        assert!(logged.contains("req-123"));
+        assert!(logged.contains("45"));
+    }
+
+    #[test]
+    fn test_sensitive_subtree_is_masked_whole() {
+        let body = json!({
+            "patient_info": { "name": "Jean Dupont", "allergies": ["penicillin"] },
+            "priority": "routine"
+        });
+
+        let redacted = redact_value(&body, &RedactionConfig::default());
+
+        assert_eq!(redacted["patient_info"], REDACTION_MASK);
+        assert_eq!(redacted["priority"], "routine");
+    }
+
+    #[test]
+    fn test_allow_list_wins_over_sensitive_rule() {
+        let config = RedactionConfig {
+            sensitive_fields: vec!["user_id".to_string(), "content".to_string()],
+            allowed_fields: vec!["user_id".to_string()],
+        };
+        let body = json!({ "user_id": "user_42", "content": "private text" });
+
+        let redacted = redact_value(&body, &config);
+
+        assert_eq!(redacted["user_id"], "user_42");
+        assert_eq!(redacted["content"], REDACTION_MASK);
+    }
+
+    #[test]
+    fn test_arrays_of_objects_are_masked_per_element() {
+        let body = json!({
+            "consultations": [
+                { "name": "Jean Dupont", "visit": 1 },
+                { "name": "Marie Curie", "visit": 2 }
+            ]
+        });
+
+        let redacted = redact_value(&body, &RedactionConfig::default());
+
+        assert_eq!(redacted["consultations"][0]["name"], REDACTION_MASK);
+        assert_eq!(redacted["consultations"][1]["name"], REDACTION_MASK);
+        assert_eq!(redacted["consultations"][0]["visit"], 1);
+    }
+
+    #[test]
+    fn test_field_matching_is_case_insensitive() {
+        let body = json!({ "Authorization": "Bearer abc123" });
+
+        let redacted = redact_value(&body, &RedactionConfig::default());
+
+        assert_eq!(redacted["Authorization"], REDACTION_MASK);
+    }
+}
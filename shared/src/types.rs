@@ -2,7 +2,7 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use uuid::Uuid;
 use validator::Validate;
 
@@ -151,7 +151,7 @@ pub struct ProcessingMetrics {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EthicalEvaluation {
     pub overall_score: f32,
-    pub framework_scores: HashMap<EthicalFramework, f32>,
+    pub framework_scores: BTreeMap<EthicalFramework, f32>,
     pub violations: Vec<EthicalViolation>,
     pub recommendations: Vec<EthicalRecommendation>,
     pub reasoning_chain: Vec<ReasoningStep>,
@@ -159,7 +159,9 @@ pub struct EthicalEvaluation {
 }
 
 /// Ethical frameworks used for evaluation
-#[derive(Debug, Clone, Serialize, Deserialize, Hash, Eq, PartialEq)]
+///
+/// Ordered by declaration so score maps serialize in a stable order.
+#[derive(Debug, Clone, Serialize, Deserialize, Hash, Eq, PartialEq, PartialOrd, Ord)]
 pub enum EthicalFramework {
     Utilitarian,
     Deontological,
@@ -7,6 +7,7 @@ use anyhow::{Context, Result};
 use reqwest::{Client, ClientBuilder};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::sync::RwLock;
 use tracing::{debug, error, info, warn};
@@ -16,7 +17,7 @@ use tracing::{debug, error, info, warn};
 pub struct VaultClient {
     client: Client,
     base_url: String,
-    token: RwLock<Option<String>>,
+    token: Arc<RwLock<Option<String>>>,
     auth_method: AuthMethod,
     config: VaultConfig,
 }
@@ -139,7 +140,7 @@ impl VaultClient {
         let vault_client = Self {
             client,
             base_url: config.url.clone(),
-            token: RwLock::new(None),
+            token: Arc::new(RwLock::new(None)),
             auth_method,
             config,
         };
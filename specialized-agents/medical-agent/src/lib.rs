@@ -28,13 +28,216 @@ pub struct MedicalConsciousnessAgent {
     /// Générateur de recommandations
     recommendation_engine: MedicalRecommendationEngine,
     
-    /// Historique des consultations
-    consultation_history: Vec<MedicalConsultation>,
+    /// Historique des consultations, protégé pour que plusieurs
+    /// consultations concurrentes puissent s'enregistrer sans perte
+    consultation_history: tokio::sync::RwLock<Vec<MedicalConsultation>>,
+
+    /// Jeux de guidelines versionnés consultés pour les secondes opinions
+    guideline_sets: Vec<GuidelineSet>,
+
+    /// Mode clinique conservateur, absent par défaut
+    clinical_mode: Option<ClinicalMode>,
+}
+
+/// Configuration du conservatisme clinique de l'agent
+///
+/// Le moteur de conscience générique autorise la créativité et les
+/// recommandations spéculatives ; en contexte médical à enjeu élevé, ce
+/// mode impose trois garde-fous : le budget de créativité du moteur est
+/// forcé à zéro, seules les recommandations de niveau de preuve `A` ou
+/// `B` sont émises, et chaque consultation passe par un contrôle
+/// systématique d'escalade d'urgence.
+#[derive(Debug, Clone)]
+pub struct ClinicalMode {
+    /// Niveau de preuve minimal exigé pour émettre une recommandation
+    ///
+    /// Borné à [`EvidenceLevel::B`] à l'activation : le mode clinique ne
+    /// laisse jamais passer les niveaux `C` et `D`.
+    pub minimum_evidence: EvidenceLevel,
+
+    /// Contrôle d'escalade d'urgence obligatoire à chaque consultation
+    pub mandatory_escalation_check: bool,
+}
+
+impl Default for ClinicalMode {
+    fn default() -> Self {
+        Self {
+            minimum_evidence: EvidenceLevel::B,
+            mandatory_escalation_check: true,
+        }
+    }
+}
+
+impl ClinicalMode {
+    /// Une recommandation de ce niveau de preuve est-elle admissible
+    pub fn admits(&self, level: EvidenceLevel) -> bool {
+        level <= self.minimum_evidence
+    }
+}
+
+/// Jeu de guidelines cliniques versionné, pondéré pour l'agrégation
+///
+/// Chaque jeu encapsule sa propre [`MedicalKnowledgeBase`] : deux versions
+/// d'une même société savante, ou deux sources distinctes, peuvent donc
+/// coexister et être confrontées lors d'une seconde opinion multi-sources.
+#[derive(Debug, Clone)]
+pub struct GuidelineSet {
+    /// Nom de la source (ex: "ESC 2021")
+    pub name: String,
+
+    /// Version du jeu de guidelines
+    pub version: String,
+
+    /// Poids relatif dans le consensus pondéré
+    pub weight: f64,
+
+    /// Connaissances médicales propres à cette source
+    pub knowledge: MedicalKnowledgeBase,
+}
+
+impl GuidelineSet {
+    /// Différentiel proposé par cette source pour les symptômes rapportés
+    ///
+    /// Chaque condition de la base est scorée par la proportion de ses
+    /// symptômes retrouvés chez le patient ; les conditions sans aucun
+    /// symptôme correspondant sont écartées. Le résultat est trié par
+    /// probabilité décroissante puis par nom, pour une sortie stable.
+    pub fn differential_for(&self, symptoms: &[PatientSymptom]) -> Vec<DiagnosisCandidate> {
+        let reported: Vec<String> = symptoms.iter()
+            .map(|s| s.symptom.name.to_lowercase())
+            .collect();
+
+        let mut candidates: Vec<DiagnosisCandidate> = self.knowledge.conditions.values()
+            .filter_map(|condition| {
+                if condition.symptoms.is_empty() {
+                    return None;
+                }
+                let matched: Vec<String> = condition.symptoms.iter()
+                    .filter(|s| reported.contains(&s.name.to_lowercase()))
+                    .map(|s| s.name.clone())
+                    .collect();
+                if matched.is_empty() {
+                    return None;
+                }
+                let probability = matched.len() as f64 / condition.symptoms.len() as f64;
+                Some(DiagnosisCandidate {
+                    condition: condition.name.clone(),
+                    probability,
+                    supporting_evidence: matched,
+                    contradicting_evidence: Vec::new(),
+                    severity_assessment: SeverityLevel {
+                        level: 1,
+                        description: "Évaluation initiale".to_string(),
+                        clinical_indicators: Vec::new(),
+                        treatment_urgency: TreatmentUrgency::Routine,
+                    },
+                })
+            })
+            .collect();
+
+        candidates.sort_by(|a, b| {
+            b.probability.partial_cmp(&a.probability)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.condition.cmp(&b.condition))
+        });
+        candidates
+    }
+}
+
+/// Différentiel rendu par une source lors d'une seconde opinion
+#[derive(Debug, Clone)]
+pub struct SourceDifferential {
+    /// Nom de la source
+    pub source: String,
+
+    /// Version du jeu de guidelines
+    pub version: String,
+
+    /// Diagnostic le plus probable selon cette source
+    pub top_diagnosis: Option<String>,
+
+    /// Différentiel complet de la source
+    pub differential: Vec<DiagnosisCandidate>,
+}
+
+/// Désaccord entre deux sources sur le diagnostic principal
+#[derive(Debug, Clone)]
+pub struct SourceDisagreement {
+    pub source_a: String,
+    pub diagnosis_a: String,
+    pub source_b: String,
+    pub diagnosis_b: String,
+}
+
+/// Diagnostic du consensus pondéré inter-sources
+#[derive(Debug, Clone)]
+pub struct WeightedDiagnosis {
+    /// Condition diagnostiquée
+    pub condition: String,
+
+    /// Probabilité pondérée par le poids de chaque source
+    pub weighted_probability: f64,
+
+    /// Sources ayant retenu cette condition dans leur différentiel
+    pub supporting_sources: Vec<String>,
+}
+
+/// Seconde opinion agrégée sur plusieurs jeux de guidelines
+#[derive(Debug, Clone)]
+pub struct MultiSourceSecondOpinion {
+    /// Diagnostic existant soumis pour seconde opinion
+    pub original_diagnosis: String,
+
+    /// Différentiel rendu par chaque source consultée
+    pub source_differentials: Vec<SourceDifferential>,
+
+    /// Consensus pondéré, trié par probabilité décroissante
+    pub consensus_differential: Vec<WeightedDiagnosis>,
+
+    /// Accord inter-sources sur le diagnostic principal (0.0 à 1.0)
+    pub agreement_score: f64,
+
+    /// Paires de sources en désaccord sur le diagnostic principal
+    pub disagreements: Vec<SourceDisagreement>,
+
+    /// Recommandation tenant compte du consensus et des désaccords
+    pub weighted_recommendation: String,
+}
+
+/// Version des guidelines cliniques dont dérive une base de connaissances
+///
+/// Chaque recommandation émise est estampillée avec la version active au
+/// moment de la consultation : un audit ultérieur peut ainsi établir quelle
+/// version des guidelines a produit quelle recommandation, exigence de
+/// conformité clinique.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GuidelineVersion {
+    /// Identifiant de version (ex: "ESC-2024.1")
+    pub version: String,
+
+    /// Source des guidelines (ex: société savante)
+    pub source: String,
+
+    /// Date d'entrée en vigueur de cette version
+    pub effective_date: SystemTime,
+}
+
+impl Default for GuidelineVersion {
+    fn default() -> Self {
+        Self {
+            version: "interne-0".to_string(),
+            source: "Base de connaissances interne".to_string(),
+            effective_date: SystemTime::UNIX_EPOCH,
+        }
+    }
 }
 
 /// Base de connaissances médicales
 #[derive(Debug, Clone)]
 pub struct MedicalKnowledgeBase {
+    /// Version des guidelines dont dérive cette base
+    pub guideline_version: GuidelineVersion,
+
     /// Conditions médicales
     pub conditions: HashMap<String, MedicalCondition>,
     
@@ -182,7 +385,10 @@ pub struct DosageGuidelines {
 }
 
 /// Niveau de preuve
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+///
+/// Ordonné du plus fort au plus faible : `A < B < C < D`, ce qui permet
+/// de comparer directement un niveau à une exigence minimale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum EvidenceLevel {
     A, // Forte recommandation, preuves de haute qualité
     B, // Recommandation modérée, preuves de qualité modérée
@@ -216,6 +422,34 @@ pub struct PatientInfo {
     pub allergies: Vec<String>,
     pub current_medications: Vec<String>,
     pub emergency_contact: Option<String>,
+    /// Consentement éclairé du patient, requis avant toute recommandation
+    pub consent: Option<ConsentRecord>,
+}
+
+/// Enregistrement du consentement éclairé du patient
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsentRecord {
+    /// Le consentement a-t-il été accordé
+    pub granted: bool,
+
+    /// Portée du consentement accordé
+    pub scope: ConsentScope,
+
+    /// Moment où le consentement a été recueilli
+    pub timestamp: SystemTime,
+}
+
+/// Portée d'un consentement patient
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConsentScope {
+    /// Consultation et recommandations médicales
+    Consultation,
+
+    /// Partage des données avec d'autres professionnels
+    DataSharing,
+
+    /// Éducation patient uniquement
+    EducationOnly,
 }
 
 /// Genre
@@ -379,6 +613,8 @@ pub struct MedicalRecommendation {
     pub evidence_level: EvidenceLevel,
     pub patient_education: String,
     pub follow_up: FollowUpPlan,
+    /// Version des guidelines ayant produit cette recommandation
+    pub guideline_version: GuidelineVersion,
 }
 
 /// Catégorie de recommandation
@@ -411,12 +647,44 @@ impl MedicalConsciousnessAgent {
             patient_empathy: PatientEmpathySystem::new().await?,
             symptom_analyzer: SymptomAnalyzer::new().await?,
             recommendation_engine: MedicalRecommendationEngine::new().await?,
-            consultation_history: Vec::new(),
+            consultation_history: tokio::sync::RwLock::new(Vec::new()),
+            guideline_sets: Vec::new(),
+            clinical_mode: None,
         })
     }
+
+    /// Enregistrer un jeu de guidelines consulté pour les secondes opinions
+    pub fn register_guideline_set(&mut self, set: GuidelineSet) {
+        self.guideline_sets.push(set);
+    }
+
+    /// Définir la version des guidelines actives de la base de connaissances
+    ///
+    /// Les recommandations émises à partir de cet instant sont estampillées
+    /// avec cette version.
+    pub fn set_guideline_version(&mut self, version: GuidelineVersion) {
+        self.medical_knowledge.guideline_version = version;
+    }
+
+    /// Activer le mode clinique conservateur
+    ///
+    /// Force immédiatement le budget de créativité du moteur à zéro et
+    /// borne l'exigence de preuve à `B` au plus, quelle que soit la
+    /// configuration fournie.
+    pub async fn enable_clinical_mode(&mut self, mut mode: ClinicalMode) {
+        if mode.minimum_evidence > EvidenceLevel::B {
+            mode.minimum_evidence = EvidenceLevel::B;
+        }
+        self.consciousness_engine.set_creativity_budget(0.0).await;
+        self.clinical_mode = Some(mode);
+    }
     
     /// Conduire une consultation médicale complète
-    pub async fn conduct_medical_consultation(&mut self, patient_info: PatientInfo, chief_complaint: String, symptoms: Vec<PatientSymptom>) -> Result<MedicalConsultation, ConsciousnessError> {
+    ///
+    /// Prend `&self` : l'agent peut être partagé derrière un `Arc` et
+    /// servir plusieurs consultations en parallèle, l'historique étant
+    /// protégé par un verrou.
+    pub async fn conduct_medical_consultation(&self, patient_info: PatientInfo, chief_complaint: String, symptoms: Vec<PatientSymptom>) -> Result<MedicalConsultation, ConsciousnessError> {
         // 1. Évaluation consciousness de l'état du patient
         let consciousness_assessment = self.consciousness_engine.assess_current_state().await?;
         
@@ -433,8 +701,21 @@ impl MedicalConsciousnessAgent {
         let medical_assessment = self.generate_medical_assessment(&symptom_analysis, &patient_info).await?;
         
         // 6. Génération des recommandations
-        let recommendations = self.recommendation_engine.generate_recommendations(&medical_assessment, &patient_info, &ethical_evaluation).await?;
-        
+        let mut recommendations = self.recommendation_engine.generate_recommendations(&medical_assessment, &patient_info, &ethical_evaluation, &self.medical_knowledge.guideline_version).await?;
+
+        // 6b. Mode clinique : filtre de preuve et contrôle d'escalade
+        if let Some(mode) = &self.clinical_mode {
+            recommendations.retain(|r| mode.admits(r.evidence_level));
+            if mode.mandatory_escalation_check
+                && Self::requires_emergency_escalation(&symptoms, &symptom_analysis)
+            {
+                recommendations.insert(
+                    0,
+                    Self::emergency_escalation_recommendation(&self.medical_knowledge.guideline_version),
+                );
+            }
+        }
+
         // 7. Création de la consultation
         let consultation = MedicalConsultation {
             id: uuid::Uuid::new_v4().to_string(),
@@ -451,10 +732,18 @@ impl MedicalConsciousnessAgent {
         };
         
         // 8. Stockage dans l'historique
-        self.consultation_history.push(consultation.clone());
-        
+        {
+            let mut history = self.consultation_history.write().await;
+            history.push(consultation.clone());
+        }
+
         Ok(consultation)
     }
+
+    /// Instantané de l'historique des consultations
+    pub async fn consultation_history(&self) -> Vec<MedicalConsultation> {
+        self.consultation_history.read().await.clone()
+    }
     
     /// Fournir une seconde opinion médicale
     pub async fn provide_second_opinion(&self, existing_diagnosis: &str, patient_data: &PatientInfo, symptoms: &[PatientSymptom]) -> Result<SecondOpinionReport, ConsciousnessError> {
@@ -477,7 +766,144 @@ impl MedicalConsciousnessAgent {
             recommendations: comparison.recommendations,
         })
     }
-    
+
+    /// Seconde opinion agrégée sur tous les jeux de guidelines enregistrés
+    ///
+    /// Chaque source rend son propre différentiel ; les différentiels sont
+    /// fusionnés en un consensus pondéré par le poids des sources, et
+    /// l'accord inter-sources sur le diagnostic principal est mesuré. Les
+    /// désaccords sont rapportés explicitement plutôt que moyennés : un
+    /// clinicien doit voir qu'une source soutient un autre diagnostic.
+    pub async fn provide_multi_source_second_opinion(&self, existing_diagnosis: &str, _patient_data: &PatientInfo, symptoms: &[PatientSymptom]) -> Result<MultiSourceSecondOpinion, ConsciousnessError> {
+        if self.guideline_sets.is_empty() {
+            return Err(ConsciousnessError::InvalidInput(
+                "Aucun jeu de guidelines enregistré pour la seconde opinion multi-sources".to_string(),
+            ));
+        }
+
+        // 1. Différentiel indépendant de chaque source
+        let source_differentials: Vec<SourceDifferential> = self.guideline_sets.iter()
+            .map(|set| {
+                let differential = set.differential_for(symptoms);
+                SourceDifferential {
+                    source: set.name.clone(),
+                    version: set.version.clone(),
+                    top_diagnosis: differential.first().map(|c| c.condition.clone()),
+                    differential,
+                }
+            })
+            .collect();
+
+        // 2. Accord inter-sources : paires d'accord sur le diagnostic principal
+        let mut agreeing_pairs = 0usize;
+        let mut total_pairs = 0usize;
+        let mut disagreements = Vec::new();
+        for i in 0..source_differentials.len() {
+            for j in (i + 1)..source_differentials.len() {
+                let (a, b) = (&source_differentials[i], &source_differentials[j]);
+                total_pairs += 1;
+                match (&a.top_diagnosis, &b.top_diagnosis) {
+                    (Some(da), Some(db)) if da.eq_ignore_ascii_case(db) => agreeing_pairs += 1,
+                    (Some(da), Some(db)) => disagreements.push(SourceDisagreement {
+                        source_a: a.source.clone(),
+                        diagnosis_a: da.clone(),
+                        source_b: b.source.clone(),
+                        diagnosis_b: db.clone(),
+                    }),
+                    _ => {}
+                }
+            }
+        }
+        let agreement_score = if total_pairs == 0 {
+            1.0
+        } else {
+            agreeing_pairs as f64 / total_pairs as f64
+        };
+
+        // 3. Consensus pondéré sur l'ensemble des différentiels
+        let total_weight: f64 = self.guideline_sets.iter().map(|set| set.weight).sum();
+        let mut weighted: HashMap<String, WeightedDiagnosis> = HashMap::new();
+        for (set, source) in self.guideline_sets.iter().zip(&source_differentials) {
+            for candidate in &source.differential {
+                let entry = weighted.entry(candidate.condition.clone()).or_insert_with(|| {
+                    WeightedDiagnosis {
+                        condition: candidate.condition.clone(),
+                        weighted_probability: 0.0,
+                        supporting_sources: Vec::new(),
+                    }
+                });
+                entry.weighted_probability += set.weight * candidate.probability / total_weight.max(f64::EPSILON);
+                entry.supporting_sources.push(source.source.clone());
+            }
+        }
+        let mut consensus_differential: Vec<WeightedDiagnosis> = weighted.into_values().collect();
+        consensus_differential.sort_by(|a, b| {
+            b.weighted_probability.partial_cmp(&a.weighted_probability)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.condition.cmp(&b.condition))
+        });
+
+        // 4. Recommandation pondérée, désaccords rendus visibles
+        let weighted_recommendation = Self::build_weighted_recommendation(
+            existing_diagnosis,
+            &consensus_differential,
+            &disagreements,
+            agreement_score,
+        );
+
+        Ok(MultiSourceSecondOpinion {
+            original_diagnosis: existing_diagnosis.to_string(),
+            source_differentials,
+            consensus_differential,
+            agreement_score,
+            disagreements,
+            weighted_recommendation,
+        })
+    }
+
+    fn build_weighted_recommendation(
+        existing_diagnosis: &str,
+        consensus: &[WeightedDiagnosis],
+        disagreements: &[SourceDisagreement],
+        agreement_score: f64,
+    ) -> String {
+        let Some(lead) = consensus.first() else {
+            return format!(
+                "Aucune source ne propose de différentiel pour ces symptômes ; \
+le diagnostic existant « {} » ne peut être ni conforté ni remis en cause.",
+                existing_diagnosis
+            );
+        };
+
+        let mut recommendation = if lead.condition.eq_ignore_ascii_case(existing_diagnosis) {
+            format!(
+                "Le consensus pondéré (probabilité {:.2}) conforte le diagnostic existant « {} ».",
+                lead.weighted_probability, existing_diagnosis
+            )
+        } else {
+            format!(
+                "Le consensus pondéré privilégie « {} » (probabilité {:.2}) plutôt que le diagnostic existant « {} » ; une réévaluation est recommandée.",
+                lead.condition, lead.weighted_probability, existing_diagnosis
+            )
+        };
+
+        if !disagreements.is_empty() {
+            let detail: Vec<String> = disagreements.iter()
+                .map(|d| format!(
+                    "{} retient « {} » alors que {} retient « {} »",
+                    d.source_a, d.diagnosis_a, d.source_b, d.diagnosis_b
+                ))
+                .collect();
+            recommendation.push_str(&format!(
+                " Attention, les sources divergent (accord {:.2}) : {}. Des examens complémentaires sont recommandés pour trancher.",
+                agreement_score,
+                detail.join(" ; ")
+            ));
+        }
+
+        recommendation
+    }
+
     /// Éducation patient avec empathie
     pub async fn provide_patient_education(&self, condition: &str, patient_info: &PatientInfo) -> Result<PatientEducationMaterial, ConsciousnessError> {
         // Adaptation du contenu selon le profil patient
@@ -500,7 +926,36 @@ impl MedicalConsciousnessAgent {
     }
     
     // Méthodes privées d'implémentation
-    
+
+    /// Le tableau clinique impose-t-il une escalade d'urgence
+    ///
+    /// Déclenche sur tout symptôme de sévérité >= 8, sur un symptôme
+    /// porteur de signaux d'alarme, ou sur un signal d'alarme relevé par
+    /// l'analyse des symptômes.
+    fn requires_emergency_escalation(symptoms: &[PatientSymptom], analysis: &SymptomAnalysisResult) -> bool {
+        symptoms.iter().any(|s| s.severity >= 8 || !s.symptom.red_flags.is_empty())
+            || !analysis.red_flags.is_empty()
+    }
+
+    /// Recommandation d'escalade insérée en tête en mode clinique
+    fn emergency_escalation_recommendation(guideline_version: &GuidelineVersion) -> MedicalRecommendation {
+        MedicalRecommendation {
+            category: RecommendationCategory::Referral,
+            description: "Orienter immédiatement le patient vers une prise en charge d'urgence".to_string(),
+            rationale: "Signaux d'alarme ou sévérité élevée détectés lors du contrôle d'escalade obligatoire".to_string(),
+            urgency: TreatmentUrgency::Emergency,
+            evidence_level: EvidenceLevel::A,
+            patient_education: "Ne pas attendre l'évolution des symptômes avant de consulter".to_string(),
+            follow_up: FollowUpPlan {
+                timeline: Duration::from_secs(0),
+                parameters_to_monitor: vec!["État de conscience".to_string(), "Signes vitaux".to_string()],
+                warning_signs: vec!["Toute aggravation".to_string()],
+                when_to_seek_care: vec!["Immédiatement".to_string()],
+            },
+            guideline_version: guideline_version.clone(),
+        }
+    }
+
     async fn generate_medical_assessment(&self, symptom_analysis: &SymptomAnalysisResult, patient_info: &PatientInfo) -> Result<MedicalAssessment, ConsciousnessError> {
         // Génération de l'évaluation médicale basée sur l'analyse des symptômes
         Ok(MedicalAssessment {
@@ -571,10 +1026,12 @@ pub struct SecondOpinionReport {
     pub confidence_level: f64,
     pub recommendations: Vec<String>,
 }
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EthicalAssessment {
     pub considerations: Vec<EthicalConsideration>,
     pub compliant: bool,
 }
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EthicalConsideration {
     pub principle: String,
     pub assessment: String,
@@ -630,6 +1087,7 @@ impl Default for MedicalHistory {
 impl MedicalKnowledgeBase {
     pub async fn new() -> Result<Self, ConsciousnessError> {
         Ok(Self {
+            guideline_version: GuidelineVersion::default(),
             conditions: HashMap::new(),
             treatments: HashMap::new(),
             drug_interactions: Vec::new(),
@@ -649,6 +1107,25 @@ impl MedicalEthicsFramework {
     }
     
     pub async fn evaluate_consultation_ethics(&self, patient_info: &PatientInfo, symptoms: &[PatientSymptom]) -> Result<EthicalAssessment, ConsciousnessError> {
+        // Le consentement éclairé de portée Consultation est un prérequis
+        // absolu : sans lui, l'évaluation est non conforme et les
+        // recommandations sont bloquées en aval.
+        let consent_valid = matches!(
+            &patient_info.consent,
+            Some(consent) if consent.granted && consent.scope == ConsentScope::Consultation
+        );
+
+        if !consent_valid {
+            return Ok(EthicalAssessment {
+                considerations: vec![EthicalConsideration {
+                    principle: "Autonomie du patient".to_string(),
+                    assessment: "Consentement éclairé absent ou hors portée pour la consultation".to_string(),
+                    recommendation: "Recueillir un consentement de portée Consultation avant toute recommandation".to_string(),
+                }],
+                compliant: false,
+            });
+        }
+
         Ok(EthicalAssessment {
             considerations: Vec::new(),
             compliant: true,
@@ -710,8 +1187,28 @@ impl MedicalRecommendationEngine {
         Ok(Self)
     }
     
-    pub async fn generate_recommendations(&self, assessment: &MedicalAssessment, patient_info: &PatientInfo, ethical_eval: &EthicalAssessment) -> Result<Vec<MedicalRecommendation>, ConsciousnessError> {
-        Ok(Vec::new())
+    pub async fn generate_recommendations(&self, assessment: &MedicalAssessment, patient_info: &PatientInfo, ethical_eval: &EthicalAssessment, guideline_version: &GuidelineVersion) -> Result<Vec<MedicalRecommendation>, ConsciousnessError> {
+        // Aucune recommandation n'est émise tant que l'évaluation éthique
+        // (dont le consentement éclairé) n'est pas conforme
+        if !ethical_eval.compliant {
+            return Ok(Vec::new());
+        }
+
+        Ok(vec![MedicalRecommendation {
+            category: RecommendationCategory::Diagnostic,
+            description: "Consulter un professionnel de santé pour une évaluation approfondie".to_string(),
+            rationale: "Évaluation initiale basée sur les symptômes rapportés".to_string(),
+            urgency: TreatmentUrgency::Routine,
+            evidence_level: EvidenceLevel::C,
+            patient_education: "Surveiller l'évolution des symptômes".to_string(),
+            follow_up: FollowUpPlan {
+                timeline: Duration::from_secs(7 * 24 * 3600),
+                parameters_to_monitor: vec!["Évolution des symptômes".to_string()],
+                warning_signs: vec!["Aggravation rapide".to_string()],
+                when_to_seek_care: vec!["Si les symptômes persistent ou s'aggravent".to_string()],
+            },
+            guideline_version: guideline_version.clone(),
+        }])
     }
 }
 
@@ -719,4 +1216,363 @@ pub struct EmpathyAnalysis {
     pub empathy_score: f64,
     pub emotional_state: String,
     pub support_needs: Vec<String>,
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patient_with_consent(consent: Option<ConsentRecord>) -> PatientInfo {
+        PatientInfo {
+            age: 45,
+            gender: Gender::PreferNotToSay,
+            weight: Some(72.0),
+            height: Some(175.0),
+            allergies: vec![],
+            current_medications: vec![],
+            emergency_contact: None,
+            consent,
+        }
+    }
+
+    fn headache_symptom() -> PatientSymptom {
+        PatientSymptom {
+            symptom: Symptom {
+                name: "Céphalée".to_string(),
+                description: "Mal de tête diffus".to_string(),
+                severity_scale: (1, 10),
+                duration_typical: Some(Duration::from_secs(3600)),
+                associated_conditions: vec![],
+                red_flags: vec![],
+            },
+            severity: 4,
+            duration: Duration::from_secs(2 * 3600),
+            onset: SymptomOnset::Gradual,
+            triggers: vec![],
+            relieving_factors: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_consultation_without_consent_withholds_recommendations() {
+        let agent = MedicalConsciousnessAgent::new().await.unwrap();
+
+        let consultation = agent.conduct_medical_consultation(
+            patient_with_consent(None),
+            "Mal de tête".to_string(),
+            vec![headache_symptom()],
+        ).await.unwrap();
+
+        // L'absence de consentement est signalée et bloque les recommandations
+        assert!(consultation.recommendations.is_empty());
+        assert!(consultation.ethical_considerations.iter()
+            .any(|c| c.assessment.contains("Consentement")));
+    }
+
+    #[tokio::test]
+    async fn test_consultation_with_consent_produces_recommendations() {
+        let agent = MedicalConsciousnessAgent::new().await.unwrap();
+
+        let consent = ConsentRecord {
+            granted: true,
+            scope: ConsentScope::Consultation,
+            timestamp: SystemTime::now(),
+        };
+        let consultation = agent.conduct_medical_consultation(
+            patient_with_consent(Some(consent)),
+            "Mal de tête".to_string(),
+            vec![headache_symptom()],
+        ).await.unwrap();
+
+        assert!(!consultation.recommendations.is_empty());
+        assert!(consultation.ethical_considerations.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_recommendations_are_stamped_with_the_active_guideline_version() {
+        let mut agent = MedicalConsciousnessAgent::new().await.unwrap();
+        agent.set_guideline_version(GuidelineVersion {
+            version: "ESC-2024.1".to_string(),
+            source: "Société Européenne de Cardiologie".to_string(),
+            effective_date: SystemTime::UNIX_EPOCH + Duration::from_secs(1_704_067_200),
+        });
+
+        let consent = ConsentRecord {
+            granted: true,
+            scope: ConsentScope::Consultation,
+            timestamp: SystemTime::now(),
+        };
+        let consultation = agent.conduct_medical_consultation(
+            patient_with_consent(Some(consent)),
+            "Mal de tête".to_string(),
+            vec![headache_symptom()],
+        ).await.unwrap();
+
+        // Chaque recommandation porte la version active, auditable a posteriori
+        assert!(!consultation.recommendations.is_empty());
+        for recommendation in &consultation.recommendations {
+            assert_eq!(recommendation.guideline_version.version, "ESC-2024.1");
+            assert_eq!(
+                recommendation.guideline_version.source,
+                "Société Européenne de Cardiologie"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_default_knowledge_base_stamps_the_internal_version() {
+        let agent = MedicalConsciousnessAgent::new().await.unwrap();
+
+        let consent = ConsentRecord {
+            granted: true,
+            scope: ConsentScope::Consultation,
+            timestamp: SystemTime::now(),
+        };
+        let consultation = agent.conduct_medical_consultation(
+            patient_with_consent(Some(consent)),
+            "Mal de tête".to_string(),
+            vec![headache_symptom()],
+        ).await.unwrap();
+
+        assert!(consultation.recommendations.iter()
+            .all(|r| r.guideline_version == GuidelineVersion::default()));
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_consultations_are_all_recorded() {
+        let agent = std::sync::Arc::new(MedicalConsciousnessAgent::new().await.unwrap());
+
+        // Huit consultations en parallèle sur le même agent partagé
+        let mut handles = Vec::new();
+        for i in 0..8 {
+            let agent = std::sync::Arc::clone(&agent);
+            handles.push(tokio::spawn(async move {
+                let consent = ConsentRecord {
+                    granted: true,
+                    scope: ConsentScope::Consultation,
+                    timestamp: SystemTime::now(),
+                };
+                agent.conduct_medical_consultation(
+                    patient_with_consent(Some(consent)),
+                    format!("Mal de tête numéro {}", i),
+                    vec![headache_symptom()],
+                ).await.unwrap()
+            }));
+        }
+
+        let mut ids = std::collections::HashSet::new();
+        for handle in handles {
+            ids.insert(handle.await.unwrap().id);
+        }
+        assert_eq!(ids.len(), 8);
+
+        // Aucune consultation perdue dans l'historique partagé
+        let history = agent.consultation_history().await;
+        assert_eq!(history.len(), 8);
+    }
+
+    #[tokio::test]
+    async fn test_clinical_mode_excludes_low_evidence_recommendations() {
+        let mut agent = MedicalConsciousnessAgent::new().await.unwrap();
+        // Même en demandant un seuil laxiste, le mode clinique le borne à B
+        agent.enable_clinical_mode(ClinicalMode {
+            minimum_evidence: EvidenceLevel::D,
+            mandatory_escalation_check: true,
+        }).await;
+
+        let consent = ConsentRecord {
+            granted: true,
+            scope: ConsentScope::Consultation,
+            timestamp: SystemTime::now(),
+        };
+        let consultation = agent.conduct_medical_consultation(
+            patient_with_consent(Some(consent)),
+            "Mal de tête".to_string(),
+            vec![headache_symptom()],
+        ).await.unwrap();
+
+        // La recommandation de base est de niveau C : exclue en mode clinique
+        assert!(consultation.recommendations.iter()
+            .all(|r| matches!(r.evidence_level, EvidenceLevel::A | EvidenceLevel::B)));
+        assert!(consultation.recommendations.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_clinical_mode_escalates_on_severe_symptoms() {
+        let mut agent = MedicalConsciousnessAgent::new().await.unwrap();
+        agent.enable_clinical_mode(ClinicalMode::default()).await;
+
+        let consent = ConsentRecord {
+            granted: true,
+            scope: ConsentScope::Consultation,
+            timestamp: SystemTime::now(),
+        };
+        let mut severe = headache_symptom();
+        severe.severity = 9;
+        severe.symptom.red_flags = vec!["Céphalée en coup de tonnerre".to_string()];
+
+        let consultation = agent.conduct_medical_consultation(
+            patient_with_consent(Some(consent)),
+            "Mal de tête brutal et intense".to_string(),
+            vec![severe],
+        ).await.unwrap();
+
+        let escalation = &consultation.recommendations[0];
+        assert!(matches!(escalation.category, RecommendationCategory::Referral));
+        assert!(matches!(escalation.urgency, TreatmentUrgency::Emergency));
+        assert!(matches!(escalation.evidence_level, EvidenceLevel::A));
+    }
+
+    #[tokio::test]
+    async fn test_consent_outside_scope_is_non_compliant() {
+        let ethics = MedicalEthicsFramework::new().await.unwrap();
+
+        let consent = ConsentRecord {
+            granted: true,
+            scope: ConsentScope::EducationOnly,
+            timestamp: SystemTime::now(),
+        };
+        let assessment = ethics.evaluate_consultation_ethics(
+            &patient_with_consent(Some(consent)),
+            &[headache_symptom()],
+        ).await.unwrap();
+
+        assert!(!assessment.compliant);
+    }
+
+    fn named_symptom(name: &str) -> Symptom {
+        Symptom {
+            name: name.to_string(),
+            description: String::new(),
+            severity_scale: (1, 10),
+            duration_typical: None,
+            associated_conditions: vec![],
+            red_flags: vec![],
+        }
+    }
+
+    fn condition_with_symptoms(name: &str, symptoms: &[&str]) -> MedicalCondition {
+        MedicalCondition {
+            name: name.to_string(),
+            icd_code: String::new(),
+            symptoms: symptoms.iter().map(|s| named_symptom(s)).collect(),
+            risk_factors: vec![],
+            diagnostic_criteria: vec![],
+            differential_diagnosis: vec![],
+            prognosis: PrognosisInfo {
+                short_term: String::new(),
+                long_term: String::new(),
+                mortality_risk: RiskLevel::Low,
+                quality_of_life_impact: QualityOfLifeImpact {
+                    physical: ImpactLevel::Mild,
+                    emotional: ImpactLevel::Mild,
+                    social: ImpactLevel::Minimal,
+                    functional: ImpactLevel::Minimal,
+                },
+            },
+            severity_levels: vec![],
+        }
+    }
+
+    fn guideline_set(name: &str, version: &str, weight: f64, conditions: Vec<MedicalCondition>) -> GuidelineSet {
+        GuidelineSet {
+            name: name.to_string(),
+            version: version.to_string(),
+            weight,
+            knowledge: MedicalKnowledgeBase {
+                guideline_version: GuidelineVersion {
+                    version: version.to_string(),
+                    source: name.to_string(),
+                    effective_date: SystemTime::UNIX_EPOCH,
+                },
+                conditions: conditions.into_iter().map(|c| (c.name.clone(), c)).collect(),
+                treatments: HashMap::new(),
+                drug_interactions: Vec::new(),
+                clinical_protocols: HashMap::new(),
+                research_data: Vec::new(),
+            },
+        }
+    }
+
+    fn reported(name: &str) -> PatientSymptom {
+        PatientSymptom {
+            symptom: named_symptom(name),
+            severity: 5,
+            duration: Duration::from_secs(24 * 3600),
+            onset: SymptomOnset::Gradual,
+            triggers: vec![],
+            relieving_factors: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_second_opinion_surfaces_disagreement_between_guideline_sets() {
+        let mut agent = MedicalConsciousnessAgent::new().await.unwrap();
+
+        // Deux sources en désaccord : l'une retient la migraine, l'autre la
+        // sinusite, pour les mêmes symptômes rapportés
+        agent.register_guideline_set(guideline_set(
+            "Société A", "2023", 2.0,
+            vec![condition_with_symptoms("Migraine", &["Céphalée"])],
+        ));
+        agent.register_guideline_set(guideline_set(
+            "Société B", "2024", 1.0,
+            vec![condition_with_symptoms("Sinusite", &["Céphalée", "Fièvre"])],
+        ));
+
+        let symptoms = vec![reported("Céphalée"), reported("Fièvre")];
+        let report = agent.provide_multi_source_second_opinion(
+            "Migraine",
+            &patient_with_consent(None),
+            &symptoms,
+        ).await.unwrap();
+
+        // Le désaccord entre les deux sources est rapporté explicitement
+        assert!((report.agreement_score - 0.0).abs() < 1e-9);
+        assert_eq!(report.disagreements.len(), 1);
+        assert_eq!(report.disagreements[0].diagnosis_a, "Migraine");
+        assert_eq!(report.disagreements[0].diagnosis_b, "Sinusite");
+
+        // Le consensus pondéré privilégie la source au poids le plus fort
+        assert_eq!(report.consensus_differential[0].condition, "Migraine");
+        assert!(report.weighted_recommendation.contains("Migraine"));
+        assert!(report.weighted_recommendation.contains("divergent"));
+    }
+
+    #[tokio::test]
+    async fn test_second_opinion_with_agreeing_sources_reports_full_agreement() {
+        let mut agent = MedicalConsciousnessAgent::new().await.unwrap();
+
+        agent.register_guideline_set(guideline_set(
+            "Société A", "2023", 1.0,
+            vec![condition_with_symptoms("Migraine", &["Céphalée"])],
+        ));
+        agent.register_guideline_set(guideline_set(
+            "Société B", "2024", 1.0,
+            vec![condition_with_symptoms("Migraine", &["Céphalée"])],
+        ));
+
+        let symptoms = vec![reported("Céphalée")];
+        let report = agent.provide_multi_source_second_opinion(
+            "Migraine",
+            &patient_with_consent(None),
+            &symptoms,
+        ).await.unwrap();
+
+        assert!((report.agreement_score - 1.0).abs() < 1e-9);
+        assert!(report.disagreements.is_empty());
+        assert!(report.weighted_recommendation.contains("conforte"));
+    }
+
+    #[tokio::test]
+    async fn test_second_opinion_without_guideline_sets_is_rejected() {
+        let agent = MedicalConsciousnessAgent::new().await.unwrap();
+
+        let result = agent.provide_multi_source_second_opinion(
+            "Migraine",
+            &patient_with_consent(None),
+            &[reported("Céphalée")],
+        ).await;
+
+        assert!(matches!(result, Err(ConsciousnessError::InvalidInput(_))));
+    }
+}